@@ -1,7 +1,9 @@
 use serde::Serialize;
 use thiserror::Error;
 
-use aihack_ai_contract::{CommandIntent, Direction, EntityKind, ItemKind, RunState, SnapshotHash};
+use aihack_ai_contract::{
+    CommandIntent, Direction, EntityKind, HungerState, ItemKind, RunState, SnapshotHash,
+};
 use aihack_runtime::{save::ReplayLineV1, GameClient};
 
 /// Headless runner가 한 accepted turn을 만들기 위해 시도할 command 후보를 제공한다.
@@ -95,7 +97,8 @@ pub fn run_replay_to_turn<C: GameClient + ?Sized>(
     })
 }
 
-/// 목표 absolute turn까지 진행하며, 한 turn에서 최대 16개 후보만 시도한다.
+/// 목표 absolute turn까지 진행하며, 한 행동에 최대 16개 후보만 시도한다.
+/// 먹기처럼 여러 턴에 걸친 명령은 replay line 하나로 남고 목표 turn을 넘을 수 있다.
 pub fn run_to_turn<C: GameClient + ?Sized>(
     session: &mut C,
     target_turn: u64,
@@ -123,6 +126,7 @@ pub fn run_to_turn_with_trace<C: GameClient + ?Sized>(
             submitted_commands += 1;
             let outcome = session.submit(command);
             let accepted = outcome.accepted;
+            let player_acted = outcome.player_actions > 0;
             trace.push(ReplayLineV1 {
                 turn_before,
                 command,
@@ -135,7 +139,8 @@ pub fn run_to_turn_with_trace<C: GameClient + ?Sized>(
                     submitted_commands,
                 });
             }
-            if accepted && session.revision().turn > turn_before {
+            // 빠른 플레이어의 행동은 게임 턴을 넘기지 않을 수 있으므로 행동을 썼는지로 판단한다.
+            if accepted && (session.revision().turn > turn_before || player_acted) {
                 advanced = true;
                 break;
             }
@@ -207,6 +212,23 @@ fn survival_candidates<C: GameClient + ?Sized>(session: &C) -> Vec<CommandIntent
         }
     }
 
+    if observation.player.paralysis_turns > 0 {
+        return vec![CommandIntent::Wait];
+    }
+
+    if matches!(
+        HungerState::from_nutrition(observation.player.hunger),
+        HungerState::Hungry | HungerState::Weak | HungerState::Fainting
+    ) {
+        if let Some(command) = observation
+            .legal_actions
+            .iter()
+            .find(|intent| matches!(intent, CommandIntent::Eat { .. }))
+        {
+            return vec![*command];
+        }
+    }
+
     for direction in priority_directions() {
         let delta = direction.delta();
         let hostile_adjacent = observation.visible_entities.iter().any(|entity| {
@@ -10,7 +10,10 @@ mod core {
         Observation, Pos,
     };
     pub mod action {
-        pub use aihack_ai_contract::{CommandIntent, DirectionalAction, InventoryAction};
+        pub use aihack_ai_contract::{
+            ApplyDirection, CommandIntent, DirectionalAction, InventoryAction, RepeatIntent,
+            RunMode,
+        };
     }
 
     pub mod observation {
@@ -28,7 +31,7 @@ mod core {
 
 mod domain {
     pub mod combat {
-        pub use aihack_ai_contract::DeathCause;
+        pub use aihack_ai_contract::{DeathCause, Missile};
     }
 
     pub mod entity {
@@ -36,15 +39,23 @@ mod domain {
     }
 
     pub mod item {
-        pub use aihack_ai_contract::ItemKind;
+        pub use aihack_ai_contract::{BucStatus, ItemKind};
+    }
+
+    pub mod kind {
+        pub use aihack_ai_contract::GlyphColor;
     }
 
     pub mod monster {
         pub use aihack_ai_contract::MonsterKind;
     }
 
+    pub mod status {
+        pub use aihack_ai_contract::HungerState;
+    }
+
     pub mod tile {
-        pub use aihack_ai_contract::TileKind;
+        pub use aihack_ai_contract::{DoorState, TileKind};
     }
 }
 
@@ -9,6 +9,9 @@ struct Args {
     high_contrast: bool,
     #[arg(long)]
     reduced_motion: bool,
+    /// NetHack `number_pad`: 숫자 키로 걷고 `n` 뒤에 횟수를 센다.
+    #[arg(long)]
+    number_pad: bool,
 }
 
 fn main() {
@@ -16,6 +19,7 @@ fn main() {
     let ui_config = aihack_tui::tui::UiRuntimeConfig {
         high_contrast: args.high_contrast,
         reduced_motion: args.reduced_motion,
+        number_pad: args.number_pad,
         ..Default::default()
     };
     if let Err(error) = aihack_tui::tui::run_tui_with_config(args.seed, ui_config) {
@@ -33,5 +37,11 @@ mod tests {
         let args = Args::try_parse_from(["aihack", "--high-contrast", "--reduced-motion"]).unwrap();
         assert!(args.high_contrast);
         assert!(args.reduced_motion);
+        assert!(!args.number_pad);
+        assert!(
+            Args::try_parse_from(["aihack", "--number-pad"])
+                .unwrap()
+                .number_pad
+        );
     }
 }
@@ -11,6 +11,10 @@ pub struct UiRuntimeConfig {
     pub high_contrast: bool,
     pub min_terminal_width: u16,
     pub min_terminal_height: u16,
+    /// NetHack `number_pad` 옵션이다. 켜면 숫자 키가 키패드 배치대로 걷고, 횟수는 `n` 뒤에
+    /// 숫자로 센다.
+    #[serde(default)]
+    pub number_pad: bool,
 }
 
 impl Default for UiRuntimeConfig {
@@ -22,6 +26,7 @@ impl Default for UiRuntimeConfig {
             high_contrast: false,
             min_terminal_width: MIN_TERMINAL_WIDTH,
             min_terminal_height: MIN_TERMINAL_HEIGHT,
+            number_pad: false,
         }
     }
 }
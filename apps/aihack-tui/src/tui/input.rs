@@ -1,5 +1,7 @@
 use crate::core::{
-    observation::ItemObservation, ActionIntent, CommandIntent, Direction, Observation, Pos,
+    action::{ApplyDirection, RunMode},
+    observation::ItemObservation,
+    ActionIntent, CommandIntent, Direction, Observation, Pos,
 };
 use ratatui::layout::Rect;
 
@@ -38,6 +40,14 @@ pub enum UiCommandCandidate {
     Quit,
     /// [v0.2.0] Phase 17: Game Over에서 새 게임 시작
     NewRun,
+    /// 숫자 키 하나를 다음 명령의 횟수 접두에 보탠다. NetHack `20s`와 같다.
+    Count(u8),
+    /// `number_pad`에서 `n`: 뒤따르는 숫자 키를 다음 명령의 횟수로 센다.
+    CountPrefix,
+    /// `^A`: 마지막으로 받아들여진 명령을 횟수까지 그대로 다시 낸다.
+    RepeatLast,
+    /// `G`: 다음 방향 키를 한 칸 걷기 대신 `RunMode::Run` 달리기로 바꾼다.
+    RunPrefix,
     /// 표시 전용 LLM 결과를 제거하며 core command는 생성하지 않는다.
     DismissLlmResult,
     LlmNarrative,
@@ -81,35 +91,68 @@ pub fn keyboard_baseline() -> Vec<(char, UiInputEvent)> {
         ),
         ('.', UiInputEvent::Key(CommandIntent::Wait)),
         ('s', UiInputEvent::Key(CommandIntent::Search)),
+        ('X', UiInputEvent::Key(CommandIntent::Explore)),
         ('o', UiInputEvent::Key(CommandIntent::Open(Direction::East))),
         (
             'c',
             UiInputEvent::Key(CommandIntent::Close(Direction::East)),
         ),
-        ('K', UiInputEvent::Key(CommandIntent::Kick(Direction::East))),
+        (
+            CTRL_D,
+            UiInputEvent::Key(CommandIntent::Kick(Direction::East)),
+        ),
         (',', UiInputEvent::Key(CommandIntent::Pickup)),
         ('i', UiInputEvent::Key(CommandIntent::ShowInventory)),
         ('>', UiInputEvent::Key(CommandIntent::Descend)),
         ('<', UiInputEvent::Key(CommandIntent::Ascend)),
         ('p', UiInputEvent::Key(CommandIntent::Pray)),
+        ('Z', UiInputEvent::Key(CommandIntent::Sit)),
+        ('F', UiInputEvent::Key(CommandIntent::QuaffFountain)),
         ('S', UiInputEvent::SaveRequest),
-        ('L', UiInputEvent::LoadRequest),
+        (CTRL_L, UiInputEvent::LoadRequest),
         ('q', UiInputEvent::Quit),
     ]
 }
 
-pub fn key_to_candidate(key: char, observation: &Observation) -> Option<UiCommandCandidate> {
-    let llm_candidate = match key {
-        'G' => Some(UiCommandCandidate::LlmNarrative),
-        'A' => Some(UiCommandCandidate::LlmSuggest),
-        'J' => Some(UiCommandCandidate::LlmJudge),
-        'Y' => Some(UiCommandCandidate::LlmApply),
-        'N' => Some(UiCommandCandidate::DismissLlmResult),
-        'R' => Some(UiCommandCandidate::LlmRetry),
+/// `^D`: NetHack처럼 걷어차기다.
+pub const CTRL_D: char = '\u{4}';
+/// `^L`: 저장 파일을 불러온다.
+pub const CTRL_L: char = '\u{c}';
+
+/// LLM CTA는 NetHack 명령 키와 겹치지 않도록 기능 키에 둔다. 결과 치우기는 footer가
+/// `[N] Dismiss`를 보일 때의 `N`이 맡는다.
+pub fn function_key_to_candidate(number: u8) -> Option<UiCommandCandidate> {
+    match number {
+        2 => Some(UiCommandCandidate::LlmNarrative),
+        3 => Some(UiCommandCandidate::LlmSuggest),
+        4 => Some(UiCommandCandidate::LlmJudge),
+        5 => Some(UiCommandCandidate::LlmApply),
+        7 => Some(UiCommandCandidate::LlmRetry),
         _ => None,
-    };
-    if llm_candidate.is_some() {
-        return llm_candidate;
+    }
+}
+
+/// NetHack `number_pad`에서 숫자 키는 키패드 배치의 방향이다. 같은 방향의 vi 키를 돌려준다.
+pub fn number_pad_direction_key(key: char) -> Option<char> {
+    match key {
+        '7' => Some('y'),
+        '8' => Some('k'),
+        '9' => Some('u'),
+        '4' => Some('h'),
+        '6' => Some('l'),
+        '1' => Some('b'),
+        '2' => Some('j'),
+        '3' => Some('n'),
+        _ => None,
+    }
+}
+
+pub fn key_to_candidate(key: char, observation: &Observation) -> Option<UiCommandCandidate> {
+    if let Some(digit) = key.to_digit(10) {
+        return Some(UiCommandCandidate::Count(digit as u8));
+    }
+    if key == 'G' {
+        return Some(UiCommandCandidate::RunPrefix);
     }
 
     let command_candidate = |intent: CommandIntent| {
@@ -119,6 +162,17 @@ pub fn key_to_candidate(key: char, observation: &Observation) -> Option<UiComman
             .contains(&ActionIntent::Command(intent))
             .then_some(UiCommandCandidate::Command(intent))
     };
+    if key.is_ascii_uppercase() {
+        if let Some((_, UiInputEvent::Key(CommandIntent::Move(direction)))) = keyboard_baseline()
+            .into_iter()
+            .find(|(candidate, _)| *candidate == key.to_ascii_lowercase())
+        {
+            return command_candidate(CommandIntent::Run {
+                direction,
+                mode: RunMode::Rush,
+            });
+        }
+    }
 
     let base = keyboard_baseline()
         .into_iter()
@@ -147,14 +201,11 @@ pub fn key_to_candidate(key: char, observation: &Observation) -> Option<UiComman
             .and_then(|item| command_candidate(CommandIntent::Wield { item })),
         'e' => first_by(|item| item.kind == crate::domain::item::ItemKind::ArmorLeather)
             .and_then(|item| command_candidate(CommandIntent::Wear { item })),
-        'q' => first_by(|item| matches!(item.kind, crate::domain::item::ItemKind::PotionHealing))
+        'q' => first_by(|item| is_potion(item.kind))
             .and_then(|item| command_candidate(CommandIntent::Quaff { item })),
         'f' => first_by(|item| {
-            matches!(
-                item.kind,
-                crate::domain::item::ItemKind::FoodRation
-                    | crate::domain::item::ItemKind::CorpseJackal
-            )
+            item.kind == crate::domain::item::ItemKind::FoodRation
+                || item.kind.corpse_of().is_some()
         })
         .and_then(|item| command_candidate(CommandIntent::Eat { item })),
         'd' => observation
@@ -164,7 +215,10 @@ pub fn key_to_candidate(key: char, observation: &Observation) -> Option<UiComman
         't' => first_by(|item| {
             matches!(
                 item.kind,
-                crate::domain::item::ItemKind::Dagger | crate::domain::item::ItemKind::Rock
+                crate::domain::item::ItemKind::Dagger
+                    | crate::domain::item::ItemKind::Spear
+                    | crate::domain::item::ItemKind::Arrow
+                    | crate::domain::item::ItemKind::Rock
             )
         })
         .and_then(|item| {
@@ -173,22 +227,56 @@ pub fn key_to_candidate(key: char, observation: &Observation) -> Option<UiComman
                 direction: Direction::East,
             })
         }),
-        'z' => first_by(|item| item.kind == crate::domain::item::ItemKind::WandMagicMissile)
-            .and_then(|item| {
-                command_candidate(CommandIntent::Zap {
-                    item,
-                    direction: Direction::East,
-                })
-            }),
-        'r' => first_by(|item| {
+        'z' => first_by(|item| {
             matches!(
                 item.kind,
-                crate::domain::item::ItemKind::ScrollReveal
-                    | crate::domain::item::ItemKind::ScrollIdentify
-                    | crate::domain::item::ItemKind::ScrollLevelTeleport
+                crate::domain::item::ItemKind::WandMagicMissile
+                    | crate::domain::item::ItemKind::WandDigging
             )
         })
-        .and_then(|item| command_candidate(CommandIntent::Read { item })),
+        .and_then(|item| {
+            command_candidate(CommandIntent::Zap {
+                item,
+                direction: Direction::East,
+            })
+        }),
+        'r' => first_by(|item| is_scroll(item.kind))
+            .and_then(|item| command_candidate(CommandIntent::Read { item })),
+        'O' => first_by(|item| item.kind.corpse_of().is_some())
+            .and_then(|item| command_candidate(CommandIntent::Offer { item })),
+        'a' => first_by(|item| item.kind == crate::domain::item::ItemKind::OilLamp)
+            .and_then(|item| {
+                command_candidate(CommandIntent::Apply {
+                    item,
+                    direction: None,
+                })
+            })
+            .or_else(|| {
+                first_by(|item| {
+                    matches!(
+                        item.kind,
+                        crate::domain::item::ItemKind::SkeletonKey
+                            | crate::domain::item::ItemKind::LockPick
+                            | crate::domain::item::ItemKind::CreditCard
+                    )
+                })
+                .and_then(|item| {
+                    command_candidate(CommandIntent::PickLock {
+                        item,
+                        direction: Direction::East,
+                    })
+                })
+            })
+            .or_else(|| {
+                first_by(|item| item.kind == crate::domain::item::ItemKind::PickAxe).and_then(
+                    |item| {
+                        command_candidate(CommandIntent::Apply {
+                            item,
+                            direction: Some(ApplyDirection::Toward(Direction::East)),
+                        })
+                    },
+                )
+            }),
         _ => None,
     }
 }
@@ -210,6 +298,12 @@ pub fn map_mouse_event(
                 let dy = pos.y - player.y;
                 direction_from_delta(dx, dy)
                     .map(|direction| UiCommandCandidate::Command(CommandIntent::Move(direction)))
+                    .or_else(|| {
+                        // 멀리 떨어진 칸은 기억한 길이 있으면 그곳으로 여행한다.
+                        observation.travel_route(pos).map(|_| {
+                            UiCommandCandidate::Command(CommandIntent::Travel { target: pos })
+                        })
+                    })
                     .or(Some(UiCommandCandidate::Inspect(pos)))
             } else if contains(layout.inspect, column, row) {
                 inspect_panel_click_candidate(layout.inspect, row, observation)
@@ -242,12 +336,12 @@ pub fn llm_footer_click_candidate(
     }
     let offset = column.saturating_sub(command.x) as usize;
     [
-        ("[G] Narrative", UiCommandCandidate::LlmNarrative),
-        ("[A] Suggest", UiCommandCandidate::LlmSuggest),
-        ("[J] Judge", UiCommandCandidate::LlmJudge),
-        ("[Y] Apply", UiCommandCandidate::LlmApply),
+        ("[F2] Narrative", UiCommandCandidate::LlmNarrative),
+        ("[F3] Suggest", UiCommandCandidate::LlmSuggest),
+        ("[F4] Judge", UiCommandCandidate::LlmJudge),
+        ("[F5] Apply", UiCommandCandidate::LlmApply),
         ("[N] Dismiss", UiCommandCandidate::DismissLlmResult),
-        ("[R] Retry", UiCommandCandidate::LlmRetry),
+        ("[F7] Retry", UiCommandCandidate::LlmRetry),
     ]
     .into_iter()
     .find_map(|(label, candidate)| {
@@ -312,18 +406,47 @@ fn primary_inventory_command(item: &ItemObservation) -> Option<CommandIntent> {
         crate::domain::item::ItemKind::ArmorLeather => {
             Some(CommandIntent::Wear { item: item.item })
         }
-        crate::domain::item::ItemKind::PotionHealing => {
-            Some(CommandIntent::Quaff { item: item.item })
-        }
-        crate::domain::item::ItemKind::ScrollReveal
-        | crate::domain::item::ItemKind::ScrollIdentify
-        | crate::domain::item::ItemKind::ScrollLevelTeleport => {
-            Some(CommandIntent::Read { item: item.item })
-        }
+        kind if is_potion(kind) => Some(CommandIntent::Quaff { item: item.item }),
+        kind if is_scroll(kind) => Some(CommandIntent::Read { item: item.item }),
         _ => None,
     }
 }
 
+fn is_potion(kind: crate::domain::item::ItemKind) -> bool {
+    use crate::domain::item::ItemKind;
+    matches!(
+        kind,
+        ItemKind::PotionHealing
+            | ItemKind::PotionExtraHealing
+            | ItemKind::PotionGainLevel
+            | ItemKind::PotionConfusion
+            | ItemKind::PotionBlindness
+            | ItemKind::PotionSleeping
+            | ItemKind::PotionSeeInvisible
+            | ItemKind::PotionSpeed
+            | ItemKind::PotionSickness
+            | ItemKind::PotionWater
+    )
+}
+
+fn is_scroll(kind: crate::domain::item::ItemKind) -> bool {
+    use crate::domain::item::ItemKind;
+    matches!(
+        kind,
+        ItemKind::ScrollReveal
+            | ItemKind::ScrollIdentify
+            | ItemKind::ScrollTeleportation
+            | ItemKind::ScrollEnchantWeapon
+            | ItemKind::ScrollEnchantArmor
+            | ItemKind::ScrollRemoveCurse
+            | ItemKind::ScrollFire
+            | ItemKind::ScrollLight
+            | ItemKind::ScrollGoldDetection
+            | ItemKind::ScrollCreateMonster
+            | ItemKind::ScrollScareMonster
+    )
+}
+
 fn direction_from_delta(dx: i16, dy: i16) -> Option<crate::core::Direction> {
     match (dx, dy) {
         (-1, -1) => Some(crate::core::Direction::NorthWest),
@@ -1,4 +1,5 @@
 use crate::core::{observation::Observation, position::Pos};
+use aihack_runtime::ContentRegistry;
 
 /// [v0.2.0] Phase 19: 자동 라벨의 종류다.
 /// 우선순위는 숫자가 낮을수록 높다 (1이 가장 높음).
@@ -43,8 +44,12 @@ pub struct AutoLabel {
 
 /// [v0.2.0] Phase 19: Observation 기준으로 자동 라벨을 수집한다.
 /// 우선순위: hostile adjacent > low HP > stairs > unidentified item > passive monster
-/// 최대 3개까지 반환하며, 각 라벨은 1200ms 동안 표시된다.
-pub fn collect_auto_labels(observation: &Observation, current_time_ms: u64) -> Vec<AutoLabel> {
+/// 최대 3개까지 반환하며, 각 라벨은 1200ms 동안 표시된다. 몬스터 글자와 이름은 `content`에서 찾는다.
+pub fn collect_auto_labels(
+    observation: &Observation,
+    content: &ContentRegistry,
+    current_time_ms: u64,
+) -> Vec<AutoLabel> {
     let mut candidates = Vec::new();
 
     // visible_entities 순회
@@ -69,11 +74,9 @@ pub fn collect_auto_labels(observation: &Observation, current_time_ms: u64) -> V
                     pos: entity.pos,
                     text: format!(
                         "[{}]",
-                        match monster_kind {
-                            crate::domain::monster::MonsterKind::Jackal => "d",
-                            crate::domain::monster::MonsterKind::Goblin => "g",
-                            _ => "?",
-                        }
+                        content
+                            .monster_info(monster_kind)
+                            .map_or('?', |info| info.glyph)
                     ),
                     created_at_ms: current_time_ms,
                     duration_ms: 1200,
@@ -83,7 +86,7 @@ pub fn collect_auto_labels(observation: &Observation, current_time_ms: u64) -> V
                 candidates.push(AutoLabel {
                     kind: LabelKind::PassiveMonster,
                     pos: entity.pos,
-                    text: "[floating eye]".to_string(),
+                    text: format!("[{}]", content.monster_name(monster_kind)),
                     created_at_ms: current_time_ms,
                     duration_ms: 1200,
                 });
@@ -5,14 +5,15 @@ use std::{
 
 use crossterm::{
     cursor,
-    event::{self, Event, KeyCode, MouseEventKind},
+    event::{self, Event, KeyCode, KeyModifiers, MouseEventKind},
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
 use ratatui::{backend::CrosstermBackend, layout::Rect, Terminal};
 
+use crate::core::action::{CommandIntent, RepeatIntent, RunMode};
 use aihack_ai_contract::{ClientRevision, Observation, RunState};
-use aihack_runtime::{save, GameClient, GameError, GameSession};
+use aihack_runtime::{save, ContentRegistry, GameClient, GameError, GameSession};
 
 use crate::llm::{
     config::{validate_user_text, LlmRequestKind, LocalLlmConfig},
@@ -71,8 +72,9 @@ pub mod viewport;
 pub use config::UiRuntimeConfig;
 pub use effects::{project_event, UiEffectEvent, UiEffectKind};
 pub use input::{
-    key_to_candidate, keyboard_baseline, llm_footer_click_candidate, map_mouse_event,
-    UiCommandCandidate, UiInputEvent, UiPanel,
+    function_key_to_candidate, key_to_candidate, keyboard_baseline, llm_footer_click_candidate,
+    map_mouse_event, number_pad_direction_key, UiCommandCandidate, UiInputEvent, UiPanel, CTRL_D,
+    CTRL_L,
 };
 pub use layout::{compute_layout, LayoutTier, TuiLayout};
 pub use theme::UiTheme;
@@ -91,6 +93,8 @@ pub trait TuiClient: GameClient {
     fn load_from_path(&mut self, path: &Path) -> Result<(), GameError>;
     fn start_new_run(&mut self) -> Result<(), GameError>;
     fn kill_count(&self) -> u32;
+    /// 물건과 몬스터의 이름과 글자를 찾는 session의 content다.
+    fn content(&self) -> &ContentRegistry;
 }
 
 impl TuiClient for GameSession {
@@ -111,6 +115,10 @@ impl TuiClient for GameSession {
     fn kill_count(&self) -> u32 {
         self.world().kill_count()
     }
+
+    fn content(&self) -> &ContentRegistry {
+        self.world().content()
+    }
 }
 
 pub struct TuiApp {
@@ -139,6 +147,12 @@ pub struct TuiApp {
     /// [v0.2.0] Phase 19: 마지막으로 라벨을 업데이트한 턴 번호.
     /// 턴이 진행될 때만 새 라벨을 수집한다.
     pub last_label_update_turn: u64,
+    /// 명령 키 앞에 모은 숫자 횟수 접두다. UI-only이며 다음 명령과 함께 소비된다.
+    pending_count: Option<u16>,
+    /// `^A`로 다시 낼 마지막으로 받아들여진 명령이다.
+    last_command: Option<CommandIntent>,
+    /// `G`를 눌러 다음 방향 키를 달리기로 바꿀 차례인지 여부다.
+    pending_run: bool,
 }
 
 impl TuiApp {
@@ -175,6 +189,9 @@ impl TuiApp {
             debug_observation_visible: false,
             active_labels: Vec::new(),
             last_label_update_turn: 0,
+            pending_count: None,
+            pending_run: false,
+            last_command: None,
         }
     }
 
@@ -365,6 +382,11 @@ impl TuiApp {
         self.client.observation()
     }
 
+    /// 화면 이름과 글자를 찾는 session의 content다.
+    pub fn content(&self) -> &ContentRegistry {
+        self.client.content()
+    }
+
     pub fn revision(&self) -> ClientRevision {
         self.client.revision()
     }
@@ -505,6 +527,87 @@ impl TuiApp {
         Viewport::from_rect(origin, observation.player_pos, layout.map)
     }
 
+    /// 숫자 키로 모은 아직 쓰지 않은 횟수 접두다.
+    pub fn pending_count(&self) -> Option<u16> {
+        self.pending_count
+    }
+
+    /// 플레이 중 글자 키를 후보로 바꾼다. footer가 `[N] Dismiss`를 보이면 `N`은 남동쪽
+    /// rush 대신 LLM 결과를 치운다. `number_pad`를 켜면 숫자 키는 키패드 배치대로 걷고,
+    /// `n` 뒤의 숫자만 횟수로 센다.
+    pub fn candidate_for_key(&self, key: char) -> Option<UiCommandCandidate> {
+        use crate::core::session::RunState;
+        let state = self.run_state();
+        let observation = self.observation();
+        let playing = !matches!(
+            state,
+            RunState::Title | RunState::CharacterCreation | RunState::GameOver { .. }
+        );
+        if playing && key == 'N' && self.offers_dismiss() {
+            return Some(UiCommandCandidate::DismissLlmResult);
+        }
+        if playing && self.config.number_pad && self.pending_count.is_none() {
+            if key == 'n' {
+                return Some(UiCommandCandidate::CountPrefix);
+            }
+            if key.is_ascii_digit() {
+                return number_pad_direction_key(key)
+                    .and_then(|key| key_to_candidate(key, &observation));
+            }
+        }
+        key_to_candidate_for_state(key, &state, &observation)
+    }
+
+    /// footer가 LLM 결과를 치우는 `[N] Dismiss`를 보이는지 본다.
+    fn offers_dismiss(&self) -> bool {
+        render_panels::llm_footer_line(
+            &self.llm_status,
+            self.validated_decision.is_some(),
+            self.has_llm_result(),
+        )
+        .contains("[N] Dismiss")
+    }
+
+    /// `G` 뒤에 아직 방향 키를 기다리는지 여부다.
+    pub fn pending_run(&self) -> bool {
+        self.pending_run
+    }
+
+    fn submit_command(&mut self, intent: CommandIntent) {
+        let mut outcome = self.client.submit(intent);
+        if outcome.accepted {
+            self.last_command = Some(intent);
+        }
+        // 달리기는 걸음마다 따로 받아들이는 명령이므로 런타임이 멈출 때까지 걸음을 잇는다.
+        while outcome.accepted
+            && self
+                .client
+                .observation()
+                .legal_actions
+                .contains(&CommandIntent::ContinueRun)
+        {
+            let step = self.client.submit(CommandIntent::ContinueRun);
+            outcome.turn_advanced |= step.turn_advanced;
+            outcome.accepted = step.accepted;
+        }
+        // [v0.2.0] Phase 19: 턴이 진행되면 새로운 자동 라벨을 수집한다.
+        if outcome.turn_advanced {
+            let observation = self.observation();
+            let current_time_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64;
+            let new_labels =
+                labels::collect_auto_labels(&observation, self.client.content(), current_time_ms);
+            // 만료된 라벨 제거 후 새 라벨 추가
+            labels::filter_expired_labels(&mut self.active_labels, current_time_ms);
+            self.active_labels.extend(new_labels);
+            // 우선순위 정렬 후 최대 3개 유지
+            self.active_labels.sort_by_key(|l| l.kind.priority());
+            self.active_labels.truncate(3);
+        }
+    }
+
     pub fn handle_candidate(
         &mut self,
         candidate: UiCommandCandidate,
@@ -513,21 +616,40 @@ impl TuiApp {
     ) -> Result<bool, GameError> {
         match candidate {
             UiCommandCandidate::Command(intent) => {
-                let outcome = self.client.submit(intent);
-                // [v0.2.0] Phase 19: 턴이 진행되면 새로운 자동 라벨을 수집한다.
-                if outcome.turn_advanced {
-                    let observation = self.observation();
-                    let current_time_ms = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_millis() as u64;
-                    let new_labels = labels::collect_auto_labels(&observation, current_time_ms);
-                    // 만료된 라벨 제거 후 새 라벨 추가
-                    labels::filter_expired_labels(&mut self.active_labels, current_time_ms);
-                    self.active_labels.extend(new_labels);
-                    // 우선순위 정렬 후 최대 3개 유지
-                    self.active_labels.sort_by_key(|l| l.kind.priority());
-                    self.active_labels.truncate(3);
+                let intent = match (std::mem::take(&mut self.pending_run), intent) {
+                    (true, CommandIntent::Move(direction)) => CommandIntent::Run {
+                        direction,
+                        mode: RunMode::Run,
+                    },
+                    _ => intent,
+                };
+                let intent = match self.pending_count.take() {
+                    Some(count) if count > 1 => RepeatIntent::from_command(intent)
+                        .map_or(intent, |intent| CommandIntent::Repeat { count, intent }),
+                    _ => intent,
+                };
+                self.submit_command(intent);
+                Ok(false)
+            }
+            UiCommandCandidate::Count(digit) => {
+                let count = self.pending_count.unwrap_or(0);
+                self.pending_count =
+                    Some(count.saturating_mul(10).saturating_add(u16::from(digit)));
+                Ok(false)
+            }
+            UiCommandCandidate::CountPrefix => {
+                self.pending_count = Some(self.pending_count.unwrap_or(0));
+                Ok(false)
+            }
+            UiCommandCandidate::RunPrefix => {
+                self.pending_run = true;
+                Ok(false)
+            }
+            UiCommandCandidate::RepeatLast => {
+                self.pending_count = None;
+                self.pending_run = false;
+                if let Some(intent) = self.last_command {
+                    self.submit_command(intent);
                 }
                 Ok(false)
             }
@@ -747,19 +869,34 @@ fn run_tui_with_service(
                             _ => None,
                         },
                         Event::Key(key) => match key.code {
-                            KeyCode::Char('N') if app.has_llm_result() => {
-                                Some(UiCommandCandidate::DismissLlmResult)
-                            }
                             KeyCode::Esc if app.has_llm_result() => {
                                 Some(UiCommandCandidate::DismissLlmResult)
                             }
                             KeyCode::Esc => Some(UiCommandCandidate::Quit),
                             // [v0.2.0] Phase 18: F9 키로 debug observation 패널을 토글한다.
                             // 이 입력은 UI-only이며 core나 snapshot hash에 영향을 주지 않는다.
+                            KeyCode::Char('a') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                Some(UiCommandCandidate::RepeatLast)
+                            }
+                            // `^D`, `^L`처럼 Ctrl 글자는 제어 문자로 바꿔 keyboard baseline에서 찾는다.
+                            KeyCode::Char(character)
+                                if key.modifiers.contains(KeyModifiers::CONTROL)
+                                    && character.is_ascii_alphabetic() =>
+                            {
+                                runtime_key_to_candidate(
+                                    KeyCode::Char(
+                                        (character.to_ascii_lowercase() as u8 & 0x1f) as char,
+                                    ),
+                                    &app.run_state(),
+                                    &app.observation(),
+                                )
+                            }
                             KeyCode::F(9) => {
                                 app.debug_observation_visible = !app.debug_observation_visible;
                                 None
                             }
+                            KeyCode::F(number) => function_key_to_candidate(number),
+                            KeyCode::Char(character) => app.candidate_for_key(character),
                             key_code => runtime_key_to_candidate(
                                 key_code,
                                 &app.run_state(),
@@ -868,11 +1005,17 @@ fn render_play_screen(frame: &mut ratatui::Frame, _size: Rect, app: &mut TuiApp)
         _ => None,
     };
 
+    let route = app
+        .hovered_pos()
+        .and_then(|pos| observation.travel_route(pos))
+        .unwrap_or_default();
     frame.render_widget(
         render_map::MapWidget {
             observation: &observation,
+            content: app.client.content(),
             viewport,
             labels: &app.active_labels,
+            route: &route,
         },
         layout.map,
     );
@@ -890,6 +1033,14 @@ fn render_play_screen(frame: &mut ratatui::Frame, _size: Rect, app: &mut TuiApp)
         layout.status,
     );
     let mut command_lines = render_panels::command_lines(&observation, app.focused_panel());
+    match app.pending_count() {
+        Some(0) => command_lines[0] = "Count:".to_string(),
+        Some(count) => command_lines[0] = format!("Count: {count}"),
+        None => {}
+    }
+    if app.pending_run() {
+        command_lines[0] = "Run: pick a direction".to_string();
+    }
     command_lines[1] = render_panels::llm_footer_line(
         app.llm_status(),
         app.validated_decision.is_some(),
@@ -905,7 +1056,11 @@ fn render_play_screen(frame: &mut ratatui::Frame, _size: Rect, app: &mut TuiApp)
     frame.render_widget(
         render_panels::TextPanel {
             title: "LOG",
-            lines: render_panels::log_lines(&observation, &app.narrative_lines()),
+            lines: render_panels::log_lines(
+                &observation,
+                app.client.content(),
+                &app.narrative_lines(),
+            ),
         },
         layout.log,
     );
@@ -914,6 +1069,7 @@ fn render_play_screen(frame: &mut ratatui::Frame, _size: Rect, app: &mut TuiApp)
             title: "INSPECT",
             lines: render_panels::inspect_lines(
                 &observation,
+                app.client.content(),
                 app.hovered_pos(),
                 app.focused_panel(),
                 &app.llm_result_lines(),
@@ -992,8 +1148,27 @@ fn render_game_over_screen(
             format!("Killed by entity {:?}", attacker.0)
         }
         crate::domain::combat::DeathCause::Trap { trap } => {
-            format!("Killed by {:?}", trap)
+            format!("Killed by {}", trap.name())
+        }
+        crate::domain::combat::DeathCause::Consumable { item } => {
+            format!("Killed by {:?}", item)
+        }
+        crate::domain::combat::DeathCause::Starvation => "Died of starvation".to_string(),
+        crate::domain::combat::DeathCause::Choked { item } => {
+            format!("Choked on {:?}", item)
+        }
+        crate::domain::combat::DeathCause::FoodPoisoning => "Died of food poisoning".to_string(),
+        crate::domain::combat::DeathCause::Petrification { item } => {
+            format!("Petrified by {:?}", item)
+        }
+        crate::domain::combat::DeathCause::DivineWrath => "Killed by divine wrath".to_string(),
+        crate::domain::combat::DeathCause::Feature { tile } => {
+            format!("Killed by {:?}", tile)
+        }
+        crate::domain::combat::DeathCause::Drowned { tile } => {
+            format!("Drowned in {:?}", tile)
         }
+        crate::domain::combat::DeathCause::Lava => "Burned by molten lava".to_string(),
     };
     let observation = app.observation();
     let lines = render_panels::game_over_lines(
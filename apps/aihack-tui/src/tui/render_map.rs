@@ -1,32 +1,82 @@
-use crate::core::Observation;
-use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+use crate::{
+    core::{Observation, Pos},
+    domain::{
+        entity::EntityKind,
+        kind::GlyphColor,
+        tile::{DoorState, TileKind},
+    },
+};
+use aihack_runtime::ContentRegistry;
+use ratatui::{buffer::Buffer, layout::Rect, style::Color, widgets::Widget};
 
 use super::{labels::AutoLabel, viewport::Viewport};
 
 /// [v0.2.0] Phase 19: 라벨 표시를 지원하는 맵 위젯이다.
 pub struct MapWidget<'a> {
     pub observation: &'a Observation,
+    /// 물건과 몬스터의 글자와 색을 찾는 session의 content다.
+    pub content: &'a ContentRegistry,
     pub viewport: Viewport,
     /// [v0.2.0] Phase 19: 맵 위에 표시할 자동 라벨 목록.
     pub labels: &'a [AutoLabel],
+    /// 마우스가 가리킨 칸까지 여행할 길이다. 지도 글자는 두고 색만 칠한다.
+    pub route: &'a [Pos],
 }
 
 impl Widget for MapWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        // 타일 렌더링
+        // 기억한 타일은 흐리게 먼저 그리고, 보이는 타일을 그 위에 그린다.
+        for tile in &self.observation.remembered_tiles {
+            if let Some((cx, cy)) = self.viewport.world_to_terminal(tile.pos, area) {
+                buf[(cx, cy)]
+                    .set_char(tile_glyph(tile.tile))
+                    .set_fg(Color::DarkGray);
+            }
+        }
         for tile in &self.observation.visible_tiles {
             if let Some((cx, cy)) = self.viewport.world_to_terminal(tile.pos, area) {
-                let glyph = match tile.tile {
-                    crate::domain::tile::TileKind::Wall => '#',
-                    crate::domain::tile::TileKind::Floor => '.',
-                    crate::domain::tile::TileKind::Door(_) => '+',
-                    crate::domain::tile::TileKind::StairsDown => '>',
-                    crate::domain::tile::TileKind::StairsUp => '<',
-                    crate::domain::tile::TileKind::Trap(_) => '^',
-                    crate::domain::tile::TileKind::HiddenDoor => '#',
-                    crate::domain::tile::TileKind::HiddenTrap(_) => '.',
+                buf[(cx, cy)].set_char(tile_glyph(tile.tile));
+            }
+        }
+        // 물건과 몬스터 글자와 색은 content가 정한다. 지금 보이지 않는 칸의 것은 흐리게 그린다.
+        let seen = |pos: Pos| {
+            self.observation
+                .visible_tiles
+                .iter()
+                .any(|tile| tile.pos == pos)
+        };
+        let objects = self.observation.remembered_objects.iter().map(|object| {
+            let info = self.content.item_info(object.kind);
+            (object.pos, info.map(|info| (info.glyph, info.color)))
+        });
+        let monsters = self.observation.remembered_monsters.iter().map(|monster| {
+            let info = self.content.monster_info(monster.kind);
+            (monster.pos, info.map(|info| (info.glyph, info.color)))
+        });
+        for (pos, info) in objects.chain(monsters) {
+            let (glyph, color) = info.unwrap_or(UNKNOWN_GLYPH);
+            if let Some((cx, cy)) = self.viewport.world_to_terminal(pos, area) {
+                let fg = if seen(pos) {
+                    glyph_color(color)
+                } else {
+                    Color::DarkGray
                 };
-                buf[(cx, cy)].set_char(glyph);
+                buf[(cx, cy)].set_char(glyph).set_fg(fg);
+            }
+        }
+        for entity in &self.observation.visible_entities {
+            let EntityKind::Monster(kind) = entity.kind else {
+                continue;
+            };
+            if !entity.alive {
+                continue;
+            }
+            if let Some((cx, cy)) = self.viewport.world_to_terminal(entity.pos, area) {
+                let (glyph, color) = self
+                    .content
+                    .monster_info(kind)
+                    .map_or(UNKNOWN_GLYPH, |info| (info.glyph, info.color));
+                buf[(cx, cy)].set_char(glyph).set_fg(glyph_color(color));
             }
         }
         if let Some((px, py)) = self
@@ -35,6 +85,11 @@ impl Widget for MapWidget<'_> {
         {
             buf[(px, py)].set_char('@');
         }
+        for pos in self.route {
+            if let Some((cx, cy)) = self.viewport.world_to_terminal(*pos, area) {
+                buf[(cx, cy)].set_fg(Color::Yellow);
+            }
+        }
 
         // [v0.2.0] Phase 19: 라벨 오버레이 렌더링
         for label in self.labels {
@@ -51,3 +106,51 @@ impl Widget for MapWidget<'_> {
         }
     }
 }
+
+/// session의 content가 모르는 종류를 그리는 글자와 색이다.
+const UNKNOWN_GLYPH: (char, GlyphColor) = ('?', GlyphColor::Gray);
+
+/// content 글자 색을 터미널 색으로 바꾼다.
+fn glyph_color(color: GlyphColor) -> Color {
+    match color {
+        GlyphColor::Black => Color::DarkGray,
+        GlyphColor::Red => Color::Red,
+        GlyphColor::Green => Color::Green,
+        GlyphColor::Brown => Color::Yellow,
+        GlyphColor::Blue => Color::Blue,
+        GlyphColor::Magenta => Color::Magenta,
+        GlyphColor::Cyan => Color::Cyan,
+        GlyphColor::Gray => Color::Gray,
+        GlyphColor::Orange => Color::LightRed,
+        GlyphColor::BrightGreen => Color::LightGreen,
+        GlyphColor::Yellow => Color::LightYellow,
+        GlyphColor::BrightBlue => Color::LightBlue,
+        GlyphColor::BrightMagenta => Color::LightMagenta,
+        GlyphColor::BrightCyan => Color::LightCyan,
+        GlyphColor::White => Color::White,
+    }
+}
+
+/// 지형 종류를 지도 글자로 바꾼다.
+fn tile_glyph(kind: TileKind) -> char {
+    match kind {
+        TileKind::Wall => '#',
+        TileKind::Floor => '.',
+        TileKind::Corridor => '#',
+        TileKind::Door(DoorState::Broken | DoorState::NoDoor) => '.',
+        TileKind::Door(_) => '+',
+        TileKind::StairsDown => '>',
+        TileKind::StairsUp => '<',
+        TileKind::Altar => '_',
+        TileKind::Fountain => '{',
+        TileKind::Sink => '#',
+        TileKind::Throne => '\\',
+        TileKind::Grave => '|',
+        TileKind::Pool | TileKind::Moat | TileKind::Lava => '}',
+        TileKind::Tree => '#',
+        TileKind::IronBars => '#',
+        TileKind::Trap(_) => '^',
+        TileKind::HiddenDoor => '#',
+        TileKind::HiddenTrap(_) => '.',
+    }
+}
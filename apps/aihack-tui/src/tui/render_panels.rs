@@ -1,9 +1,16 @@
 use crate::{
-    core::{EntityObservation, GameEvent, Observation, Pos},
-    domain::{entity::EntityKind, item::ItemKind, tile::TileKind},
+    core::{observation::ItemObservation, EntityObservation, GameEvent, Observation, Pos},
+    domain::{
+        combat::Missile,
+        entity::EntityKind,
+        item::{BucStatus, ItemKind},
+        status::HungerState,
+        tile::TileKind,
+    },
     ui::tui::UiPanel,
 };
 use aihack_llm::config::LlmRequestKind;
+use aihack_runtime::ContentRegistry;
 use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
 
 use super::LlmUiStatus;
@@ -53,7 +60,7 @@ pub fn status_lines(observation: &Observation) -> Vec<String> {
     } else {
         "stable"
     };
-    vec![
+    let mut lines = vec![
         format!("turn {}", observation.turn),
         format!(
             "hp {}/{} {}",
@@ -70,7 +77,31 @@ pub fn status_lines(observation: &Observation) -> Vec<String> {
             "pos {},{}",
             observation.player_pos.x, observation.player_pos.y
         ),
+    ];
+    let conditions = condition_labels(observation);
+    if !conditions.is_empty() {
+        lines.push(conditions.join(" "));
+    }
+    lines
+}
+
+fn condition_labels(observation: &Observation) -> Vec<&'static str> {
+    let player = &observation.player;
+    let hunger = HungerState::from_nutrition(player.hunger).status_label();
+    let conditions = [
+        (player.confusion_turns > 0, "Conf"),
+        (player.blindness_turns > 0, "Blind"),
+        (player.paralysis_turns > 0, "Helpless"),
+        (player.very_fast_turns > 0 || player.fast, "Fast"),
+        (player.hallucinating, "Hallu"),
     ]
+    .into_iter()
+    .filter_map(|(active, label)| active.then_some(label));
+    hunger
+        .into_iter()
+        .chain(player.encumbrance.status_label())
+        .chain(conditions)
+        .collect()
 }
 
 pub fn command_lines(observation: &Observation, focused_panel: UiPanel) -> Vec<String> {
@@ -96,7 +127,7 @@ pub fn llm_status_lines(status: &LlmUiStatus) -> Vec<String> {
     let (badge, body) = match status {
         LlmUiStatus::Disabled => ("LLM: OFF", "Local LLM disabled; core play is available."),
         LlmUiStatus::Connecting => ("LLM: ...", "Connecting to loopback provider."),
-        LlmUiStatus::Ready => ("LLM: READY", "[G] Narrative [A] Suggest [J] Judge"),
+        LlmUiStatus::Ready => ("LLM: READY", "[F2] Narrative [F3] Suggest [F4] Judge"),
         LlmUiStatus::Pending { kind, request_id } => {
             return vec![
                 "LLM: WAIT".to_string(),
@@ -109,20 +140,20 @@ pub fn llm_status_lines(status: &LlmUiStatus) -> Vec<String> {
         }
         LlmUiStatus::Busy => (
             "LLM: BUSY",
-            "Queue capacity 16 reached. [R] Retry [N] Dismiss",
+            "Queue capacity 16 reached. [F7] Retry [N] Dismiss",
         ),
         LlmUiStatus::Timeout { kind } => {
             return vec![
                 "LLM: TIMEOUT".to_string(),
                 format!(
-                    "{} request timed out. [R] Retry [N] Dismiss",
+                    "{} request timed out. [F7] Retry [N] Dismiss",
                     request_kind_label(kind)
                 ),
             ];
         }
         LlmUiStatus::Unavailable => (
             "LLM: DOWN",
-            "Loopback provider unavailable. [R] Retry [N] Dismiss",
+            "Loopback provider unavailable. [F7] Retry [N] Dismiss",
         ),
         LlmUiStatus::Invalid => ("LLM: INVALID", "Response or input rejected. [N] Dismiss"),
         LlmUiStatus::Stale => (
@@ -139,7 +170,7 @@ pub fn llm_footer_line(
     has_result: bool,
 ) -> String {
     if has_valid_suggestion {
-        "[Y] Apply [N] Dismiss".to_string()
+        "[F5] Apply [N] Dismiss".to_string()
     } else if !matches!(status, LlmUiStatus::Ready | LlmUiStatus::Disabled) {
         llm_status_lines(status).get(1).cloned().unwrap_or_default()
     } else if has_result {
@@ -167,8 +198,12 @@ fn request_kind_label(kind: &LlmRequestKind) -> &'static str {
     }
 }
 
-pub fn log_lines(observation: &Observation, narrative_lines: &[String]) -> Vec<String> {
-    let mut out = recent_priority_messages(observation);
+pub fn log_lines(
+    observation: &Observation,
+    content: &ContentRegistry,
+    narrative_lines: &[String],
+) -> Vec<String> {
+    let mut out = recent_priority_messages(observation, content);
     if out.len() < 4 {
         out.extend(narrative_lines.iter().take(4 - out.len()).cloned());
     }
@@ -177,12 +212,13 @@ pub fn log_lines(observation: &Observation, narrative_lines: &[String]) -> Vec<S
 
 pub fn inspect_lines(
     observation: &Observation,
+    content: &ContentRegistry,
     hovered: Option<Pos>,
     focused_panel: UiPanel,
     decision_lines: &[String],
 ) -> Vec<String> {
     if let Some(pos) = hovered {
-        return hovered_inspect_lines(observation, pos);
+        return hovered_inspect_lines(observation, content, pos);
     }
 
     if !decision_lines.is_empty() {
@@ -191,12 +227,16 @@ pub fn inspect_lines(
         return lines;
     }
 
-    let mut lines = inventory_lines(observation);
+    let mut lines = inventory_lines(observation, content);
     lines.push(format!("focus {:?}", focused_panel));
     lines
 }
 
-fn hovered_inspect_lines(observation: &Observation, pos: Pos) -> Vec<String> {
+fn hovered_inspect_lines(
+    observation: &Observation,
+    content: &ContentRegistry,
+    pos: Pos,
+) -> Vec<String> {
     let tile = observation
         .visible_tiles
         .iter()
@@ -207,7 +247,7 @@ fn hovered_inspect_lines(observation: &Observation, pos: Pos) -> Vec<String> {
         .visible_entities
         .iter()
         .find(|entity| entity.pos == pos)
-        .map(entity_line)
+        .map(|entity| entity_line(entity, content))
         .unwrap_or_else(|| "entity none".to_string());
     vec![
         format!("hover {},{}", pos.x, pos.y),
@@ -217,7 +257,7 @@ fn hovered_inspect_lines(observation: &Observation, pos: Pos) -> Vec<String> {
     ]
 }
 
-fn inventory_lines(observation: &Observation) -> Vec<String> {
+fn inventory_lines(observation: &Observation, content: &ContentRegistry) -> Vec<String> {
     observation
         .inventory
         .iter()
@@ -231,7 +271,7 @@ fn inventory_lines(observation: &Observation) -> Vec<String> {
             format!(
                 "{} {}{}{}",
                 item.letter.0,
-                item_label(item.kind),
+                inventory_item_label(item, content),
                 slot,
                 identified
             )
@@ -239,13 +279,37 @@ fn inventory_lines(observation: &Observation) -> Vec<String> {
         .collect()
 }
 
-fn recent_priority_messages(observation: &Observation) -> Vec<String> {
+/// 성수/불경한 물, 희석·부식·먹다 만 상태와 강화치를 inventory 표시명에 반영한다.
+fn inventory_item_label(item: &ItemObservation, content: &ContentRegistry) -> String {
+    let base = match (item.kind, item.buc) {
+        (ItemKind::PotionWater, BucStatus::Blessed) => "holy water",
+        (ItemKind::PotionWater, BucStatus::Cursed) => "unholy water",
+        (kind, _) => content.item_name(kind),
+    };
+    let base = match item.erosion {
+        0 => base.to_string(),
+        1 => format!("rusty {base}"),
+        2 => format!("very rusty {base}"),
+        _ => format!("thoroughly rusty {base}"),
+    };
+    if item.partly_eaten {
+        format!("partly eaten {base}")
+    } else if item.diluted {
+        format!("diluted {base}")
+    } else if item.enchantment != 0 {
+        format!("{:+} {base}", item.enchantment)
+    } else {
+        base
+    }
+}
+
+fn recent_priority_messages(observation: &Observation, content: &ContentRegistry) -> Vec<String> {
     let mut lines = observation
         .last_events
         .iter()
         .rev()
         .take(3)
-        .map(event_line)
+        .map(|event| event_line(event, content))
         .collect::<Vec<_>>();
     if observation.player.max_hp > 0 && observation.player.hp * 10 <= observation.player.max_hp * 3
     {
@@ -254,7 +318,7 @@ fn recent_priority_messages(observation: &Observation) -> Vec<String> {
     lines
 }
 
-fn event_line(event: &GameEvent) -> String {
+fn event_line(event: &GameEvent, content: &ContentRegistry) -> String {
     match event {
         GameEvent::AttackResolved {
             hit: true, damage, ..
@@ -264,22 +328,31 @@ fn event_line(event: &GameEvent) -> String {
         GameEvent::DoorChanged { to, .. } => format!("> door {to:?}"),
         GameEvent::CommandRejected { reason } => format!("x {reason}"),
         GameEvent::TrapTriggered { damage, .. } => format!("! trap {damage}"),
+        GameEvent::MissileFired { missile, .. } => {
+            format!("! incoming {}", missile_label(*missile, content))
+        }
         _ => format!("{event:?}"),
     }
 }
 
-fn entity_line(entity: &EntityObservation) -> String {
+fn entity_line(entity: &EntityObservation, content: &ContentRegistry) -> String {
     let hp = entity.hp.map(|hp| format!(" hp {hp}")).unwrap_or_default();
-    format!("entity {}{}", entity_kind_label(entity.kind), hp)
+    let asleep = if entity.asleep { " sleeping" } else { "" };
+    let fleeing = if entity.fleeing { " fleeing" } else { "" };
+    format!(
+        "entity {}{}{}{}",
+        entity_kind_label(entity.kind, content),
+        hp,
+        asleep,
+        fleeing
+    )
 }
 
-fn entity_kind_label(kind: EntityKind) -> &'static str {
+fn entity_kind_label(kind: EntityKind, content: &ContentRegistry) -> &str {
     match kind {
         EntityKind::Player => "player",
-        EntityKind::Monster(crate::domain::monster::MonsterKind::Jackal) => "jackal",
-        EntityKind::Monster(crate::domain::monster::MonsterKind::Goblin) => "goblin",
-        EntityKind::Monster(crate::domain::monster::MonsterKind::FloatingEye) => "floating eye",
-        EntityKind::Item(kind) => item_label(kind),
+        EntityKind::Monster(kind) => content.monster_name(kind),
+        EntityKind::Item(kind) => content.item_name(kind),
     }
 }
 
@@ -287,27 +360,32 @@ fn tile_label(tile: TileKind) -> &'static str {
     match tile {
         TileKind::Wall => "wall",
         TileKind::Floor => "floor",
+        TileKind::Corridor => "corridor",
         TileKind::Door(_) => "door",
         TileKind::StairsDown => "stairs down",
         TileKind::StairsUp => "stairs up",
-        TileKind::Trap(_) => "trap",
+        TileKind::Altar => "altar",
+        TileKind::Fountain => "fountain",
+        TileKind::Sink => "sink",
+        TileKind::Throne => "throne",
+        TileKind::Grave => "grave",
+        TileKind::Pool => "water",
+        TileKind::Moat => "moat",
+        TileKind::Lava => "molten lava",
+        TileKind::Tree => "tree",
+        TileKind::IronBars => "iron bars",
+        TileKind::Trap(trap) => trap.name(),
         TileKind::HiddenDoor => "hidden door",
         TileKind::HiddenTrap(_) => "hidden trap",
     }
 }
 
-fn item_label(kind: ItemKind) -> &'static str {
-    match kind {
-        ItemKind::Dagger => "dagger",
-        ItemKind::FoodRation => "food ration",
-        ItemKind::PotionHealing => "healing potion",
-        ItemKind::WandMagicMissile => "wand",
-        ItemKind::ScrollReveal => "reveal scroll",
-        ItemKind::ScrollIdentify => "identify scroll",
-        ItemKind::ScrollLevelTeleport => "teleport scroll",
-        ItemKind::Rock => "rock",
-        ItemKind::ArmorLeather => "leather armor",
-        ItemKind::CorpseJackal => "jackal corpse",
+fn missile_label(missile: Missile, content: &ContentRegistry) -> &str {
+    match missile {
+        Missile::Item(kind) => content.item_name(kind),
+        Missile::Venom => "venom",
+        Missile::Breath => "breath",
+        Missile::MagicMissile => "magic missile",
     }
 }
 
@@ -423,8 +501,12 @@ pub fn debug_observation_lines(observation: &Observation) -> Vec<String> {
             observation.player.hp, observation.player.max_hp
         ),
         format!("hunger: {}", observation.player.hunger),
-        format!("luck: {}", observation.player.luck),
+        format!("luck: {:?}", observation.player.luck),
         format!("prayer_cooldown: {}", observation.player.prayer_cooldown),
+        format!(
+            "alignment: {} (anger {})",
+            observation.player.alignment, observation.player.god_anger
+        ),
         format!("paralysis_turns: {}", observation.player.paralysis_turns),
         format!("hallucinating: {}", observation.player.hallucinating),
         format!("visible_tiles: {}", observation.visible_tiles.len()),
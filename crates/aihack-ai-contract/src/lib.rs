@@ -5,13 +5,18 @@ use serde::{Deserialize, Serialize};
 pub mod llm;
 pub mod observation;
 pub use aihack_core::{
-    action::{ActionIntent, CommandIntent, DirectionalAction, InventoryAction, NarrativeTopic},
+    action::{
+        ActionIntent, ApplyDirection, CommandIntent, DirectionalAction, InventoryAction,
+        NarrativeTopic, RepeatIntent, RunMode,
+    },
     domain::{
-        combat::DeathCause,
+        combat::{DeathCause, Missile},
         entity::EntityKind,
-        item::ItemKind,
+        item::{BucStatus, ItemKind},
+        kind::GlyphColor,
         monster::MonsterKind,
-        tile::{TileKind, TrapKind},
+        status::{Encumbrance, HungerState},
+        tile::{DoorState, TileKind, TrapKind},
     },
     event::GameEvent,
     hash::SnapshotHash,
@@ -1,9 +1,13 @@
+use std::collections::HashMap;
+
 use aihack_core::{
     action::{ActionIntent, CommandIntent},
     domain::{
         entity::EntityKind,
         inventory::InventoryLetter,
-        item::{EquipmentSlot, ItemKind},
+        item::{BucStatus, EquipmentSlot, ItemKind},
+        monster::MonsterKind,
+        status::Encumbrance,
         tile::TileKind,
     },
     event::GameEvent,
@@ -12,7 +16,9 @@ use aihack_core::{
 };
 use serde::{Deserialize, Serialize};
 
-pub const OBSERVATION_SCHEMA_VERSION: u16 = 1;
+/// 관측 JSON 스키마 버전이다. 2에서 `player.luck`이 정수 대신 [`LuckFeeling`]이 되었고, 몬스터와
+/// 아이템 종류가 variant 이름 대신 content id 문자열(`"monster.jackal"`)로 나간다.
+pub const OBSERVATION_SCHEMA_VERSION: u16 = 2;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RunStateSummary {
@@ -25,6 +31,14 @@ pub enum RunStateSummary {
     GameOver,
 }
 
+/// 플레이어가 느끼는 운이다. luckstone 보너스를 더한 luck의 부호만 드러낸다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LuckFeeling {
+    Unlucky,
+    Neutral,
+    Lucky,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PlayerObservation {
     pub entity: EntityId,
@@ -33,10 +47,43 @@ pub struct PlayerObservation {
     pub max_hp: i16,
     pub current_level: LevelId,
     pub hunger: i16,
-    pub luck: i16,
+    /// 수치 luck은 숨기고 enlightenment처럼 느낌만 보여 준다.
+    pub luck: LuckFeeling,
     pub prayer_cooldown: u16,
     pub paralysis_turns: u8,
     pub hallucinating: bool,
+    #[serde(default)]
+    pub experience_level: u8,
+    /// 허기 등 일시적 감소가 반영된 현재 힘이다.
+    #[serde(default)]
+    pub strength: u8,
+    #[serde(default)]
+    pub dexterity: u8,
+    #[serde(default)]
+    pub confusion_turns: u16,
+    #[serde(default)]
+    pub blindness_turns: u16,
+    #[serde(default)]
+    pub very_fast_turns: u16,
+    #[serde(default)]
+    pub sees_invisible: bool,
+    #[serde(default)]
+    pub telepathic: bool,
+    /// 영구 intrinsic 속도다. 일시적인 속도는 `very_fast_turns`가 보여 준다.
+    #[serde(default)]
+    pub fast: bool,
+    #[serde(default)]
+    pub encumbrance: Encumbrance,
+    #[serde(default)]
+    pub food_poisoning_turns: u16,
+    /// 기도 결과를 정하는 alignment record다.
+    #[serde(default)]
+    pub alignment: i16,
+    #[serde(default)]
+    pub god_anger: u8,
+    /// 아직 쓰지 않은 소원 수다. 0보다 크면 `Wish` 명령이 열린다.
+    #[serde(default)]
+    pub pending_wishes: u8,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -46,6 +93,12 @@ pub struct EntityObservation {
     pub pos: Pos,
     pub hp: Option<i16>,
     pub alive: bool,
+    /// 눈으로 보이는 몬스터가 잠들어 있다. 텔레파시로만 느끼는 몬스터는 늘 `false`다.
+    #[serde(default)]
+    pub asleep: bool,
+    /// 눈으로 보이는 몬스터가 달아나는 중이다.
+    #[serde(default)]
+    pub fleeing: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -63,6 +116,15 @@ pub struct Observation {
     pub player: PlayerObservation,
     pub player_pos: Pos,
     pub visible_tiles: Vec<TileObservation>,
+    /// 지금은 보이지 않지만 전에 본 현재 층 칸이다. `visible`은 언제나 false다.
+    #[serde(default)]
+    pub remembered_tiles: Vec<TileObservation>,
+    /// 현재 층에서 마지막으로 본 바닥 물건이다. 보이는 칸은 지금 모습과 같다.
+    #[serde(default)]
+    pub remembered_objects: Vec<RememberedObjectObservation>,
+    /// 시야 밖 칸에서 마지막으로 본 몬스터다. 지금 보이는 몬스터는 `visible_entities`에 있다.
+    #[serde(default)]
+    pub remembered_monsters: Vec<RememberedMonsterObservation>,
     pub visible_entities: Vec<EntityObservation>,
     pub inventory: Vec<ItemObservation>,
     pub last_events: Vec<GameEvent>,
@@ -70,6 +132,30 @@ pub struct Observation {
     pub legal_actions: Vec<CommandIntent>,
 }
 
+impl Observation {
+    /// `Travel { target }`가 지금 걸을 길의 칸들이다. 보이거나 기억하는 칸만으로 runtime과 같은
+    /// [`aihack_core::movement::find_travel_path`]를 풀므로, UI가 상태를 바꾸지 않고 미리 보일 수 있다.
+    pub fn travel_route(&self, target: Pos) -> Option<Vec<Pos>> {
+        let known = self
+            .visible_tiles
+            .iter()
+            .chain(&self.remembered_tiles)
+            .map(|tile| (tile.pos, tile.tile))
+            .collect::<HashMap<_, _>>();
+        let path = aihack_core::movement::find_travel_path(self.player_pos, target, |pos| {
+            known.get(&pos).copied()
+        })?;
+        Some(
+            path.into_iter()
+                .scan(self.player_pos, |pos, direction| {
+                    *pos = pos.offset(direction.delta());
+                    Some(*pos)
+                })
+                .collect(),
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ItemObservation {
     pub item: EntityId,
@@ -77,6 +163,17 @@ pub struct ItemObservation {
     pub letter: InventoryLetter,
     pub equipped_slot: Option<EquipmentSlot>,
     pub identified: bool,
+    #[serde(default)]
+    pub buc: BucStatus,
+    #[serde(default)]
+    pub diluted: bool,
+    #[serde(default)]
+    pub enchantment: i8,
+    #[serde(default)]
+    pub erosion: u8,
+    /// 여러 턴에 걸쳐 먹던 시체를 먹다 멈춘 상태다.
+    #[serde(default)]
+    pub partly_eaten: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -85,4 +182,19 @@ pub struct TileObservation {
     pub rel: Delta,
     pub tile: TileKind,
     pub visible: bool,
+    /// 지도 조명이나 광원으로 밝은 칸인지 여부다. 어두운 칸은 곁에 있을 때만 보인다.
+    #[serde(default)]
+    pub lit: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RememberedObjectObservation {
+    pub pos: Pos,
+    pub kind: ItemKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RememberedMonsterObservation {
+    pub pos: Pos,
+    pub kind: MonsterKind,
 }
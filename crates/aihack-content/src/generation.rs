@@ -0,0 +1,155 @@
+//! 무작위 몬스터·아이템 생성 표다.
+//!
+//! NetHack `makemon.c`의 `rndmonst`/`m_initgrp`와 `mkobj.c`의 `mkobjprobs`/`oc_prob`를 content
+//! 선언으로 옮겼다. 몬스터는 `monsters.toml`의 `frequency`와 `generation` 플래그로, 아이템은
+//! `items.toml`의 `[[class]]` 확률과 항목별 `probability`로 고른다.
+
+use aihack_core::{
+    domain::{
+        combat::rn2,
+        item::{ItemClass, ItemKind},
+        monster::MonsterKind,
+    },
+    rng::GameRng,
+};
+
+use crate::{item_class_from_name, ContentRegistry, MonsterData};
+
+/// `generation`에 쓸 수 있는 플래그다. NetHack `G_NOGEN`/`G_HELL`/`G_NOHELL`/`G_SGROUP`/`G_LGROUP`과
+/// 같다.
+pub const GENERATION_FLAGS: [&str; 5] = [
+    "no_gen",
+    "hell_only",
+    "no_hell",
+    "small_group",
+    "large_group",
+];
+
+/// 무작위 몬스터를 고르는 자리의 난이도다. NetHack `level_difficulty`처럼 던전 깊이를 쓴다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelDifficulty {
+    pub depth: i16,
+    pub experience_level: u8,
+    /// 게헨놈 안이다. `hell_only` 몬스터는 여기서만, `no_hell` 몬스터는 여기 밖에서만 나온다.
+    pub in_hell: bool,
+}
+
+impl LevelDifficulty {
+    /// 나올 수 있는 몬스터 난이도의 하한이다. NetHack `monmin_difficulty`(`zlevel / 6`)다.
+    pub fn min_monster_difficulty(self) -> i16 {
+        self.depth / 6
+    }
+
+    /// 나올 수 있는 몬스터 난이도의 상한이다. NetHack `monmax_difficulty`
+    /// (`(zlevel + u.ulevel) / 2`)다.
+    pub fn max_monster_difficulty(self) -> i16 {
+        (self.depth + i16::from(self.experience_level)) / 2
+    }
+
+    fn allows(self, monster: &MonsterData) -> bool {
+        let has = |flag: &str| monster.generation.iter().any(|entry| entry == flag);
+        monster.frequency > 0
+            && !has("no_gen")
+            && !(self.in_hell && has("no_hell"))
+            && (self.in_hell || !has("hell_only"))
+            && (self.min_monster_difficulty()..=self.max_monster_difficulty())
+                .contains(&monster.difficulty)
+    }
+}
+
+impl ContentRegistry {
+    /// 난이도 범위 안에서 무작위로 나올 수 있는 몬스터를 `frequency` 비율로 고른다. 나올 몬스터가
+    /// 없으면 `None`이다.
+    pub fn select_monster(
+        &self,
+        rng: &mut GameRng,
+        difficulty: LevelDifficulty,
+    ) -> Option<MonsterKind> {
+        let candidates = self
+            .monsters()
+            .filter(|monster| difficulty.allows(monster))
+            .map(|monster| (monster, u32::from(monster.frequency)));
+        pick_weighted(rng, candidates).map(|monster| MonsterKind::intern(&monster.id))
+    }
+
+    /// 무작위로 만든 몬스터와 함께 나오는 무리의 수다. NetHack `makemon`처럼 `small_group`은 1/2로
+    /// `rnd(3)`, `large_group`은 2/3로 `rnd(10)`, 나머지 1/3은 `rnd(3)`을 굴린 뒤 경험 레벨 3 미만이면
+    /// 4로, 5 미만이면 2로 나누고 적어도 1이다(`m_initgrp`). 무리가 아니면 0이다.
+    pub fn monster_group_size(
+        &self,
+        rng: &mut GameRng,
+        kind: MonsterKind,
+        experience_level: u8,
+    ) -> u8 {
+        let Some(monster) = self.monster(kind.content_id()) else {
+            return 0;
+        };
+        let has = |flag: &str| monster.generation.iter().any(|entry| entry == flag);
+        let size = if has("small_group") && rn2(rng, 2) == 1 {
+            3
+        } else if has("large_group") {
+            if rn2(rng, 3) == 0 {
+                3
+            } else {
+                10
+            }
+        } else {
+            return 0;
+        };
+        let count = (rn2(rng, size) + 1)
+            / match experience_level {
+                0..=2 => 4,
+                3..=4 => 2,
+                _ => 1,
+            };
+        count.max(1) as u8
+    }
+
+    /// 무작위 아이템을 고른다. `[[class]]` 확률로 종류를 고르고 그 안에서 `probability`로 고른다.
+    /// NetHack `mkobj(RANDOM_CLASS)`와 같으며, 나올 아이템이 없는 종류는 빼고 고른다.
+    pub fn select_item(&self, rng: &mut GameRng) -> Option<ItemKind> {
+        let classes = self
+            .item_classes()
+            .filter_map(|class| {
+                let kind = item_class_from_name(&class.kind)?;
+                self.generated_items(kind)
+                    .next()
+                    .map(|_| (kind, u32::from(class.probability)))
+            })
+            .collect::<Vec<_>>();
+        let class = pick_weighted(rng, classes)?;
+        self.select_item_of_class(rng, class)
+    }
+
+    /// 한 종류 안에서 `probability` 비율로 아이템을 고른다. NetHack `mkobj(class)`와 같다.
+    pub fn select_item_of_class(&self, rng: &mut GameRng, class: ItemClass) -> Option<ItemKind> {
+        let candidates = self
+            .generated_items(class)
+            .map(|item| (item, u32::from(item.probability)));
+        pick_weighted(rng, candidates).map(|item| ItemKind::intern(&item.id))
+    }
+
+    fn generated_items(&self, class: ItemClass) -> impl Iterator<Item = &crate::ItemData> {
+        self.items().filter(move |item| {
+            item.probability > 0 && item_class_from_name(&item.kind) == Some(class)
+        })
+    }
+}
+
+/// 무게에 비례해 하나를 고른다. NetHack이 `rnd(총합)`에서 앞부터 빼 나가는 방식과 같다.
+fn pick_weighted<T>(rng: &mut GameRng, entries: impl IntoIterator<Item = (T, u32)>) -> Option<T> {
+    let entries = entries.into_iter().collect::<Vec<_>>();
+    let total = entries.iter().map(|(_, weight)| *weight).sum::<u32>();
+    if total == 0 {
+        return None;
+    }
+    let mut roll = (rng.next_u64() % u64::from(total)) as u32;
+    entries.into_iter().find_map(|(entry, weight)| {
+        if roll < weight {
+            Some(entry)
+        } else {
+            roll -= weight;
+            None
+        }
+    })
+}
@@ -8,20 +8,32 @@ pub mod core {
     }
 }
 
+pub mod generation;
 pub mod schema;
 
-use std::sync::OnceLock;
+use std::{collections::BTreeMap, sync::OnceLock};
 
 pub use schema::{
-    ContentRegistry, DoorData, HiddenDoorData, HiddenTrapData, ItemData, LevelData, LevelItemData,
-    LevelMonsterData, MonsterData, WallData, CONTENT_SCHEMA_VERSION,
+    ContentRegistry, CorpseData, CorpseEffectData, DoorData, FeatureData, HiddenDoorData,
+    HiddenTrapData, ItemClassData, ItemData, LevelData, LevelGoldData, LevelItemData,
+    LevelMonsterData, MonsterAttackData, MonsterData, MonsterPassiveData, PotionMixData,
+    ScrollData, ScrollEffectData, TerrainData, TrapData, UnlockData, WallData,
+    CONTENT_SCHEMA_VERSION,
 };
 
 use aihack_core::domain::map::MapLayout;
 use aihack_core::domain::{
     combat::{AttackProfile, DamageRoll},
-    item::{ConsumableEffect, ItemClass, ItemData as CoreItemData, ItemKind, WandEffect},
-    monster::{MonsterAiKind, MonsterKind, MonsterPassive, MonsterTemplate},
+    item::{
+        ConsumableEffect, CorpseEffects, IntrinsicGrant, ItemClass, ItemData as CoreItemData,
+        ItemKind, Material, UnlockTool, WandEffect,
+    },
+    kind::{GlyphColor, KindInfo},
+    monster::{
+        AttackType, CorpseTemplate, DamageType, MonsterAiKind, MonsterAttack, MonsterAttacks,
+        MonsterKind, MonsterPassive, MonsterTemplate, RangedAttack,
+    },
+    status::Intrinsic,
     tile::{DoorState, TileKind, TrapKind},
 };
 use aihack_core::error::ContentError;
@@ -76,11 +88,7 @@ pub fn monster_template_from_registry(
     kind: MonsterKind,
     registry: &ContentRegistry,
 ) -> Result<MonsterTemplate, ContentError> {
-    let id = match kind {
-        MonsterKind::Jackal => "monster.jackal",
-        MonsterKind::Goblin => "monster.goblin",
-        MonsterKind::FloatingEye => "monster.floating_eye",
-    };
+    let id = kind.content_id();
     let definition = registry
         .monster(id)
         .ok_or_else(|| ContentError::UnknownReference {
@@ -98,22 +106,33 @@ pub fn monster_template_from_registry(
             })
         }
     };
-    let damage = parse_damage(&definition.damage)?;
-    let passive = match definition.passive.as_deref() {
-        Some("paralyze_on_melee") => Some(MonsterPassive::ParalyzeOnMelee),
-        None => None,
-        Some(value) => {
-            return Err(ContentError::UnknownReference {
-                owner: id.to_owned(),
-                target: value.to_owned(),
+    let attacks = definition
+        .attacks
+        .iter()
+        .map(|attack| monster_attack(id, attack))
+        .collect::<Result<Vec<_>, _>>()?;
+    let passive = definition
+        .passive
+        .as_ref()
+        .map(|passive| {
+            Ok::<_, ContentError>(MonsterPassive {
+                damage_type: damage_type(id, Some(&passive.damage_type))?,
+                damage: parse_damage(&passive.damage)?,
             })
-        }
-    };
-    let name = match kind {
-        MonsterKind::Jackal => "bite",
-        MonsterKind::Goblin => "short sword",
-        MonsterKind::FloatingEye => "gaze",
-    };
+        })
+        .transpose()?;
+    let ranged = attacks.iter().find_map(|attack| match attack.attack_type {
+        AttackType::Weapon => Some(RangedAttack::Weapon),
+        AttackType::Spit => Some(RangedAttack::Spit {
+            damage: attack.damage,
+            damage_type: attack.damage_type,
+        }),
+        AttackType::Breath => Some(RangedAttack::Breath {
+            damage: attack.damage,
+            damage_type: attack.damage_type,
+        }),
+        _ => None,
+    });
     Ok(MonsterTemplate {
         kind,
         ai_kind,
@@ -121,10 +140,165 @@ pub fn monster_template_from_registry(
         ac: definition.ac,
         hit_bonus: definition.hit_bonus,
         damage_bonus: 0,
-        attack_profile: AttackProfile::natural(name, damage),
+        attacks: MonsterAttacks::from_slice(&attacks),
         speed: definition.speed,
         passive,
+        opens_doors: definition.opens_doors,
+        ranged,
         difficulty: definition.difficulty as u16,
+        corpse: definition
+            .corpse
+            .as_ref()
+            .map(|corpse| corpse_template(id, corpse))
+            .transpose()?,
+    })
+}
+
+fn monster_attack(id: &str, attack: &MonsterAttackData) -> Result<MonsterAttack, ContentError> {
+    let attack_type = attack_type_from_name(&attack.attack_type).ok_or_else(|| {
+        ContentError::UnknownReference {
+            owner: id.to_owned(),
+            target: attack.attack_type.clone(),
+        }
+    })?;
+    Ok(MonsterAttack {
+        attack_type,
+        damage_type: damage_type(id, attack.damage_type.as_deref())?,
+        damage: parse_damage(&attack.damage)?,
+    })
+}
+
+fn damage_type(id: &str, name: Option<&str>) -> Result<DamageType, ContentError> {
+    let Some(name) = name else {
+        return Ok(DamageType::Physical);
+    };
+    damage_type_from_name(name).ok_or_else(|| ContentError::UnknownReference {
+        owner: id.to_owned(),
+        target: name.to_owned(),
+    })
+}
+
+/// 몬스터 content의 공격 수단 이름이다.
+pub fn attack_type_from_name(name: &str) -> Option<AttackType> {
+    Some(match name {
+        "bite" => AttackType::Bite,
+        "claw" => AttackType::Claw,
+        "touch" => AttackType::Touch,
+        "gaze" => AttackType::Gaze,
+        "weapon" => AttackType::Weapon,
+        "engulf" => AttackType::Engulf,
+        "breath" => AttackType::Breath,
+        "spit" => AttackType::Spit,
+        _ => return None,
+    })
+}
+
+/// 몬스터 content의 피해 유형 이름이다.
+pub fn damage_type_from_name(name: &str) -> Option<DamageType> {
+    Some(match name {
+        "physical" => DamageType::Physical,
+        "fire" => DamageType::Fire,
+        "poison" => DamageType::Poison,
+        "drain" => DamageType::Drain,
+        "stick" => DamageType::Stick,
+        "steal" => DamageType::Steal,
+        "paralyze" => DamageType::Paralyze,
+        _ => return None,
+    })
+}
+
+/// item content의 `kind` 이름이다. `gem`은 돌 종류다.
+pub fn item_class_from_name(name: &str) -> Option<ItemClass> {
+    Some(match name {
+        "weapon" => ItemClass::Weapon,
+        "food" => ItemClass::Food,
+        "potion" => ItemClass::Potion,
+        "wand" => ItemClass::Wand,
+        "scroll" => ItemClass::Scroll,
+        "armor" => ItemClass::Armor,
+        "gem" => ItemClass::Rock,
+        "tool" => ItemClass::Tool,
+        "ring" => ItemClass::Ring,
+        "coin" => ItemClass::Coin,
+        _ => return None,
+    })
+}
+
+/// Content intrinsic 이름을 core intrinsic으로 바꾼다.
+pub fn intrinsic_from_name(name: &str) -> Option<Intrinsic> {
+    Some(match name {
+        "poison_resistance" => Intrinsic::PoisonResistance,
+        "fire_resistance" => Intrinsic::FireResistance,
+        "telepathy" => Intrinsic::Telepathy,
+        "fast" => Intrinsic::Fast,
+        "stealth" => Intrinsic::Stealth,
+        _ => return None,
+    })
+}
+
+fn corpse_template(id: &str, corpse: &CorpseData) -> Result<CorpseTemplate, ContentError> {
+    let mut effects = CorpseEffects::default();
+    for effect in corpse.effects.as_deref().unwrap_or_default() {
+        match effect {
+            CorpseEffectData::Poisonous => effects.poisonous = true,
+            CorpseEffectData::Acidic => effects.acidic = true,
+            CorpseEffectData::Petrifying => effects.petrifying = true,
+            CorpseEffectData::Intrinsic { intrinsic, chance } => {
+                let intrinsic = intrinsic_from_name(intrinsic).ok_or_else(|| {
+                    ContentError::UnknownReference {
+                        owner: id.to_owned(),
+                        target: intrinsic.clone(),
+                    }
+                })?;
+                effects.intrinsic = Some(IntrinsicGrant {
+                    intrinsic,
+                    chance: *chance,
+                });
+            }
+        }
+    }
+    Ok(CorpseTemplate {
+        weight: corpse.weight,
+        nutrition: corpse.nutrition,
+        leave_chance: corpse.chance,
+        effects,
+    })
+}
+
+/// 시체 item data는 items.toml이 아니라 몬스터의 `[monster.corpse]` 선언에서 만든다.
+fn corpse_item_data(
+    kind: ItemKind,
+    monster: MonsterKind,
+    registry: &ContentRegistry,
+) -> Result<CoreItemData, ContentError> {
+    let corpse = monster_template_from_registry(monster, registry)?
+        .corpse
+        .ok_or_else(|| ContentError::UnknownReference {
+            owner: "item factory".to_owned(),
+            target: kind.content_id().to_owned(),
+        })?;
+    Ok(CoreItemData {
+        kind,
+        class: ItemClass::Corpse,
+        glyph: '%',
+        weight: corpse.weight,
+        base_price: 0,
+        ac_bonus: 0,
+        material: None,
+        attack_profile: None,
+        consumable_effect: None,
+        confused_effect: None,
+        wand_effect: None,
+        max_charges: None,
+        nutrition: Some(corpse.nutrition),
+        corpse: Some(corpse.effects),
+        confers_luck: false,
+        break_luck: 0,
+        light_radius: 0,
+        unlock: None,
+        digs: false,
+        delay: 0,
+        launcher: None,
     })
 }
 
@@ -145,44 +319,109 @@ fn parse_damage(value: &str) -> Result<DamageRoll, ContentError> {
     Ok(DamageRoll::new(dice, sides))
 }
 
+/// registry가 아는 모든 아이템 종류다. 몬스터가 선언한 시체도 들어가며, 규칙 코드가 상수로 쓰는
+/// 종류가 선언 순서대로 먼저 오고 content에만 있는 종류가 뒤에 온다.
+pub fn item_kinds(registry: &ContentRegistry) -> Vec<ItemKind> {
+    let mut kinds = registry
+        .items()
+        .map(|item| ItemKind::intern(&item.id))
+        .chain(
+            registry
+                .monsters()
+                .filter(|monster| monster.corpse.is_some())
+                .map(|monster| ItemKind::intern(&corpse_content_id(&monster.id))),
+        )
+        .collect::<Vec<_>>();
+    kinds.sort();
+    kinds
+}
+
+/// 몬스터 시체의 item content id다. `monster.jackal`의 시체는 `item.corpse.jackal`이다.
+fn corpse_content_id(monster_id: &str) -> String {
+    format!(
+        "item.corpse.{}",
+        monster_id.strip_prefix("monster.").unwrap_or(monster_id)
+    )
+}
+
+/// 검증한 content id에 종류 번호를 붙이고 그 이름과 글자, 색을 모은다. 시체는 몬스터 이름에
+/// ` corpse`를 붙이고 몬스터와 같은 색의 `%`로 그린다.
+pub(crate) fn kind_info(
+    items: &BTreeMap<String, ItemData>,
+    monsters: &BTreeMap<String, MonsterData>,
+) -> (
+    BTreeMap<ItemKind, KindInfo>,
+    BTreeMap<MonsterKind, KindInfo>,
+) {
+    let info = |name: &str, glyph: &str, color: Option<&str>| KindInfo {
+        name: name.to_owned(),
+        glyph: glyph.chars().next().unwrap_or('?'),
+        color: color.and_then(GlyphColor::from_name).unwrap_or_default(),
+    };
+    let mut item_info = items
+        .values()
+        .map(|item| {
+            let kind = ItemKind::intern(&item.id);
+            (kind, info(&item.name, &item.glyph, item.color.as_deref()))
+        })
+        .collect::<BTreeMap<_, _>>();
+    let mut monster_info = BTreeMap::new();
+    for monster in monsters.values() {
+        let kind = MonsterKind::intern(&monster.id);
+        let monster_kind = info(&monster.name, &monster.glyph, monster.color.as_deref());
+        if monster.corpse.is_some() {
+            item_info.insert(
+                ItemKind::intern(&corpse_content_id(&monster.id)),
+                KindInfo {
+                    name: format!("{} corpse", monster.name),
+                    glyph: '%',
+                    color: monster_kind.color,
+                },
+            );
+        }
+        monster_info.insert(kind, monster_kind);
+    }
+    (item_info, monster_info)
+}
+
+/// 두 물약의 혼합 결과 content id를 찾는다. 혼합표는 양방향으로 조회한다.
+pub fn potion_mix_result<'a>(
+    registry: &'a ContentRegistry,
+    first: &str,
+    second: &str,
+) -> Option<&'a str> {
+    let lookup = |owner: &str, other: &str| {
+        registry
+            .item(owner)?
+            .mix
+            .as_deref()?
+            .iter()
+            .find(|mix| mix.with == other)
+            .map(|mix| mix.result.as_str())
+    };
+    lookup(first, second).or_else(|| lookup(second, first))
+}
+
 /// Registry definition을 core가 소비하는 item data로 변환한다.
 pub fn item_data_from_registry(
     kind: ItemKind,
     registry: &ContentRegistry,
 ) -> Result<CoreItemData, ContentError> {
-    let id = match kind {
-        ItemKind::Dagger => "item.weapon.dagger",
-        ItemKind::FoodRation => "item.food.ration",
-        ItemKind::PotionHealing => "item.potion.healing",
-        ItemKind::WandMagicMissile => "item.wand.magic_missile",
-        ItemKind::ScrollReveal => "item.scroll.reveal",
-        ItemKind::ScrollIdentify => "item.scroll.identify",
-        ItemKind::ScrollLevelTeleport => "item.scroll.teleport",
-        ItemKind::Rock => "item.weapon.rock",
-        ItemKind::ArmorLeather => "item.armor.leather",
-        ItemKind::CorpseJackal => "item.corpse.jackal",
-    };
+    if let Some(monster) = kind.corpse_of() {
+        return corpse_item_data(kind, monster, registry);
+    }
+    let id = kind.content_id();
     let definition = registry
         .item(id)
         .ok_or_else(|| ContentError::UnknownReference {
             owner: "item factory".to_owned(),
             target: id.to_owned(),
         })?;
-    let class = match definition.kind.as_str() {
-        "weapon" => ItemClass::Weapon,
-        "food" => ItemClass::Food,
-        "potion" => ItemClass::Potion,
-        "wand" => ItemClass::Wand,
-        "scroll" => ItemClass::Scroll,
-        "armor" => ItemClass::Armor,
-        "corpse" => ItemClass::Corpse,
-        other => {
-            return Err(ContentError::UnknownReference {
-                owner: id.to_owned(),
-                target: other.to_owned(),
-            })
-        }
-    };
+    let class =
+        item_class_from_name(&definition.kind).ok_or_else(|| ContentError::UnknownReference {
+            owner: id.to_owned(),
+            target: definition.kind.clone(),
+        })?;
     let glyph = definition
         .glyph
         .chars()
@@ -195,28 +434,30 @@ pub fn item_data_from_registry(
         .damage
         .as_deref()
         .map(|value| {
-            let name = match kind {
-                ItemKind::Dagger => "dagger",
-                ItemKind::Rock => "rock",
-                _ => "weapon",
-            };
             Ok(AttackProfile {
-                name,
+                name: "",
                 hit_bonus: definition.hit_bonus.unwrap_or_default(),
                 damage: parse_damage(value)?,
+                damage_bonus: 0,
             })
         })
         .transpose()?;
     let consumable_effect = match definition.effect.as_deref() {
-        Some("heal_1d8_plus_4") => Some(ConsumableEffect::Heal {
-            dice: 1,
-            sides: 8,
-            bonus: 4,
-        }),
-        Some("reveal") => Some(ConsumableEffect::RevealLevel),
-        Some("identify") => Some(ConsumableEffect::IdentifySingle),
-        Some("teleport") => Some(ConsumableEffect::LevelTeleport),
-        Some("magic_missile") | None => None,
+        Some(effect) if effect.starts_with("heal_") => Some(parse_heal_effect(id, effect)?),
+        Some("gain_level") => Some(ConsumableEffect::GainLevel),
+        Some("confusion") => Some(ConsumableEffect::Confusion),
+        Some("blindness") => Some(ConsumableEffect::Blindness),
+        Some("sleeping") => Some(ConsumableEffect::Sleeping),
+        Some("see_invisible") => Some(ConsumableEffect::SeeInvisible),
+        Some("speed") => Some(ConsumableEffect::Speed),
+        Some("sickness") => Some(ConsumableEffect::Sickness),
+        Some("water") => Some(ConsumableEffect::Water),
+        Some("magic_missile" | "digging") => None,
+        None => definition
+            .scroll
+            .as_ref()
+            .map(|scroll| scroll_effect(&scroll.effect))
+            .transpose()?,
         Some(effect) => {
             return Err(ContentError::UnknownReference {
                 owner: id.to_owned(),
@@ -235,12 +476,110 @@ pub fn item_data_from_registry(
         weight: definition.weight,
         base_price: definition.base_price.unwrap_or_default() as u32,
         ac_bonus: definition.ac_bonus.unwrap_or_default(),
+        material: definition
+            .material
+            .as_deref()
+            .map(|material| match material {
+                "iron" => Ok(Material::Iron),
+                "leather" => Ok(Material::Leather),
+                "mineral" => Ok(Material::Mineral),
+                other => Err(ContentError::UnknownReference {
+                    owner: id.to_owned(),
+                    target: other.to_owned(),
+                }),
+            })
+            .transpose()?,
         attack_profile,
         consumable_effect,
-        wand_effect: (definition.effect.as_deref() == Some("magic_missile"))
-            .then_some(WandEffect::MagicMissile),
+        confused_effect: definition
+            .scroll
+            .as_ref()
+            .and_then(|scroll| scroll.confused.as_ref())
+            .map(scroll_effect)
+            .transpose()?,
+        wand_effect: match definition.effect.as_deref() {
+            Some("magic_missile") => Some(WandEffect::MagicMissile),
+            Some("digging") => Some(WandEffect::Digging),
+            _ => None,
+        },
         max_charges: definition.charges,
         nutrition: definition.nutrition,
+        corpse: None,
+        confers_luck: definition.confers_luck.unwrap_or_default(),
+        break_luck: definition.break_luck.unwrap_or_default(),
+        light_radius: definition.light_radius.unwrap_or_default(),
+        unlock: definition.unlock.as_ref().map(|unlock| UnlockTool {
+            base_chance: unlock.base_chance,
+            dexterity_multiplier: unlock.dexterity_multiplier,
+            can_lock: unlock.can_lock,
+        }),
+        digs: definition.digs.unwrap_or_default(),
+        delay: definition.delay.unwrap_or_default(),
+        launcher: definition
+            .launcher
+            .as_deref()
+            .map(|launcher| {
+                ItemKind::from_content_id(launcher).ok_or_else(|| ContentError::UnknownReference {
+                    owner: id.to_owned(),
+                    target: launcher.to_owned(),
+                })
+            })
+            .transpose()?,
+    })
+}
+
+/// 두루마리 효과 선언을 core effect로 변환한다.
+fn scroll_effect(effect: &ScrollEffectData) -> Result<ConsumableEffect, ContentError> {
+    Ok(match effect {
+        ScrollEffectData::RevealLevel => ConsumableEffect::RevealLevel,
+        ScrollEffectData::Identify => ConsumableEffect::IdentifySingle,
+        ScrollEffectData::IdentifySelf => ConsumableEffect::IdentifySource,
+        ScrollEffectData::Teleport => ConsumableEffect::Teleport,
+        ScrollEffectData::LevelTeleport => ConsumableEffect::LevelTeleport,
+        ScrollEffectData::EnchantWeapon => ConsumableEffect::EnchantWeapon,
+        ScrollEffectData::EnchantArmor => ConsumableEffect::EnchantArmor,
+        ScrollEffectData::ErodeproofWeapon => ConsumableEffect::ErodeproofWeapon,
+        ScrollEffectData::ErodeproofArmor => ConsumableEffect::ErodeproofArmor,
+        ScrollEffectData::RemoveCurse => ConsumableEffect::RemoveCurse,
+        ScrollEffectData::ScrambleCurses => ConsumableEffect::ScrambleCurses,
+        ScrollEffectData::Fire { damage, radius } => {
+            let damage = parse_damage(damage)?;
+            ConsumableEffect::Fire {
+                dice: damage.dice,
+                sides: damage.sides,
+                radius: *radius,
+            }
+        }
+        ScrollEffectData::Light { radius, lit } => ConsumableEffect::Light {
+            radius: *radius,
+            lit: *lit,
+        },
+        ScrollEffectData::GoldDetection => ConsumableEffect::GoldDetection,
+        ScrollEffectData::TrapDetection => ConsumableEffect::TrapDetection,
+        ScrollEffectData::CreateMonster { count } => {
+            ConsumableEffect::CreateMonster { count: *count }
+        }
+        ScrollEffectData::ScareMonsters => ConsumableEffect::ScareMonsters,
+        ScrollEffectData::RallyMonsters => ConsumableEffect::RallyMonsters,
+    })
+}
+
+/// `heal_<dice>d<sides>_plus_<bonus>` 형식의 회복 효과를 해석한다.
+fn parse_heal_effect(id: &str, effect: &str) -> Result<ConsumableEffect, ContentError> {
+    let invalid = || ContentError::UnknownReference {
+        owner: id.to_owned(),
+        target: effect.to_owned(),
+    };
+    let (roll, bonus) = effect
+        .strip_prefix("heal_")
+        .and_then(|rest| rest.split_once("_plus_"))
+        .ok_or_else(invalid)?;
+    let damage = parse_damage(roll)?;
+    let bonus = bonus.parse().map_err(|_| invalid())?;
+    Ok(ConsumableEffect::Heal {
+        dice: damage.dice,
+        sides: damage.sides,
+        bonus,
     })
 }
 
@@ -278,8 +617,11 @@ pub fn level_tile_overrides(level: &LevelData) -> Result<Vec<(Pos, TileKind)>, C
     }
     for door in level.door.as_deref().unwrap_or_default() {
         let state = match door.state.as_str() {
-            "closed" => DoorState::Closed,
+            "closed" | "random" => DoorState::Closed,
             "open" => DoorState::Open,
+            "locked" => DoorState::Locked,
+            "broken" => DoorState::Broken,
+            "no_door" => DoorState::NoDoor,
             other => {
                 return Err(ContentError::UnknownReference {
                     owner: level.level_id.clone(),
@@ -293,17 +635,50 @@ pub fn level_tile_overrides(level: &LevelData) -> Result<Vec<(Pos, TileKind)>, C
         tiles.push((position(level, &door.pos)?, TileKind::HiddenDoor));
     }
     for trap in level.hidden_trap.as_deref().unwrap_or_default() {
-        if trap.trap != "pit" {
-            return Err(ContentError::UnknownReference {
-                owner: level.level_id.clone(),
-                target: trap.trap.clone(),
-            });
-        }
         tiles.push((
             position(level, &trap.pos)?,
-            TileKind::HiddenTrap(TrapKind::Pit),
+            TileKind::HiddenTrap(level_trap_kind(level, &trap.trap)?),
         ));
     }
+    for trap in level.trap.as_deref().unwrap_or_default() {
+        tiles.push((
+            position(level, &trap.pos)?,
+            TileKind::Trap(level_trap_kind(level, &trap.trap)?),
+        ));
+    }
+    for feature in level.feature.as_deref().unwrap_or_default() {
+        let tile = match feature.kind.as_str() {
+            "altar" => TileKind::Altar,
+            "fountain" => TileKind::Fountain,
+            "sink" => TileKind::Sink,
+            "throne" => TileKind::Throne,
+            "grave" => TileKind::Grave,
+            other => {
+                return Err(ContentError::UnknownReference {
+                    owner: level.level_id.clone(),
+                    target: other.to_owned(),
+                })
+            }
+        };
+        tiles.push((position(level, &feature.pos)?, tile));
+    }
+    for terrain in level.terrain.as_deref().unwrap_or_default() {
+        let tile = match terrain.kind.as_str() {
+            "corridor" => TileKind::Corridor,
+            "pool" => TileKind::Pool,
+            "moat" => TileKind::Moat,
+            "lava" => TileKind::Lava,
+            "tree" => TileKind::Tree,
+            "iron_bars" => TileKind::IronBars,
+            other => {
+                return Err(ContentError::UnknownReference {
+                    owner: level.level_id.clone(),
+                    target: other.to_owned(),
+                })
+            }
+        };
+        tiles.push((position(level, &terrain.pos)?, tile));
+    }
     if let Some(pos) = &level.stairs_down {
         tiles.push((position(level, pos)?, TileKind::StairsDown));
     }
@@ -313,6 +688,32 @@ pub fn level_tile_overrides(level: &LevelData) -> Result<Vec<(Pos, TileKind)>, C
     Ok(tiles)
 }
 
+/// Content 함정 이름을 core trap kind로 바꾼다.
+pub fn trap_kind_from_name(name: &str) -> Option<TrapKind> {
+    Some(match name {
+        "pit" => TrapKind::Pit,
+        "arrow" => TrapKind::Arrow,
+        "dart" => TrapKind::Dart,
+        "bear_trap" => TrapKind::BearTrap,
+        "sleeping_gas" => TrapKind::SleepingGas,
+        "rust" => TrapKind::Rust,
+        "teleport" => TrapKind::Teleport,
+        "trap_door" => TrapKind::TrapDoor,
+        "hole" => TrapKind::Hole,
+        "land_mine" => TrapKind::LandMine,
+        "rolling_boulder" => TrapKind::RollingBoulder,
+        "magic" => TrapKind::Magic,
+        _ => return None,
+    })
+}
+
+fn level_trap_kind(level: &LevelData, name: &str) -> Result<TrapKind, ContentError> {
+    trap_kind_from_name(name).ok_or_else(|| ContentError::UnknownReference {
+        owner: level.level_id.clone(),
+        target: name.to_owned(),
+    })
+}
+
 impl MapLayout for LevelData {
     fn level_id(&self) -> &str {
         &self.level_id
@@ -326,6 +727,41 @@ impl MapLayout for LevelData {
     fn tile_overrides(&self) -> Result<Vec<(Pos, TileKind)>, ContentError> {
         level_tile_overrides(self)
     }
+    fn unlit_positions(&self) -> Result<Vec<Pos>, ContentError> {
+        let mut positions = Vec::new();
+        for region in self.dark_region.as_deref().unwrap_or_default() {
+            let from = position(self, &[region.x_range[0], region.y_range[0]])?;
+            let to = position(self, &[region.x_range[1], region.y_range[1]])?;
+            for y in from.y..=to.y {
+                for x in from.x..=to.x {
+                    positions.push(Pos { x, y });
+                }
+            }
+        }
+        Ok(positions)
+    }
+    fn vision_radius(&self) -> Option<i16> {
+        self.vision_radius
+    }
+    fn undiggable_positions(&self) -> Result<Vec<Pos>, ContentError> {
+        Ok(self
+            .wall
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|wall| wall.undiggable)
+            .flat_map(|wall| (wall.y_range[0]..=wall.y_range[1]).map(move |y| Pos { x: wall.x, y }))
+            .collect())
+    }
+    fn random_doors(&self) -> Result<Vec<Pos>, ContentError> {
+        self.door
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .filter(|door| door.state == "random")
+            .map(|door| position(self, &door.pos))
+            .collect()
+    }
 }
 
 fn position(level: &LevelData, value: &[i16]) -> Result<Pos, ContentError> {
@@ -341,53 +777,56 @@ fn position(level: &LevelData, value: &[i16]) -> Result<Pos, ContentError> {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LevelSpawn {
-    Monster { kind: MonsterKind, pos: Pos },
-    Item { kind: ItemKind, pos: Pos },
+    Monster {
+        kind: MonsterKind,
+        pos: Pos,
+        asleep: bool,
+        peaceful: bool,
+    },
+    Item {
+        kind: ItemKind,
+        pos: Pos,
+    },
+    /// `amount`개가 든 금화 더미다.
+    Gold {
+        pos: Pos,
+        amount: u32,
+    },
 }
 
 /// Validated level reference를 core가 소비하는 typed spawn plan으로 변환한다.
 pub fn level_spawns(level: &LevelData) -> Result<Vec<LevelSpawn>, ContentError> {
     let mut spawns = Vec::new();
     for monster in level.monster.as_deref().unwrap_or_default() {
-        let kind = match monster.id.as_str() {
-            "monster.jackal" => MonsterKind::Jackal,
-            "monster.goblin" => MonsterKind::Goblin,
-            "monster.floating_eye" => MonsterKind::FloatingEye,
-            _ => {
-                return Err(ContentError::UnknownReference {
-                    owner: level.level_id.clone(),
-                    target: monster.id.clone(),
-                })
+        let kind = MonsterKind::from_content_id(&monster.id).ok_or_else(|| {
+            ContentError::UnknownReference {
+                owner: level.level_id.clone(),
+                target: monster.id.clone(),
             }
-        };
+        })?;
         spawns.push(LevelSpawn::Monster {
             kind,
             pos: position(level, &monster.pos)?,
+            asleep: monster.asleep,
+            peaceful: monster.peaceful,
         });
     }
     for item in level.item.as_deref().unwrap_or_default() {
-        let kind = match item.id.as_str() {
-            "item.weapon.dagger" => ItemKind::Dagger,
-            "item.food.ration" => ItemKind::FoodRation,
-            "item.potion.healing" => ItemKind::PotionHealing,
-            "item.wand.magic_missile" => ItemKind::WandMagicMissile,
-            "item.scroll.reveal" => ItemKind::ScrollReveal,
-            "item.scroll.identify" => ItemKind::ScrollIdentify,
-            "item.scroll.teleport" => ItemKind::ScrollLevelTeleport,
-            "item.weapon.rock" => ItemKind::Rock,
-            "item.armor.leather" => ItemKind::ArmorLeather,
-            "item.corpse.jackal" => ItemKind::CorpseJackal,
-            _ => {
-                return Err(ContentError::UnknownReference {
-                    owner: level.level_id.clone(),
-                    target: item.id.clone(),
-                })
-            }
-        };
+        let kind =
+            ItemKind::from_content_id(&item.id).ok_or_else(|| ContentError::UnknownReference {
+                owner: level.level_id.clone(),
+                target: item.id.clone(),
+            })?;
         spawns.push(LevelSpawn::Item {
             kind,
             pos: position(level, &item.pos)?,
         });
     }
+    for gold in level.gold.as_deref().unwrap_or_default() {
+        spawns.push(LevelSpawn::Gold {
+            pos: position(level, &gold.pos)?,
+            amount: gold.amount,
+        });
+    }
     Ok(spawns)
 }
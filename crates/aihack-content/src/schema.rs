@@ -2,6 +2,12 @@ use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use aihack_core::domain::{
+    item::ItemKind,
+    kind::{GlyphColor, KindInfo},
+    monster::{MonsterKind, MAX_ATTACKS},
+};
+
 use crate::core::error::ContentError;
 
 pub const CONTENT_SCHEMA_VERSION: u16 = 1;
@@ -9,8 +15,12 @@ pub const CONTENT_SCHEMA_VERSION: u16 = 1;
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct ItemData {
     pub id: String,
+    /// 화면과 메시지에 쓰는 이름이다.
+    pub name: String,
     pub kind: String,
     pub glyph: String,
+    /// 글자 색이다. NetHack `CLR_*` 이름을 소문자로 쓰며 없으면 gray다.
+    pub color: Option<String>,
     pub weight: i16,
     pub slot: Option<String>,
     pub hit_bonus: Option<i16>,
@@ -20,20 +30,155 @@ pub struct ItemData {
     pub nutrition: Option<i16>,
     pub ac_bonus: Option<i16>,
     pub base_price: Option<i32>,
+    /// 부식 판정용 재질이다. `iron`만 녹슨다.
+    pub material: Option<String>,
+    /// `#dip` 혼합표다. 어느 쪽 물약에 선언해도 양방향으로 적용된다.
+    pub mix: Option<Vec<PotionMixData>>,
+    /// 두루마리 효과 선언이다. `kind = "scroll"`인 항목에만 허용한다.
+    pub scroll: Option<ScrollData>,
+    /// 소지하면 luck을 주는 돌이다.
+    pub confers_luck: Option<bool>,
+    /// 던져서 깨질 때 바뀌는 luck이다.
+    pub break_luck: Option<i16>,
+    /// 켜 두면 주위를 밝히는 반경이다.
+    pub light_radius: Option<i16>,
+    /// 자물쇠를 여닫는 도구 선언이다. `kind = "tool"`인 항목에만 허용한다.
+    pub unlock: Option<UnlockData>,
+    /// 벽과 바닥을 파는 도구다. `kind = "tool"`인 항목에만 허용한다.
+    pub digs: Option<bool>,
+    /// 먹거나 입는 데 더 걸리는 행동 수다. NetHack `oc_delay`와 같고 음식과 갑옷에만 허용한다.
+    pub delay: Option<u8>,
+    /// 제 피해를 내려면 있어야 하는 발사기 item id다. 화살의 활과 같다.
+    pub launcher: Option<String>,
+    /// 같은 종류 안에서 무작위로 나올 상대 확률이다. NetHack `oc_prob`와 같고 0이면 나오지 않는다.
+    #[serde(default)]
+    pub probability: u16,
+}
+
+/// `[[class]]` 표다. 무작위 아이템은 먼저 이 확률로 종류를 고른다. NetHack `mkobjprobs`와 같다.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ItemClassData {
+    /// `[[item]]`의 `kind`와 같은 종류 이름이다.
+    pub kind: String,
+    pub probability: u16,
+}
+
+/// `[item.unlock]` 표다. 성공률은 `base_chance + dexterity_multiplier * 민첩`이다.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct UnlockData {
+    pub base_chance: u8,
+    pub dexterity_multiplier: u8,
+    #[serde(default)]
+    pub can_lock: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ScrollData {
+    pub effect: ScrollEffectData,
+    /// 혼란 상태로 읽었을 때의 효과다. 없으면 평소 효과를 그대로 쓴다.
+    pub confused: Option<ScrollEffectData>,
+}
+
+/// `type` 태그로 구분하는 두루마리 효과 선언이다.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ScrollEffectData {
+    RevealLevel,
+    Identify,
+    IdentifySelf,
+    Teleport,
+    LevelTeleport,
+    EnchantWeapon,
+    EnchantArmor,
+    ErodeproofWeapon,
+    ErodeproofArmor,
+    RemoveCurse,
+    ScrambleCurses,
+    Fire { damage: String, radius: i16 },
+    Light { radius: i16, lit: bool },
+    GoldDetection,
+    TrapDetection,
+    CreateMonster { count: u8 },
+    ScareMonsters,
+    RallyMonsters,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct PotionMixData {
+    pub with: String,
+    pub result: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct MonsterData {
     pub id: String,
+    /// 화면과 메시지에 쓰는 이름이다. 시체는 `<이름> corpse`가 된다.
+    pub name: String,
     pub glyph: String,
+    /// 글자 색이다. 시체도 같은 색이다.
+    pub color: Option<String>,
     pub hp: i16,
     pub ac: i16,
     pub hit_bonus: i16,
-    pub damage: String,
     pub ai: String,
     pub speed: i16,
     pub difficulty: i16,
-    pub passive: Option<String>,
+    /// 무작위 생성 빈도다. NetHack `G_FREQ`처럼 0..=7이고 0이면 무작위로 나오지 않는다.
+    #[serde(default)]
+    pub frequency: u8,
+    /// 무작위 생성 플래그다. 쓸 수 있는 이름은 [`crate::generation::GENERATION_FLAGS`]다.
+    #[serde(default)]
+    pub generation: Vec<String>,
+    /// 곁의 플레이어에게 차례마다 차례로 쓰는 공격이다. 무기·침·숨 공격은 떨어져 있을 때도 쓴다.
+    #[serde(default)]
+    pub attacks: Vec<MonsterAttackData>,
+    /// 근접 공격한 플레이어가 받는 수동 반격이다.
+    pub passive: Option<MonsterPassiveData>,
+    /// 닫힌 문을 열고 지나갈 수 있다. NetHack에서 손이 있고 아주 작지 않은 몬스터다.
+    #[serde(default)]
+    pub opens_doors: bool,
+    /// 없으면 시체를 남기지 않는다.
+    pub corpse: Option<CorpseData>,
+}
+
+/// 몬스터 공격 하나의 선언이다. NetHack `ATTK(AT_*, AD_*, n, d)`와 같다.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MonsterAttackData {
+    /// bite, claw, touch, gaze, weapon, engulf, breath, spit 중 하나다.
+    #[serde(rename = "type")]
+    pub attack_type: String,
+    /// physical, fire, poison, drain, stick, steal, paralyze 중 하나다. 없으면 physical이다.
+    pub damage_type: Option<String>,
+    pub damage: String,
+}
+
+/// 몬스터 수동 반격 선언이다. 마비의 `damage`는 굳는 턴 수다.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MonsterPassiveData {
+    pub damage_type: String,
+    pub damage: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CorpseData {
+    pub weight: i16,
+    pub nutrition: i16,
+    /// 죽을 때 `1/chance` 확률로 시체를 남긴다.
+    pub chance: u8,
+    pub effects: Option<Vec<CorpseEffectData>>,
+}
+
+/// `type` 태그로 구분하는 시체 먹기 효과 선언이다.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum CorpseEffectData {
+    Poisonous,
+    Acidic,
+    Petrifying,
+    Intrinsic { intrinsic: String, chance: u8 },
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -50,14 +195,28 @@ pub struct LevelData {
     pub door: Option<Vec<DoorData>>,
     pub hidden_door: Option<Vec<HiddenDoorData>>,
     pub hidden_trap: Option<Vec<HiddenTrapData>>,
+    /// 처음부터 드러나 있는 함정이다.
+    pub trap: Option<Vec<TrapData>>,
+    /// 제단, 분수 같은 던전 특수 지형이다.
+    pub feature: Option<Vec<FeatureData>>,
+    /// 통로, 물, 용암, 나무, 쇠창살처럼 바닥을 대신하는 지형이다.
+    pub terrain: Option<Vec<TerrainData>>,
+    /// 불이 꺼진 사각 영역이다. 두 범위 모두 양 끝을 포함한다.
+    pub dark_region: Option<Vec<DarkRegionData>>,
+    /// 밝은 칸이라도 이 거리 밖은 보이지 않게 하는 시야 반경이다. 없으면 제한하지 않는다.
+    pub vision_radius: Option<i16>,
     pub monster: Option<Vec<LevelMonsterData>>,
     pub item: Option<Vec<LevelItemData>>,
+    pub gold: Option<Vec<LevelGoldData>>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct WallData {
     pub x: i16,
     pub y_range: Vec<i16>,
+    /// 곡괭이나 파기 지팡이로 뚫을 수 없는 벽이다. NetHack `NON_DIGGABLE`과 같다.
+    #[serde(default)]
+    pub undiggable: bool,
 }
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct DoorData {
@@ -76,19 +235,53 @@ pub struct HiddenTrapData {
     pub tile: String,
 }
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TrapData {
+    pub pos: Vec<i16>,
+    pub trap: String,
+}
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct FeatureData {
+    pub pos: Vec<i16>,
+    pub kind: String,
+}
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct TerrainData {
+    pub pos: Vec<i16>,
+    pub kind: String,
+}
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DarkRegionData {
+    pub x_range: Vec<i16>,
+    pub y_range: Vec<i16>,
+}
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct LevelMonsterData {
     pub id: String,
     pub pos: Vec<i16>,
+    /// 잠든 채로 놓인다. NetHack 특수 레벨 `MONSTER: ..., asleep`과 같다.
+    #[serde(default)]
+    pub asleep: bool,
+    /// 평화로운 채로 놓인다. NetHack 특수 레벨 `MONSTER: ..., peaceful`과 같다.
+    #[serde(default)]
+    pub peaceful: bool,
 }
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct LevelItemData {
     pub id: String,
     pub pos: Vec<i16>,
 }
+/// 바닥에 놓인 금화 더미다. NetHack 특수 레벨 `GOLD: amount, place`와 같다.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct LevelGoldData {
+    pub pos: Vec<i16>,
+    pub amount: u32,
+}
 
 #[derive(Debug, Deserialize)]
 struct ItemsToml {
     item: Vec<ItemData>,
+    #[serde(default)]
+    class: Vec<ItemClassData>,
 }
 #[derive(Debug, Deserialize)]
 struct MonstersToml {
@@ -96,13 +289,17 @@ struct MonstersToml {
 }
 
 /// 정렬된 immutable registry. Runtime은 이 조회 전용 API만 사용한다.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ContentRegistry {
     schema_version: u16,
     content_hash: String,
     items: BTreeMap<String, ItemData>,
+    item_classes: BTreeMap<String, ItemClassData>,
     monsters: BTreeMap<String, MonsterData>,
     levels: BTreeMap<String, LevelData>,
+    /// 이 registry가 선언한 종류의 이름과 글자, 색이다. 시체도 들어간다.
+    item_info: BTreeMap<ItemKind, KindInfo>,
+    monster_info: BTreeMap<MonsterKind, KindInfo>,
 }
 
 impl ContentRegistry {
@@ -137,7 +334,10 @@ impl ContentRegistry {
                 message: format!("unsupported schema_version: {schema_version}"),
             });
         }
-        let items = parse::<ItemsToml>("items.toml", items_toml)?.item;
+        let ItemsToml {
+            item: items,
+            class: item_classes,
+        } = parse::<ItemsToml>("items.toml", items_toml)?;
         let monsters = parse::<MonstersToml>("monsters.toml", monsters_toml)?.monster;
         let levels = level_sources
             .iter()
@@ -145,17 +345,24 @@ impl ContentRegistry {
             .collect::<Result<Vec<_>, _>>()?;
 
         let items = index_by_id(items, |entry| &entry.id)?;
+        let item_classes = index_by_id(item_classes, |entry| &entry.kind)?;
         let monsters = index_by_id(monsters, |entry| &entry.id)?;
         let levels = index_by_id(levels, |entry| &entry.level_id)?;
         validate(&items, &monsters, &levels)?;
+        validate_item_classes(&item_classes)?;
+        let (item_info, monster_info) = crate::kind_info(&items, &monsters);
 
-        let content_hash = canonical_hash(schema_version, &items, &monsters, &levels)?;
+        let content_hash =
+            canonical_hash(schema_version, &items, &item_classes, &monsters, &levels)?;
         Ok(Self {
             schema_version,
             content_hash,
             items,
+            item_classes,
             monsters,
             levels,
+            item_info,
+            monster_info,
         })
     }
 
@@ -177,12 +384,32 @@ impl ContentRegistry {
     pub fn items(&self) -> impl Iterator<Item = &ItemData> {
         self.items.values()
     }
+    pub fn item_classes(&self) -> impl Iterator<Item = &ItemClassData> {
+        self.item_classes.values()
+    }
     pub fn monsters(&self) -> impl Iterator<Item = &MonsterData> {
         self.monsters.values()
     }
     pub fn levels(&self) -> impl Iterator<Item = &LevelData> {
         self.levels.values()
     }
+    /// 이 registry가 선언한 아이템 종류의 표시 정보다. 다른 content의 종류면 `None`이다.
+    pub fn item_info(&self, kind: ItemKind) -> Option<&KindInfo> {
+        self.item_info.get(&kind)
+    }
+    pub fn monster_info(&self, kind: MonsterKind) -> Option<&KindInfo> {
+        self.monster_info.get(&kind)
+    }
+    /// 이 registry가 선언한 아이템 이름이다. 모르는 종류면 content id를 쓴다.
+    pub fn item_name(&self, kind: ItemKind) -> &str {
+        self.item_info(kind)
+            .map_or_else(|| kind.content_id(), |info| &info.name)
+    }
+    /// 이 registry가 선언한 몬스터 이름이다. 모르는 종류면 content id를 쓴다.
+    pub fn monster_name(&self, kind: MonsterKind) -> &str {
+        self.monster_info(kind)
+            .map_or_else(|| kind.content_id(), |info| &info.name)
+    }
 }
 
 fn parse<T: for<'a> Deserialize<'a>>(file: &str, source: &str) -> Result<T, ContentError> {
@@ -212,6 +439,7 @@ fn validate(
     levels: &BTreeMap<String, LevelData>,
 ) -> Result<(), ContentError> {
     for item in items.values() {
+        validate_display("items.toml", &item.id, &item.glyph, item.color.as_deref())?;
         if item.base_price.is_some_and(|price| price < 0) {
             return Err(ContentError::Parse {
                 file: "items.toml".to_owned(),
@@ -221,8 +449,90 @@ fn validate(
         if let Some(damage) = &item.damage {
             validate_dice(damage)?;
         }
+        if let Some(material) = item
+            .material
+            .as_deref()
+            .filter(|material| !matches!(*material, "iron" | "leather" | "mineral"))
+        {
+            return Err(ContentError::UnknownReference {
+                owner: item.id.clone(),
+                target: material.to_owned(),
+            });
+        }
+        if (item.kind == "scroll") != item.scroll.is_some()
+            || (item.scroll.is_some() && item.effect.is_some())
+        {
+            return Err(ContentError::Parse {
+                file: "items.toml".to_owned(),
+                message: format!(
+                    "{} scroll must declare exactly one [item.scroll] table",
+                    item.id
+                ),
+            });
+        }
+        if let Some(scroll) = &item.scroll {
+            for effect in std::iter::once(&scroll.effect).chain(scroll.confused.as_ref()) {
+                match effect {
+                    ScrollEffectData::Fire { damage, radius } => {
+                        validate_dice(damage)?;
+                        validate_radius(&item.id, *radius)?;
+                    }
+                    ScrollEffectData::Light { radius, .. } => validate_radius(&item.id, *radius)?,
+                    ScrollEffectData::CreateMonster { count: 0 } => {
+                        return Err(ContentError::Parse {
+                            file: "items.toml".to_owned(),
+                            message: format!("{} create_monster count must be positive", item.id),
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if let Some(radius) = item.light_radius {
+            validate_radius(&item.id, radius)?;
+        }
+        if item.unlock.is_some() && item.kind != "tool" {
+            return Err(ContentError::Parse {
+                file: "items.toml".to_owned(),
+                message: format!("{} unlock is only allowed on tools", item.id),
+            });
+        }
+        if item.digs.is_some() && item.kind != "tool" {
+            return Err(ContentError::Parse {
+                file: "items.toml".to_owned(),
+                message: format!("{} digs is only allowed on tools", item.id),
+            });
+        }
+        if item.delay.is_some() && !matches!(item.kind.as_str(), "food" | "armor") {
+            return Err(ContentError::Parse {
+                file: "items.toml".to_owned(),
+                message: format!("{} delay is only allowed on food and armor", item.id),
+            });
+        }
+        if item.break_luck.is_some_and(|luck| luck == 0) {
+            return Err(ContentError::Parse {
+                file: "items.toml".to_owned(),
+                message: format!("{} break_luck must be non-zero", item.id),
+            });
+        }
+        for mix in item.mix.as_deref().unwrap_or_default() {
+            for target in [&mix.with, &mix.result] {
+                if items.get(target).is_none_or(|entry| entry.kind != "potion") {
+                    return Err(ContentError::UnknownReference {
+                        owner: item.id.clone(),
+                        target: target.clone(),
+                    });
+                }
+            }
+        }
     }
     for monster in monsters.values() {
+        validate_display(
+            "monsters.toml",
+            &monster.id,
+            &monster.glyph,
+            monster.color.as_deref(),
+        )?;
         if !(0..=12).contains(&monster.speed) {
             return Err(ContentError::Parse {
                 file: "monsters.toml".to_owned(),
@@ -235,10 +545,20 @@ fn validate(
                 message: format!("{} difficulty must be positive", monster.id),
             });
         }
-        validate_dice(&monster.damage)?;
+        validate_attacks(monster)?;
+        validate_generation(monster)?;
+        if let Some(corpse) = &monster.corpse {
+            validate_corpse(&monster.id, corpse)?;
+        }
     }
     for level in levels.values() {
         validate_level_coordinates(level)?;
+        if level.vision_radius.is_some_and(|radius| radius <= 0) {
+            return Err(ContentError::Parse {
+                file: "levels".to_owned(),
+                message: format!("{} vision_radius must be positive", level.level_id),
+            });
+        }
         for entry in level.monster.as_deref().unwrap_or_default() {
             if !monsters.contains_key(&entry.id) {
                 return Err(ContentError::UnknownReference {
@@ -255,6 +575,32 @@ fn validate(
                 });
             }
         }
+        if level
+            .gold
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .any(|entry| entry.amount == 0)
+        {
+            return Err(ContentError::Parse {
+                file: "levels".to_owned(),
+                message: format!("{} gold amount must be positive", level.level_id),
+            });
+        }
+        for name in level_trap_names(level) {
+            let Some(kind) = crate::trap_kind_from_name(name) else {
+                return Err(ContentError::UnknownReference {
+                    owner: level.level_id.clone(),
+                    target: name.to_owned(),
+                });
+            };
+            if kind.is_hole() && !has_level_below(level, levels) {
+                return Err(ContentError::UnknownReference {
+                    owner: level.level_id.clone(),
+                    target: format!("{name} destination below depth {}", level.depth),
+                });
+            }
+        }
         if level.stairs_down.is_some() && !has_paired_up(level, levels) {
             return Err(ContentError::MissingStairsPair {
                 level: level.level_id.clone(),
@@ -269,6 +615,130 @@ fn validate(
     Ok(())
 }
 
+/// 글자는 한 글자여야 하고 색은 아는 이름이어야 한다.
+fn validate_display(
+    file: &str,
+    id: &str,
+    glyph: &str,
+    color: Option<&str>,
+) -> Result<(), ContentError> {
+    if glyph.chars().count() != 1 {
+        return Err(ContentError::Parse {
+            file: file.to_owned(),
+            message: format!("{id} glyph must contain one character"),
+        });
+    }
+    if let Some(color) = color.filter(|color| GlyphColor::from_name(color).is_none()) {
+        return Err(ContentError::UnknownReference {
+            owner: id.to_owned(),
+            target: color.to_owned(),
+        });
+    }
+    Ok(())
+}
+
+fn validate_generation(monster: &MonsterData) -> Result<(), ContentError> {
+    if monster.frequency > 7 {
+        return Err(ContentError::Parse {
+            file: "monsters.toml".to_owned(),
+            message: format!("{} frequency must be in 0..=7", monster.id),
+        });
+    }
+    if let Some(flag) = monster
+        .generation
+        .iter()
+        .find(|flag| !crate::generation::GENERATION_FLAGS.contains(&flag.as_str()))
+    {
+        return Err(ContentError::UnknownReference {
+            owner: monster.id.clone(),
+            target: flag.clone(),
+        });
+    }
+    let has = |flag: &str| monster.generation.iter().any(|entry| entry == flag);
+    if (has("hell_only") && has("no_hell")) || (has("small_group") && has("large_group")) {
+        return Err(ContentError::Parse {
+            file: "monsters.toml".to_owned(),
+            message: format!("{} has conflicting generation flags", monster.id),
+        });
+    }
+    Ok(())
+}
+
+fn validate_item_classes(classes: &BTreeMap<String, ItemClassData>) -> Result<(), ContentError> {
+    for class in classes.values() {
+        if crate::item_class_from_name(&class.kind).is_none() {
+            return Err(ContentError::UnknownReference {
+                owner: "items.toml class".to_owned(),
+                target: class.kind.clone(),
+            });
+        }
+        if class.probability == 0 {
+            return Err(ContentError::Parse {
+                file: "items.toml".to_owned(),
+                message: format!("{} class probability must be positive", class.kind),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn validate_attacks(monster: &MonsterData) -> Result<(), ContentError> {
+    if monster.attacks.len() > MAX_ATTACKS {
+        return Err(ContentError::Parse {
+            file: "monsters.toml".to_owned(),
+            message: format!("{} has more than {MAX_ATTACKS} attacks", monster.id),
+        });
+    }
+    let unknown = |target: &str| ContentError::UnknownReference {
+        owner: monster.id.clone(),
+        target: target.to_owned(),
+    };
+    for attack in &monster.attacks {
+        crate::attack_type_from_name(&attack.attack_type)
+            .ok_or_else(|| unknown(&attack.attack_type))?;
+        if let Some(name) = attack.damage_type.as_deref() {
+            crate::damage_type_from_name(name).ok_or_else(|| unknown(name))?;
+        }
+        validate_dice(&attack.damage)?;
+    }
+    if let Some(passive) = &monster.passive {
+        crate::damage_type_from_name(&passive.damage_type)
+            .ok_or_else(|| unknown(&passive.damage_type))?;
+        validate_dice(&passive.damage)?;
+    }
+    Ok(())
+}
+
+fn validate_corpse(id: &str, corpse: &CorpseData) -> Result<(), ContentError> {
+    if corpse.weight <= 0 || corpse.nutrition < 0 || corpse.chance == 0 {
+        return Err(ContentError::Parse {
+            file: "monsters.toml".to_owned(),
+            message: format!("{id} corpse needs positive weight and chance"),
+        });
+    }
+    for effect in corpse.effects.as_deref().unwrap_or_default() {
+        if let CorpseEffectData::Intrinsic { intrinsic, chance } = effect {
+            if crate::intrinsic_from_name(intrinsic).is_none() || *chance == 0 {
+                return Err(ContentError::UnknownReference {
+                    owner: id.to_owned(),
+                    target: intrinsic.clone(),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_radius(id: &str, radius: i16) -> Result<(), ContentError> {
+    if !(0..=10).contains(&radius) {
+        return Err(ContentError::Parse {
+            file: "items.toml".to_owned(),
+            message: format!("{id} effect radius must be in 0..=10"),
+        });
+    }
+    Ok(())
+}
+
 fn validate_dice(value: &str) -> Result<(), ContentError> {
     if value == "0" {
         return Ok(());
@@ -317,6 +787,17 @@ fn validate_level_coordinates(level: &LevelData) -> Result<(), ContentError> {
             check(&[wall.x, y])?;
         }
     }
+    for region in level.dark_region.as_deref().unwrap_or_default() {
+        let ([x0, x1], [y0, y1]) = (region.x_range.as_slice(), region.y_range.as_slice()) else {
+            return Err(ContentError::InvalidCoordinate {
+                level: level.level_id.clone(),
+                x: region.x_range.first().copied().unwrap_or_default(),
+                y: region.y_range.first().copied().unwrap_or_default(),
+            });
+        };
+        check(&[*x0, *y0])?;
+        check(&[*x1, *y1])?;
+    }
     for pos in level
         .door
         .as_deref()
@@ -339,6 +820,14 @@ fn validate_level_coordinates(level: &LevelData) -> Result<(), ContentError> {
                 .iter()
                 .map(|entry| &entry.pos),
         )
+        .chain(
+            level
+                .trap
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|entry| &entry.pos),
+        )
         .chain(
             level
                 .monster
@@ -355,6 +844,14 @@ fn validate_level_coordinates(level: &LevelData) -> Result<(), ContentError> {
                 .iter()
                 .map(|entry| &entry.pos),
         )
+        .chain(
+            level
+                .gold
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|entry| &entry.pos),
+        )
     {
         check(pos)?;
     }
@@ -377,6 +874,29 @@ fn coordinate(level: &LevelData, value: &[i16]) -> Result<(), ContentError> {
     Ok(())
 }
 
+fn level_trap_names(level: &LevelData) -> impl Iterator<Item = &str> {
+    level
+        .hidden_trap
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .map(|entry| entry.trap.as_str())
+        .chain(
+            level
+                .trap
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|entry| entry.trap.as_str()),
+        )
+}
+
+fn has_level_below(level: &LevelData, levels: &BTreeMap<String, LevelData>) -> bool {
+    levels
+        .values()
+        .any(|other| other.branch == level.branch && other.depth == level.depth + 1)
+}
+
 fn has_paired_up(level: &LevelData, levels: &BTreeMap<String, LevelData>) -> bool {
     levels.values().any(|other| {
         other.branch == level.branch && other.depth == level.depth + 1 && other.stairs_up.is_some()
@@ -393,15 +913,14 @@ fn has_paired_down(level: &LevelData, levels: &BTreeMap<String, LevelData>) -> b
 fn canonical_hash(
     schema_version: u16,
     items: &BTreeMap<String, ItemData>,
+    item_classes: &BTreeMap<String, ItemClassData>,
     monsters: &BTreeMap<String, MonsterData>,
     levels: &BTreeMap<String, LevelData>,
 ) -> Result<String, ContentError> {
-    let canonical =
-        serde_json::to_vec(&(schema_version, items, monsters, levels)).map_err(|error| {
-            ContentError::Parse {
-                file: "content".to_owned(),
-                message: error.to_string(),
-            }
+    let canonical = serde_json::to_vec(&(schema_version, items, item_classes, monsters, levels))
+        .map_err(|error| ContentError::Parse {
+            file: "content".to_owned(),
+            message: error.to_string(),
         })?;
     let hash = canonical.iter().fold(0xcbf29ce484222325_u64, |hash, byte| {
         (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{ids::EntityId, position::Direction};
+use crate::{
+    domain::item::ItemKind,
+    ids::EntityId,
+    position::{Direction, Pos},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum NarrativeTopic {
@@ -24,12 +28,40 @@ pub enum InventoryAction {
     Read,
 }
 
+/// 방향을 묻는 도구에 `#apply`로 주는 대답이다. NetHack `getdir`의 여덟 방향과 아래(`>`)다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ApplyDirection {
+    Toward(Direction),
+    Down,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CommandIntent {
     Wait,
     Quit,
     Move(Direction),
     Search,
+    /// 방해받지 않는 한 `intent`를 `count`번 되풀이한다. NetHack의 `n20s`/`20s` 횟수 접두와 같고,
+    /// replay에는 되풀이 전체가 한 명령으로 남는다.
+    Repeat {
+        count: u16,
+        intent: RepeatIntent,
+    },
+    /// `_`: 기억하는 지도의 가장 짧은 길로 `target`까지 한 칸씩 걷는다.
+    /// 몬스터가 나타나거나 물건 위에 서는 등 눈여겨볼 일이 생기면 멈춘다.
+    Travel {
+        target: Pos,
+    },
+    /// `Shift`+방향이나 `G`+방향: 눈여겨볼 일이 생길 때까지 한 방향으로 계속 걷는다.
+    /// 이 명령은 첫 걸음만 걷고, 남은 걸음은 [`CommandIntent::ContinueRun`]으로 한 걸음씩
+    /// 따로 받아들인다. 언제 멈출지는 런타임이 걸음마다 정하며, 걸음들은 `run_group`을 함께 갖는다.
+    Run {
+        direction: Direction,
+        mode: RunMode,
+    },
+    /// 기억하는 지도에서 가장 가까운 미탐험 경계로 걷기를 되풀이한다. 닫힌 문은 열고,
+    /// autopickup 종류의 물건은 줍는다. 멈춘 까닭은 `OccupationInterrupted` 사건으로 남는다.
+    Explore,
     Kick(Direction),
     Open(Direction),
     Close(Direction),
@@ -61,10 +93,89 @@ pub enum CommandIntent {
     Read {
         item: EntityId,
     },
+    /// `#dip`: `item`을 인벤토리의 물약 `into`에 담근다.
+    Dip {
+        item: EntityId,
+        into: EntityId,
+    },
+    /// `#apply`: 도구를 쓴다. 등불은 켜거나 끄고, 곡괭이는 `direction` 쪽 벽이나 발밑 바닥을
+    /// 뚫릴 때까지 occupation으로 이어 판다. 방해받아 멈춘 곳을 다시 파면 이어서 판다.
+    /// 곡괭이는 방향이 없으면 "In what direction do you want to dig?"로 거부한다.
+    Apply {
+        item: EntityId,
+        direction: Option<ApplyDirection>,
+    },
+    /// `#apply`: 열쇠, 자물쇠 따개, 신용카드로 `direction` 쪽 문의 자물쇠를 여닫는다.
+    PickLock {
+        item: EntityId,
+        direction: Direction,
+    },
+    /// `#force`: 휘두르는 무기로 `direction` 쪽 잠긴 문을 비틀어 연다.
+    Force {
+        direction: Direction,
+    },
     Pray,
+    /// `#offer`: 발밑 제단에 시체를 바친다.
+    Offer {
+        item: EntityId,
+    },
+    /// `#sit`: 발밑 지형에 앉는다. 왕좌에서만 효과가 있다.
+    Sit,
+    /// 발밑 분수의 물을 마신다.
+    QuaffFountain,
+    /// `#dip`: `item`을 발밑 분수에 담근다.
+    DipFountain {
+        item: EntityId,
+    },
+    /// 남은 소원 하나로 `kind` 아이템을 얻는다. 턴을 쓰지 않는다.
+    Wish {
+        kind: ItemKind,
+    },
     Descend,
     Ascend,
     AcknowledgeMore,
+    /// 달리던 방향으로 한 걸음 더 달린다. 달리는 중에만 합법 행동에 있고, 다른 명령을 내면
+    /// 달리기는 끝난다. NetHack `context.run`이 남아 `moveloop`가 다시 `domove`를 부르는 것과 같다.
+    ContinueRun,
+}
+
+/// NetHack `context.run`의 달리기 방식이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RunMode {
+    /// `Shift`+방향(`run = 1`): 곁의 문, 물건, 계단은 지나치고 앞이 막히거나 몬스터가 길을
+    /// 막을 때 멈춘다.
+    Rush,
+    /// `G`+방향(`run = 3`): 곁에 문, 물건, 함정, 계단 같은 지형이 보이면 멈춘다.
+    Run,
+}
+
+/// 횟수 접두를 붙여 되풀이할 수 있는 명령이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RepeatIntent {
+    Wait,
+    Search,
+    Move(Direction),
+}
+
+impl RepeatIntent {
+    /// 횟수를 붙일 수 있는 명령이면 그 되풀이 형태를 돌려준다.
+    pub fn from_command(intent: CommandIntent) -> Option<Self> {
+        match intent {
+            CommandIntent::Wait => Some(Self::Wait),
+            CommandIntent::Search => Some(Self::Search),
+            CommandIntent::Move(direction) => Some(Self::Move(direction)),
+            _ => None,
+        }
+    }
+
+    /// 한 번만 할 때의 명령이다.
+    pub fn command(self) -> CommandIntent {
+        match self {
+            Self::Wait => CommandIntent::Wait,
+            Self::Search => CommandIntent::Search,
+            Self::Move(direction) => CommandIntent::Move(direction),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
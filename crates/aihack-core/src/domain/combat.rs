@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    domain::{entity::EntityStore, tile::TrapKind},
+    domain::{
+        entity::EntityStore,
+        item::ItemKind,
+        tile::{TileKind, TrapKind},
+    },
     ids::EntityId,
     rng::GameRng,
 };
@@ -21,12 +25,27 @@ impl DamageRoll {
     }
 }
 
+/// 몬스터의 원거리 공격으로 날아가는 것이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Missile {
+    /// 던지거나 쏜 무기다. 떨어진 자리에 남는다.
+    Item(ItemKind),
+    Venom,
+    Breath,
+    MagicMissile,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct AttackProfile {
+    /// 자연 공격이나 맨손의 이름이다. content 무기는 비워 두고, 이름은 그 종류로 session의
+    /// content registry에서 찾는다.
     #[serde(skip)]
     pub name: &'static str,
     pub hit_bonus: i16,
     pub damage: DamageRoll,
+    /// 무기 강화치처럼 공격 수단 자체가 더하는 피해 보정이다.
+    #[serde(default)]
+    pub damage_bonus: i16,
 }
 
 impl AttackProfile {
@@ -35,6 +54,7 @@ impl AttackProfile {
             name: "dagger",
             hit_bonus: 1,
             damage: DamageRoll::new(1, 4),
+            damage_bonus: 0,
         }
     }
     pub const fn natural(name: &'static str, damage: DamageRoll) -> Self {
@@ -42,14 +62,46 @@ impl AttackProfile {
             name,
             hit_bonus: 0,
             damage,
+            damage_bonus: 0,
         }
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum DeathCause {
-    Combat { attacker: EntityId },
-    Trap { trap: TrapKind },
+    Combat {
+        attacker: EntityId,
+    },
+    Trap {
+        trap: TrapKind,
+    },
+    /// 오염된 물약이나 연금술 폭발처럼 소모품이 직접 원인인 사망이다.
+    Consumable {
+        item: ItemKind,
+    },
+    Starvation,
+    /// 배부른 상태에서 더 먹다가 음식에 목이 막힌 경우다.
+    Choked {
+        item: ItemKind,
+    },
+    /// 상한 시체로 얻은 식중독이 낫지 않은 경우다.
+    FoodPoisoning,
+    /// 석화시키는 시체를 먹고 돌이 된 경우다.
+    Petrification {
+        item: ItemKind,
+    },
+    /// 분노한 신의 번개와 분해 광선에 맞은 경우다.
+    DivineWrath,
+    /// 왕좌의 전기 충격처럼 던전 특수 지형이 직접 원인인 사망이다.
+    Feature {
+        tile: TileKind,
+    },
+    /// 물이나 해자에 빠져 기어 나오지 못한 경우다.
+    Drowned {
+        tile: TileKind,
+    },
+    /// 용암에 빠져 불타 죽은 경우다.
+    Lava,
 }
 
 pub fn roll_die(rng: &mut GameRng, sides: i16) -> i16 {
@@ -57,6 +109,11 @@ pub fn roll_die(rng: &mut GameRng, sides: i16) -> i16 {
     (rng.next_u64() % sides as u64) as i16 + 1
 }
 
+/// NetHack `rn2(n)`처럼 `0..n` 범위 값을 굴린다. `n`이 1보다 작으면 항상 0이다.
+pub fn rn2(rng: &mut GameRng, n: i16) -> i32 {
+    i32::from(roll_die(rng, n.max(1)) - 1)
+}
+
 pub fn roll_damage(
     rng: &mut GameRng,
     damage: DamageRoll,
@@ -99,6 +156,19 @@ pub fn resolve_attack_with_profile(
     attacker_id: EntityId,
     defender_id: EntityId,
     profile: AttackProfile,
+) -> Option<AttackResolution> {
+    resolve_attack_with_to_hit_die(entities, rng, attacker_id, defender_id, profile, 20)
+}
+
+/// 명중 판정에 `to_hit_sides`면 주사위를 굴린다. NetHack `mattacku`는 i번째 공격을
+/// `rnd(20 + i)`로 굴려 뒤의 공격일수록 덜 맞는다.
+pub fn resolve_attack_with_to_hit_die(
+    entities: &mut EntityStore,
+    rng: &mut GameRng,
+    attacker_id: EntityId,
+    defender_id: EntityId,
+    profile: AttackProfile,
+    to_hit_sides: i16,
 ) -> Option<AttackResolution> {
     let attacker = entities.get(attacker_id)?.clone();
     let defender = entities.get(defender_id)?.clone();
@@ -107,18 +177,18 @@ pub fn resolve_attack_with_profile(
     if !attacker_alive || !defender_alive {
         return None;
     }
-    let d20 = roll_die(rng, 20);
+    let to_hit = roll_die(rng, to_hit_sides);
     let (attack_roll, defense, hit) = attack_roll_value(
         attacker_stats.hit_bonus,
         defender_stats.ac,
         profile.hit_bonus,
-        d20,
+        to_hit,
     );
     let damage = if hit {
         roll_damage(
             rng,
             profile.damage,
-            attacker_stats.damage_bonus,
+            attacker_stats.damage_bonus + profile.damage_bonus,
             defender_stats.damage_reduction,
         )
     } else {
@@ -4,8 +4,11 @@ use crate::{
     domain::{
         combat::{AttackProfile, DamageRoll},
         inventory::InventoryLetter,
-        item::{ItemData, ItemKind},
-        monster::{MonsterAiKind, MonsterKind, MonsterPassive, MonsterTemplate},
+        item::{BucStatus, ItemData, ItemKind, MAX_EROSION},
+        monster::{
+            DamageType, MonsterAiKind, MonsterAttacks, MonsterKind, MonsterPassive,
+            MonsterTemplate, RangedAttack,
+        },
         player::adventurer_template,
     },
     ids::{EntityId, LevelId},
@@ -56,6 +59,10 @@ pub struct ActorStats {
     pub damage_reduction: i16,
     pub damage: DamageRoll,
     pub weapon_hit_bonus: i16,
+    /// 몬스터가 곁의 플레이어에게 차례마다 쓰는 공격 목록이다. 비어 있으면 이전 저장본처럼
+    /// `damage`로 한 번 친다.
+    #[serde(default)]
+    pub attacks: MonsterAttacks,
     #[serde(default = "default_actor_speed")]
     pub speed: i16,
     #[serde(default)]
@@ -64,10 +71,53 @@ pub struct ActorStats {
     pub passive: Option<MonsterPassive>,
     #[serde(default)]
     pub difficulty: u16,
+    /// 쫓는 길에 닫힌 문이 있으면 열고 지나간다. NetHack `can_open`과 같다.
+    #[serde(default)]
+    pub opens_doors: bool,
+    #[serde(default)]
+    pub ranged: Option<RangedAttack>,
+    /// 숨 공격을 다시 쓰기까지 남은 행동 수다. NetHack `mspec_used`와 같다.
+    #[serde(default)]
+    pub ranged_cooldown: u8,
+    /// 구덩이·곰덫에 붙잡혀 움직일 수 없는 남은 턴이다. 몬스터는 NetHack `mtrapped`처럼
+    /// 0이 아니면 붙잡힌 것이고, 남은 턴 대신 움직이려 할 때마다 탈출을 굴린다.
+    #[serde(default)]
+    pub trapped_turns: u8,
+    /// 수면 가스 등으로 몬스터가 아무 행동도 못 하는 남은 턴이다.
+    /// 플레이어의 마비는 `Status::paralysis_turns`가 맡는다.
+    #[serde(default)]
+    pub frozen_turns: u8,
+    /// 잠든 몬스터는 소음에 깰 때까지 아무 행동도 하지 않는다.
+    #[serde(default)]
+    pub asleep: bool,
+    /// 혼란에 빠져 플레이어를 쫓지 않고 아무 쪽으로나 걷는다. NetHack `mconf`처럼 행동할
+    /// 차례마다 1/50로 풀린다.
+    #[serde(default)]
+    pub confused: bool,
+    /// 눈이 멀어 플레이어를 보지 못하는 남은 턴이다. NetHack `mblinded`와 같다.
+    #[serde(default)]
+    pub blinded_turns: u8,
+    /// 겁을 먹고 플레이어에게서 달아나는 중이다. NetHack `mflee`와 같다.
+    #[serde(default)]
+    pub fleeing: bool,
+    /// 달아남이 끝나기까지 남은 턴이다. 0이면 용기를 되찾을 때까지 달아난다(`mfleetim`).
+    #[serde(default)]
+    pub flee_turns: u8,
+    /// 쌓아 둔 이동력이다. NetHack `movement`처럼 [`NORMAL_SPEED`] 이상이면 한 번 행동한다.
+    /// 이전 저장본은 한 번 움직일 만큼 가진 것으로 읽는다.
+    #[serde(default = "default_actor_speed")]
+    pub movement: i16,
 }
 
+/// 한 번 행동하는 데 드는 이동력이자 보통 속도다. NetHack `NORMAL_SPEED`와 같다.
+pub const NORMAL_SPEED: i16 = 12;
+
 fn default_actor_speed() -> i16 {
-    12
+    NORMAL_SPEED
+}
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
 }
 
 /// [v0.1.0] Phase 5 actor/item 공용 위치다. Consumed tombstone은 assigned_letter를 유지한다.
@@ -110,6 +160,29 @@ pub enum EntityPayload {
         location: EntityLocation,
         assigned_letter: Option<InventoryLetter>,
         charges: Option<u8>,
+        #[serde(default)]
+        buc: BucStatus,
+        #[serde(default)]
+        diluted: bool,
+        #[serde(default)]
+        enchantment: i8,
+        #[serde(default)]
+        erodeproof: bool,
+        /// 녹슨 정도다. 0은 멀쩡하고 `MAX_EROSION`이면 완전히 녹슬었다.
+        #[serde(default)]
+        erosion: u8,
+        /// 시체가 생긴 뒤 지난 턴 수다. 썩음 판정에 쓰인다. 등불은 켜져 있던 턴 수를 센다.
+        #[serde(default)]
+        age: u16,
+        /// 먹다 만 시체에서 이미 먹은 횟수다.
+        #[serde(default)]
+        bites: u8,
+        /// 등불처럼 켜서 빛을 내는 중인지 여부다.
+        #[serde(default)]
+        lit: bool,
+        /// 금화 더미에 든 금화 수다. NetHack 금화의 `quan`이며 금화가 아니면 0이다.
+        #[serde(default, skip_serializing_if = "is_zero")]
+        coins: u32,
     },
 }
 
@@ -201,8 +274,12 @@ impl Entity {
                 stats,
                 ..
             } => stats.passive.or_else(|| {
-                (stats.ai_kind.is_none() && *kind == MonsterKind::FloatingEye)
-                    .then_some(MonsterPassive::ParalyzeOnMelee)
+                (stats.ai_kind.is_none() && *kind == MonsterKind::FloatingEye).then_some(
+                    MonsterPassive {
+                        damage_type: DamageType::Paralyze,
+                        damage: DamageRoll::new(2, 1),
+                    },
+                )
             }),
             _ => None,
         }
@@ -243,6 +320,7 @@ impl Entity {
                 location,
                 assigned_letter,
                 charges,
+                ..
             } => Some((*kind, data, *location, *assigned_letter, *charges)),
             EntityPayload::Actor { .. } => None,
         }
@@ -261,23 +339,17 @@ impl Entity {
         }
     }
 
+    /// 몬스터의 첫 근접 공격이다. 공격 목록이 없는 이전 저장본은 `damage`로 친다.
     pub fn natural_attack_profile(&self) -> Option<AttackProfile> {
         match &self.payload {
             EntityPayload::Actor {
-                kind: ActorKind::Monster(MonsterKind::Jackal),
-                stats,
-                ..
-            } => Some(AttackProfile::natural("bite", stats.damage)),
-            EntityPayload::Actor {
-                kind: ActorKind::Monster(MonsterKind::Goblin),
-                stats,
-                ..
-            } => Some(AttackProfile::natural("short sword", stats.damage)),
-            EntityPayload::Actor {
-                kind: ActorKind::Monster(MonsterKind::FloatingEye),
+                kind: ActorKind::Monster(_),
                 stats,
                 ..
-            } => Some(AttackProfile::natural("gaze", stats.damage)),
+            } => Some(match stats.attacks.first_melee() {
+                Some(attack) => AttackProfile::natural(attack.attack_type.name(), attack.damage),
+                None => AttackProfile::natural("hit", stats.damage),
+            }),
             EntityPayload::Actor {
                 kind: ActorKind::Player,
                 ..
@@ -323,10 +395,22 @@ impl EntityStore {
                 damage_reduction: 0,
                 damage: template.attack_profile.damage,
                 weapon_hit_bonus: template.attack_profile.hit_bonus,
-                speed: 12,
+                attacks: MonsterAttacks::default(),
+                speed: NORMAL_SPEED,
                 ai_kind: None,
                 passive: None,
+                opens_doors: false,
+                ranged: None,
+                ranged_cooldown: 0,
                 difficulty: 0,
+                trapped_turns: 0,
+                frozen_turns: 0,
+                asleep: false,
+                confused: false,
+                blinded_turns: 0,
+                fleeing: false,
+                flee_turns: 0,
+                movement: NORMAL_SPEED,
             },
         )
     }
@@ -348,12 +432,27 @@ impl EntityStore {
                 hit_bonus: template.hit_bonus,
                 damage_bonus: template.damage_bonus,
                 damage_reduction: 0,
-                damage: template.attack_profile.damage,
-                weapon_hit_bonus: template.attack_profile.hit_bonus,
+                damage: template
+                    .attacks
+                    .first_melee()
+                    .map_or(DamageRoll::none(), |attack| attack.damage),
+                weapon_hit_bonus: 0,
+                attacks: template.attacks,
                 speed: template.speed,
                 ai_kind: Some(template.ai_kind),
                 passive: template.passive,
+                opens_doors: template.opens_doors,
+                ranged: template.ranged,
+                ranged_cooldown: 0,
                 difficulty: template.difficulty,
+                trapped_turns: 0,
+                frozen_turns: 0,
+                asleep: false,
+                confused: false,
+                blinded_turns: 0,
+                fleeing: false,
+                flee_turns: 0,
+                movement: 0,
             },
         )
     }
@@ -394,6 +493,15 @@ impl EntityStore {
                 location,
                 assigned_letter: None,
                 charges: data.max_charges,
+                buc: BucStatus::Uncursed,
+                diluted: false,
+                enchantment: 0,
+                erodeproof: false,
+                erosion: 0,
+                age: 0,
+                bites: 0,
+                lit: false,
+                coins: 0,
             },
         });
         id
@@ -504,6 +612,17 @@ impl EntityStore {
         true
     }
 
+    pub fn set_faction(&mut self, id: EntityId, next: Faction) -> bool {
+        let Some(entity) = self.get_mut(id) else {
+            return false;
+        };
+        let EntityPayload::Actor { faction, .. } = &mut entity.payload else {
+            return false;
+        };
+        *faction = next;
+        true
+    }
+
     pub fn set_pos(&mut self, id: EntityId, pos: Pos) -> bool {
         let Some((level, _)) = self.actor_location(id) else {
             return false;
@@ -520,10 +639,12 @@ impl EntityStore {
         let Some(entity) = self.get_mut(id) else {
             return false;
         };
-        let Some((location, _, _)) = entity.actor_mut() else {
+        let Some((location, stats, _)) = entity.actor_mut() else {
             return false;
         };
         *location = EntityLocation::OnMap { level, pos };
+        // NetHack `teleds`처럼 자리를 옮기면 붙잡혀 있던 함정에서 풀려난다.
+        stats.trapped_turns = 0;
         true
     }
 
@@ -590,6 +711,185 @@ impl EntityStore {
         true
     }
 
+    pub fn item_buc(&self, id: EntityId) -> Option<BucStatus> {
+        match &self.get(id)?.payload {
+            EntityPayload::Item { buc, .. } => Some(*buc),
+            EntityPayload::Actor { .. } => None,
+        }
+    }
+
+    pub fn set_item_buc(&mut self, id: EntityId, next: BucStatus) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { buc, .. }) => {
+                *buc = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn item_diluted(&self, id: EntityId) -> bool {
+        matches!(
+            self.get(id).map(|entity| &entity.payload),
+            Some(EntityPayload::Item { diluted: true, .. })
+        )
+    }
+
+    pub fn set_item_diluted(&mut self, id: EntityId, next: bool) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { diluted, .. }) => {
+                *diluted = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn item_enchantment(&self, id: EntityId) -> i8 {
+        match self.get(id).map(|entity| &entity.payload) {
+            Some(EntityPayload::Item { enchantment, .. }) => *enchantment,
+            _ => 0,
+        }
+    }
+
+    pub fn set_item_enchantment(&mut self, id: EntityId, next: i8) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { enchantment, .. }) => {
+                *enchantment = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn item_erodeproof(&self, id: EntityId) -> bool {
+        matches!(
+            self.get(id).map(|entity| &entity.payload),
+            Some(EntityPayload::Item {
+                erodeproof: true,
+                ..
+            })
+        )
+    }
+
+    pub fn set_item_erodeproof(&mut self, id: EntityId, next: bool) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { erodeproof, .. }) => {
+                *erodeproof = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn item_erosion(&self, id: EntityId) -> u8 {
+        match self.get(id).map(|entity| &entity.payload) {
+            Some(EntityPayload::Item { erosion, .. }) => *erosion,
+            _ => 0,
+        }
+    }
+
+    pub fn set_item_erosion(&mut self, id: EntityId, next: u8) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { erosion, .. }) => {
+                *erosion = next.min(MAX_EROSION);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn item_age(&self, id: EntityId) -> u16 {
+        match self.get(id).map(|entity| &entity.payload) {
+            Some(EntityPayload::Item { age, .. }) => *age,
+            _ => 0,
+        }
+    }
+
+    pub fn set_item_age(&mut self, id: EntityId, next: u16) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { age, .. }) => {
+                *age = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn item_coins(&self, id: EntityId) -> u32 {
+        match self.get(id).map(|entity| &entity.payload) {
+            Some(EntityPayload::Item { coins, .. }) => *coins,
+            _ => 0,
+        }
+    }
+
+    pub fn set_item_coins(&mut self, id: EntityId, next: u32) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { coins, .. }) => {
+                *coins = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn item_lit(&self, id: EntityId) -> bool {
+        matches!(
+            self.get(id).map(|entity| &entity.payload),
+            Some(EntityPayload::Item { lit: true, .. })
+        )
+    }
+
+    pub fn set_item_lit(&mut self, id: EntityId, next: bool) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { lit, .. }) => {
+                *lit = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn item_bites(&self, id: EntityId) -> u8 {
+        match self.get(id).map(|entity| &entity.payload) {
+            Some(EntityPayload::Item { bites, .. }) => *bites,
+            _ => 0,
+        }
+    }
+
+    pub fn set_item_bites(&mut self, id: EntityId, next: u8) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item { bites, .. }) => {
+                *bites = next;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// 물약 혼합처럼 같은 entity가 다른 종류로 바뀔 때 kind와 data를 함께 교체한다.
+    pub fn transform_item(
+        &mut self,
+        id: EntityId,
+        next_kind: ItemKind,
+        next_data: ItemData,
+    ) -> bool {
+        match self.get_mut(id).map(|entity| &mut entity.payload) {
+            Some(EntityPayload::Item {
+                kind,
+                data,
+                charges,
+                ..
+            }) => {
+                *kind = next_kind;
+                *data = next_data;
+                *charges = next_data.max_charges;
+                true
+            }
+            _ => false,
+        }
+    }
+
     pub fn clear_monsters(&mut self) {
         for entity in &mut self.entities {
             if matches!(entity.kind(), EntityKind::Monster(_)) {
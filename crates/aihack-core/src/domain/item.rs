@@ -1,19 +1,73 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::combat::AttackProfile;
+use crate::domain::{
+    combat::AttackProfile, kind::builtin_kinds, monster::MonsterKind, status::Intrinsic,
+};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum ItemKind {
-    Dagger,
-    FoodRation,
-    PotionHealing,
-    WandMagicMissile,
-    ScrollReveal,
-    ScrollIdentify,
-    ScrollLevelTeleport,
-    Rock,
-    ArmorLeather,
-    CorpseJackal,
+/// content id로 정해지는 아이템 종류다. 규칙 코드가 이름으로 부르는 종류는 상수로 있고, content에만
+/// 있는 종류도 같은 방식으로 만들어진다. 이름과 글자는 content registry가 들고 있다.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ItemKind(u16);
+
+builtin_kinds! {
+    ItemKind,
+    ITEM_KINDS {
+        Dagger => "item.weapon.dagger",
+        Spear => "item.weapon.spear",
+        Arrow => "item.weapon.arrow",
+        Bow => "item.weapon.bow",
+        FoodRation => "item.food.ration",
+        PotionHealing => "item.potion.healing",
+        PotionExtraHealing => "item.potion.extra_healing",
+        PotionGainLevel => "item.potion.gain_level",
+        PotionConfusion => "item.potion.confusion",
+        PotionBlindness => "item.potion.blindness",
+        PotionSleeping => "item.potion.sleeping",
+        PotionSeeInvisible => "item.potion.see_invisible",
+        PotionSpeed => "item.potion.speed",
+        PotionSickness => "item.potion.sickness",
+        PotionWater => "item.potion.water",
+        WandMagicMissile => "item.wand.magic_missile",
+        ScrollReveal => "item.scroll.reveal",
+        ScrollIdentify => "item.scroll.identify",
+        ScrollTeleportation => "item.scroll.teleport",
+        ScrollEnchantWeapon => "item.scroll.enchant_weapon",
+        ScrollEnchantArmor => "item.scroll.enchant_armor",
+        ScrollRemoveCurse => "item.scroll.remove_curse",
+        ScrollFire => "item.scroll.fire",
+        ScrollLight => "item.scroll.light",
+        ScrollGoldDetection => "item.scroll.gold_detection",
+        ScrollCreateMonster => "item.scroll.create_monster",
+        ScrollScareMonster => "item.scroll.scare_monster",
+        Rock => "item.weapon.rock",
+        ArmorLeather => "item.armor.leather",
+        Luckstone => "item.gem.luckstone",
+        Mirror => "item.tool.mirror",
+        RingAdornment => "item.ring.adornment",
+        RingProtection => "item.ring.protection",
+        OilLamp => "item.tool.oil_lamp",
+        SkeletonKey => "item.tool.skeleton_key",
+        LockPick => "item.tool.lock_pick",
+        CreditCard => "item.tool.credit_card",
+        PickAxe => "item.tool.pick_axe",
+        WandDigging => "item.wand.digging",
+        CorpseJackal => "item.corpse.jackal",
+        CorpseGoblin => "item.corpse.goblin",
+        CorpseFloatingEye => "item.corpse.floating_eye",
+        CorpseWaterMoccasin => "item.corpse.water_moccasin",
+        GoldPiece => "item.coin.gold",
+    }
+    aliases {
+        ScrollLevelTeleport => ScrollTeleportation,
+    }
+}
+
+impl ItemKind {
+    /// 이 아이템이 어느 몬스터의 시체인지 돌려준다. 시체 content id는 `item.corpse.<몬스터>`다.
+    pub fn corpse_of(self) -> Option<MonsterKind> {
+        let monster = self.content_id().strip_prefix("item.corpse.")?;
+        MonsterKind::from_content_id(&format!("monster.{monster}"))
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -26,6 +80,10 @@ pub enum ItemClass {
     Rock,
     Armor,
     Corpse,
+    Tool,
+    Ring,
+    /// 금화 더미다. 주우면 소지품 대신 소지금에 더해진다.
+    Coin,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -36,15 +94,131 @@ pub enum EquipmentSlot {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ConsumableEffect {
-    Heal { dice: i16, sides: i16, bonus: i16 },
+    Heal {
+        dice: i16,
+        sides: i16,
+        bonus: i16,
+    },
+    GainLevel,
+    Confusion,
+    Blindness,
+    Sleeping,
+    SeeInvisible,
+    Speed,
+    Sickness,
+    Water,
     RevealLevel,
     IdentifySingle,
+    /// 읽은 두루마리 자신의 종류만 식별한다.
+    IdentifySource,
+    /// 현재 층의 무작위 안전 지점으로 이동한다.
+    Teleport,
     LevelTeleport,
+    EnchantWeapon,
+    EnchantArmor,
+    /// 착용·장비한 아이템을 부식되지 않게 보호한다.
+    ErodeproofWeapon,
+    ErodeproofArmor,
+    RemoveCurse,
+    /// 소지품의 축복/저주 상태를 무작위로 뒤섞는다.
+    ScrambleCurses,
+    /// `radius`가 0이면 플레이어 손에서만 타오른다.
+    Fire {
+        dice: i16,
+        sides: i16,
+        radius: i16,
+    },
+    Light {
+        radius: i16,
+        lit: bool,
+    },
+    GoldDetection,
+    TrapDetection,
+    CreateMonster {
+        count: u8,
+    },
+    /// 보이는 몬스터가 겁을 먹고 달아난다. 저주받았으면 [`ConsumableEffect::RallyMonsters`]가 된다.
+    ScareMonsters,
+    /// 보이는 몬스터가 깨어나고 달아나기를 멈춘다.
+    RallyMonsters,
+}
+
+/// 아이템의 축복/저주 상태다. 물약 효과 강도와 성수 판정에 쓰인다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BucStatus {
+    Blessed,
+    #[default]
+    Uncursed,
+    Cursed,
+}
+
+impl BucStatus {
+    /// NetHack `bcsign()`과 같은 +1/0/-1 보정값이다.
+    pub const fn sign(self) -> i16 {
+        match self {
+            Self::Blessed => 1,
+            Self::Uncursed => 0,
+            Self::Cursed => -1,
+        }
+    }
+}
+
+/// 녹이 최대로 슨 단계다. NetHack `MAX_ERODE`와 같다.
+pub const MAX_EROSION: u8 = 3;
+
+/// 부식 판정에 쓰는 아이템 재질이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Material {
+    Iron,
+    Leather,
+    Mineral,
+}
+
+impl Material {
+    pub fn is_rustprone(self) -> bool {
+        self == Self::Iron
+    }
+}
+
+/// 시체를 먹었을 때 적용되는 몬스터 고유 효과다. NetHack `eatcorpse`/`cpostfx` 판정에 쓰인다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CorpseEffects {
+    pub poisonous: bool,
+    pub acidic: bool,
+    pub petrifying: bool,
+    pub intrinsic: Option<IntrinsicGrant>,
+}
+
+/// 다 먹었을 때 `1/chance` 확률로 얻는 intrinsic이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct IntrinsicGrant {
+    pub intrinsic: Intrinsic,
+    pub chance: u8,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum WandEffect {
     MagicMissile,
+    /// 벽을 뚫고 문을 부수는 광선이다.
+    Digging,
+}
+
+/// 자물쇠를 여닫는 도구의 성공률이다. NetHack `pick_lock`의 `ch` 계산과 같다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct UnlockTool {
+    /// 민첩과 무관하게 더해지는 기본 성공률(%)이다.
+    pub base_chance: u8,
+    /// 민첩 1마다 더해지는 성공률(%)이다.
+    pub dexterity_multiplier: u8,
+    /// 문을 잠글 수도 있는지 여부다. 신용카드는 열기만 한다.
+    pub can_lock: bool,
+}
+
+impl UnlockTool {
+    /// 한 턴 시도가 성공할 확률(%)이다.
+    pub fn chance(self, dexterity: u8) -> u16 {
+        u16::from(self.base_chance) + u16::from(self.dexterity_multiplier) * u16::from(dexterity)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -56,9 +230,38 @@ pub struct ItemData {
     pub base_price: u32,
     #[serde(default)]
     pub ac_bonus: i16,
+    #[serde(default)]
+    pub material: Option<Material>,
     pub attack_profile: Option<AttackProfile>,
     pub consumable_effect: Option<ConsumableEffect>,
+    /// 혼란 상태로 읽었을 때 대신 적용되는 효과다.
+    #[serde(default)]
+    pub confused_effect: Option<ConsumableEffect>,
     pub wand_effect: Option<WandEffect>,
     pub max_charges: Option<u8>,
     pub nutrition: Option<i16>,
+    /// 시체일 때만 있는 먹기 효과다.
+    #[serde(default)]
+    pub corpse: Option<CorpseEffects>,
+    /// 소지하면 luck 보너스를 주고 luck이 0으로 돌아가는 것을 막는다. NetHack luckstone이다.
+    #[serde(default)]
+    pub confers_luck: bool,
+    /// 던져서 깨질 때 바뀌는 luck이다. 0이면 던져도 깨지지 않는다.
+    #[serde(default)]
+    pub break_luck: i16,
+    /// 켜 두면 주위를 밝히는 반경이다. 0이면 광원이 아니다.
+    #[serde(default)]
+    pub light_radius: i16,
+    /// 자물쇠를 여닫는 도구일 때만 있는 성공률이다.
+    #[serde(default)]
+    pub unlock: Option<UnlockTool>,
+    /// `#apply`로 벽이나 바닥을 파는 도구인지 여부다.
+    #[serde(default)]
+    pub digs: bool,
+    /// 먹거나 입는 데 더 걸리는 행동 수다. NetHack `oc_delay`와 같다.
+    #[serde(default)]
+    pub delay: u8,
+    /// 제 피해를 내려면 있어야 하는 발사기다. 화살의 활처럼 NetHack `ammo_and_launcher`와 같다.
+    #[serde(default)]
+    pub launcher: Option<ItemKind>,
 }
@@ -0,0 +1,252 @@
+//! content id로 정해지는 몬스터·아이템 종류다.
+//!
+//! 종류는 content id를 처음 본 순서대로 붙인 작은 번호다. 규칙 코드가 이름으로 부르는 종류는
+//! 선언 순서대로 앞 번호를 미리 받아 상수로 쓸 수 있고, content에만 있는 종류는 registry가
+//! 읽을 때 뒤 번호를 받는다. 번호 표는 content id만 들고, 이름과 글자, 색은 session이 쓰는
+//! content registry가 [`KindInfo`]로 들고 있다. 저장본과 관측은 번호 대신 content id 문자열을
+//! 쓰므로 content 순서가 바뀌어도 그대로 읽히며, 읽어 본 적 없는 content id는 받지 않는다.
+
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// NetHack `CLR_*`와 같은 글자 색이다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GlyphColor {
+    Black,
+    Red,
+    Green,
+    Brown,
+    Blue,
+    Magenta,
+    Cyan,
+    #[default]
+    Gray,
+    Orange,
+    BrightGreen,
+    Yellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    White,
+}
+
+impl GlyphColor {
+    /// content의 `color` 이름을 색으로 바꾼다.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "black" => Self::Black,
+            "red" => Self::Red,
+            "green" => Self::Green,
+            "brown" => Self::Brown,
+            "blue" => Self::Blue,
+            "magenta" => Self::Magenta,
+            "cyan" => Self::Cyan,
+            "gray" => Self::Gray,
+            "orange" => Self::Orange,
+            "bright_green" => Self::BrightGreen,
+            "yellow" => Self::Yellow,
+            "bright_blue" => Self::BrightBlue,
+            "bright_magenta" => Self::BrightMagenta,
+            "bright_cyan" => Self::BrightCyan,
+            "white" => Self::White,
+            _ => return None,
+        })
+    }
+}
+
+/// content가 선언한 종류의 표시 정보다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KindInfo {
+    pub name: String,
+    pub glyph: char,
+    pub color: GlyphColor,
+}
+
+#[derive(Debug)]
+struct KindTable {
+    content_ids: Vec<&'static str>,
+    ids: HashMap<&'static str, u16>,
+}
+
+/// 한 종류 공간(몬스터 또는 아이템)의 content id 표다. 번호는 한 번 붙으면 바뀌지 않는다.
+/// content registry를 읽을 때만 새 id가 붙으므로 표는 content에 선언된 id 수보다 커지지 않는다.
+#[derive(Debug)]
+pub(crate) struct KindRegistry {
+    /// 규칙 코드가 상수로 쓰는 종류의 (상수 이름, content id)다. 번호는 이 순서다.
+    builtin: &'static [(&'static str, &'static str)],
+    /// 예전 저장본이 쓰던 다른 상수 이름과 지금 상수 이름이다.
+    aliases: &'static [(&'static str, &'static str)],
+    table: OnceLock<RwLock<KindTable>>,
+}
+
+impl KindRegistry {
+    pub(crate) const fn new(
+        builtin: &'static [(&'static str, &'static str)],
+        aliases: &'static [(&'static str, &'static str)],
+    ) -> Self {
+        Self {
+            builtin,
+            aliases,
+            table: OnceLock::new(),
+        }
+    }
+
+    fn lock(&self) -> &RwLock<KindTable> {
+        self.table.get_or_init(|| {
+            let content_ids = self
+                .builtin
+                .iter()
+                .map(|(_, content_id)| *content_id)
+                .collect::<Vec<_>>();
+            let ids = content_ids
+                .iter()
+                .enumerate()
+                .map(|(index, content_id)| (*content_id, index as u16))
+                .collect();
+            RwLock::new(KindTable { content_ids, ids })
+        })
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, KindTable> {
+        self.lock()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, KindTable> {
+        self.lock()
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub(crate) fn lookup(&self, content_id: &str) -> Option<u16> {
+        self.read().ids.get(content_id).copied()
+    }
+
+    pub(crate) fn intern(&self, content_id: &str) -> u16 {
+        if let Some(index) = self.lookup(content_id) {
+            return index;
+        }
+        let mut table = self.write();
+        if let Some(index) = table.ids.get(content_id) {
+            return *index;
+        }
+        let index = u16::try_from(table.content_ids.len()).expect("kind table overflow");
+        // 번호는 process 안에서 바뀌지 않아야 하므로 id 문자열도 끝까지 남긴다.
+        let content_id: &'static str = Box::leak(content_id.to_owned().into_boxed_str());
+        table.content_ids.push(content_id);
+        table.ids.insert(content_id, index);
+        index
+    }
+
+    pub(crate) fn content_id(&self, index: u16) -> &'static str {
+        self.read().content_ids[usize::from(index)]
+    }
+
+    /// content id가 생기기 전 저장본이 쓰던 상수 이름의 번호다.
+    pub(crate) fn builtin_by_name(&self, name: &str) -> Option<u16> {
+        let name = self
+            .aliases
+            .iter()
+            .find(|(alias, _)| *alias == name)
+            .map_or(name, |(_, current)| current);
+        self.builtin
+            .iter()
+            .position(|(builtin, _)| *builtin == name)
+            .map(|index| index as u16)
+    }
+
+    pub(crate) fn builtin_name(&self, index: u16) -> Option<&'static str> {
+        self.builtin.get(usize::from(index)).map(|(name, _)| *name)
+    }
+}
+
+/// 규칙 코드가 부르는 종류를 상수로 선언하고 content id 표를 만든다. 상수 번호는 선언 순서다.
+/// `aliases`는 예전 저장본의 다른 상수 이름을 지금 상수로 읽는다.
+macro_rules! builtin_kinds {
+    (
+        $kind:ident,
+        $registry:ident { $($variant:ident => $content_id:literal,)* }
+        $(aliases { $($alias:ident => $current:ident,)* })?
+    ) => {
+        #[repr(u16)]
+        enum Builtin {
+            $($variant,)*
+        }
+
+        #[allow(non_upper_case_globals)]
+        impl $kind {
+            $(pub const $variant: Self = Self(Builtin::$variant as u16);)*
+        }
+
+        static $registry: $crate::domain::kind::KindRegistry =
+            $crate::domain::kind::KindRegistry::new(
+                &[$((stringify!($variant), $content_id),)*],
+                &[$($((stringify!($alias), stringify!($current)),)*)?],
+            );
+
+        $crate::domain::kind::content_kind!($kind, $registry);
+    };
+}
+
+/// content id로 찾고 저장하는 종류 newtype의 공통 구현이다.
+#[doc(hidden)]
+macro_rules! content_kind {
+    ($kind:ident, $registry:ident) => {
+        impl $kind {
+            /// 이미 아는 content id의 종류다. 처음 보는 id면 `None`이다.
+            pub fn from_content_id(content_id: &str) -> Option<Self> {
+                $registry.lookup(content_id).map(Self)
+            }
+
+            /// content id에 번호를 붙인다. 처음 보는 id면 새 번호를 받는다.
+            /// 검증한 content를 읽는 registry만 부른다.
+            pub fn intern(content_id: &str) -> Self {
+                Self($registry.intern(content_id))
+            }
+
+            pub fn content_id(self) -> &'static str {
+                $registry.content_id(self.0)
+            }
+        }
+
+        impl std::fmt::Debug for $kind {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match $registry.builtin_name(self.0) {
+                    Some(name) => f.write_str(name),
+                    None => write!(f, "{}({:?})", stringify!($kind), self.content_id()),
+                }
+            }
+        }
+
+        impl serde::Serialize for $kind {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.content_id())
+            }
+        }
+
+        /// content id를 읽는다. content id 전의 저장본이 쓰던 상수 이름도 받는다.
+        /// 어떤 content registry도 선언하지 않은 id는 번호를 새로 붙이지 않고 거절한다.
+        impl<'de> serde::Deserialize<'de> for $kind {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                $registry
+                    .builtin_by_name(&value)
+                    .or_else(|| $registry.lookup(&value))
+                    .map(Self)
+                    .ok_or_else(|| {
+                        serde::de::Error::custom(format!(
+                            "unknown {} content id: {value}",
+                            stringify!($kind)
+                        ))
+                    })
+            }
+        }
+    };
+}
+
+pub(crate) use {builtin_kinds, content_kind};
@@ -4,6 +4,7 @@ use crate::{
     domain::tile::{DoorState, TileKind, TrapKind},
     error::{ContentError, GameError},
     position::Pos,
+    vision::DEFAULT_VISION_RADIUS,
 };
 
 /// Content adapter가 core map 생성에 제공하는 검증된 layout projection이다.
@@ -12,6 +13,22 @@ pub trait MapLayout {
     fn depth(&self) -> i16;
     fn dimensions(&self) -> (i16, i16);
     fn tile_overrides(&self) -> Result<Vec<(Pos, TileKind)>, ContentError>;
+    /// 불이 꺼진 어두운 칸이다. 통로는 따로 적지 않아도 어둡다.
+    fn unlit_positions(&self) -> Result<Vec<Pos>, ContentError> {
+        Ok(Vec::new())
+    }
+    /// 밝은 칸이라도 이 거리 밖은 보이지 않게 하는 선택적 시야 반경이다.
+    fn vision_radius(&self) -> Option<i16> {
+        None
+    }
+    /// 파서 뚫을 수 없는 벽 위치다. 지도 가장자리는 따로 적지 않아도 뚫을 수 없다.
+    fn undiggable_positions(&self) -> Result<Vec<Pos>, ContentError> {
+        Ok(Vec::new())
+    }
+    /// 세션을 만들 때 상태를 무작위로 정하는 문 위치다. 그 전까지는 닫힌 문으로 둔다.
+    fn random_doors(&self) -> Result<Vec<Pos>, ContentError> {
+        Ok(Vec::new())
+    }
 }
 
 pub const PHASE2_WIDTH: i16 = 40;
@@ -31,6 +48,21 @@ pub struct GameMap {
     pub width: i16,
     pub height: i16,
     tiles: Vec<TileKind>,
+    /// 타일별 조명 상태다. 이전 저장본처럼 비어 있으면 모든 타일이 밝은 것으로 본다.
+    #[serde(default)]
+    lit: Vec<bool>,
+    /// 한 번만 나오는 보상을 이미 꺼낸 특수 지형 위치다. NetHack `levl[x][y].looted`와 같다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    looted: Vec<Pos>,
+    /// 밝기와 무관하게 시야를 자르는 반경이다. 없으면 시선이 닿는 밝은 칸은 모두 보인다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    vision_radius: Option<i16>,
+    /// 파서 뚫을 수 없는 벽 위치다. NetHack `W_NONDIGGABLE`과 같다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    undiggable: Vec<Pos>,
+    /// 아직 상태를 굴리지 않은 무작위 문 위치다. 세션 생성 뒤에는 항상 비어 있다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    random_doors: Vec<Pos>,
 }
 
 impl GameMap {
@@ -41,6 +73,11 @@ impl GameMap {
             width,
             height,
             tiles: vec![TileKind::Floor; (i32::from(width) * i32::from(height)) as usize],
+            lit: vec![true; (i32::from(width) * i32::from(height)) as usize],
+            looted: Vec::new(),
+            vision_radius: level.vision_radius(),
+            undiggable: level.undiggable_positions()?,
+            random_doors: level.random_doors()?,
         };
         for (pos, tile) in level.tile_overrides()? {
             map.set_tile(pos, tile)
@@ -49,6 +86,17 @@ impl GameMap {
                     x: pos.x,
                     y: pos.y,
                 })?;
+            if tile == TileKind::Corridor {
+                let _ = map.set_lit(pos, false);
+            }
+        }
+        for pos in level.unlit_positions()? {
+            map.set_lit(pos, false)
+                .map_err(|_| ContentError::InvalidCoordinate {
+                    level: level.level_id().to_owned(),
+                    x: pos.x,
+                    y: pos.y,
+                })?;
         }
         Ok(map)
     }
@@ -58,6 +106,11 @@ impl GameMap {
             width: PHASE2_WIDTH,
             height: PHASE2_HEIGHT,
             tiles: vec![TileKind::Floor; (PHASE2_WIDTH * PHASE2_HEIGHT) as usize],
+            lit: vec![true; (PHASE2_WIDTH * PHASE2_HEIGHT) as usize],
+            looted: Vec::new(),
+            vision_radius: Some(DEFAULT_VISION_RADIUS),
+            undiggable: Vec::new(),
+            random_doors: Vec::new(),
         };
 
         for x in 0..PHASE2_WIDTH {
@@ -97,6 +150,11 @@ impl GameMap {
             width: PHASE2_WIDTH,
             height: PHASE2_HEIGHT,
             tiles: vec![TileKind::Floor; (PHASE2_WIDTH * PHASE2_HEIGHT) as usize],
+            lit: vec![true; (PHASE2_WIDTH * PHASE2_HEIGHT) as usize],
+            looted: Vec::new(),
+            vision_radius: None,
+            undiggable: Vec::new(),
+            random_doors: Vec::new(),
         };
 
         for x in 0..PHASE2_WIDTH {
@@ -151,6 +209,61 @@ impl GameMap {
         &self.tiles
     }
 
+    /// 저장본 호환용 빈 조명 layer를 타일 수만큼 펼친 row-major 조명 상태다.
+    pub fn lit_tiles(&self) -> Vec<bool> {
+        (0..self.tiles.len())
+            .map(|idx| self.lit.get(idx).copied().unwrap_or(true))
+            .collect()
+    }
+
+    pub fn is_lit(&self, pos: Pos) -> bool {
+        self.index(pos)
+            .map(|idx| self.lit.get(idx).copied().unwrap_or(true))
+            .unwrap_or(false)
+    }
+
+    pub fn set_lit(&mut self, pos: Pos, lit: bool) -> Result<(), GameError> {
+        let idx = self.index(pos)?;
+        if self.lit.len() != self.tiles.len() {
+            self.lit = vec![true; self.tiles.len()];
+        }
+        self.lit[idx] = lit;
+        Ok(())
+    }
+
+    pub fn vision_radius(&self) -> Option<i16> {
+        self.vision_radius
+    }
+
+    pub fn is_looted(&self, pos: Pos) -> bool {
+        self.looted.contains(&pos)
+    }
+
+    pub fn set_looted(&mut self, pos: Pos) {
+        if !self.looted.contains(&pos) {
+            self.looted.push(pos);
+            self.looted.sort_by_key(|pos| (pos.y, pos.x));
+        }
+    }
+
+    pub fn looted_positions(&self) -> &[Pos] {
+        &self.looted
+    }
+
+    /// 파서 지형을 바꿀 수 있는 칸인지 여부다. 지도 가장자리는 언제나 뚫을 수 없다.
+    pub fn is_diggable(&self, pos: Pos) -> bool {
+        pos.x > 0
+            && pos.y > 0
+            && pos.x < self.width - 1
+            && pos.y < self.height - 1
+            && !self.undiggable.contains(&pos)
+    }
+
+    /// 상태를 정하지 않은 무작위 문 위치를 꺼내고 목록을 비운다.
+    pub fn take_random_doors(&mut self) -> Vec<Pos> {
+        std::mem::take(&mut self.random_doors)
+    }
+
     fn index(&self, pos: Pos) -> Result<usize, GameError> {
         if !self.contains(pos) {
             return Err(GameError::OutOfBounds { pos });
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{item::ItemKind, monster::MonsterKind, tile::TileKind},
+    ids::LevelId,
+    position::Pos,
+};
+
+/// 한 칸을 마지막으로 보았을 때의 모습이다. NetHack `levl[x][y].glyph`에 해당한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RememberedGlyph {
+    pub tile: TileKind,
+    /// 바닥에 놓여 있던 맨 위 물건이다.
+    pub object: Option<ItemKind>,
+    /// 그 칸에 서 있던 몬스터다. 시야를 벗어나도 다시 볼 때까지 남는다.
+    pub monster: Option<MonsterKind>,
+    /// 마지막으로 보았을 때 밝았는지다. 시야 밖 칸은 지금 밝기가 아니라 이 값으로 그린다.
+    #[serde(default)]
+    pub lit: bool,
+}
+
+/// 층 하나의 칸별 기억이다. 한 번도 보지 못한 칸은 `None`이다.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LevelMemory {
+    pub id: LevelId,
+    pub width: i16,
+    pub height: i16,
+    glyphs: Vec<Option<RememberedGlyph>>,
+}
+
+impl LevelMemory {
+    pub fn new(id: LevelId, width: i16, height: i16) -> Self {
+        Self {
+            id,
+            width,
+            height,
+            glyphs: vec![None; (width.max(0) as usize) * (height.max(0) as usize)],
+        }
+    }
+
+    pub fn glyph(&self, pos: Pos) -> Option<RememberedGlyph> {
+        self.index(pos).and_then(|index| self.glyphs[index])
+    }
+
+    pub fn remember(&mut self, pos: Pos, glyph: RememberedGlyph) {
+        if let Some(index) = self.index(pos) {
+            self.glyphs[index] = Some(glyph);
+        }
+    }
+
+    /// 기억하는 칸을 행 우선 순서로 돌려준다.
+    pub fn remembered(&self) -> impl Iterator<Item = (Pos, RememberedGlyph)> + '_ {
+        let width = self.width.max(1);
+        self.glyphs
+            .iter()
+            .enumerate()
+            .filter_map(move |(index, glyph)| {
+                glyph.map(|glyph| {
+                    (
+                        Pos {
+                            x: index as i16 % width,
+                            y: index as i16 / width,
+                        },
+                        glyph,
+                    )
+                })
+            })
+    }
+
+    fn index(&self, pos: Pos) -> Option<usize> {
+        (pos.x >= 0 && pos.y >= 0 && pos.x < self.width && pos.y < self.height)
+            .then(|| pos.y as usize * self.width as usize + pos.x as usize)
+    }
+}
+
+/// 층마다 본 것을 기억하는 지도 기억이다. 들어가 본 층만 항목을 가진다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapMemory {
+    pub levels: Vec<LevelMemory>,
+}
+
+impl MapMemory {
+    pub fn level(&self, id: LevelId) -> Option<&LevelMemory> {
+        self.levels.iter().find(|level| level.id == id)
+    }
+
+    /// `id` 층의 기억을 돌려주고, 처음 들어간 층이면 빈 기억을 만든다.
+    pub fn level_mut(&mut self, id: LevelId, width: i16, height: i16) -> &mut LevelMemory {
+        let index = match self.levels.iter().position(|level| level.id == id) {
+            Some(index) => index,
+            None => {
+                self.levels.push(LevelMemory::new(id, width, height));
+                self.levels.sort_by_key(|level| level.id);
+                self.levels
+                    .iter()
+                    .position(|level| level.id == id)
+                    .expect("방금 넣은 층 기억이 있어야 한다")
+            }
+        };
+        &mut self.levels[index]
+    }
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::combat::AttackProfile;
+use crate::domain::{
+    combat::DamageRoll,
+    item::{CorpseEffects, ItemKind},
+    kind::builtin_kinds,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MonsterAiKind {
@@ -9,16 +13,142 @@ pub enum MonsterAiKind {
     Stationary,
 }
 
+/// 몬스터를 근접 공격한 플레이어가 받는 수동 반격이다. NetHack `AT_NONE`/`AT_BOOM` 공격과 같다.
+/// 마비는 `damage`를 굴린 턴 동안 굳고, 나머지 피해 유형은 굴린 만큼 체력을 잃는다.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum MonsterPassive {
-    ParalyzeOnMelee,
+pub struct MonsterPassive {
+    pub damage_type: DamageType,
+    pub damage: DamageRoll,
 }
 
+/// 몬스터 공격 수단이다. NetHack `AT_*`와 같다.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum MonsterKind {
-    Jackal,
-    Goblin,
-    FloatingEye,
+pub enum AttackType {
+    Bite,
+    Claw,
+    Touch,
+    /// 플레이어가 몬스터를 볼 수 있어야 통한다.
+    Gaze,
+    /// 곁에서는 손에 든 무기로 치고, 떨어져 있으면 지닌 무기를 던진다.
+    Weapon,
+    /// 삼킨 플레이어를 놓아줄 때까지 붙잡는다.
+    Engulf,
+    /// 떨어져 있을 때만 쓴다. 한 번 쓰면 `ActorStats.ranged_cooldown` 동안 쉰다.
+    Breath,
+    /// 떨어져 있을 때만 쓴다.
+    Spit,
+}
+
+impl AttackType {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Bite => "bite",
+            Self::Claw => "claw",
+            Self::Touch => "touch",
+            Self::Gaze => "gaze",
+            Self::Weapon => "weapon",
+            Self::Engulf => "engulf",
+            Self::Breath => "breath",
+            Self::Spit => "spit",
+        }
+    }
+
+    /// 곁의 플레이어에게 `mattacku`가 쓰는 공격이다. 숨과 침은 떨어져 있을 때만 쓴다.
+    pub fn is_melee(self) -> bool {
+        !matches!(self, Self::Breath | Self::Spit)
+    }
+}
+
+/// 공격이 맞았을 때 피해 외에 주는 효과다. NetHack `AD_*`와 같다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DamageType {
+    #[default]
+    Physical,
+    /// 불 저항이 있으면 피해가 없다.
+    Fire,
+    /// 1/8 확률로 힘을 잃거나 더 다친다. `AD_DRST`다.
+    Poison,
+    /// 1/3 확률로 경험 레벨을 잃는다. `AD_DRLI`다.
+    Drain,
+    /// 몬스터에게 들러붙어 떨어질 때까지 달아나지 못한다. `AD_STCK`다.
+    Stick,
+    /// 지닌 물건 하나를 훔쳐 사라진다. `AD_SITM`이다.
+    Steal,
+    /// 몸이 굳는다. `AD_PLYS`다.
+    Paralyze,
+}
+
+/// 몬스터 content가 선언하는 공격 하나다. NetHack `struct attack`과 같다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MonsterAttack {
+    pub attack_type: AttackType,
+    pub damage_type: DamageType,
+    pub damage: DamageRoll,
+}
+
+/// NetHack `NATTK`처럼 몬스터 하나가 가질 수 있는 공격 수다.
+pub const MAX_ATTACKS: usize = 6;
+
+/// 몬스터가 차례마다 차례로 쓰는 공격 목록이다. `ActorStats`가 `Copy`로 남도록 고정 길이로 둔다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MonsterAttacks([Option<MonsterAttack>; MAX_ATTACKS]);
+
+impl MonsterAttacks {
+    /// 앞의 `MAX_ATTACKS`개만 담는다.
+    pub fn from_slice(attacks: &[MonsterAttack]) -> Self {
+        let mut slots = [None; MAX_ATTACKS];
+        for (slot, attack) in slots.iter_mut().zip(attacks) {
+            *slot = Some(*attack);
+        }
+        Self(slots)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = MonsterAttack> + '_ {
+        self.0.iter().flatten().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    pub fn first_melee(&self) -> Option<MonsterAttack> {
+        self.iter().find(|attack| attack.attack_type.is_melee())
+    }
+}
+
+/// 곁에 있지 않은 플레이어를 노리는 원거리 공격이다. content는 공격 목록의 첫 무기·침·숨
+/// 공격에서 고른다. NetHack `AT_WEAP`/`AT_SPIT`/`AT_BREA`와 같다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RangedAttack {
+    /// 지닌 창·화살·단검·돌을 던지고 공격 지팡이를 쏜다. 화살은 활이 있어야 쏜다.
+    Weapon,
+    /// 산성 독을 뱉는다.
+    Spit {
+        damage: DamageRoll,
+        #[serde(default)]
+        damage_type: DamageType,
+    },
+    /// 숨을 내뿜는다. 한 번 쓰면 `ActorStats.ranged_cooldown` 동안 쉰다.
+    Breath {
+        damage: DamageRoll,
+        #[serde(default)]
+        damage_type: DamageType,
+    },
+}
+
+/// content id로 정해지는 몬스터 종류다. 이름과 글자, 색은 content에서 온다.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MonsterKind(u16);
+
+builtin_kinds! {
+    MonsterKind,
+    MONSTER_KINDS {
+        Jackal => "monster.jackal",
+        Goblin => "monster.goblin",
+        FloatingEye => "monster.floating_eye",
+        WaterMoccasin => "monster.water_moccasin",
+        WaterDemon => "monster.water_demon",
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -29,25 +159,52 @@ pub struct MonsterTemplate {
     pub ac: i16,
     pub hit_bonus: i16,
     pub damage_bonus: i16,
-    pub attack_profile: AttackProfile,
+    pub attacks: MonsterAttacks,
     pub speed: i16,
     pub passive: Option<MonsterPassive>,
+    /// 닫힌 문을 열고 지나갈 수 있다.
+    pub opens_doors: bool,
+    pub ranged: Option<RangedAttack>,
     pub difficulty: u16,
+    /// `None`이면 시체를 남기지 않는다.
+    pub corpse: Option<CorpseTemplate>,
+}
+
+/// 몬스터 content가 선언하는 시체 정보다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CorpseTemplate {
+    pub weight: i16,
+    pub nutrition: i16,
+    /// 죽을 때 `1/leave_chance` 확률로 시체를 남긴다.
+    pub leave_chance: u8,
+    pub effects: CorpseEffects,
 }
 
 impl MonsterKind {
+    /// `ActorStats.ai_kind`가 없는 예전 저장본의 행동 방식이다.
     pub fn ai_kind(self) -> MonsterAiKind {
         match self {
             Self::Jackal => MonsterAiKind::Wander,
-            Self::Goblin => MonsterAiKind::ChaseVisiblePlayer,
             Self::FloatingEye => MonsterAiKind::Stationary,
+            _ => MonsterAiKind::ChaseVisiblePlayer,
         }
     }
+
+    /// 이 몬스터의 시체 아이템이다. content가 시체를 선언하지 않았으면 `None`이다.
+    pub fn corpse_kind(self) -> Option<ItemKind> {
+        let monster = self.content_id().strip_prefix("monster.")?;
+        ItemKind::from_content_id(&format!("item.corpse.{monster}"))
+    }
+
+    /// `ActorStats.difficulty`가 없는 예전 저장본의 난이도다.
     pub fn difficulty(self) -> u8 {
         match self {
             Self::Jackal => 1,
             Self::Goblin => 2,
             Self::FloatingEye => 5,
+            Self::WaterMoccasin => 7,
+            Self::WaterDemon => 11,
+            _ => 0,
         }
     }
 }
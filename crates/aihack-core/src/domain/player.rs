@@ -1,4 +1,13 @@
-use crate::domain::combat::AttackProfile;
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    action::RunMode,
+    domain::combat::AttackProfile,
+    ids::{EntityId, LevelId},
+    position::{Direction, Pos},
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PlayerTemplate {
@@ -6,15 +15,166 @@ pub struct PlayerTemplate {
     pub ac: i16,
     pub hit_bonus: i16,
     pub damage_bonus: i16,
+    pub strength: u8,
+    /// 자물쇠 따기와 문 걷어차기에 쓰는 민첩이다.
+    pub dexterity: u8,
+    /// 시작 alignment record다. NetHack 3.6의 새 캐릭터처럼 10에서 시작한다.
+    pub alignment: i16,
     pub attack_profile: AttackProfile,
 }
 
+/// 진행 중인 파기다. NetHack `context.digging`과 같다. 같은 곳을 다시 파면 `effort`가 이어진다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DigProgress {
+    pub level: LevelId,
+    pub pos: Pos,
+    /// 발밑 바닥을 파 내려가는 중인지 여부다.
+    pub down: bool,
+    pub effort: u16,
+}
+
+/// 일을 시작한 뒤로 본 적대 몬스터와 마지막 체력이다. 이 둘이 바뀌면 occupation이 방해받는다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OccupationWatch {
+    pub hp: i16,
+    pub seen: Vec<EntityId>,
+}
+
+/// 걸음 사이에 이어지는 달리기다. NetHack `context.run`처럼 world에 남으므로 저장하고
+/// 불러와도 이어 달리며, 다음 걸음(`CommandIntent::ContinueRun`)이 합법인지를 정한다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ActiveRun {
+    /// 이 달리기의 걸음들이 `TurnOutcome::run_group`으로 함께 갖는 번호다.
+    pub group: u64,
+    pub occupation: Occupation,
+    pub watch: OccupationWatch,
+}
+
+/// 플레이어가 최근에 선 칸들이다. NetHack `track.c`의 `utrack`처럼 새것부터
+/// [`PlayerTrack::SIZE`]개까지 남기고, 플레이어를 놓친 몬스터가 이 자취를 따라온다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerTrack {
+    positions: VecDeque<Pos>,
+}
+
+impl PlayerTrack {
+    /// NetHack `UTSZ`다.
+    pub const SIZE: usize = 50;
+
+    /// NetHack `settrack`다. 같은 칸에 머물면 다시 남기지 않는다.
+    pub fn record(&mut self, pos: Pos) {
+        if self.positions.front() == Some(&pos) {
+            return;
+        }
+        if self.positions.len() == Self::SIZE {
+            self.positions.pop_back();
+        }
+        self.positions.push_front(pos);
+    }
+
+    /// NetHack `initrack`다. 다른 층으로 가면 자취가 끊긴다.
+    pub fn clear(&mut self) {
+        self.positions.clear();
+    }
+
+    /// NetHack `gettrack`다. `pos` 곁의 자취 가운데 가장 새것이다. 가장 새 곁 자취가 `pos`
+    /// 자신이면 더 따라갈 곳이 없으므로 `None`이다.
+    pub fn next_from(&self, pos: Pos) -> Option<Pos> {
+        self.positions
+            .iter()
+            .copied()
+            .find(|step| step.chebyshev_distance(pos) <= 1)
+            .filter(|step| *step != pos)
+    }
+}
+
+/// 한 명령으로 시작해 여러 행동에 걸쳐 이어지는 일이다. NetHack `set_occupation()`으로 거는
+/// 일과 횟수 접두(`multi`)로 되풀이하는 명령을 함께 다루며, 명령을 낸 session이 끝나거나
+/// 방해받을 때까지 이어 간다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Occupation {
+    /// 음식을 한 입씩 먹는다. `can_choke`는 배부른 상태에서 먹기 시작했는지 여부다.
+    Eating { item: EntityId, can_choke: bool },
+    /// 남은 횟수만큼 더 찾는다.
+    Searching { remaining: u16 },
+    /// 남은 횟수만큼 더 쉰다.
+    Resting { remaining: u16 },
+    /// 횟수를 붙인 이동으로 남은 칸만큼 같은 방향으로 더 걷는다.
+    Moving {
+        direction: Direction,
+        remaining: u16,
+    },
+    /// `_` travel로 `target`까지 걷는다. 걸음마다 길을 다시 찾는다.
+    Traveling { target: Pos },
+    /// `mode`로 달리는 중이다. `direction`은 마지막으로 걸은 방향이다.
+    Running { direction: Direction, mode: RunMode },
+    /// 자동 탐험 중이다. 걸음마다 가장 가까운 미탐험 경계를 다시 고른다.
+    Exploring,
+    /// 갑옷을 입는 중이다. 남은 행동을 다 쓰면 입혀진다.
+    Donning { item: EntityId, remaining: u8 },
+    /// 곡괭이로 파던 곳이 뚫릴 때까지 판다.
+    Digging {
+        item: EntityId,
+        direction: Option<Direction>,
+    },
+}
+
+/// occupation을 멈추게 한 까닭이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InterruptReason {
+    /// 적대 몬스터가 새로 보이거나 곁에 왔다.
+    MonsterAppeared { monster: EntityId },
+    /// 플레이어가 피해를 입었다.
+    Damaged,
+    /// 허기 단계가 바뀌었다.
+    HungerChanged,
+    /// 기절이나 마비로 움직일 수 없게 되었다.
+    Helpless,
+    /// 배가 너무 불러 먹기를 그만두었다. NetHack "Continue eating?"에 아니라고 답한 것과 같다.
+    TooFull,
+    /// 달리는 중에 메시지가 나왔다.
+    Message,
+    /// 기억하는 지도에서 더 갈 수 있는 미탐험 경계가 없다.
+    Explored,
+    /// 체력이 최대치의 절반 이하로 떨어졌다.
+    LowHitPoints,
+    /// 발밑에 autopickup으로 줍지 않은 물건이 있다.
+    ItemsHere,
+    /// 플레이어가 덫을 밟았다.
+    Trap,
+    /// 다음 걸음이 막혔다.
+    Blocked,
+}
+
 pub fn adventurer_template() -> PlayerTemplate {
     PlayerTemplate {
         hp: 16,
         ac: 0,
         hit_bonus: 2,
         damage_bonus: 0,
+        strength: 14,
+        dexterity: 10,
+        alignment: 10,
         attack_profile: AttackProfile::dagger(),
     }
 }
+
+/// NetHack `abon()`의 힘 부분이다. 18/xx 힘은 다루지 않는다.
+pub fn strength_hit_bonus(strength: u8) -> i16 {
+    match strength {
+        ..=5 => -2,
+        6..=7 => -1,
+        8..=16 => 0,
+        _ => 1,
+    }
+}
+
+/// NetHack `dbon()`의 힘 부분이다.
+pub fn strength_damage_bonus(strength: u8) -> i16 {
+    match strength {
+        ..=5 => -1,
+        6..=15 => 0,
+        16 => 1,
+        _ => 2,
+    }
+}
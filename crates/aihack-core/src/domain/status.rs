@@ -20,16 +20,178 @@ impl Status {
         }
     }
     pub fn hunger_state(&self) -> HungerState {
-        match self.nutrition {
-            i16::MIN..=0 => HungerState::Fainting,
-            1..=50 => HungerState::Weak,
-            51..=150 => HungerState::Hungry,
-            151..=1000 => HungerState::NotHungry,
-            1001..=i16::MAX => HungerState::Satiated,
+        HungerState::from_nutrition(self.nutrition)
+    }
+}
+
+/// 물약과 증기 같은 효과가 남기는 플레이어 시한부 상태다.
+/// 값은 남은 턴 수이며 0이면 비활성이다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conditions {
+    pub confusion_turns: u16,
+    pub blindness_turns: u16,
+    pub see_invisible_turns: u16,
+    /// 축복된 투명 감지 물약이 주는 영구 intrinsic이다.
+    pub see_invisible: bool,
+    pub very_fast_turns: u16,
+    /// 시체를 먹어 얻는 영구 intrinsic이다.
+    #[serde(default)]
+    pub poison_resistance: bool,
+    /// 불 공격과 불 수동 반격의 피해를 막는다.
+    #[serde(default)]
+    pub fire_resistance: bool,
+    #[serde(default)]
+    pub telepathy: bool,
+    /// 영구 intrinsic 속도다. NetHack `HFast & INTRINSIC`와 같다.
+    #[serde(default)]
+    pub fast: bool,
+    /// 잠든 몬스터 곁을 깨우지 않고 지나간다. NetHack `Stealth`와 같다.
+    #[serde(default)]
+    pub stealth: bool,
+    /// 상한 시체로 생긴 치명적인 식중독이다. 0이 되면 죽으므로 `tick()`이 아니라
+    /// `systems::corpses`가 줄인다.
+    #[serde(default)]
+    pub food_poisoning_turns: u16,
+}
+
+impl Conditions {
+    pub fn is_confused(&self) -> bool {
+        self.confusion_turns > 0
+    }
+
+    pub fn is_blind(&self) -> bool {
+        self.blindness_turns > 0
+    }
+
+    pub fn sees_invisible(&self) -> bool {
+        self.see_invisible || self.see_invisible_turns > 0
+    }
+
+    pub fn has_intrinsic(&self, intrinsic: Intrinsic) -> bool {
+        match intrinsic {
+            Intrinsic::PoisonResistance => self.poison_resistance,
+            Intrinsic::FireResistance => self.fire_resistance,
+            Intrinsic::Telepathy => self.telepathy,
+            Intrinsic::Fast => self.fast,
+            Intrinsic::Stealth => self.stealth,
+        }
+    }
+
+    pub fn grant_intrinsic(&mut self, intrinsic: Intrinsic) {
+        match intrinsic {
+            Intrinsic::PoisonResistance => self.poison_resistance = true,
+            Intrinsic::FireResistance => self.fire_resistance = true,
+            Intrinsic::Telepathy => self.telepathy = true,
+            Intrinsic::Fast => self.fast = true,
+            Intrinsic::Stealth => self.stealth = true,
+        }
+    }
+
+    /// 턴 경과 시 각 timer를 줄이고 이번 턴에 끝난 상태를 돌려준다.
+    pub fn tick(&mut self) -> Vec<StatusEffect> {
+        let mut expired = Vec::new();
+        for (timer, effect) in [
+            (&mut self.confusion_turns, StatusEffect::Confusion),
+            (&mut self.blindness_turns, StatusEffect::Blindness),
+            (&mut self.see_invisible_turns, StatusEffect::SeeInvisible),
+            (&mut self.very_fast_turns, StatusEffect::VeryFast),
+        ] {
+            if *timer > 0 {
+                *timer -= 1;
+                if *timer == 0 {
+                    expired.push(effect);
+                }
+            }
+        }
+        expired
+    }
+}
+
+/// 이벤트와 관찰에서 쓰는 시한부 상태 이름이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum StatusEffect {
+    Confusion,
+    Blindness,
+    Sleep,
+    SeeInvisible,
+    VeryFast,
+    FoodPoisoning,
+}
+
+/// 시체를 먹어 얻을 수 있는 영구 능력이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Intrinsic {
+    PoisonResistance,
+    FireResistance,
+    Telepathy,
+    Fast,
+    Stealth,
+}
+
+/// 짐 무게에 따른 부담 단계다. NetHack `near_capacity()`와 같은 다섯 단계다.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Encumbrance {
+    #[default]
+    Unencumbered,
+    Burdened,
+    Stressed,
+    Strained,
+    Overtaxed,
+    Overloaded,
+}
+
+impl Encumbrance {
+    /// 상태 줄에 보일 이름이다. 부담이 없으면 `None`이다.
+    pub fn status_label(self) -> Option<&'static str> {
+        match self {
+            Self::Unencumbered => None,
+            Self::Burdened => Some("Burdened"),
+            Self::Stressed => Some("Stressed"),
+            Self::Strained => Some("Strained"),
+            Self::Overtaxed => Some("Overtaxed"),
+            Self::Overloaded => Some("Overloaded"),
         }
     }
 }
 
+/// 기도로 신이 고쳐 줄 수 있는 곤경이다. NetHack `in_trouble()`의 우선순위 순서로
+/// 선언하며, 앞의 세 가지가 major trouble이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Trouble {
+    FoodPoisoning,
+    Starving,
+    LowHitPoints,
+    CursedItems,
+    TrappedInPit,
+    Hungry,
+    Blind,
+    Confused,
+    Hallucinating,
+}
+
+impl Trouble {
+    pub fn is_major(self) -> bool {
+        matches!(
+            self,
+            Self::FoodPoisoning | Self::Starving | Self::LowHitPoints
+        )
+    }
+}
+
+/// luck을 바꾼 행동이다. luck 값 자체는 관찰에 드러나지 않으므로 이벤트에는 원인만 남긴다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LuckCause {
+    BrokeMirror,
+    KilledPeaceful,
+    PrayedTooSoon,
+    /// 제단에 시체를 바쳐 신의 호의를 얻은 경우다.
+    Sacrificed,
+    /// 왕좌에 앉아 luck이 바뀐 경우다.
+    SatOnThrone,
+}
+
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HungerState {
@@ -41,3 +203,27 @@ pub enum HungerState {
     /// v0.2 직렬화/API 호환용 legacy variant. 3.6.7 projection에서는 생성하지 않는다.
     Oversatiated,
 }
+
+impl HungerState {
+    /// nutrition 값을 NetHack 3.6.7 허기 단계로 투영한다.
+    pub fn from_nutrition(nutrition: i16) -> Self {
+        match nutrition {
+            i16::MIN..=0 => HungerState::Fainting,
+            1..=50 => HungerState::Weak,
+            51..=150 => HungerState::Hungry,
+            151..=1000 => HungerState::NotHungry,
+            1001..=i16::MAX => HungerState::Satiated,
+        }
+    }
+
+    /// 상태 줄에 보이는 NetHack 허기 표시다. 평소에는 아무것도 표시하지 않는다.
+    pub fn status_label(self) -> Option<&'static str> {
+        match self {
+            HungerState::Satiated | HungerState::Oversatiated => Some("Satiated"),
+            HungerState::NotHungry => None,
+            HungerState::Hungry => Some("Hungry"),
+            HungerState::Weak => Some("Weak"),
+            HungerState::Fainting => Some("Fainting"),
+        }
+    }
+}
@@ -4,39 +4,155 @@ use serde::{Deserialize, Serialize};
 pub enum DoorState {
     Closed,
     Open,
+    /// 닫혀 있고 잠긴 문이다. 열쇠나 자물쇠 따개로 열거나 걷어차 부숴야 한다.
+    Locked,
+    /// 걷어차여 부서진 문이다. 다시 닫을 수 없고 대각선으로도 지나갈 수 있다.
+    Broken,
+    /// 문짝이 없는 출입구다. NetHack `D_NODOOR`와 같다.
+    NoDoor,
+}
+
+impl DoorState {
+    /// 문짝이 달려 있어 대각선 이동을 막는 출입구인지 여부다.
+    pub fn is_intact(self) -> bool {
+        matches!(self, Self::Closed | Self::Open | Self::Locked)
+    }
+
+    /// 문짝이 가로막고 있는 상태인지 여부다.
+    pub fn is_closed(self) -> bool {
+        matches!(self, Self::Closed | Self::Locked)
+    }
+}
+
+/// 자물쇠를 다루는 방법이다. NetHack `lock.c`의 `picklock`과 `forcelock`에 해당한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LockMethod {
+    Unlock,
+    Lock,
+    Force,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TrapKind {
     Pit,
+    Arrow,
+    Dart,
+    BearTrap,
+    SleepingGas,
+    Rust,
+    Teleport,
+    TrapDoor,
+    Hole,
+    LandMine,
+    RollingBoulder,
+    Magic,
+}
+
+impl TrapKind {
+    pub const ALL: [TrapKind; 12] = [
+        TrapKind::Pit,
+        TrapKind::Arrow,
+        TrapKind::Dart,
+        TrapKind::BearTrap,
+        TrapKind::SleepingGas,
+        TrapKind::Rust,
+        TrapKind::Teleport,
+        TrapKind::TrapDoor,
+        TrapKind::Hole,
+        TrapKind::LandMine,
+        TrapKind::RollingBoulder,
+        TrapKind::Magic,
+    ];
+
+    /// NetHack `defsyms` 설명과 같은 함정 이름이다.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Pit => "pit",
+            Self::Arrow => "arrow trap",
+            Self::Dart => "dart trap",
+            Self::BearTrap => "bear trap",
+            Self::SleepingGas => "sleeping gas trap",
+            Self::Rust => "rust trap",
+            Self::Teleport => "teleportation trap",
+            Self::TrapDoor => "trap door",
+            Self::Hole => "hole",
+            Self::LandMine => "land mine",
+            Self::RollingBoulder => "rolling boulder trap",
+            Self::Magic => "magic trap",
+        }
+    }
+
+    /// 아래층으로 떨어뜨리는 함정인지 여부다.
+    pub fn is_hole(self) -> bool {
+        matches!(self, Self::TrapDoor | Self::Hole)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TileKind {
     Wall,
     Floor,
+    /// 방 사이를 잇는 통로다. NetHack `CORR`와 같다.
+    Corridor,
     Door(DoorState),
     HiddenDoor,
     Trap(TrapKind),
     HiddenTrap(TrapKind),
     StairsDown,
     StairsUp,
+    /// 떨어뜨린 아이템의 축복/저주가 드러나고 시체를 바칠 수 있는 제단이다.
+    Altar,
+    Fountain,
+    Sink,
+    Throne,
+    /// 파내면 시체나 몬스터가 나오는 무덤이다.
+    Grave,
+    /// 빠지면 허우적거리다 기어 나오지 못하면 익사하는 물웅덩이다.
+    Pool,
+    /// 웅덩이와 같은 규칙을 따르는 해자다.
+    Moat,
+    /// 빠지면 불타 죽는 용암이다.
+    Lava,
+    /// 지나갈 수 없고 시야도 가리는 나무다.
+    Tree,
+    /// 지나갈 수 없지만 너머가 보이는 쇠창살이다.
+    IronBars,
 }
 
 impl TileKind {
+    /// 걸어서 들어가도 되는 지형인지 여부다. 물과 용암은 헛디딜 때만 들어간다.
     pub fn is_movement_passable(self) -> bool {
         matches!(
             self,
             Self::Floor
-                | Self::Door(DoorState::Open)
+                | Self::Corridor
+                | Self::Door(DoorState::Open | DoorState::Broken | DoorState::NoDoor)
                 | Self::Trap(_)
                 | Self::HiddenTrap(_)
                 | Self::StairsDown
                 | Self::StairsUp
+                | Self::Altar
+                | Self::Fountain
+                | Self::Sink
+                | Self::Throne
+                | Self::Grave
         )
     }
     pub fn is_los_transparent(self) -> bool {
-        self.is_movement_passable()
+        self.is_movement_passable() || self.is_liquid() || self == Self::IronBars
+    }
+    /// 빠질 수 있는 액체 지형인지 여부다. NetHack `is_pool_or_lava`와 같다.
+    pub fn is_liquid(self) -> bool {
+        matches!(self, Self::Pool | Self::Moat | Self::Lava)
+    }
+    /// 메시지에 쓰는 액체 이름이다.
+    pub fn liquid_name(self) -> Option<&'static str> {
+        match self {
+            Self::Pool => Some("water"),
+            Self::Moat => Some("moat"),
+            Self::Lava => Some("lava"),
+            _ => None,
+        }
     }
     pub fn revealed_equivalent(self) -> Self {
         match self {
@@ -52,6 +168,17 @@ impl TileKind {
             other => other,
         }
     }
+    /// 던전 특수 지형인지 여부다. NetHack `IS_FURNITURE`와 같다.
+    pub fn is_feature(self) -> bool {
+        matches!(
+            self,
+            Self::Altar | Self::Fountain | Self::Sink | Self::Throne | Self::Grave
+        )
+    }
+    /// 대각선으로 드나들 수 없는 문 달린 출입구인지 여부다. NetHack `IS_DOOR`와 `D_BROKEN|D_NODOOR` 검사를 합친 것이다.
+    pub fn is_intact_doorway(self) -> bool {
+        matches!(self, Self::Door(state) if state.is_intact())
+    }
     pub fn is_hidden(self) -> bool {
         matches!(self, Self::HiddenDoor | Self::HiddenTrap(_))
     }
@@ -4,7 +4,6 @@ use crate::{
         tile::{DoorState, TileKind},
     },
     error::GameError,
-    event::GameEvent,
     position::Pos,
 };
 
@@ -27,35 +26,3 @@ pub fn change_door(
         tile => Err(GameError::NoDoor { pos, tile }),
     }
 }
-
-pub fn kick_door(map: &mut GameMap, pos: Pos) -> Result<Vec<GameEvent>, GameError> {
-    match map.tile(pos)? {
-        TileKind::HiddenDoor => {
-            map.set_tile(pos, TileKind::Door(DoorState::Open))?;
-            Ok(vec![
-                GameEvent::TileRevealed {
-                    pos,
-                    tile: TileKind::Door(DoorState::Closed),
-                },
-                GameEvent::DoorKicked { pos },
-                GameEvent::DoorChanged {
-                    pos,
-                    from: DoorState::Closed,
-                    to: DoorState::Open,
-                },
-            ])
-        }
-        TileKind::Door(DoorState::Closed) => {
-            map.set_tile(pos, TileKind::Door(DoorState::Open))?;
-            Ok(vec![
-                GameEvent::DoorKicked { pos },
-                GameEvent::DoorChanged {
-                    pos,
-                    from: DoorState::Closed,
-                    to: DoorState::Open,
-                },
-            ])
-        }
-        tile => Err(GameError::NoDoor { pos, tile }),
-    }
-}
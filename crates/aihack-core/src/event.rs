@@ -2,10 +2,13 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     domain::{
-        combat::DeathCause,
+        combat::{DeathCause, Missile},
         inventory::InventoryLetter,
-        item::EquipmentSlot,
-        tile::{DoorState, TileKind, TrapKind},
+        item::{BucStatus, EquipmentSlot, ItemKind},
+        monster::MonsterKind,
+        player::{InterruptReason, Occupation},
+        status::{HungerState, Intrinsic, LuckCause, StatusEffect, Trouble},
+        tile::{DoorState, LockMethod, TileKind, TrapKind},
     },
     ids::{EntityId, LevelId},
     position::{Direction, Pos},
@@ -38,11 +41,23 @@ pub enum GameEvent {
         pos: Pos,
         tile: TileKind,
     },
+    /// 분수가 마르거나 왕좌가 사라지는 등 던전 특수 지형이 바뀐 경우다.
+    FeatureChanged {
+        pos: Pos,
+        from: TileKind,
+        to: TileKind,
+    },
     ItemPickedUp {
         entity: EntityId,
         item: EntityId,
         letter: InventoryLetter,
     },
+    /// 금화 더미를 주워 소지금에 더한 경우다.
+    GoldPickedUp {
+        entity: EntityId,
+        item: EntityId,
+        amount: u32,
+    },
     ItemDropped {
         entity: EntityId,
         item: EntityId,
@@ -62,6 +77,91 @@ pub enum GameEvent {
         amount: i16,
         hp_after: i16,
     },
+    EntityDamaged {
+        entity: EntityId,
+        amount: i16,
+        hp_after: i16,
+    },
+    /// nutrition threshold를 넘어 허기 단계가 바뀐 경우다.
+    HungerChanged {
+        entity: EntityId,
+        from: HungerState,
+        to: HungerState,
+    },
+    StatusChanged {
+        entity: EntityId,
+        effect: StatusEffect,
+        turns: u16,
+    },
+    ExperienceLevelChanged {
+        entity: EntityId,
+        level: u8,
+        max_hp_after: i16,
+    },
+    PotionShattered {
+        item: EntityId,
+        pos: Pos,
+        target: Option<EntityId>,
+    },
+    ItemDipped {
+        entity: EntityId,
+        item: EntityId,
+        into: EntityId,
+    },
+    ItemTransformed {
+        item: EntityId,
+        from: ItemKind,
+        to: ItemKind,
+    },
+    ItemBucChanged {
+        item: EntityId,
+        buc: BucStatus,
+    },
+    ItemEnchanted {
+        item: EntityId,
+        enchantment: i8,
+    },
+    ItemErodeproofed {
+        item: EntityId,
+    },
+    /// 녹 함정 등으로 아이템 부식 단계가 오른 경우다.
+    ItemEroded {
+        item: EntityId,
+        erosion: u8,
+    },
+    /// 과도한 강화로 아이템이 증발하는 것처럼 소지품에서 사라진 경우다.
+    ItemDestroyed {
+        item: EntityId,
+    },
+    /// 시체가 오래되어 완전히 썩어 없어진 경우다.
+    CorpseRotted {
+        item: EntityId,
+    },
+    /// 등불을 켜거나 끄거나 기름이 다해 꺼진 경우다.
+    LightSourceChanged {
+        item: EntityId,
+        lit: bool,
+    },
+    /// 시체를 먹고 영구 능력을 얻은 경우다.
+    IntrinsicGained {
+        entity: EntityId,
+        intrinsic: Intrinsic,
+    },
+    EntityTeleported {
+        entity: EntityId,
+        from: Pos,
+        to: Pos,
+    },
+    AreaLit {
+        center: Pos,
+        radius: i16,
+        lit: bool,
+    },
+    MonsterCreated {
+        entity: EntityId,
+        kind: MonsterKind,
+        pos: Pos,
+    },
     TrapTriggered {
         entity: EntityId,
         trap: TrapKind,
@@ -74,6 +174,14 @@ pub enum GameEvent {
         from: Pos,
         to: Pos,
     },
+    /// 몬스터가 원거리 공격을 날린 경우다. `to`는 날아간 것이 멈춘 칸이고, 맞았는지는
+    /// 뒤따르는 `AttackResolved`가 알린다.
+    MissileFired {
+        attacker: EntityId,
+        missile: Missile,
+        from: Pos,
+        to: Pos,
+    },
     WandZapped {
         entity: EntityId,
         item: EntityId,
@@ -91,6 +199,50 @@ pub enum GameEvent {
     DoorKicked {
         pos: Pos,
     },
+    /// 파기로 지형이 바뀐 경우다. 벽은 통로가 되고 바닥은 구덩이를 거쳐 구멍이 된다.
+    TileDug {
+        pos: Pos,
+        from: TileKind,
+        to: TileKind,
+    },
+    /// 잠들어 있던 몬스터가 소음에 깬 경우다.
+    MonsterWokeUp {
+        entity: EntityId,
+    },
+    /// 몬스터가 겁을 먹고 달아나기 시작한 경우다. `turns`가 0이면 기한 없이 달아난다.
+    MonsterFled {
+        entity: EntityId,
+        turns: u8,
+    },
+    /// 달아나던 몬스터가 용기를 되찾은 경우다.
+    MonsterRallied {
+        entity: EntityId,
+    },
+    /// 몬스터가 플레이어를 붙잡거나 삼킨 경우다.
+    PlayerHeld {
+        holder: EntityId,
+    },
+    /// 붙잡던 몬스터가 죽거나 떨어져 플레이어가 풀려난 경우다.
+    PlayerReleased {
+        holder: EntityId,
+    },
+    /// 몬스터가 플레이어의 물건을 훔친 경우다.
+    ItemStolen {
+        thief: EntityId,
+        item: EntityId,
+    },
+    /// 자물쇠를 열거나 잠그거나 비틀어 보려 한 경우다. `tool`은 쓴 도구나 무기다.
+    LockWorked {
+        pos: Pos,
+        tool: ItemKind,
+        method: LockMethod,
+        success: bool,
+    },
+    /// 여러 턴에 걸친 일이 끝나기 전에 멈춘 경우다. `occupation`은 멈춘 시점의 남은 일이다.
+    OccupationInterrupted {
+        occupation: Occupation,
+        reason: InterruptReason,
+    },
     PassiveAttackTriggered {
         source: EntityId,
         target: EntityId,
@@ -99,6 +251,27 @@ pub enum GameEvent {
         entity: EntityId,
         cooldown_after: u16,
     },
+    /// 기도를 들은 신이 곤경 하나를 고친 경우다.
+    TroubleFixed {
+        entity: EntityId,
+        trouble: Trouble,
+    },
+    /// 너무 이른 기도 등으로 신의 분노가 커진 경우다.
+    GodAngered {
+        entity: EntityId,
+        anger: u8,
+    },
+    /// 소원으로 아이템을 얻은 경우다.
+    WishGranted {
+        entity: EntityId,
+        item: EntityId,
+        kind: ItemKind,
+    },
+    /// 거울을 깨거나 평화로운 몬스터를 죽이는 등으로 luck이 바뀐 경우다.
+    LuckChanged {
+        entity: EntityId,
+        cause: LuckCause,
+    },
     AttackResolved {
         attacker: EntityId,
         defender: EntityId,
@@ -1,10 +1,10 @@
 use crate::{
-    domain::entity::EntityKind,
+    domain::{entity::EntityKind, tile::TileKind},
     ids::{EntityId, LevelId},
     position::Pos,
 };
 
-pub const WORLD_INVARIANT_COUNT: u8 = 6;
+pub const WORLD_INVARIANT_COUNT: u8 = 8;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum WorldInvariantError {
@@ -29,6 +29,17 @@ pub enum WorldInvariantError {
         player: EntityId,
         owner: EntityId,
     },
+    /// 플레이어가 벽이나 닫힌 문처럼 들어갈 수 없는 지형 안에 있다.
+    PlayerInSolidTerrain {
+        level: LevelId,
+        pos: Pos,
+        tile: TileKind,
+    },
+    /// 지도 가장자리 칸이 지나갈 수 있는 지형으로 바뀌었다.
+    MapBorderOpen {
+        level: LevelId,
+        pos: Pos,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -52,6 +63,9 @@ pub trait WorldInvariantView {
     fn entity_kind(&self, entity: EntityId) -> Option<EntityKind>;
     fn actor_location(&self, entity: EntityId) -> Option<(LevelId, Pos)>;
     fn inventory_owner(&self) -> EntityId;
+    fn level_ids(&self) -> Vec<LevelId>;
+    fn level_size(&self, level: LevelId) -> Option<(i16, i16)>;
+    fn tile_at(&self, level: LevelId, pos: Pos) -> Option<TileKind>;
 }
 
 pub fn validate_world(world: &impl WorldInvariantView) -> InvariantReport {
@@ -92,9 +106,35 @@ pub fn validate_world(world: &impl WorldInvariantView) -> InvariantReport {
     if owner != player {
         errors.push(WorldInvariantError::InventoryOwnerMismatch { player, owner });
     }
+    if let Some(tile) = world.tile_at(current_level, player_pos) {
+        if !tile.is_movement_passable() && !tile.is_liquid() {
+            errors.push(WorldInvariantError::PlayerInSolidTerrain {
+                level: current_level,
+                pos: player_pos,
+                tile,
+            });
+        }
+    }
+    for level in world.level_ids() {
+        if let Some(pos) = open_border(world, level) {
+            errors.push(WorldInvariantError::MapBorderOpen { level, pos });
+        }
+    }
     report(errors)
 }
 
+/// 파기 같은 지형 변경이 지도 밖으로 나가는 길을 내지 않았는지 가장자리 칸을 훑는다.
+fn open_border(world: &impl WorldInvariantView, level: LevelId) -> Option<Pos> {
+    let (width, height) = world.level_size(level)?;
+    let horizontal = (0..width).flat_map(|x| [Pos { x, y: 0 }, Pos { x, y: height - 1 }]);
+    let vertical = (0..height).flat_map(|y| [Pos { x: 0, y }, Pos { x: width - 1, y }]);
+    horizontal.chain(vertical).find(|pos| {
+        world
+            .tile_at(level, *pos)
+            .is_some_and(|tile| tile.is_movement_passable())
+    })
+}
+
 fn report(errors: Vec<WorldInvariantError>) -> InvariantReport {
     InvariantReport {
         checked: WORLD_INVARIANT_COUNT,
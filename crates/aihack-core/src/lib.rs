@@ -29,8 +29,10 @@ pub mod domain {
     pub mod entity;
     pub mod inventory;
     pub mod item;
+    pub mod kind;
     pub mod level;
     pub mod map;
+    pub mod memory;
     pub mod monster;
     pub mod player;
     pub mod status;
@@ -1,5 +1,7 @@
+use std::collections::{HashMap, VecDeque};
+
 use crate::{
-    domain::map::GameMap,
+    domain::{map::GameMap, tile::TileKind},
     error::GameError,
     ids::{EntityId, LevelId},
     position::{Direction, Pos},
@@ -48,9 +50,33 @@ pub fn validate_destination_on_level(
 ) -> Result<(), GameError> {
     require_passable(world.map(level), to)?;
     require_unoccupied(world, actor, level, to)?;
+    validate_doorway(world.map(level), from, to, direction)?;
     validate_path(world, actor, level, from, direction)
 }
 
+/// 문짝이 달린 출입구로는 대각선으로 드나들 수 없다. NetHack `test_move`의 문 검사와 같다.
+pub fn validate_doorway(
+    map: &GameMap,
+    from: Pos,
+    to: Pos,
+    direction: Direction,
+) -> Result<(), GameError> {
+    if !direction.is_diagonal() {
+        return Ok(());
+    }
+    if map.tile(to)?.is_intact_doorway() {
+        return Err(GameError::CommandRejected(
+            "You can't move diagonally into an intact doorway.".to_string(),
+        ));
+    }
+    if map.tile(from)?.is_intact_doorway() {
+        return Err(GameError::CommandRejected(
+            "You can't move diagonally out of an intact doorway.".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 /// 목적지 공격이 허용되는 action도 공유할 수 있는 대각선 경로 검사다.
 pub fn validate_path(
     world: &impl MovementWorld,
@@ -78,6 +104,96 @@ pub fn is_passable_for_actor(
     validate_actor_destination(world, actor, direction).is_ok()
 }
 
+/// `from`에서 `target`까지 아는 칸만 밟는 가장 짧은 길을 걸음 방향의 순서로 찾는다.
+///
+/// NetHack `findtravelpath`처럼 너비 우선으로 찾는다. `known_tile`이 `None`인 칸은 모르는 칸이라
+/// 밟지 않고, 한 걸음마다 [`validate_destination_on_level`]과 같은 통과·출입구·대각선 모서리
+/// 규칙을 따른다. 본 함정은 목적지가 아니면 돌아가며, 몬스터 점유는 걸을 때 따로 판단한다.
+/// 길이 없으면 `None`이고 이미 목적지에 있으면 빈 길이다.
+pub fn find_travel_path(
+    from: Pos,
+    target: Pos,
+    known_tile: impl Fn(Pos) -> Option<TileKind>,
+) -> Option<Vec<Direction>> {
+    find_path_to(from, known_tile, |pos| pos == target)
+}
+
+/// [`find_travel_path`]와 같은 규칙으로 `is_goal`을 만족하는 가장 가까운 칸까지의 길을 찾는다.
+pub fn find_path_to(
+    from: Pos,
+    known_tile: impl Fn(Pos) -> Option<TileKind>,
+    is_goal: impl Fn(Pos) -> bool,
+) -> Option<Vec<Direction>> {
+    let mut came_from = HashMap::from([(from, None)]);
+    let mut frontier = VecDeque::from([from]);
+    while let Some(pos) = frontier.pop_front() {
+        if is_goal(pos) {
+            let mut path = Vec::new();
+            let mut cursor = pos;
+            while let Some(Some((previous, direction))) = came_from.get(&cursor).copied() {
+                path.push(direction);
+                cursor = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for direction in Direction::ALL {
+            let next = pos.offset(direction.delta());
+            if !came_from.contains_key(&next)
+                && is_travel_step(&known_tile, pos, next, is_goal(next), direction)
+            {
+                came_from.insert(next, Some((pos, direction)));
+                frontier.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// `origin`에서 닿는 칸마다 가장 짧은 걸음 수를 잰다.
+///
+/// [`find_path_to`]와 같은 통과·출입구·대각선 모서리 규칙을 쓰되 함정은 돌아가지 않는다. 걸음
+/// 규칙이 양방향으로 같으므로 플레이어 자리에서 한 번 재면 여러 몬스터가 값이 줄어드는 곁 칸을
+/// 따라 플레이어에게 오는 길로 함께 쓸 수 있다.
+pub fn distance_field(origin: Pos, tile: impl Fn(Pos) -> Option<TileKind>) -> HashMap<Pos, u16> {
+    let mut distances = HashMap::from([(origin, 0)]);
+    let mut frontier = VecDeque::from([origin]);
+    while let Some(pos) = frontier.pop_front() {
+        let distance = distances[&pos] + 1;
+        for direction in Direction::ALL {
+            let next = pos.offset(direction.delta());
+            if !distances.contains_key(&next) && is_travel_step(&tile, pos, next, true, direction) {
+                distances.insert(next, distance);
+                frontier.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+fn is_travel_step(
+    known_tile: &impl Fn(Pos) -> Option<TileKind>,
+    from: Pos,
+    to: Pos,
+    to_is_goal: bool,
+    direction: Direction,
+) -> bool {
+    let passable = |pos| known_tile(pos).is_some_and(TileKind::is_movement_passable);
+    let Some(tile) = known_tile(to) else {
+        return false;
+    };
+    if !tile.is_movement_passable() || (matches!(tile, TileKind::Trap(_)) && !to_is_goal) {
+        return false;
+    }
+    let Some((a, b)) = direction.orthogonal_components() else {
+        return true;
+    };
+    !tile.is_intact_doorway()
+        && !known_tile(from).is_some_and(TileKind::is_intact_doorway)
+        && passable(from.offset(a.delta()))
+        && passable(from.offset(b.delta()))
+}
+
 fn require_unoccupied(
     world: &impl MovementWorld,
     actor: EntityId,
@@ -97,9 +213,12 @@ fn require_unoccupied(
 
 #[cfg(test)]
 mod tests {
-    use super::{validate_actor_destination, MovementWorld};
+    use super::{distance_field, find_travel_path, validate_actor_destination, MovementWorld};
     use crate::{
-        domain::{map::GameMap, tile::TileKind},
+        domain::{
+            map::GameMap,
+            tile::{DoorState, TileKind},
+        },
         ids::{EntityId, LevelId},
         position::{Direction, Pos},
     };
@@ -154,4 +273,63 @@ mod tests {
 
         assert!(validate_actor_destination(&world, EntityId(1), Direction::SouthEast).is_err());
     }
+
+    #[test]
+    fn diagonal_move_rejects_an_intact_doorway_but_not_a_broken_one() {
+        let mut map = GameMap::fixture_phase2();
+        map.set_tile(Pos { x: 6, y: 4 }, TileKind::Door(DoorState::Open))
+            .unwrap();
+        let mut world = TestWorld {
+            map,
+            actor: EntityId(1),
+            location: (LevelId::main(1), Pos { x: 5, y: 5 }),
+            occupied: None,
+        };
+
+        assert!(validate_actor_destination(&world, EntityId(1), Direction::NorthEast).is_err());
+
+        world
+            .map
+            .set_tile(Pos { x: 6, y: 4 }, TileKind::Door(DoorState::Broken))
+            .unwrap();
+        assert!(validate_actor_destination(&world, EntityId(1), Direction::NorthEast).is_ok());
+    }
+
+    #[test]
+    fn travel_path_goes_around_walls_and_stays_on_known_tiles() {
+        let mut map = GameMap::fixture_phase2();
+        for y in 3..=7 {
+            map.set_tile(Pos { x: 7, y }, TileKind::Wall).unwrap();
+        }
+        let from = Pos { x: 5, y: 5 };
+        let target = Pos { x: 9, y: 5 };
+        let known = |pos: Pos| map.tile(pos).ok();
+
+        let path = find_travel_path(from, target, known).unwrap();
+        let end = path
+            .iter()
+            .fold(from, |pos, direction| pos.offset(direction.delta()));
+        assert_eq!(end, target);
+        // 벽 모서리를 대각선으로 스치지 못하므로 아래쪽 끝을 곧게 돌아간다.
+        assert_eq!(path.len(), 8);
+        assert_eq!(find_travel_path(from, from, known), Some(Vec::new()));
+
+        let unknown_beyond_wall = |pos: Pos| (pos.x < 7).then(|| map.tile(pos).ok()).flatten();
+        assert_eq!(find_travel_path(from, target, unknown_beyond_wall), None);
+    }
+
+    #[test]
+    fn distance_field_counts_steps_around_walls_from_the_origin() {
+        let mut map = GameMap::fixture_phase2();
+        for y in 3..=7 {
+            map.set_tile(Pos { x: 7, y }, TileKind::Wall).unwrap();
+        }
+        let origin = Pos { x: 9, y: 5 };
+
+        let distances = distance_field(origin, |pos| map.tile(pos).ok());
+
+        assert_eq!(distances[&origin], 0);
+        assert_eq!(distances[&Pos { x: 5, y: 5 }], 8);
+        assert!(!distances.contains_key(&Pos { x: 7, y: 5 }));
+    }
 }
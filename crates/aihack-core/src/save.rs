@@ -2,7 +2,14 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     action::CommandIntent,
-    domain::{inventory::Inventory, item::ItemKind, level::LevelRegistry},
+    domain::{
+        inventory::Inventory,
+        item::ItemKind,
+        level::LevelRegistry,
+        memory::MapMemory,
+        player::{ActiveRun, DigProgress, PlayerTrack},
+        status::Conditions,
+    },
     event::GameEvent,
     hash::SnapshotHash,
     ids::{EntityId, LevelId},
@@ -12,6 +19,8 @@ use crate::{
     world::WorldState,
 };
 
+/// JSON save 스키마 버전이다. 몬스터와 아이템 종류는 content id 문자열(`"item.weapon.dagger"`)로
+/// 쓰지만, 그 전 v1 save가 쓰던 variant 이름(`"Dagger"`)도 같은 종류로 읽으므로 1 그대로다.
 pub const SAVE_SCHEMA_VERSION_V1: u16 = 1;
 
 /// v1 JSON save에 포함되는 world 상태다. runtime-only 사망 원인은 저장하지 않는다.
@@ -27,9 +36,52 @@ pub struct SavedWorldV1<E> {
     pub prayer_cooldown: u16,
     pub paralysis_turns: u8,
     pub hallucinating: bool,
+    #[serde(default)]
+    pub conditions: Conditions,
+    #[serde(default = "default_experience_level")]
+    pub experience_level: u8,
+    #[serde(default = "default_strength")]
+    pub strength: u8,
+    #[serde(default = "default_dexterity")]
+    pub dexterity: u8,
+    #[serde(default = "default_alignment")]
+    pub alignment: i16,
+    #[serde(default)]
+    pub god_anger: u8,
+    #[serde(default)]
+    pub pending_wishes: u8,
     pub kill_count: u32,
     pub gold: u32,
     pub identified_items: Vec<ItemKind>,
+    #[serde(default)]
+    pub map_memory: MapMemory,
+    #[serde(default)]
+    pub player_track: PlayerTrack,
+    #[serde(default)]
+    pub digging: Option<DigProgress>,
+    #[serde(default)]
+    pub held_by: Option<EntityId>,
+    #[serde(default)]
+    pub turn_actions: u8,
+    /// 달리지 않을 때는 적지 않으므로 달리기 전의 v1 save와 같은 모양이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub running: Option<ActiveRun>,
+}
+
+fn default_experience_level() -> u8 {
+    1
+}
+
+fn default_strength() -> u8 {
+    crate::domain::player::adventurer_template().strength
+}
+
+fn default_dexterity() -> u8 {
+    crate::domain::player::adventurer_template().dexterity
+}
+
+fn default_alignment() -> i16 {
+    crate::domain::player::adventurer_template().alignment
 }
 
 impl<E: Clone> From<&WorldState<E>> for SavedWorldV1<E> {
@@ -45,9 +97,22 @@ impl<E: Clone> From<&WorldState<E>> for SavedWorldV1<E> {
             prayer_cooldown: world.prayer_cooldown,
             paralysis_turns: world.paralysis_turns,
             hallucinating: world.hallucinating,
+            conditions: world.conditions.clone(),
+            experience_level: world.experience_level,
+            strength: world.strength,
+            dexterity: world.dexterity,
+            alignment: world.alignment,
+            god_anger: world.god_anger,
+            pending_wishes: world.pending_wishes,
             kill_count: world.kill_count,
             gold: world.gold,
             identified_items: world.identified_items.clone(),
+            map_memory: world.map_memory.clone(),
+            player_track: world.player_track.clone(),
+            digging: world.digging,
+            held_by: world.held_by,
+            turn_actions: world.turn_actions,
+            running: world.running.clone(),
         }
     }
 }
@@ -65,9 +130,22 @@ impl<E> From<SavedWorldV1<E>> for WorldState<E> {
             prayer_cooldown: saved.prayer_cooldown,
             paralysis_turns: saved.paralysis_turns,
             hallucinating: saved.hallucinating,
+            conditions: saved.conditions,
+            experience_level: saved.experience_level,
+            strength: saved.strength,
+            dexterity: saved.dexterity,
+            alignment: saved.alignment,
+            god_anger: saved.god_anger,
+            pending_wishes: saved.pending_wishes,
             kill_count: saved.kill_count,
             gold: saved.gold,
             identified_items: saved.identified_items,
+            map_memory: saved.map_memory,
+            player_track: saved.player_track,
+            digging: saved.digging,
+            held_by: saved.held_by,
+            turn_actions: saved.turn_actions,
+            running: saved.running,
             last_death_cause: None,
         }
     }
@@ -1,4 +1,8 @@
-use crate::{domain::map::GameMap, event::GameEvent, position::Pos};
+use crate::{
+    domain::{map::GameMap, tile::TileKind},
+    event::GameEvent,
+    position::Pos,
+};
 
 pub fn reveal_tile(map: &mut GameMap, pos: Pos) -> Option<GameEvent> {
     let current = map.tile(pos).ok()?;
@@ -25,3 +29,18 @@ pub fn reveal_all_hidden_tiles(map: &mut GameMap) -> Vec<GameEvent> {
     }
     events
 }
+
+/// 숨겨진 함정만 공개한다. 숨겨진 문은 그대로 둔다.
+pub fn reveal_hidden_traps(map: &mut GameMap) -> Vec<GameEvent> {
+    let (width, height) = (map.width, map.height);
+    let mut events = Vec::new();
+    for y in 0..height {
+        for x in 0..width {
+            let pos = Pos { x, y };
+            if matches!(map.tile(pos), Ok(TileKind::HiddenTrap(_))) {
+                events.extend(reveal_tile(map, pos));
+            }
+        }
+    }
+    events
+}
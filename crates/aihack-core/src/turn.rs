@@ -6,6 +6,17 @@ use crate::{event::GameEvent, hash::SnapshotHash, run_state::RunState};
 pub struct TurnOutcome {
     pub accepted: bool,
     pub turn_advanced: bool,
+    /// 이 명령이 마지막으로 행동한 게임 턴에서 플레이어가 지금까지 행동한 횟수다.
+    /// 보통 속도면 언제나 1이고, 빠르면 한 턴에 2 이상이 된다. 턴을 쓰지 않은 명령은 0이다.
+    #[serde(default)]
+    pub player_actions: u8,
+    /// 이 명령으로 지나간 게임 턴 수다. 먹기나 여러 번 찾기처럼 여러 턴에 걸친 일은 1보다 크다.
+    #[serde(default)]
+    pub turns_elapsed: u64,
+    /// 달리기 한 번의 걸음들이 함께 갖는 번호다. 달리기는 걸음마다 따로 받아들인 명령이라
+    /// replay에 걸음 수만큼 줄이 남고, 이 번호로 한 달리기를 묶는다. 달리기가 아니면 `None`이다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_group: Option<u64>,
     pub events: Vec<GameEvent>,
     pub snapshot_hash: SnapshotHash,
     pub next_state: RunState,
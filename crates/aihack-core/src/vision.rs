@@ -1,16 +1,26 @@
+//! NetHack `vision.c` 규칙을 따르는 시야다.
+//!
+//! 시선이 닿는 칸 가운데 밝은 칸과 바로 곁의 칸만 보인다. 칸은 지도에 저장된
+//! 조명이나 근처 광원으로 밝아진다. 지도에 시야 반경이 있으면 그 밖은 밝아도 보이지 않는다.
+
 use crate::{domain::map::GameMap, position::Pos};
 
+/// content가 시야 반경을 켤 때 쓰는 기존 고정 반경이다.
 pub const DEFAULT_VISION_RADIUS: i16 = 8;
 
-pub fn visible_positions(map: &GameMap, origin: Pos) -> Vec<Pos> {
+/// 켜진 등불처럼 주위를 밝히는 광원이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LightSource {
+    pub pos: Pos,
+    pub radius: i16,
+}
+
+pub fn visible_positions(map: &GameMap, origin: Pos, lights: &[LightSource]) -> Vec<Pos> {
     let mut out = Vec::new();
-    for y in (origin.y - DEFAULT_VISION_RADIUS)..=(origin.y + DEFAULT_VISION_RADIUS) {
-        for x in (origin.x - DEFAULT_VISION_RADIUS)..=(origin.x + DEFAULT_VISION_RADIUS) {
+    for y in 0..map.height {
+        for x in 0..map.width {
             let pos = Pos { x, y };
-            if !map.contains(pos) || origin.chebyshev_distance(pos) > DEFAULT_VISION_RADIUS {
-                continue;
-            }
-            if has_line_of_sight(map, origin, pos) {
+            if is_visible(map, origin, pos, lights) {
                 out.push(pos);
             }
         }
@@ -18,6 +28,29 @@ pub fn visible_positions(map: &GameMap, origin: Pos) -> Vec<Pos> {
     out
 }
 
+/// `origin`에서 `pos`가 보이는지 여부다. 곁의 칸은 어두워도 보인다.
+pub fn is_visible(map: &GameMap, origin: Pos, pos: Pos, lights: &[LightSource]) -> bool {
+    map.contains(pos)
+        && within_vision_radius(map, origin, pos)
+        && (origin.chebyshev_distance(pos) <= 1 || is_lit(map, pos, lights))
+        && has_line_of_sight(map, origin, pos)
+}
+
+/// 지도에 시야 반경이 있으면 그 안쪽인지, 없으면 언제나 참이다.
+pub fn within_vision_radius(map: &GameMap, origin: Pos, pos: Pos) -> bool {
+    map.vision_radius()
+        .is_none_or(|radius| origin.chebyshev_distance(pos) <= radius)
+}
+
+/// 지도 조명이나 시선이 닿는 광원 반경 안에 있어 밝은 칸인지 여부다.
+pub fn is_lit(map: &GameMap, pos: Pos, lights: &[LightSource]) -> bool {
+    map.is_lit(pos)
+        || lights.iter().any(|light| {
+            light.pos.chebyshev_distance(pos) <= light.radius
+                && has_line_of_sight(map, light.pos, pos)
+        })
+}
+
 pub fn has_line_of_sight(map: &GameMap, from: Pos, to: Pos) -> bool {
     if from == to {
         return true;
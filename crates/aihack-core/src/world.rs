@@ -1,7 +1,15 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    domain::{combat::DeathCause, inventory::Inventory, item::ItemKind, level::LevelRegistry},
+    domain::{
+        combat::DeathCause,
+        inventory::Inventory,
+        item::ItemKind,
+        level::LevelRegistry,
+        memory::MapMemory,
+        player::{ActiveRun, DigProgress, PlayerTrack},
+        status::Conditions,
+    },
     ids::{EntityId, LevelId},
 };
 
@@ -18,9 +26,32 @@ pub struct WorldState<E> {
     pub prayer_cooldown: u16,
     pub paralysis_turns: u8,
     pub hallucinating: bool,
+    pub conditions: Conditions,
+    pub experience_level: u8,
+    /// 허기로 인한 일시 감소를 반영하지 않은 기본 힘이다.
+    pub strength: u8,
+    pub dexterity: u8,
+    /// NetHack `u.ualign.record`다. 기도 결과와 신의 반응을 정한다.
+    pub alignment: i16,
+    /// NetHack `u.ugangr`다. 0보다 크면 기도가 통하지 않는다.
+    pub god_anger: u8,
+    /// 분수의 물의 악마나 왕좌가 준, 아직 쓰지 않은 소원 수다.
+    pub pending_wishes: u8,
     pub kill_count: u32,
     pub gold: u32,
     pub identified_items: Vec<ItemKind>,
+    /// 층마다 마지막으로 본 지형, 물건, 몬스터 모습이다. 시야를 계산할 때마다 갱신된다.
+    pub map_memory: MapMemory,
+    /// 몬스터가 따라오는 플레이어의 최근 자취다.
+    pub player_track: PlayerTrack,
+    /// 곡괭이로 파던 곳과 그동안 들인 힘이다.
+    pub digging: Option<DigProgress>,
+    /// 플레이어를 붙잡거나 삼킨 몬스터다. NetHack `u.ustuck`과 같다.
+    pub held_by: Option<EntityId>,
+    /// 현재 게임 턴에 플레이어가 이미 쓴 행동 수다. 새 턴이 시작되면 0이 된다.
+    pub turn_actions: u8,
+    /// 달리는 중이면 다음 걸음에 쓸 달리기다.
+    pub running: Option<ActiveRun>,
     /// 현재 실행 중인 사망 처리만 위한 transient 값이며 v1 save에는 넣지 않는다.
     pub last_death_cause: Option<DeathCause>,
 }
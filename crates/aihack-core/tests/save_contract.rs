@@ -1,6 +1,14 @@
 use aihack_core::{
-    domain::{combat::DeathCause, entity::EntityStore, inventory::Inventory, level::LevelRegistry},
+    action::RunMode,
+    domain::{
+        combat::DeathCause,
+        entity::EntityStore,
+        inventory::Inventory,
+        level::LevelRegistry,
+        player::{ActiveRun, Occupation, OccupationWatch, PlayerTrack},
+    },
     ids::EntityId,
+    position::Direction,
     save::SavedWorldV1,
     world::WorldState,
 };
@@ -18,9 +26,32 @@ fn saved_world_roundtrip_preserves_persisted_state_and_resets_runtime_death_caus
         prayer_cooldown: 3,
         paralysis_turns: 2,
         hallucinating: true,
+        conditions: Default::default(),
+        experience_level: 1,
+        strength: 14,
+        dexterity: 10,
+        alignment: 10,
+        god_anger: 0,
+        pending_wishes: 0,
         kill_count: 7,
         gold: 42,
         identified_items: Vec::new(),
+        map_memory: Default::default(),
+        player_track: PlayerTrack::default(),
+        digging: None,
+        held_by: None,
+        turn_actions: 0,
+        running: Some(ActiveRun {
+            group: 12,
+            occupation: Occupation::Running {
+                direction: Direction::East,
+                mode: RunMode::Run,
+            },
+            watch: OccupationWatch {
+                hp: 10,
+                seen: vec![EntityId(2)],
+            },
+        }),
         last_death_cause: Some(DeathCause::Combat {
             attacker: EntityId(9),
         }),
@@ -33,5 +64,6 @@ fn saved_world_roundtrip_preserves_persisted_state_and_resets_runtime_death_caus
     assert_eq!(restored.nutrition, world.nutrition);
     assert_eq!(restored.luck, world.luck);
     assert_eq!(restored.gold, world.gold);
+    assert_eq!(restored.running, world.running);
     assert_eq!(restored.last_death_cause, None);
 }
@@ -1,7 +1,8 @@
 use std::{sync::Arc, time::Duration};
 
 use aihack_ai_contract::{
-    ActionIntent, ActionSpace, ClientRevision, CommandIntent, Direction, Observation,
+    ActionIntent, ActionSpace, ApplyDirection, ClientRevision, CommandIntent, Direction,
+    Observation,
 };
 use serde::Deserialize;
 use serde_json::{json, Value};
@@ -223,6 +224,24 @@ fn wire_command_value(command: CommandIntent) -> Value {
         CommandIntent::Quit => json!({ "type": "QUIT" }),
         CommandIntent::Move(direction) => direction_action("MOVE", direction),
         CommandIntent::Search => json!({ "type": "SEARCH" }),
+        CommandIntent::Repeat { count, intent } => json!({
+            "type": "REPEAT",
+            "count": count,
+            "intent": wire_command_value(intent.command()),
+        }),
+        CommandIntent::Travel { target } => json!({
+            "type": "TRAVEL",
+            "target": { "x": target.x, "y": target.y },
+        }),
+        CommandIntent::Run { direction, mode } => json!({
+            "type": "RUN",
+            "direction": direction_name(direction),
+            "mode": match mode {
+                aihack_ai_contract::RunMode::Rush => "RUSH",
+                aihack_ai_contract::RunMode::Run => "RUN",
+            },
+        }),
+        CommandIntent::Explore => json!({ "type": "EXPLORE" }),
         CommandIntent::Kick(direction) => direction_action("KICK", direction),
         CommandIntent::Open(direction) => direction_action("OPEN", direction),
         CommandIntent::Close(direction) => direction_action("CLOSE", direction),
@@ -244,10 +263,35 @@ fn wire_command_value(command: CommandIntent) -> Value {
             "direction": direction_name(direction),
         }),
         CommandIntent::Read { item } => item_action("READ", item.0),
+        CommandIntent::Dip { item, into } => json!({
+            "type": "DIP",
+            "item": item.0,
+            "into": into.0,
+        }),
         CommandIntent::Pray => json!({ "type": "PRAY" }),
+        CommandIntent::Apply { item, direction } => json!({
+            "type": "APPLY",
+            "item": item.0,
+            "direction": direction.map(|direction| match direction {
+                ApplyDirection::Toward(direction) => direction_name(direction),
+                ApplyDirection::Down => "DOWN",
+            }),
+        }),
+        CommandIntent::PickLock { item, direction } => json!({
+            "type": "PICK_LOCK",
+            "item": item.0,
+            "direction": direction_name(direction),
+        }),
+        CommandIntent::Force { direction } => direction_action("FORCE", direction),
+        CommandIntent::Offer { item } => item_action("OFFER", item.0),
+        CommandIntent::Sit => json!({ "type": "SIT" }),
+        CommandIntent::QuaffFountain => json!({ "type": "QUAFF_FOUNTAIN" }),
+        CommandIntent::DipFountain { item } => item_action("DIP_FOUNTAIN", item.0),
+        CommandIntent::Wish { kind } => json!({ "type": "WISH", "kind": kind }),
         CommandIntent::Descend => json!({ "type": "DESCEND" }),
         CommandIntent::Ascend => json!({ "type": "ASCEND" }),
         CommandIntent::AcknowledgeMore => json!({ "type": "ACKNOWLEDGE_MORE" }),
+        CommandIntent::ContinueRun => json!({ "type": "CONTINUE_RUN" }),
     }
 }
 
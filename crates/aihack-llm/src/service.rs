@@ -10,7 +10,7 @@ use std::{
 
 use aihack_ai_contract::{
     ActionSpace, ClientRevision, EntityObservation, GameEvent, ItemObservation, Observation,
-    PlayerObservation, RunStateSummary, TileObservation,
+    PlayerObservation, RunStateSummary, TileObservation, OBSERVATION_SCHEMA_VERSION,
 };
 use serde::Serialize;
 
@@ -22,7 +22,8 @@ use crate::{
 
 pub use aihack_ai_contract::llm::LlmPayload;
 
-pub const LLM_SCHEMA_VERSION: u16 = 1;
+/// LLM wire 스키마 버전이다. wire는 관측 일부를 그대로 싣기 때문에 관측 스키마 버전을 따른다.
+pub const LLM_SCHEMA_VERSION: u16 = OBSERVATION_SCHEMA_VERSION;
 pub type SessionRevision = ClientRevision;
 pub type VisibleTile = TileObservation;
 pub type VisibleEntity = EntityObservation;
@@ -227,7 +228,7 @@ fn run_worker(
         push_response(
             &response_queue,
             LlmResponseEnvelope {
-                schema_version: 1,
+                schema_version: LLM_SCHEMA_VERSION,
                 request_id: request.request_id,
                 revision,
                 result,
@@ -352,7 +353,7 @@ mod tests {
             push_response(
                 &queue,
                 LlmResponseEnvelope {
-                    schema_version: 1,
+                    schema_version: LLM_SCHEMA_VERSION,
                     request_id: RequestId::new(),
                     revision: ClientRevision {
                         turn,
@@ -3,6 +3,7 @@ use aihack_core::{
     domain::{
         inventory::Inventory,
         level::{LevelRegistry, PHASE5_LEVEL1_ID},
+        player::{adventurer_template, PlayerTrack},
     },
     error::ContentError,
     ids::EntityId,
@@ -10,10 +11,13 @@ use aihack_core::{
     world::WorldState,
 };
 
-use crate::domain::{
-    entity::{EntityLocation, EntityStore},
-    item::{try_item_data_from_registry, ItemKind},
-    monster::try_monster_template_from_registry,
+use crate::{
+    domain::{
+        entity::{EntityLocation, EntityStore, Faction},
+        item::{try_item_data_from_registry, ItemKind},
+        monster::try_monster_template_from_registry,
+    },
+    systems::prayer::INITIAL_PRAYER_TIMEOUT,
 };
 
 fn spawn_item(
@@ -38,14 +42,26 @@ pub fn initial_world(registry: &ContentRegistry) -> Result<WorldState<EntityStor
         x: level.player_start[0],
         y: level.player_start[1],
     });
+    let mut gold = Vec::new();
     for spawn in aihack_content::level_spawns(level)? {
         match spawn {
-            aihack_content::LevelSpawn::Monster { kind, pos } => {
-                entities.spawn_monster_with_template(
+            aihack_content::LevelSpawn::Monster {
+                kind,
+                pos,
+                asleep,
+                peaceful,
+            } => {
+                let monster = entities.spawn_monster_with_template(
                     kind,
                     try_monster_template_from_registry(kind, registry)?,
                     pos,
                 );
+                if let Some(stats) = entities.actor_stats_mut(monster) {
+                    stats.asleep = asleep;
+                }
+                if peaceful {
+                    entities.set_faction(monster, Faction::Neutral);
+                }
             }
             aihack_content::LevelSpawn::Item { kind, pos } => {
                 spawn_item(
@@ -58,6 +74,7 @@ pub fn initial_world(registry: &ContentRegistry) -> Result<WorldState<EntityStor
                     },
                 )?;
             }
+            aihack_content::LevelSpawn::Gold { pos, amount } => gold.push((pos, amount)),
         }
     }
     let mut inventory = Inventory::new(player_id);
@@ -85,7 +102,7 @@ pub fn initial_world(registry: &ContentRegistry) -> Result<WorldState<EntityStor
     for (kind, pos) in [
         (ItemKind::ArmorLeather, Pos { x: 7, y: 5 }),
         (ItemKind::ScrollIdentify, Pos { x: 9, y: 5 }),
-        (ItemKind::ScrollLevelTeleport, Pos { x: 11, y: 5 }),
+        (ItemKind::ScrollTeleportation, Pos { x: 11, y: 5 }),
     ] {
         spawn_item(
             &mut entities,
@@ -97,6 +114,19 @@ pub fn initial_world(registry: &ContentRegistry) -> Result<WorldState<EntityStor
             },
         )?;
     }
+    // 금화 더미는 시작 소지품과 바닥 물건 뒤에 만들어 그 물건들의 entity id를 바꾸지 않는다.
+    for (pos, amount) in gold {
+        let pile = spawn_item(
+            &mut entities,
+            registry,
+            ItemKind::GoldPiece,
+            EntityLocation::OnMap {
+                level: PHASE5_LEVEL1_ID,
+                pos,
+            },
+        )?;
+        entities.set_item_coins(pile, amount);
+    }
     Ok(WorldState {
         levels: LevelRegistry::from_layouts(registry.levels())?,
         current_level: PHASE5_LEVEL1_ID,
@@ -105,12 +135,25 @@ pub fn initial_world(registry: &ContentRegistry) -> Result<WorldState<EntityStor
         inventory,
         nutrition: 900,
         luck: 0,
-        prayer_cooldown: 0,
+        prayer_cooldown: INITIAL_PRAYER_TIMEOUT,
         paralysis_turns: 0,
         hallucinating: false,
+        conditions: Default::default(),
+        experience_level: 1,
+        strength: adventurer_template().strength,
+        dexterity: adventurer_template().dexterity,
+        alignment: adventurer_template().alignment,
+        god_anger: 0,
+        pending_wishes: 0,
         kill_count: 0,
         gold: 0,
         identified_items: Vec::new(),
+        map_memory: Default::default(),
+        player_track: PlayerTrack::default(),
+        digging: None,
+        held_by: None,
+        turn_actions: 0,
+        running: None,
         last_death_cause: None,
     })
 }
@@ -26,12 +26,18 @@ impl EntityStore {
         Self(aihack_core::domain::entity::EntityStore::new())
     }
 
+    /// embedded content의 `kind` 몬스터를 만드는 fixture 생성이다. content에 없는 종류면 panic한다.
     pub fn spawn_monster(&mut self, kind: MonsterKind, pos: Pos) -> EntityId {
-        self.spawn_monster_with_template(kind, crate::domain::monster::monster_template(kind), pos)
+        let template = crate::domain::monster::try_monster_template(kind)
+            .expect("fixture monsters come from the embedded content");
+        self.spawn_monster_with_template(kind, template, pos)
     }
 
+    /// embedded content의 `kind` 아이템을 만드는 fixture 생성이다. content에 없는 종류면 panic한다.
     pub fn spawn_item(&mut self, kind: ItemKind, location: EntityLocation) -> EntityId {
-        self.spawn_item_with_data(kind, crate::domain::item::item_data(kind), location)
+        let data = crate::domain::item::try_item_data(kind)
+            .expect("fixture items come from the embedded content");
+        self.spawn_item_with_data(kind, data, location)
     }
 
     pub fn spawn(
@@ -5,13 +5,12 @@ use aihack_core::{
 };
 
 pub use aihack_core::domain::item::{
-    ConsumableEffect, EquipmentSlot, ItemClass, ItemData, ItemKind, WandEffect,
+    BucStatus, ConsumableEffect, CorpseEffects, EquipmentSlot, IntrinsicGrant, ItemClass, ItemData,
+    ItemKind, WandEffect,
 };
 
-pub fn item_data(kind: ItemKind) -> ItemData {
-    try_item_data(kind).expect("embedded content registry is validated before item creation")
-}
-
+/// embedded content로 `kind`의 자료를 만든다. session 안에서는 world의 content를 쓰는
+/// [`GameWorld::item_kind_data`](crate::world::GameWorld::item_kind_data)로 찾는다.
 pub fn try_item_data(kind: ItemKind) -> Result<ItemData, ContentError> {
     try_item_data_from_registry(kind, aihack_content::registry()?)
 }
@@ -24,30 +23,19 @@ pub fn try_item_data_from_registry(
 }
 
 pub fn item_kind_from_id(id: &str) -> Result<ItemKind, ContentError> {
-    match id {
-        "item.weapon.dagger" => Ok(ItemKind::Dagger),
-        "item.food.ration" => Ok(ItemKind::FoodRation),
-        "item.potion.healing" => Ok(ItemKind::PotionHealing),
-        "item.wand.magic_missile" => Ok(ItemKind::WandMagicMissile),
-        "item.scroll.reveal" => Ok(ItemKind::ScrollReveal),
-        "item.scroll.identify" => Ok(ItemKind::ScrollIdentify),
-        "item.scroll.teleport" => Ok(ItemKind::ScrollLevelTeleport),
-        "item.weapon.rock" => Ok(ItemKind::Rock),
-        "item.armor.leather" => Ok(ItemKind::ArmorLeather),
-        "item.corpse.jackal" => Ok(ItemKind::CorpseJackal),
-        _ => Err(ContentError::UnknownReference {
-            owner: "item kind".to_owned(),
-            target: id.to_owned(),
-        }),
-    }
+    ItemKind::from_content_id(id).ok_or_else(|| ContentError::UnknownReference {
+        owner: "item kind".to_owned(),
+        target: id.to_owned(),
+    })
 }
 
 pub const UNARMED_ATTACK: AttackProfile = AttackProfile {
     name: "unarmed",
     hit_bonus: 0,
     damage: DamageRoll { dice: 1, sides: 2 },
+    damage_bonus: 0,
 };
 
-pub fn shop_base_price(kind: ItemKind) -> u32 {
-    item_data(kind).base_price
+pub fn shop_base_price(kind: ItemKind) -> Result<u32, ContentError> {
+    try_item_data(kind).map(|data| data.base_price)
 }
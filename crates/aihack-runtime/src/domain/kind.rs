@@ -0,0 +1 @@
+pub use aihack_core::domain::kind::{GlyphColor, KindInfo};
@@ -1,3 +1,4 @@
 pub mod entity;
 pub mod item;
+pub mod kind;
 pub mod monster;
@@ -1,13 +1,13 @@
 use aihack_content::ContentRegistry;
 use aihack_core::error::ContentError;
 
-pub use aihack_core::domain::monster::{MonsterAiKind, MonsterKind, MonsterTemplate};
-
-pub fn monster_template(kind: MonsterKind) -> MonsterTemplate {
-    try_monster_template(kind)
-        .expect("embedded content registry is validated before monster creation")
-}
+pub use aihack_core::domain::monster::{
+    AttackType, CorpseTemplate, DamageType, MonsterAiKind, MonsterAttack, MonsterAttacks,
+    MonsterKind, MonsterPassive, MonsterTemplate,
+};
 
+/// embedded content로 `kind`의 template을 만든다. session 안에서는 world의 content를 쓰는
+/// [`GameWorld::monster_template`](crate::world::GameWorld::monster_template)으로 찾는다.
 pub fn try_monster_template(kind: MonsterKind) -> Result<MonsterTemplate, ContentError> {
     try_monster_template_from_registry(kind, aihack_content::registry()?)
 }
@@ -20,15 +20,10 @@ pub fn try_monster_template_from_registry(
 }
 
 pub fn monster_kind_from_id(id: &str) -> Result<MonsterKind, ContentError> {
-    match id {
-        "monster.jackal" => Ok(MonsterKind::Jackal),
-        "monster.goblin" => Ok(MonsterKind::Goblin),
-        "monster.floating_eye" => Ok(MonsterKind::FloatingEye),
-        _ => Err(ContentError::UnknownReference {
-            owner: "monster kind".to_owned(),
-            target: id.to_owned(),
-        }),
-    }
+    MonsterKind::from_content_id(id).ok_or_else(|| ContentError::UnknownReference {
+        owner: "monster kind".to_owned(),
+        target: id.to_owned(),
+    })
 }
 
 pub fn monster_ai_kind(kind: MonsterKind) -> MonsterAiKind {
@@ -11,6 +11,7 @@ pub mod systems;
 mod transaction;
 pub mod world;
 
+pub use aihack_content::ContentRegistry;
 pub use aihack_core::error::{ContentError, GameError};
 pub use client::GameClient;
 pub use session::GameSession;
@@ -1,5 +1,7 @@
+use std::collections::HashSet;
+
 use aihack_core::{
-    action::{ActionIntent, CommandIntent, InventoryAction},
+    action::{ActionIntent, ApplyDirection, CommandIntent, InventoryAction, RepeatIntent, RunMode},
     domain::{
         entity::EntityLocation,
         item::{EquipmentSlot, ItemClass},
@@ -12,16 +14,20 @@ use aihack_core::{
 
 use crate::{
     systems::{
+        dig,
         doors::door_state_in_direction,
+        explore, luck,
         movement::{is_bump_attack_for_legal_action, is_passable_for_legal_action},
-        vision::visible_positions,
+        occupation,
+        vision::{self, visible_positions},
     },
     world::GameWorld,
 };
 
 pub use aihack_ai_contract::{
-    ActionSpace, EntityObservation, ItemObservation, Observation, PlayerObservation,
-    RunStateSummary, TileObservation, OBSERVATION_SCHEMA_VERSION,
+    ActionSpace, EntityObservation, ItemObservation, LuckFeeling, Observation, PlayerObservation,
+    RememberedMonsterObservation, RememberedObjectObservation, RunStateSummary, TileObservation,
+    OBSERVATION_SCHEMA_VERSION,
 };
 
 pub fn from_world(
@@ -31,8 +37,10 @@ pub fn from_world(
     event_log: &[GameEvent],
     world: &GameWorld,
 ) -> Observation {
-    let mut visible_tiles = visible_positions(world)
-        .into_iter()
+    let visible = visible_positions(world).into_iter().collect::<HashSet<_>>();
+    let mut visible_tiles = visible
+        .iter()
+        .copied()
         .filter_map(|pos| {
             world
                 .current_map()
@@ -43,10 +51,16 @@ pub fn from_world(
                     rel: world.player_pos().delta_to(pos),
                     tile: tile.observation_equivalent(),
                     visible: true,
+                    lit: vision::is_lit(world, pos),
                 })
         })
         .collect::<Vec<_>>();
     visible_tiles.sort_by_key(|tile| (tile.pos.y, tile.pos.x));
+    let remembered = world
+        .map_memory
+        .level(world.current_level())
+        .map(|memory| memory.remembered().collect::<Vec<_>>())
+        .unwrap_or_default();
 
     let legal_actions = legal_actions(world, run_state);
     Observation {
@@ -58,6 +72,34 @@ pub fn from_world(
         player: player_observation(world),
         player_pos: world.player_pos(),
         visible_tiles,
+        remembered_tiles: remembered
+            .iter()
+            .filter(|(pos, _)| !visible.contains(pos))
+            .map(|(pos, glyph)| TileObservation {
+                pos: *pos,
+                rel: world.player_pos().delta_to(*pos),
+                tile: glyph.tile,
+                visible: false,
+                lit: glyph.lit,
+            })
+            .collect(),
+        remembered_objects: remembered
+            .iter()
+            .filter_map(|(pos, glyph)| {
+                glyph
+                    .object
+                    .map(|kind| RememberedObjectObservation { pos: *pos, kind })
+            })
+            .collect(),
+        remembered_monsters: remembered
+            .iter()
+            .filter(|(pos, _)| !visible.contains(pos))
+            .filter_map(|(pos, glyph)| {
+                glyph
+                    .monster
+                    .map(|kind| RememberedMonsterObservation { pos: *pos, kind })
+            })
+            .collect(),
         visible_entities: visible_entities(world),
         inventory: inventory_observations(world),
         last_events: event_log
@@ -104,15 +146,35 @@ fn player_observation(world: &GameWorld) -> PlayerObservation {
         max_hp: stats.max_hp,
         current_level: world.current_level(),
         hunger: world.nutrition,
-        luck: world.luck,
+        luck: match luck::effective_luck(world).signum() {
+            -1 => LuckFeeling::Unlucky,
+            0 => LuckFeeling::Neutral,
+            _ => LuckFeeling::Lucky,
+        },
         prayer_cooldown: world.prayer_cooldown,
         paralysis_turns: world.paralysis_turns,
         hallucinating: world.hallucinating,
+        experience_level: world.experience_level,
+        strength: world.effective_strength(),
+        dexterity: world.dexterity,
+        confusion_turns: world.conditions.confusion_turns,
+        blindness_turns: world.conditions.blindness_turns,
+        very_fast_turns: world.conditions.very_fast_turns,
+        sees_invisible: world.conditions.sees_invisible(),
+        telepathic: world.conditions.telepathy,
+        fast: world.conditions.fast,
+        encumbrance: world.encumbrance(),
+        food_poisoning_turns: world.conditions.food_poisoning_turns,
+        alignment: world.alignment,
+        god_anger: world.god_anger,
+        pending_wishes: world.pending_wishes,
     }
 }
 
+/// 눈이 먼 텔레파시 보유자는 같은 층의 살아 있는 몬스터를 모두 감지한다.
 fn visible_entities(world: &GameWorld) -> Vec<EntityObservation> {
-    let visible = visible_positions(world);
+    let visible = visible_positions(world).into_iter().collect::<HashSet<_>>();
+    let senses_minds = world.conditions.telepathy && world.conditions.is_blind();
     let mut entities = world
         .entities
         .entities()
@@ -122,13 +184,16 @@ fn visible_entities(world: &GameWorld) -> Vec<EntityObservation> {
                 return None;
             }
             if let Some((_, _, level, pos, stats, alive)) = entity.actor() {
-                if level == world.current_level() && visible.contains(&pos) {
+                let seen = visible.contains(&pos);
+                if level == world.current_level() && (seen || (senses_minds && alive)) {
                     return Some(EntityObservation {
                         entity: entity.id,
                         kind: entity.kind(),
                         pos,
                         hp: Some(stats.hp),
                         alive,
+                        asleep: seen && stats.asleep,
+                        fleeing: seen && stats.fleeing,
                     });
                 }
             }
@@ -165,6 +230,11 @@ fn inventory_observations(world: &GameWorld) -> Vec<ItemObservation> {
                     None
                 },
                 identified: world.is_item_identified(kind),
+                buc: world.entities.item_buc(entry.item).unwrap_or_default(),
+                diluted: world.entities.item_diluted(entry.item),
+                enchantment: world.entities.item_enchantment(entry.item),
+                erosion: world.entities.item_erosion(entry.item),
+                partly_eaten: world.entities.item_bites(entry.item) > 0,
             })
         })
         .collect()
@@ -213,7 +283,9 @@ fn legal_actions(world: &GameWorld, run_state: RunState) -> Vec<CommandIntent> {
                         }
                     }
                     InventoryAction::Read => {
-                        if item_has_class(world, item, ItemClass::Scroll) {
+                        if item_has_class(world, item, ItemClass::Scroll)
+                            && !world.conditions.is_blind()
+                        {
                             actions.push(CommandIntent::Read { item });
                         }
                     }
@@ -230,21 +302,67 @@ fn playing_actions(world: &GameWorld) -> Vec<CommandIntent> {
     let mut actions = vec![
         CommandIntent::Wait,
         CommandIntent::Search,
+        CommandIntent::Repeat {
+            count: occupation::SUGGESTED_COUNT,
+            intent: RepeatIntent::Search,
+        },
+        CommandIntent::Repeat {
+            count: occupation::SUGGESTED_COUNT,
+            intent: RepeatIntent::Wait,
+        },
         CommandIntent::Pray,
+        CommandIntent::Sit,
         CommandIntent::ShowInventory,
     ];
-    if world
+    if explore::stop_reason(world, &[]).is_none() {
+        actions.push(CommandIntent::Explore);
+    }
+    if let Some(item) = world
         .entities
         .item_at(world.current_level(), world.player_pos())
-        .is_some()
     {
-        actions.push(CommandIntent::Pickup);
+        if world.can_lift(item) {
+            actions.push(CommandIntent::Pickup);
+        }
+    }
+    for item in world
+        .entities
+        .items_at(world.current_level(), world.player_pos())
+    {
+        if item_is_edible(world, item) {
+            actions.push(CommandIntent::Eat { item });
+        }
     }
     match world.current_map().tile(world.player_pos()) {
         Ok(TileKind::StairsDown) => actions.push(CommandIntent::Descend),
         Ok(TileKind::StairsUp) => actions.push(CommandIntent::Ascend),
+        Ok(TileKind::Altar) => {
+            for item in world
+                .entities
+                .items_at(world.current_level(), world.player_pos())
+                .into_iter()
+                .chain(world.inventory.entries.iter().map(|entry| entry.item))
+            {
+                if item_has_class(world, item, ItemClass::Corpse) {
+                    actions.push(CommandIntent::Offer { item });
+                }
+            }
+        }
+        Ok(TileKind::Fountain) => {
+            actions.push(CommandIntent::QuaffFountain);
+            for entry in &world.inventory.entries {
+                actions.push(CommandIntent::DipFountain { item: entry.item });
+            }
+        }
         _ => {}
     }
+    if world.pending_wishes > 0 {
+        for kind in world.item_kinds() {
+            if kind.corpse_of().is_none() {
+                actions.push(CommandIntent::Wish { kind });
+            }
+        }
+    }
     for entry in &world.inventory.entries {
         if let Some(data) = world.entities.item_data(entry.item) {
             if data.class == ItemClass::Weapon {
@@ -261,10 +379,32 @@ fn playing_actions(world: &GameWorld) -> Vec<CommandIntent> {
             {
                 actions.push(CommandIntent::Eat { item: entry.item });
             }
-            if data.class == ItemClass::Scroll {
+            if data.light_radius > 0 {
+                actions.push(CommandIntent::Apply {
+                    item: entry.item,
+                    direction: None,
+                });
+            }
+            if data.class == ItemClass::Scroll && !world.conditions.is_blind() {
                 actions.push(CommandIntent::Read { item: entry.item });
             }
-            if matches!(data.class, ItemClass::Weapon | ItemClass::Rock) {
+            {
+                for into in &world.inventory.entries {
+                    if into.item != entry.item
+                        && item_has_class(world, into.item, ItemClass::Potion)
+                    {
+                        actions.push(CommandIntent::Dip {
+                            item: entry.item,
+                            into: into.item,
+                        });
+                    }
+                }
+            }
+            if matches!(
+                data.class,
+                ItemClass::Weapon | ItemClass::Rock | ItemClass::Potion
+            ) || data.break_luck != 0
+            {
                 for direction in Direction::ALL {
                     actions.push(CommandIntent::Throw {
                         item: entry.item,
@@ -284,16 +424,67 @@ fn playing_actions(world: &GameWorld) -> Vec<CommandIntent> {
             }
         }
     }
+    let lock_tools = world
+        .inventory
+        .entries
+        .iter()
+        .filter_map(|entry| Some((entry.item, world.entities.item_data(entry.item)?.unlock?)))
+        .collect::<Vec<_>>();
+    let can_force = world
+        .inventory
+        .equipped_melee
+        .is_some_and(|weapon| item_has_class(world, weapon, ItemClass::Weapon));
+    let diggers = world
+        .inventory
+        .entries
+        .iter()
+        .map(|entry| entry.item)
+        .filter(|item| {
+            world
+                .entities
+                .item_data(*item)
+                .is_some_and(|data| data.digs)
+        })
+        .collect::<Vec<_>>();
+    let dig_directions = std::iter::once(ApplyDirection::Down)
+        .chain(Direction::ALL.into_iter().map(ApplyDirection::Toward))
+        .filter(|direction| dig::check_dig(world, dig::dig_direction(*direction)).is_ok())
+        .collect::<Vec<_>>();
+    for item in &diggers {
+        for direction in &dig_directions {
+            actions.push(CommandIntent::Apply {
+                item: *item,
+                direction: Some(*direction),
+            });
+        }
+    }
     for direction in Direction::ALL {
-        if is_passable_for_legal_action(world, direction)
-            || is_bump_attack_for_legal_action(world, direction)
-        {
+        if is_passable_for_legal_action(world, direction) {
+            actions.push(CommandIntent::Move(direction));
+            for mode in [RunMode::Rush, RunMode::Run] {
+                actions.push(CommandIntent::Run { direction, mode });
+            }
+        } else if is_bump_attack_for_legal_action(world, direction) {
             actions.push(CommandIntent::Move(direction));
         }
-        match door_state_in_direction(world, direction) {
+        let door = door_state_in_direction(world, direction);
+        match door {
             Some(DoorState::Closed) => actions.push(CommandIntent::Open(direction)),
             Some(DoorState::Open) => actions.push(CommandIntent::Close(direction)),
-            None => {}
+            _ => {}
+        }
+        if let Some(door @ (DoorState::Closed | DoorState::Locked)) = door {
+            for (item, unlock) in &lock_tools {
+                if door == DoorState::Locked || unlock.can_lock {
+                    actions.push(CommandIntent::PickLock {
+                        item: *item,
+                        direction,
+                    });
+                }
+            }
+            if door == DoorState::Locked && can_force {
+                actions.push(CommandIntent::Force { direction });
+            }
         }
         actions.push(CommandIntent::Kick(direction));
     }
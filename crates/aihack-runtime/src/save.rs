@@ -4,7 +4,13 @@ use std::{
     path::{Component, Path, PathBuf},
 };
 
-use aihack_core::{error::GameError, meta::GameMeta, rng::GameRng, session::SessionState};
+use aihack_content::ContentRegistry;
+use aihack_core::{
+    error::{ContentError, GameError},
+    meta::GameMeta,
+    rng::GameRng,
+    session::SessionState,
+};
 
 use crate::{domain::entity::EntityStore, session::GameSession, world::GameWorld};
 
@@ -64,6 +70,8 @@ pub fn append_replay_line(path: &Path, line: &ReplayLineV1) -> Result<(), GameEr
 }
 
 pub fn read_replay_lines(path: &Path) -> Result<Vec<ReplayLineV1>, GameError> {
+    // replay의 종류 content id는 embedded content가 붙인 번호로만 읽힌다.
+    aihack_content::registry()?;
     let file = File::open(path).map_err(|error| GameError::Io(error.to_string()))?;
     let reader = BufReader::new(file);
     reader
@@ -87,7 +95,9 @@ pub fn save_session_to_path(session: &GameSession, path: &Path) -> Result<(), Ga
     fs::rename(&temp_path, path).map_err(|error| GameError::Io(error.to_string()))
 }
 
+/// embedded content로 저장본을 읽는다. content id에 번호를 붙이도록 registry를 먼저 읽는다.
 pub fn load_session_from_path(path: &Path) -> Result<GameSession, GameError> {
+    aihack_content::registry()?;
     let payload = fs::read_to_string(path).map_err(|error| GameError::Io(error.to_string()))?;
     let save: SaveDataV1 = serde_json::from_str(&payload)
         .map_err(|error| GameError::Serialization(error.to_string()))?;
@@ -109,19 +119,35 @@ impl GameSession {
     }
 
     pub fn from_save_data(save: SaveDataV1) -> Result<Self, GameError> {
+        Self::from_save_data_with_registry(save, aihack_content::registry()?)
+    }
+
+    /// 저장본을 `registry` content로 되살린다. 저장본의 몬스터나 아이템 종류 중 하나라도
+    /// `registry`가 선언하지 않았으면 그 session은 규칙을 찾을 수 없으므로 받지 않는다.
+    pub fn from_save_data_with_registry(
+        save: SaveDataV1,
+        registry: &ContentRegistry,
+    ) -> Result<Self, GameError> {
         if save.schema_version != SAVE_SCHEMA_VERSION_V1 {
             return Err(GameError::SaveSchemaVersionMismatch {
                 expected: SAVE_SCHEMA_VERSION_V1,
                 actual: save.schema_version,
             });
         }
+        let world = GameWorld::from_saved_world_with_registry(save.world, registry);
+        if let Some(kind) = world.unknown_kinds().first() {
+            return Err(GameError::Content(ContentError::UnknownReference {
+                owner: "save".to_owned(),
+                target: (*kind).to_owned(),
+            }));
+        }
         Ok(Self {
             inner: SessionState {
                 meta: GameMeta { seed: save.seed },
                 rng: GameRng::from_state(save.rng_state),
                 turn: save.turn,
                 state: save.run_state,
-                world: GameWorld::from_saved_world(save.world),
+                world,
                 event_log: save.event_log,
             },
         })
@@ -1,14 +1,22 @@
 use std::ops::{Deref, DerefMut};
 
-use aihack_ai_contract::{ClientRevision, Observation};
+use aihack_ai_contract::{ActionIntent, ClientRevision, Observation};
 use aihack_content::ContentRegistry;
 use aihack_core::{
-    action::{CommandIntent, DirectionalAction, InventoryAction},
-    domain::{combat::DeathCause, monster::MonsterPassive, tile::TrapKind},
+    action::{
+        ApplyDirection, CommandIntent, DirectionalAction, InventoryAction, RepeatIntent, RunMode,
+    },
+    domain::{
+        combat::DeathCause,
+        item::ItemKind,
+        player::{ActiveRun, InterruptReason, Occupation, OccupationWatch},
+        status::{HungerState, StatusEffect},
+        tile::TileKind,
+    },
     error::ContentError,
     event::{GameEvent, MessagePriority},
     ids::EntityId,
-    position::Direction,
+    position::{Direction, Pos},
     rng::GameRng,
     session::SessionState,
     turn::TurnOutcome,
@@ -20,7 +28,9 @@ use crate::{
     observation,
     snapshot::GameSnapshot,
     systems::{
-        combat, death, doors, items, monster_ai, movement, projectiles, score, stairs, traps,
+        combat, corpses, death, dig, doors, explore, features, hunger, items, light, luck,
+        monster_ai, monster_attacks, morale, movement, occupation, prayer, projectiles, score,
+        speed, stairs, terrain, traps, travel, vision,
     },
     world::GameWorld,
 };
@@ -56,13 +66,15 @@ impl GameSession {
         seed: u64,
         registry: &ContentRegistry,
     ) -> Result<Self, ContentError> {
+        let mut world = GameWorld::try_fixture_phase5_with_registry(registry)?;
+        doors::generate_doors(&mut world, seed);
         Ok(Self {
             inner: SessionState {
                 meta: GameMeta { seed },
                 rng: GameRng::new(seed),
                 turn: 0,
                 state: RunState::Title,
-                world: GameWorld::try_fixture_phase5_with_registry(registry)?,
+                world,
                 event_log: Vec::new(),
             },
         })
@@ -82,13 +94,15 @@ impl GameSession {
     }
 
     pub fn new(seed: u64) -> Self {
+        let mut world = GameWorld::fixture_phase4();
+        doors::generate_doors(&mut world, seed);
         Self {
             inner: SessionState {
                 meta: GameMeta { seed },
                 rng: GameRng::new(seed),
                 turn: 0,
                 state: RunState::Title,
-                world: GameWorld::fixture_phase4(),
+                world,
                 event_log: Vec::new(),
             },
         }
@@ -188,6 +202,9 @@ impl GameSession {
     }
 
     fn submit_in_playing(&mut self, intent: CommandIntent) -> TurnOutcome {
+        if intent != CommandIntent::ContinueRun {
+            self.world.running = None;
+        }
         if self.world.paralysis_turns > 0
             && !matches!(intent, CommandIntent::Wait | CommandIntent::Quit)
         {
@@ -198,6 +215,11 @@ impl GameSession {
             CommandIntent::Quit => self.submit_quit(),
             CommandIntent::Move(direction) => self.submit_move(direction),
             CommandIntent::Search => self.submit_search(),
+            CommandIntent::Repeat { count, intent } => self.submit_repeat(count, intent),
+            CommandIntent::Travel { target } => self.submit_travel(target),
+            CommandIntent::Run { direction, mode } => self.submit_run(direction, mode),
+            CommandIntent::ContinueRun => self.submit_continue_run(),
+            CommandIntent::Explore => self.submit_explore(),
             CommandIntent::Kick(direction) => self.submit_kick(direction),
             CommandIntent::Open(direction) => self.submit_open(direction),
             CommandIntent::Close(direction) => self.submit_close(direction),
@@ -211,7 +233,16 @@ impl GameSession {
             CommandIntent::Eat { item } => self.submit_eat(item),
             CommandIntent::Zap { item, direction } => self.submit_zap(item, direction),
             CommandIntent::Read { item } => self.submit_read(item),
+            CommandIntent::Dip { item, into } => self.submit_dip(item, into),
+            CommandIntent::Apply { item, direction } => self.submit_apply(item, direction),
+            CommandIntent::PickLock { item, direction } => self.submit_pick_lock(item, direction),
+            CommandIntent::Force { direction } => self.submit_force(direction),
             CommandIntent::Pray => self.submit_pray(),
+            CommandIntent::Offer { item } => self.submit_offer(item),
+            CommandIntent::Sit => self.submit_sit(),
+            CommandIntent::QuaffFountain => self.submit_quaff_fountain(),
+            CommandIntent::DipFountain { item } => self.submit_dip_fountain(item),
+            CommandIntent::Wish { kind } => self.submit_wish(kind),
             CommandIntent::Descend => self.submit_descend(),
             CommandIntent::Ascend => self.submit_ascend(),
             CommandIntent::AcknowledgeMore => {
@@ -283,13 +314,21 @@ impl GameSession {
     }
 
     pub fn observation(&self) -> Observation {
-        observation::from_world(
+        let mut observation = observation::from_world(
             self.meta.seed,
             self.turn,
             self.state,
             &self.event_log,
             &self.world,
-        )
+        );
+        if self.world.running.is_some() {
+            observation.legal_actions.push(CommandIntent::ContinueRun);
+            observation
+                .action_space
+                .commands
+                .push(ActionIntent::Command(CommandIntent::ContinueRun));
+        }
+        observation
     }
 
     fn submit_wait(&mut self) -> TurnOutcome {
@@ -298,6 +337,8 @@ impl GameSession {
     }
 
     fn submit_move(&mut self, direction: Direction) -> TurnOutcome {
+        let state = &mut self.inner;
+        let direction = movement::confused_direction(&state.world, &mut state.rng, direction);
         let from = self.world.player_pos();
         let to = from.offset(direction.delta());
         if let Some(defender) = self
@@ -309,6 +350,32 @@ impl GameSession {
                 return self.submit_bump_attack(defender);
             }
         }
+        let state = &mut self.inner;
+        if let Some(events) = monster_attacks::struggle_free(&mut state.world, &mut state.rng) {
+            return self.accept_turn(events);
+        }
+        if let Some(events) = traps::struggle_out_of_trap(&mut self.world) {
+            return self.accept_turn(events);
+        }
+        if let Some(name) = self
+            .world
+            .current_map()
+            .tile(to)
+            .ok()
+            .and_then(TileKind::liquid_name)
+        {
+            if !self.world.conditions.is_confused() {
+                return self.reject(format!("you avoid stepping into the {name}"));
+            }
+            let state = &mut self.inner;
+            return match terrain::stumble_into_liquid(&mut state.world, &mut state.rng, direction) {
+                Ok(events) => {
+                    self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+                    self.accept_turn(events)
+                }
+                Err(error) => self.reject(format!("{error}")),
+            };
+        }
         match movement::move_player(&mut self.world, direction) {
             Ok(()) => {
                 let to = self.world.player_pos();
@@ -317,15 +384,8 @@ impl GameSession {
                     from,
                     to,
                 }];
-                events.extend(traps::trigger_player_trap(&mut self.world));
-                let player_id = self.world.player_id();
-                events.extend(death::collect_death_events_if_hp_depleted(
-                    &mut self.world,
-                    player_id,
-                    DeathCause::Trap {
-                        trap: TrapKind::Pit,
-                    },
-                ));
+                let state = &mut self.inner;
+                events.extend(traps::trigger_player_trap(&mut state.world, &mut state.rng));
                 self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
                 self.accept_turn(events)
             }
@@ -342,19 +402,19 @@ impl GameSession {
             return self.reject("bump attack target is not attackable".to_string());
         };
         let mut events = vec![combat::attack_event(&resolution)];
-        if matches!(
-            self.world
-                .entities
-                .get(defender)
-                .and_then(|entity| entity.monster_passive()),
-            Some(MonsterPassive::ParalyzeOnMelee)
-        ) {
-            self.world.paralysis_turns = self.world.paralysis_turns.max(2);
-            events.push(GameEvent::PassiveAttackTriggered {
-                source: defender,
-                target: attacker,
-            });
-        }
+        let state = &mut self.inner;
+        events.extend(morale::after_player_attack(
+            &mut state.world,
+            &mut state.rng,
+            defender,
+            resolution.hit,
+        ));
+        let state = &mut self.inner;
+        events.extend(monster_attacks::passive(
+            &mut state.world,
+            &mut state.rng,
+            defender,
+        ));
         events.extend(death::collect_death_events_after_attack(
             &mut self.world,
             attacker,
@@ -372,10 +432,124 @@ impl GameSession {
     }
 
     fn submit_search(&mut self) -> TurnOutcome {
-        let events = traps::search(&mut self.world);
+        let state = &mut self.inner;
+        let events = traps::search(&mut state.world, &mut state.rng);
         self.accept_turn(events)
     }
 
+    /// 횟수를 붙인 명령은 첫 번을 한 번짜리 명령과 똑같이 하고, 남은 횟수를 occupation으로 이어 간다.
+    fn submit_repeat(&mut self, count: u16, intent: RepeatIntent) -> TurnOutcome {
+        if count == 0 {
+            return self.reject("repeat count must be at least 1".to_string());
+        }
+        let watch = occupation::watch(&self.world);
+        let remaining = count - 1;
+        let (outcome, occupation) = match intent {
+            RepeatIntent::Wait => (self.submit_wait(), Occupation::Resting { remaining }),
+            RepeatIntent::Search => (self.submit_search(), Occupation::Searching { remaining }),
+            RepeatIntent::Move(direction) => (
+                self.submit_move(direction),
+                Occupation::Moving {
+                    direction,
+                    remaining,
+                },
+            ),
+        };
+        if !outcome.accepted {
+            return outcome;
+        }
+        self.continue_occupation(outcome, watch, occupation)
+    }
+
+    /// 첫 걸음은 보통 이동과 같고, 남은 길은 걸음마다 다시 찾으며 occupation으로 이어 간다.
+    fn submit_travel(&mut self, target: Pos) -> TurnOutcome {
+        if target == self.world.player_pos() {
+            return self.reject("you are already there".to_string());
+        }
+        let Some(direction) = travel::next_step(&self.world, target) else {
+            return self.reject(format!("no known path to {target:?}"));
+        };
+        let watch = occupation::watch(&self.world);
+        let outcome = self.submit_move(direction);
+        if !outcome.accepted {
+            return outcome;
+        }
+        self.continue_occupation(outcome, watch, Occupation::Traveling { target })
+    }
+
+    /// 첫 걸음만 걷고 달리기를 건다. 묶음 번호는 이 명령 앞까지 쌓인 사건 수라
+    /// 저장했다 불러와도 session 안에서 겹치지 않는다.
+    fn submit_run(&mut self, direction: Direction, mode: RunMode) -> TurnOutcome {
+        let run = ActiveRun {
+            group: self.event_log.len() as u64,
+            occupation: Occupation::Running { direction, mode },
+            watch: occupation::watch(&self.world),
+        };
+        let outcome = self.submit_move(direction);
+        if !outcome.accepted {
+            return outcome;
+        }
+        self.keep_running(outcome, run)
+    }
+
+    /// 걸려 있는 달리기의 다음 걸음을 한 행동으로 받아들인다.
+    fn submit_continue_run(&mut self) -> TurnOutcome {
+        let Some(run) = self.world.running.take() else {
+            return self.reject("not running".to_string());
+        };
+        let next_turn = self.turn + 1;
+        let state = &mut self.inner;
+        let (events, occupation) =
+            occupation::perform(&mut state.world, &mut state.rng, run.occupation, next_turn);
+        self.state = death::state_after_deaths_at(&self.world, next_turn);
+        let outcome = self.accept_turn(events);
+        self.keep_running(outcome, ActiveRun { occupation, ..run })
+    }
+
+    /// 방금 받아들인 걸음 `outcome`을 달리기 묶음에 넣고, 런타임 규칙으로 다음 걸음이 있는지 정한다.
+    /// 더 갈 수 없으면 조용히, 방해받았으면 `OccupationInterrupted`를 이 걸음에 남기고 멈춘다.
+    fn keep_running(&mut self, mut outcome: TurnOutcome, mut run: ActiveRun) -> TurnOutcome {
+        outcome.run_group = Some(run.group);
+        if self.state != RunState::Playing || !occupation::continues(&self.world, run.occupation) {
+            return outcome;
+        }
+        match occupation::interruption(&mut run.watch, &self.world, run.occupation, &outcome.events)
+        {
+            Some(reason) => {
+                let mut events = vec![GameEvent::OccupationInterrupted {
+                    occupation: run.occupation,
+                    reason,
+                }];
+                events.extend(occupation::stop_message(run.occupation));
+                self.event_log.extend(events.clone());
+                outcome.events.extend(events);
+                outcome.snapshot_hash = self.snapshot().stable_hash();
+            }
+            None => {
+                self.world.running = Some(run);
+                outcome.snapshot_hash = self.snapshot().stable_hash();
+            }
+        }
+        outcome
+    }
+
+    /// 첫 행동부터 occupation과 같은 탐험 걸음을 쓴다. 시작부터 멈출 까닭이 있으면 받지 않는다.
+    fn submit_explore(&mut self) -> TurnOutcome {
+        if let Some(reason) = explore::stop_reason(&self.world, &[]) {
+            return self.reject(match reason {
+                InterruptReason::Explored => "nothing left to explore".to_string(),
+                reason => format!("cannot explore now: {reason:?}"),
+            });
+        }
+        let watch = occupation::watch(&self.world);
+        let state = &mut self.inner;
+        let Some(events) = occupation::explore_step(&mut state.world, &mut state.rng) else {
+            return self.reject("cannot explore now".to_string());
+        };
+        self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+        self.accept_occupation(events, watch, Occupation::Exploring)
+    }
+
     fn submit_throw(&mut self, item: EntityId, direction: Direction) -> TurnOutcome {
         let state = &mut self.inner;
         match projectiles::throw_item(&mut state.world, &mut state.rng, item, direction) {
@@ -389,7 +563,12 @@ impl GameSession {
 
     fn submit_drop(&mut self, item: EntityId) -> TurnOutcome {
         match items::drop(&mut self.world, item) {
-            Ok(event) => self.accept_turn(vec![event]),
+            Ok(event) => {
+                let mut events = vec![event];
+                let state = &mut self.inner;
+                events.extend(features::after_drop(&mut state.world, &mut state.rng, item));
+                self.accept_turn(events)
+            }
             Err(error) => self.reject(error),
         }
     }
@@ -405,21 +584,45 @@ impl GameSession {
     fn submit_quaff(&mut self, item: EntityId) -> TurnOutcome {
         let state = &mut self.inner;
         match items::quaff(&mut state.world, &mut state.rng, item) {
-            Ok(events) => self.accept_turn(events),
+            Ok(events) => {
+                self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+                self.accept_turn(events)
+            }
             Err(error) => self.reject(error),
         }
     }
 
     fn submit_eat(&mut self, item: EntityId) -> TurnOutcome {
-        match items::eat(&mut self.world, item) {
-            Ok(events) => self.accept_turn(events),
+        let watch = occupation::watch(&self.world);
+        let can_choke = self.world.hunger_state() == HungerState::Satiated;
+        let state = &mut self.inner;
+        match items::eat(&mut state.world, &mut state.rng, item, can_choke) {
+            Ok(events) => {
+                self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+                self.accept_occupation(events, watch, Occupation::Eating { item, can_choke })
+            }
             Err(error) => self.reject(error),
         }
     }
 
     fn submit_wear(&mut self, item: EntityId) -> TurnOutcome {
-        match items::wear(&mut self.world, item) {
-            Ok(Some(event)) => self.accept_turn(vec![event]),
+        match items::donning_delay(&self.world, item) {
+            Ok(Some(0)) => match items::wear(&mut self.world, item) {
+                Ok(Some(event)) => self.accept_turn(vec![event]),
+                Ok(None) => self.accept_without_turn(Vec::new()),
+                Err(error) => self.reject(error),
+            },
+            Ok(Some(delay)) => {
+                let watch = occupation::watch(&self.world);
+                self.accept_occupation(
+                    Vec::new(),
+                    watch,
+                    Occupation::Donning {
+                        item,
+                        remaining: delay,
+                    },
+                )
+            }
             Ok(None) => self.accept_without_turn(Vec::new()),
             Err(error) => self.reject(error),
         }
@@ -437,29 +640,138 @@ impl GameSession {
     }
 
     fn submit_read(&mut self, item: EntityId) -> TurnOutcome {
-        match items::read(&mut self.world, item) {
-            Ok(events) => self.accept_turn(events),
+        let state = &mut self.inner;
+        match items::read(&mut state.world, &mut state.rng, item) {
+            Ok(events) => {
+                self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+                self.accept_turn(events)
+            }
+            Err(error) => self.reject(error),
+        }
+    }
+
+    fn submit_dip(&mut self, item: EntityId, into: EntityId) -> TurnOutcome {
+        let state = &mut self.inner;
+        match items::dip(&mut state.world, &mut state.rng, item, into) {
+            Ok(events) => {
+                self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+                self.accept_turn(events)
+            }
             Err(error) => self.reject(error),
         }
     }
 
     fn submit_kick(&mut self, direction: Direction) -> TurnOutcome {
-        match doors::kick_door(&mut self.world, direction) {
+        let pos = self.world.player_pos().offset(direction.delta());
+        if self.world.current_map().tile(pos) == Ok(TileKind::Sink) {
+            let state = &mut self.inner;
+            let events = features::kick_sink(&mut state.world, &mut state.rng, pos);
+            self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+            return self.accept_turn(events);
+        }
+        let state = &mut self.inner;
+        match doors::kick_door(&mut state.world, &mut state.rng, direction) {
             Ok(events) => self.accept_turn(events),
             Err(error) => self.reject(format!("{error}")),
         }
     }
 
     fn submit_pray(&mut self) -> TurnOutcome {
-        if self.world.prayer_cooldown > 0 {
-            return self.reject("prayer is on cooldown".to_string());
-        }
-        self.world.prayer_cooldown = 20;
-        self.world.luck = self.world.luck.saturating_add(1).min(3);
-        self.accept_turn(vec![GameEvent::PrayerOffered {
-            entity: self.world.player_id(),
-            cooldown_after: self.world.prayer_cooldown,
-        }])
+        let state = &mut self.inner;
+        let events = prayer::pray(&mut state.world, &mut state.rng);
+        self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+        self.accept_turn(events)
+    }
+
+    /// 곡괭이는 방향을 받아 파고, 다른 도구는 등불로 켜거나 끈다.
+    fn submit_apply(&mut self, item: EntityId, direction: Option<ApplyDirection>) -> TurnOutcome {
+        if self
+            .world
+            .entities
+            .item_data(item)
+            .is_some_and(|data| data.digs)
+        {
+            return match direction {
+                Some(direction) => self.submit_dig(item, dig::dig_direction(direction)),
+                None => self.reject("In what direction do you want to dig?".to_owned()),
+            };
+        }
+        match light::apply_lamp(&mut self.world, item) {
+            Ok(events) => self.accept_turn(events),
+            Err(error) => self.reject(error),
+        }
+    }
+
+    fn submit_pick_lock(&mut self, item: EntityId, direction: Direction) -> TurnOutcome {
+        let state = &mut self.inner;
+        match doors::pick_lock(&mut state.world, &mut state.rng, item, direction) {
+            Ok(events) => self.accept_turn(events),
+            Err(error) => self.reject(error),
+        }
+    }
+
+    fn submit_force(&mut self, direction: Direction) -> TurnOutcome {
+        let state = &mut self.inner;
+        match doors::force_lock(&mut state.world, &mut state.rng, direction) {
+            Ok(events) => self.accept_turn(events),
+            Err(error) => self.reject(error),
+        }
+    }
+
+    fn submit_dig(&mut self, item: EntityId, direction: Option<Direction>) -> TurnOutcome {
+        let watch = occupation::watch(&self.world);
+        let state = &mut self.inner;
+        match dig::dig(&mut state.world, &mut state.rng, item, direction) {
+            Ok(events) => {
+                self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+                self.accept_occupation(events, watch, Occupation::Digging { item, direction })
+            }
+            Err(error) => self.reject(error),
+        }
+    }
+
+    fn submit_offer(&mut self, item: EntityId) -> TurnOutcome {
+        let turn = self.turn + 1;
+        match features::offer(&mut self.world, item, turn) {
+            Ok(events) => self.accept_turn(events),
+            Err(error) => self.reject(error),
+        }
+    }
+
+    fn submit_sit(&mut self) -> TurnOutcome {
+        let state = &mut self.inner;
+        let events = features::sit(&mut state.world, &mut state.rng);
+        self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+        self.accept_turn(events)
+    }
+
+    fn submit_quaff_fountain(&mut self) -> TurnOutcome {
+        let state = &mut self.inner;
+        match features::quaff_fountain(&mut state.world, &mut state.rng) {
+            Ok(events) => {
+                self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+                self.accept_turn(events)
+            }
+            Err(error) => self.reject(error),
+        }
+    }
+
+    fn submit_dip_fountain(&mut self, item: EntityId) -> TurnOutcome {
+        let state = &mut self.inner;
+        match features::dip_fountain(&mut state.world, &mut state.rng, item) {
+            Ok(events) => {
+                self.state = death::state_after_deaths_at(&self.world, self.turn + 1);
+                self.accept_turn(events)
+            }
+            Err(error) => self.reject(error),
+        }
+    }
+
+    fn submit_wish(&mut self, kind: ItemKind) -> TurnOutcome {
+        match features::grant_wish(&mut self.world, kind) {
+            Ok(events) => self.accept_without_turn(events),
+            Err(error) => self.reject(error),
+        }
     }
 
     fn submit_open(&mut self, direction: Direction) -> TurnOutcome {
@@ -508,40 +820,159 @@ impl GameSession {
         }])
     }
 
+    /// 플레이어가 행동 한 번을 쓴 뒤 다음 플레이어 차례까지 진행한다. NetHack `moveloop`처럼
+    /// 플레이어 이동력이 남아 있으면 같은 게임 턴에 다시 차례가 오고, 플레이어와 몬스터가 모두
+    /// 이동력을 다 쓰면 새 게임 턴을 시작한다.
     fn accept_turn(&mut self, mut events: Vec<GameEvent>) -> TurnOutcome {
+        let player_id = self.world.player_id();
+        speed::spend_move(&mut self.world, player_id);
+        self.world.turn_actions = self.world.turn_actions.saturating_add(1);
+        let player_actions = self.world.turn_actions;
+        let mut turns_elapsed = 0;
+        while !matches!(self.state, RunState::GameOver { .. }) {
+            if !speed::can_act(&self.world, player_id)
+                && !monster_ai::any_monster_can_act(&self.world)
+            {
+                self.start_turn(&mut events, turns_elapsed == 0);
+                turns_elapsed += 1;
+            }
+            if !matches!(self.state, RunState::GameOver { .. }) {
+                let turn = self.turn;
+                let state = &mut self.inner;
+                events.extend(monster_ai::run_monster_turn(
+                    &mut state.world,
+                    &mut state.rng,
+                    &mut state.state,
+                    turn,
+                ));
+            }
+            if speed::can_act(&self.world, player_id) {
+                break;
+            }
+        }
+        let turn = self.turn;
+        let state = &mut self.inner;
+        corpses::leave_corpses(&mut state.world, &mut state.rng, &events);
+        let kills = prayer::record_kills(&mut state.world, &mut state.rng, &events, turn);
+        events.extend(kills);
+        vision::remember_visible(&mut self.world);
+        self.event_log.extend(events.clone());
+
+        TurnOutcome {
+            accepted: true,
+            turn_advanced: turns_elapsed > 0,
+            player_actions,
+            turns_elapsed,
+            run_group: None,
+            events,
+            snapshot_hash: self.snapshot().stable_hash(),
+            next_state: self.state,
+        }
+    }
+
+    /// 첫 행동을 받아들인 뒤 `occupation`이 끝나거나 방해받을 때까지 한 행동씩 이어 간다.
+    /// `watch`는 첫 행동 전에 만든 것이어야 하며, 모든 행동의 사건은 한 `TurnOutcome`에 모인다.
+    fn accept_occupation(
+        &mut self,
+        events: Vec<GameEvent>,
+        watch: OccupationWatch,
+        occupation: Occupation,
+    ) -> TurnOutcome {
+        let outcome = self.accept_turn(events);
+        self.continue_occupation(outcome, watch, occupation)
+    }
+
+    /// 이미 받아들인 첫 행동의 `outcome`에 남은 `occupation`의 행동들을 이어 붙인다.
+    fn continue_occupation(
+        &mut self,
+        mut outcome: TurnOutcome,
+        mut watch: OccupationWatch,
+        mut occupation: Occupation,
+    ) -> TurnOutcome {
+        let mut step_start = 0;
+        while self.state == RunState::Playing && occupation::continues(&self.world, occupation) {
+            if let Some(reason) = occupation::interruption(
+                &mut watch,
+                &self.world,
+                occupation,
+                &outcome.events[step_start..],
+            ) {
+                let mut events = vec![GameEvent::OccupationInterrupted { occupation, reason }];
+                events.extend(occupation::stop_message(occupation));
+                self.event_log.extend(events.clone());
+                outcome.events.extend(events);
+                outcome.snapshot_hash = self.snapshot().stable_hash();
+                break;
+            }
+            let next_turn = self.turn + 1;
+            let state = &mut self.inner;
+            let (events, next) =
+                occupation::perform(&mut state.world, &mut state.rng, occupation, next_turn);
+            occupation = next;
+            self.state = death::state_after_deaths_at(&self.world, next_turn);
+            let step = self.accept_turn(events);
+            step_start = outcome.events.len();
+            outcome.turn_advanced |= step.turn_advanced;
+            outcome.turns_elapsed += step.turns_elapsed;
+            outcome.player_actions = step.player_actions;
+            outcome.events.extend(step.events);
+            outcome.snapshot_hash = step.snapshot_hash;
+            outcome.next_state = step.next_state;
+        }
+        outcome
+    }
+
+    /// 새 게임 턴을 연다. 턴마다 한 번 도는 배고픔, 상태 시간, 광원 연료를 처리하고 이동력을 준다.
+    /// 한 명령에서 처음 여는 턴의 `TurnStarted`는 그 명령의 사건들 맨 앞에 둔다.
+    fn start_turn(&mut self, events: &mut Vec<GameEvent>, first: bool) {
         let next_turn = self.turn + 1;
-        events.insert(0, GameEvent::TurnStarted { turn: next_turn });
+        if first {
+            events.insert(0, GameEvent::TurnStarted { turn: next_turn });
+        } else {
+            events.push(GameEvent::TurnStarted { turn: next_turn });
+        }
         self.turn = next_turn;
-        self.world.nutrition = self.world.nutrition.saturating_sub(1);
         self.world.prayer_cooldown = self.world.prayer_cooldown.saturating_sub(1);
+        luck::tick_luck(&mut self.world, next_turn);
         if self.world.paralysis_turns > 0 {
             self.world.paralysis_turns -= 1;
         }
         if !matches!(self.state, RunState::GameOver { .. }) {
             let state = &mut self.inner;
-            events.extend(monster_ai::run_monster_turn(
-                &mut state.world,
-                &mut state.rng,
-                &mut state.state,
-                next_turn,
-            ));
+            events.extend(hunger::digest(&mut state.world, &mut state.rng));
+            events.extend(corpses::tick_food_poisoning(&mut state.world));
+            self.state = death::state_after_deaths_at(&self.world, next_turn);
         }
-        self.event_log.extend(events.clone());
-
-        TurnOutcome {
-            accepted: true,
-            turn_advanced: true,
-            events,
-            snapshot_hash: self.snapshot().stable_hash(),
-            next_state: self.state,
+        events.extend(corpses::age_corpses(&mut self.world));
+        events.extend(light::burn_lamps(&mut self.world));
+        let player_id = self.world.player_id();
+        for effect in self.world.conditions.tick() {
+            events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect,
+                turns: 0,
+            });
+            events.push(GameEvent::Message {
+                priority: MessagePriority::Info,
+                text: condition_expired_message(effect).to_string(),
+            });
         }
+        events.extend(morale::tick(&mut self.world));
+        monster_ai::tick_blindness(&mut self.world);
+        events.extend(monster_attacks::release_if_apart(&mut self.world));
+        let state = &mut self.inner;
+        speed::grant_movement(&mut state.world, &mut state.rng);
     }
 
     fn accept_without_turn(&mut self, events: Vec<GameEvent>) -> TurnOutcome {
+        vision::remember_visible(&mut self.world);
         self.event_log.extend(events.clone());
         TurnOutcome {
             accepted: true,
             turn_advanced: false,
+            player_actions: 0,
+            turns_elapsed: 0,
+            run_group: None,
             events,
             snapshot_hash: self.snapshot().stable_hash(),
             next_state: self.state,
@@ -552,6 +983,9 @@ impl GameSession {
         TurnOutcome {
             accepted: false,
             turn_advanced: false,
+            player_actions: 0,
+            turns_elapsed: 0,
+            run_group: None,
             events: vec![GameEvent::CommandRejected { reason }],
             snapshot_hash: self.snapshot().stable_hash(),
             next_state: self.state,
@@ -559,6 +993,17 @@ impl GameSession {
     }
 }
 
+fn condition_expired_message(effect: StatusEffect) -> &'static str {
+    match effect {
+        StatusEffect::Confusion => "You feel less confused now.",
+        StatusEffect::Blindness => "You can see again.",
+        StatusEffect::Sleep => "You wake up.",
+        StatusEffect::SeeInvisible => "Your vision seems less keen.",
+        StatusEffect::VeryFast => "You feel yourself slowing down.",
+        StatusEffect::FoodPoisoning => "You feel better.",
+    }
+}
+
 impl crate::client::GameClient for GameSession {
     fn observation(&self) -> Observation {
         GameSession::observation(self)
@@ -2,7 +2,9 @@ use aihack_core::{
     domain::{
         entity::{EntityKind, EntityLocation},
         inventory::{InventoryEntry, InventoryLetter},
-        item::ItemKind,
+        item::{BucStatus, ItemKind},
+        player::ActiveRun,
+        status::Conditions,
         tile::TileKind,
     },
     event::GameEvent,
@@ -29,6 +31,22 @@ pub struct EntitySnapshot {
     pub location: Option<EntityLocation>,
     pub assigned_letter: Option<InventoryLetter>,
     pub charges: Option<u8>,
+    pub buc: Option<BucStatus>,
+    pub diluted: bool,
+    pub enchantment: i8,
+    pub erodeproof: bool,
+    pub erosion: u8,
+    pub age: u16,
+    pub bites: u8,
+    pub lit: bool,
+    pub trapped_turns: u8,
+    pub frozen_turns: u8,
+    pub asleep: bool,
+    pub confused: bool,
+    pub blinded_turns: u8,
+    pub fleeing: bool,
+    pub flee_turns: u8,
+    pub movement: i16,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -37,6 +55,9 @@ pub struct LevelSnapshot {
     pub map_width: i16,
     pub map_height: i16,
     pub map_tiles: Vec<TileKind>,
+    pub map_lit: Vec<bool>,
+    pub map_looted: Vec<Pos>,
+    pub map_vision_radius: Option<i16>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -65,9 +86,20 @@ pub struct GameSnapshot {
     pub prayer_cooldown: u16,
     pub paralysis_turns: u8,
     pub hallucinating: bool,
+    pub conditions: Conditions,
+    pub experience_level: u8,
+    pub strength: u8,
+    pub dexterity: u8,
+    pub alignment: i16,
+    pub god_anger: u8,
+    pub pending_wishes: u8,
     pub kill_count: u32,
     pub gold: u32,
     pub identified_items: Vec<ItemKind>,
+    /// 걸려 있는 달리기다. 다음 걸음의 합법 여부를 바꾸므로 hash에 넣되, 달리지 않으면 적지 않아
+    /// 그 전의 hash와 같다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub running: Option<ActiveRun>,
 }
 
 impl GameSnapshot {
@@ -94,6 +126,22 @@ impl GameSnapshot {
                         location: Some(EntityLocation::OnMap { level, pos }),
                         assigned_letter: None,
                         charges: None,
+                        buc: None,
+                        diluted: false,
+                        enchantment: 0,
+                        erodeproof: false,
+                        erosion: 0,
+                        age: 0,
+                        bites: 0,
+                        lit: false,
+                        trapped_turns: stats.trapped_turns,
+                        frozen_turns: stats.frozen_turns,
+                        asleep: stats.asleep,
+                        confused: stats.confused,
+                        blinded_turns: stats.blinded_turns,
+                        fleeing: stats.fleeing,
+                        flee_turns: stats.flee_turns,
+                        movement: stats.movement,
                     }
                 } else {
                     let (kind, _, location, assigned_letter, charges) =
@@ -108,6 +156,22 @@ impl GameSnapshot {
                         location: Some(location),
                         assigned_letter,
                         charges,
+                        buc: world.entities.item_buc(entity.id),
+                        diluted: world.entities.item_diluted(entity.id),
+                        enchantment: world.entities.item_enchantment(entity.id),
+                        erodeproof: world.entities.item_erodeproof(entity.id),
+                        erosion: world.entities.item_erosion(entity.id),
+                        age: world.entities.item_age(entity.id),
+                        bites: world.entities.item_bites(entity.id),
+                        lit: world.entities.item_lit(entity.id),
+                        trapped_turns: 0,
+                        frozen_turns: 0,
+                        asleep: false,
+                        confused: false,
+                        blinded_turns: 0,
+                        fleeing: false,
+                        flee_turns: 0,
+                        movement: 0,
                     }
                 }
             })
@@ -123,6 +187,9 @@ impl GameSnapshot {
                 map_width: level.map.width,
                 map_height: level.map.height,
                 map_tiles: level.map.tiles().to_vec(),
+                map_lit: level.map.lit_tiles(),
+                map_looted: level.map.looted_positions().to_vec(),
+                map_vision_radius: level.map.vision_radius(),
             })
             .collect::<Vec<_>>();
         levels.sort_by_key(|level| level.id);
@@ -149,9 +216,17 @@ impl GameSnapshot {
             prayer_cooldown: world.prayer_cooldown,
             paralysis_turns: world.paralysis_turns,
             hallucinating: world.hallucinating,
+            conditions: world.conditions.clone(),
+            experience_level: world.experience_level,
+            strength: world.strength,
+            dexterity: world.dexterity,
+            alignment: world.alignment,
+            god_anger: world.god_anger,
+            pending_wishes: world.pending_wishes,
             kill_count: world.kill_count,
             gold: world.gold,
             identified_items: world.identified_items.clone(),
+            running: world.running.clone(),
         }
     }
 
@@ -2,13 +2,14 @@ use aihack_core::{
     domain::{
         combat::{AttackProfile, DamageRoll},
         entity::Entity,
+        player::{strength_damage_bonus, strength_hit_bonus},
     },
     event::GameEvent,
     ids::EntityId,
     rng::GameRng,
 };
 
-use crate::{domain::item::UNARMED_ATTACK, world::GameWorld};
+use crate::{domain::item::UNARMED_ATTACK, systems::luck, world::GameWorld};
 
 pub use aihack_core::domain::combat::AttackResolution;
 
@@ -21,7 +22,9 @@ pub fn resolve_attack(
     let attacker = world.entities.get(attacker_id)?.clone();
     let mut profile = attack_profile_for(world, attacker_id, &attacker);
     if attacker_id == world.player_id {
-        profile.hit_bonus = profile.hit_bonus.saturating_add(world.luck);
+        profile.hit_bonus = profile
+            .hit_bonus
+            .saturating_add(luck::effective_luck(world));
     }
     resolve_attack_with_profile(world, rng, attacker_id, defender_id, profile)
 }
@@ -42,6 +45,24 @@ pub fn resolve_attack_with_profile(
     )
 }
 
+pub fn resolve_attack_with_to_hit_die(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    attacker_id: EntityId,
+    defender_id: EntityId,
+    profile: AttackProfile,
+    to_hit_sides: i16,
+) -> Option<AttackResolution> {
+    aihack_core::domain::combat::resolve_attack_with_to_hit_die(
+        &mut world.entities,
+        rng,
+        attacker_id,
+        defender_id,
+        profile,
+        to_hit_sides,
+    )
+}
+
 pub fn attack_event(resolution: &AttackResolution) -> GameEvent {
     GameEvent::AttackResolved {
         attacker: resolution.attacker,
@@ -59,12 +80,22 @@ pub fn attack_profile_for(
     attacker: &Entity,
 ) -> AttackProfile {
     if attacker_id == world.player_id {
-        return world
+        let mut profile = world
             .inventory
             .equipped_melee
-            .and_then(|item| world.entities.item_data(item))
-            .and_then(|data| data.attack_profile)
+            .and_then(|weapon| {
+                let mut profile = world.entities.item_data(weapon)?.attack_profile?;
+                let enchantment = i16::from(world.entities.item_enchantment(weapon));
+                profile.hit_bonus += enchantment;
+                profile.damage_bonus +=
+                    enchantment - i16::from(world.entities.item_erosion(weapon));
+                Some(profile)
+            })
             .unwrap_or(UNARMED_ATTACK);
+        let strength = world.effective_strength();
+        profile.hit_bonus += strength_hit_bonus(strength);
+        profile.damage_bonus += strength_damage_bonus(strength);
+        return profile;
     }
     attacker.natural_attack_profile().unwrap_or(UNARMED_ATTACK)
 }
@@ -97,3 +128,7 @@ pub fn roll_damage(
 pub fn roll_die(rng: &mut GameRng, sides: i16) -> i16 {
     aihack_core::domain::combat::roll_die(rng, sides)
 }
+
+pub fn rn2(rng: &mut GameRng, n: i16) -> i32 {
+    aihack_core::domain::combat::rn2(rng, n)
+}
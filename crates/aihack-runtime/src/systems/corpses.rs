@@ -0,0 +1,339 @@
+//! 몬스터 시체의 생성, 부패, 먹기 효과다.
+//!
+//! 시체의 무게, 영양, 남길 확률과 먹기 효과는 몬스터 content의 `[monster.corpse]`
+//! 표가 선언한다. 판정 순서는 NetHack 3.6.7 `eatcorpse`/`cpostfx`를 따른다.
+
+use aihack_core::{
+    domain::{
+        combat::DeathCause,
+        entity::{EntityKind, EntityLocation},
+        item::{BucStatus, ItemClass, ItemKind},
+        status::{Intrinsic, StatusEffect},
+    },
+    event::{GameEvent, MessagePriority},
+    ids::EntityId,
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{
+        combat::{rn2, roll_die},
+        death,
+        effects::{self, EffectOutcome},
+        hunger,
+    },
+    world::GameWorld,
+};
+
+/// 시체가 완전히 썩어 사라지는 나이다. NetHack `ROT_AGE`와 같다.
+pub const CORPSE_ROT_AGE: u16 = 250;
+/// 먹기 시작할 때 `1/ROTTEN_FOOD_CHANCE` 확률로 상한 음식 효과가 난다.
+pub const ROTTEN_FOOD_CHANCE: i16 = 7;
+/// 힘은 이 값 아래로 내려가지 않는다.
+const MIN_STRENGTH: u8 = 3;
+
+/// 이번 행동에서 죽은 몬스터마다 content 확률에 따라 시체를 남긴다.
+pub fn leave_corpses(world: &mut GameWorld, rng: &mut GameRng, events: &[GameEvent]) {
+    for event in events {
+        let GameEvent::EntityDied { entity, .. } = event else {
+            continue;
+        };
+        let Some(EntityKind::Monster(monster)) = world.entities.get(*entity).map(|e| e.kind())
+        else {
+            continue;
+        };
+        let Some(corpse) = world
+            .monster_template(monster)
+            .ok()
+            .and_then(|template| template.corpse)
+        else {
+            continue;
+        };
+        let Some((level, pos)) = world.entities.actor_location(*entity) else {
+            continue;
+        };
+        let Some((kind, data)) = monster
+            .corpse_kind()
+            .and_then(|kind| Some((kind, world.item_kind_data(kind).ok()?)))
+        else {
+            continue;
+        };
+        if roll_die(rng, i16::from(corpse.leave_chance)) == 1 {
+            world
+                .entities
+                .spawn_item_with_data(kind, data, EntityLocation::OnMap { level, pos });
+        }
+    }
+}
+
+/// 모든 시체의 나이를 한 턴 올리고 `CORPSE_ROT_AGE`에 닿은 시체를 없앤다.
+pub fn age_corpses(world: &mut GameWorld) -> Vec<GameEvent> {
+    let corpses = world
+        .entities
+        .entities()
+        .iter()
+        .filter_map(|entity| {
+            let (kind, data, location, _, _) = entity.item()?;
+            (data.class == ItemClass::Corpse && location != EntityLocation::Consumed)
+                .then_some((entity.id, kind, location))
+        })
+        .collect::<Vec<_>>();
+    let mut events = Vec::new();
+    for (item, kind, location) in corpses {
+        let age = world.entities.item_age(item).saturating_add(1);
+        world.entities.set_item_age(item, age);
+        if age < CORPSE_ROT_AGE {
+            continue;
+        }
+        if location
+            == (EntityLocation::Inventory {
+                owner: world.player_id,
+            })
+        {
+            world.inventory.remove(item);
+            events.push(GameEvent::Message {
+                priority: MessagePriority::Info,
+                text: format!("Your {} rots away.", corpse_name(world, kind)),
+            });
+        }
+        world
+            .entities
+            .set_item_location(item, EntityLocation::Consumed);
+        events.push(GameEvent::CorpseRotted { item });
+    }
+    events
+}
+
+/// 식중독 timer를 줄이고 다 되면 플레이어를 죽인다.
+pub fn tick_food_poisoning(world: &mut GameWorld) -> Vec<GameEvent> {
+    if world.conditions.food_poisoning_turns == 0 {
+        return Vec::new();
+    }
+    world.conditions.food_poisoning_turns -= 1;
+    if world.conditions.food_poisoning_turns > 0 {
+        return Vec::new();
+    }
+    let mut outcome = EffectOutcome::new(true);
+    outcome.message(MessagePriority::Danger, "You die from your illness.");
+    outcome
+        .events
+        .extend(death::kill_player(world, DeathCause::FoodPoisoning));
+    outcome.events
+}
+
+/// 시체를 먹는 데 걸리는 횟수다. NetHack `3 + (cwt >> 6)`과 같다.
+pub fn corpse_rounds(weight: i16) -> u8 {
+    (3 + (weight.max(0) >> 6)).min(i16::from(u8::MAX)) as u8
+}
+
+/// 시체를 먹기 시작한다. 첫 입에 부패와 몬스터 고유 효과를 판정하고 한 입 먹는다.
+/// 나머지는 먹기 occupation이 [`bite_corpse`]로 이어 먹는다.
+pub fn eat_corpse(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    can_choke: bool,
+) -> Vec<GameEvent> {
+    let Some(data) = world.entities.item_data(item).copied() else {
+        return Vec::new();
+    };
+    let kind = data.kind;
+    let name = corpse_name(world, kind);
+    let corpse = data.corpse.unwrap_or_default();
+    let bites = world.entities.item_bites(item);
+    let mut outcome = EffectOutcome::new(true);
+
+    if bites > 0 {
+        outcome.message(MessagePriority::Info, "You resume your meal.");
+    } else if corpse.petrifying {
+        outcome.message(MessagePriority::Danger, "You turn to stone.");
+        outcome.events.extend(death::kill_player(
+            world,
+            DeathCause::Petrification { item: kind },
+        ));
+        return outcome.events;
+    } else {
+        let mut rotted = i32::from(world.entities.item_age(item)) / (10 + rn2(rng, 20));
+        rotted += match world.entities.item_buc(item).unwrap_or_default() {
+            BucStatus::Cursed => 2,
+            BucStatus::Blessed => -2,
+            BucStatus::Uncursed => 0,
+        };
+        if !corpse.acidic && rotted > 5 {
+            outcome.message(MessagePriority::Danger, "Ulch - that meat was tainted!");
+            outcome.message(MessagePriority::Danger, "You feel deathly sick.");
+            world.conditions.food_poisoning_turns = 10 + rn2(rng, 10) as u16;
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: world.player_id,
+                effect: StatusEffect::FoodPoisoning,
+                turns: world.conditions.food_poisoning_turns,
+            });
+            finish_meal(world, item, &mut outcome);
+            return outcome.events;
+        }
+        let troubled = if corpse.acidic {
+            outcome.message(
+                MessagePriority::Warning,
+                "You have a very bad case of stomach acid.",
+            );
+            let damage = roll_die(rng, 15);
+            effects::damage_player(world, &mut outcome, kind, damage);
+            true
+        } else if corpse.poisonous && rn2(rng, 5) != 0 {
+            outcome.message(
+                MessagePriority::Warning,
+                "Ecch - that must have been poisonous!",
+            );
+            if world.conditions.poison_resistance {
+                outcome.message(MessagePriority::Info, "You seem unaffected by the poison.");
+            } else {
+                let loss = roll_die(rng, 4) as u8;
+                world.strength = world.strength.saturating_sub(loss).max(MIN_STRENGTH);
+                let damage = roll_die(rng, 15);
+                effects::damage_player(world, &mut outcome, kind, damage);
+            }
+            true
+        } else if rotted > 3 && rn2(rng, 5) != 0 {
+            outcome.message(MessagePriority::Warning, "You feel sick.");
+            let damage = roll_die(rng, 8);
+            effects::damage_player(world, &mut outcome, kind, damage);
+            true
+        } else {
+            false
+        };
+        if !world.player_alive() {
+            return outcome.events;
+        }
+        if !troubled && roll_die(rng, ROTTEN_FOOD_CHANCE) == 1 {
+            rotten_food(world, rng, &mut outcome);
+        } else {
+            outcome.message(
+                MessagePriority::Info,
+                &format!("This {name} tastes terrible!"),
+            );
+        }
+    }
+
+    outcome
+        .events
+        .extend(bite_corpse(world, rng, item, can_choke));
+    outcome.events
+}
+
+/// 먹던 시체를 한 입 더 먹는다. 다 먹으면 intrinsic을 얻을 수 있다.
+pub fn bite_corpse(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    can_choke: bool,
+) -> Vec<GameEvent> {
+    let Some(data) = world.entities.item_data(item).copied() else {
+        return Vec::new();
+    };
+    let kind = data.kind;
+    let name = corpse_name(world, kind);
+    let corpse = data.corpse.unwrap_or_default();
+    let rounds = corpse_rounds(data.weight);
+    let bites = world.entities.item_bites(item);
+    let mut outcome = EffectOutcome::new(true);
+
+    let before = world.nutrition;
+    world.nutrition = world.nutrition.saturating_add(bite_nutrition(
+        data.nutrition.unwrap_or_default(),
+        rounds,
+        bites,
+    ));
+    world.entities.set_item_bites(item, bites + 1);
+    if bites + 1 >= rounds {
+        outcome.message(
+            MessagePriority::Info,
+            &format!("You finish eating the {name}."),
+        );
+        finish_meal(world, item, &mut outcome);
+        if let Some(grant) = corpse.intrinsic {
+            if !world.conditions.has_intrinsic(grant.intrinsic)
+                && roll_die(rng, i16::from(grant.chance)) == 1
+            {
+                world.conditions.grant_intrinsic(grant.intrinsic);
+                outcome.message(MessagePriority::Info, intrinsic_message(grant.intrinsic));
+                outcome.events.push(GameEvent::IntrinsicGained {
+                    entity: world.player_id,
+                    intrinsic: grant.intrinsic,
+                });
+            }
+        }
+    }
+    outcome
+        .events
+        .extend(hunger::after_eating(world, rng, kind, before, can_choke));
+    outcome.events
+}
+
+/// `rounds`번에 나눠 먹는 음식의 `bites`번째 입이 주는 영양이다. 나머지는 뒤쪽 입에 몰린다.
+pub fn bite_nutrition(nutrition: i16, rounds: u8, bites: u8) -> i16 {
+    let nutrition = i32::from(nutrition);
+    let eaten = |bites: u8| nutrition * i32::from(bites) / i32::from(rounds.max(1));
+    (eaten(bites + 1) - eaten(bites)) as i16
+}
+
+/// NetHack `rottenfood`처럼 어지럼, 실명, 기절 중 하나가 일어날 수 있다.
+fn rotten_food(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    let player_id = world.player_id;
+    if rn2(rng, 4) == 0 {
+        outcome.message(MessagePriority::Warning, "You feel rather light-headed.");
+        let turns = (roll_die(rng, 4) + roll_die(rng, 4)) as u16;
+        world.conditions.confusion_turns = world.conditions.confusion_turns.saturating_add(turns);
+        outcome.events.push(GameEvent::StatusChanged {
+            entity: player_id,
+            effect: StatusEffect::Confusion,
+            turns: world.conditions.confusion_turns,
+        });
+    } else if rn2(rng, 4) == 0 && !world.conditions.is_blind() {
+        outcome.message(MessagePriority::Warning, "Everything suddenly goes dark.");
+        let turns = (roll_die(rng, 25) + roll_die(rng, 25)) as u16;
+        world.conditions.blindness_turns = world.conditions.blindness_turns.saturating_add(turns);
+        outcome.events.push(GameEvent::StatusChanged {
+            entity: player_id,
+            effect: StatusEffect::Blindness,
+            turns: world.conditions.blindness_turns,
+        });
+    } else if rn2(rng, 3) == 0 {
+        outcome.message(MessagePriority::Danger, "The world spins and goes dark.");
+        world.paralysis_turns = world.paralysis_turns.max(roll_die(rng, 10) as u8);
+        outcome.events.push(GameEvent::StatusChanged {
+            entity: player_id,
+            effect: StatusEffect::Sleep,
+            turns: u16::from(world.paralysis_turns),
+        });
+    } else {
+        outcome.message(MessagePriority::Info, "Blecch!  Rotten food!");
+    }
+}
+
+fn finish_meal(world: &mut GameWorld, item: EntityId, outcome: &mut EffectOutcome) {
+    world.inventory.remove(item);
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Consumed);
+    outcome.events.push(GameEvent::ItemConsumed {
+        entity: world.player_id,
+        item,
+    });
+}
+
+fn intrinsic_message(intrinsic: Intrinsic) -> &'static str {
+    match intrinsic {
+        Intrinsic::PoisonResistance => "You feel healthy.",
+        Intrinsic::Telepathy => "You feel a strange mental acuity.",
+        Intrinsic::Fast => "You feel quick!",
+        Intrinsic::Stealth => "You feel stealthy!",
+        Intrinsic::FireResistance => "You feel a momentary chill.",
+    }
+}
+
+fn corpse_name(world: &GameWorld, kind: ItemKind) -> String {
+    world
+        .content()
+        .item_info(kind)
+        .map_or_else(|| "corpse".to_owned(), |info| info.name.clone())
+}
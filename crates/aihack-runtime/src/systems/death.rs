@@ -1,16 +1,11 @@
 use aihack_core::{
-    domain::{
-        combat::DeathCause,
-        entity::{EntityKind, EntityLocation},
-        item::ItemKind,
-        monster::MonsterKind,
-    },
+    domain::{combat::DeathCause, entity::EntityLocation},
     event::GameEvent,
     ids::EntityId,
     run_state::RunState,
 };
 
-use crate::{domain::item::item_data, systems::score, world::GameWorld};
+use crate::{systems::score, world::GameWorld};
 
 pub fn collect_death_events_after_attack(
     world: &mut GameWorld,
@@ -37,8 +32,6 @@ pub fn collect_death_events_if_hp_depleted(
         return Vec::new();
     }
 
-    let location = world.entities.actor_location(entity);
-    let kind = world.entities.get(entity).map(|entity| entity.kind());
     let difficulty = world
         .entities
         .get(entity)
@@ -50,16 +43,30 @@ pub fn collect_death_events_if_hp_depleted(
     } else {
         world.kill_count += 1;
         world.gold = world.gold.saturating_add(u32::from(difficulty));
+        drop_inventory(world, entity);
     }
-    let events = vec![GameEvent::EntityDied { entity, cause }];
-    if let (Some((level, pos)), Some(EntityKind::Monster(MonsterKind::Jackal))) = (location, kind) {
-        world.entities.spawn_item_with_data(
-            ItemKind::CorpseJackal,
-            item_data(ItemKind::CorpseJackal),
-            EntityLocation::OnMap { level, pos },
-        );
+    vec![GameEvent::EntityDied { entity, cause }]
+}
+
+/// 죽은 몬스터가 지니던 물건은 그 자리에 떨어진다. NetHack `relobj`와 같다.
+fn drop_inventory(world: &mut GameWorld, monster: EntityId) {
+    let Some((level, pos)) = world.entities.actor_location(monster) else {
+        return;
+    };
+    for item in world.entities.inventory_items(monster) {
+        world
+            .entities
+            .set_item_location(item, EntityLocation::OnMap { level, pos });
+    }
+}
+
+/// 굶주림이나 석화처럼 피해량과 무관하게 플레이어를 즉사시킨다.
+pub fn kill_player(world: &mut GameWorld, cause: DeathCause) -> Vec<GameEvent> {
+    let player_id = world.player_id;
+    if let Some(stats) = world.entities.actor_stats_mut(player_id) {
+        stats.hp = 0;
     }
-    events
+    collect_death_events_if_hp_depleted(world, player_id, cause)
 }
 
 pub fn state_after_deaths(world: &GameWorld) -> RunState {
@@ -0,0 +1,266 @@
+//! 곡괭이로 벽을 뚫고 바닥을 파 내려가기, 파기 지팡이 광선이다.
+//!
+//! 판정은 NetHack 3.6.7 `dig.c`의 `dig`/`dighole`/`zap_dig`를 따른다. 곡괭이 파기는
+//! 뚫릴 때까지 이어지는 occupation이며 턴마다 [`keep_digging`]으로 힘을 들인다. 들인 힘은
+//! `WorldState::digging`에 남아 방해받은 뒤 같은 곳을 다시 파면 이어진다. 벽과 문을 바꾸는
+//! 지형 변경은 곡괭이와 지팡이가 모두 [`dig_through`]를 거친다.
+
+use aihack_core::{
+    action::ApplyDirection,
+    domain::{
+        player::{strength_damage_bonus, strength_hit_bonus, DigProgress},
+        tile::{DoorState, TileKind, TrapKind},
+    },
+    event::{GameEvent, MessagePriority},
+    ids::{EntityId, LevelId},
+    position::{Direction, Pos},
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{combat::rn2, effects::EffectOutcome, monster_ai, traps},
+    world::GameWorld,
+};
+
+/// 벽에 구멍이 뚫리는 누적 힘이다.
+pub const WALL_EFFORT: u16 = 100;
+/// 발밑에 구덩이가 생기는 누적 힘이다.
+pub const PIT_EFFORT: u16 = 50;
+/// 구덩이가 아래층으로 이어지는 구멍이 되는 누적 힘이다.
+pub const HOLE_EFFORT: u16 = 250;
+
+/// 곡괭이로 `direction` 쪽 벽을, `None`이면 발밑 바닥을 파기 시작해 한 턴 판다.
+pub fn dig(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    direction: Option<Direction>,
+) -> Result<Vec<GameEvent>, String> {
+    check_digger(world, item, direction)?;
+    let continuing = is_digging(world, direction);
+    let mut events = vec![message(match (continuing, direction.is_none()) {
+        (false, false) => "You start digging.",
+        (false, true) => "You start digging downward.",
+        (true, false) => "You continue digging.",
+        (true, true) => "You continue digging downward.",
+    })];
+    events.extend(dig_effort(world, rng, item, direction));
+    Ok(events)
+}
+
+/// 파던 곳을 한 턴 더 판다. 파기 occupation이 턴마다 부른다.
+pub fn keep_digging(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    direction: Option<Direction>,
+) -> Result<Vec<GameEvent>, String> {
+    check_digger(world, item, direction)?;
+    Ok(dig_effort(world, rng, item, direction))
+}
+
+/// 지금 자리에서 `direction` 쪽을 파다 만 힘이 남아 있는지 본다.
+pub fn is_digging(world: &GameWorld, direction: Option<Direction>) -> bool {
+    let (pos, down) = dig_target(world, direction);
+    let level = world.current_level();
+    world.digging.is_some_and(|progress| {
+        progress.level == level && progress.pos == pos && progress.down == down
+    })
+}
+
+fn check_digger(
+    world: &GameWorld,
+    item: EntityId,
+    direction: Option<Direction>,
+) -> Result<(), String> {
+    if !world.inventory.contains(item) {
+        return Err("item is not in inventory".to_string());
+    }
+    if !world.entities.item_data(item).is_some_and(|data| data.digs) {
+        return Err("item cannot dig".to_string());
+    }
+    check_dig(world, direction)
+}
+
+fn dig_target(world: &GameWorld, direction: Option<Direction>) -> (Pos, bool) {
+    match direction {
+        Some(direction) => (world.player_pos().offset(direction.delta()), false),
+        None => (world.player_pos(), true),
+    }
+}
+
+fn dig_effort(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    direction: Option<Direction>,
+) -> Vec<GameEvent> {
+    let level = world.current_level();
+    let (pos, down) = dig_target(world, direction);
+    let effort = if is_digging(world, direction) {
+        world.digging.map_or(0, |progress| progress.effort)
+    } else {
+        0
+    }
+    .saturating_add(effort_bonus(world, rng, item));
+    world.digging = Some(DigProgress {
+        level,
+        pos,
+        down,
+        effort,
+    });
+    let mut events = monster_ai::wake_nearby(world);
+
+    if !down {
+        if effort > WALL_EFFORT {
+            world.digging = None;
+            let tile = world.current_map().tile(pos).ok();
+            events.extend(dig_through(world, pos));
+            events.push(message(if tile == Some(TileKind::HiddenDoor) {
+                "You break through a secret door!"
+            } else {
+                "You make an opening in the wall."
+            }));
+        }
+        return events;
+    }
+
+    let here = world.current_map().tile(pos).ok();
+    if effort > HOLE_EFFORT {
+        world.digging = None;
+        events.extend(change_tile(world, pos, TileKind::Trap(TrapKind::Hole)));
+        events.push(message("You dig a hole through the floor."));
+        let mut outcome = EffectOutcome::new(true);
+        traps::fall_through(world, rng, world.player_id, TrapKind::Hole, &mut outcome);
+        events.extend(outcome.events);
+    } else if effort > PIT_EFFORT && here != Some(TileKind::Trap(TrapKind::Pit)) {
+        events.extend(change_tile(world, pos, TileKind::Trap(TrapKind::Pit)));
+        events.push(message("You dig a pit in the floor."));
+    }
+    events
+}
+
+/// 파기 지팡이 광선이다. 뚫을 수 있는 벽은 통로로, 문은 문짝 없는 출입구로 만들며 나아간다.
+pub fn zap_dig(world: &mut GameWorld, rng: &mut GameRng, direction: Direction) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    let mut depth = 8 + rn2(rng, 18);
+    let mut pos = world.player_pos();
+    while depth > 0 {
+        depth -= 1;
+        pos = pos.offset(direction.delta());
+        let Ok(tile) = world.current_map().tile(pos) else {
+            break;
+        };
+        match tile {
+            TileKind::Wall | TileKind::HiddenDoor => {
+                if !world.current_map().is_diggable(pos) {
+                    events.push(message("The wall glows then fades."));
+                    break;
+                }
+                events.extend(dig_through(world, pos));
+                depth -= 2;
+            }
+            TileKind::Door(state) if state.is_intact() => {
+                events.extend(dig_through(world, pos));
+                events.push(message("The door is razed!"));
+                depth -= 2;
+            }
+            tile if !tile.is_movement_passable() && !tile.is_liquid() => break,
+            _ => {}
+        }
+    }
+    events
+}
+
+/// 벽은 통로로, 숨은 문은 부서진 문으로, 문은 문짝 없는 출입구로 바꾼다.
+/// 곡괭이와 파기 지팡이가 함께 쓰는 지형 변경 경로이며 뚫을 수 없는 칸은 건드리지 않는다.
+pub fn dig_through(world: &mut GameWorld, pos: Pos) -> Option<GameEvent> {
+    if !world.current_map().is_diggable(pos) {
+        return None;
+    }
+    let to = match world.current_map().tile(pos).ok()? {
+        TileKind::Wall => TileKind::Corridor,
+        TileKind::HiddenDoor => TileKind::Door(DoorState::Broken),
+        TileKind::Door(state) if state.is_intact() => TileKind::Door(DoorState::NoDoor),
+        _ => return None,
+    };
+    change_tile(world, pos, to)
+}
+
+fn change_tile(world: &mut GameWorld, pos: Pos, to: TileKind) -> Option<GameEvent> {
+    let from = world.current_map().tile(pos).ok()?;
+    world.current_map_mut().set_tile(pos, to).ok()?;
+    let level = world.current_level();
+    if world
+        .digging
+        .is_some_and(|progress| progress.level == level && progress.pos == pos && !progress.down)
+    {
+        world.digging = None;
+    }
+    Some(GameEvent::TileDug { pos, from, to })
+}
+
+/// `#apply`의 방향 대답을 파는 쪽으로 바꾼다. 아래는 `None`이다.
+pub fn dig_direction(direction: ApplyDirection) -> Option<Direction> {
+    match direction {
+        ApplyDirection::Toward(direction) => Some(direction),
+        ApplyDirection::Down => None,
+    }
+}
+
+/// 지금 자리에서 `direction` 쪽(또는 `None`이면 발밑)을 팔 수 있는지 확인한다.
+pub fn check_dig(world: &GameWorld, direction: Option<Direction>) -> Result<(), String> {
+    match direction {
+        Some(direction) => check_dig_wall(world, world.player_pos().offset(direction.delta())),
+        None => check_dig_down(world),
+    }
+}
+
+fn check_dig_wall(world: &GameWorld, pos: Pos) -> Result<(), String> {
+    match world.current_map().tile(pos) {
+        Ok(TileKind::Wall | TileKind::HiddenDoor) if world.current_map().is_diggable(pos) => Ok(()),
+        Ok(TileKind::Wall | TileKind::HiddenDoor) => {
+            Err("This wall is too hard to dig into.".to_string())
+        }
+        Ok(TileKind::Tree) => Err("You need an axe to cut down a tree.".to_string()),
+        Ok(TileKind::IronBars) => Err("The iron bars are too hard to dig through.".to_string()),
+        _ => Err("You swing your pick through thin air.".to_string()),
+    }
+}
+
+fn check_dig_down(world: &GameWorld) -> Result<(), String> {
+    let level = world.current_level();
+    let below = LevelId {
+        branch: level.branch,
+        depth: level.depth + 1,
+    };
+    let diggable_floor = matches!(
+        world.current_map().tile(world.player_pos()),
+        Ok(TileKind::Floor | TileKind::Corridor | TileKind::Trap(TrapKind::Pit))
+    );
+    if world.levels.contains(below) && diggable_floor {
+        Ok(())
+    } else {
+        Err("The floor here is too hard to dig in.".to_string())
+    }
+}
+
+/// 한 턴에 들이는 힘이다. NetHack `dig()`의 `10 + rn2(5) + abon() + uwep->spe - greatest_erosion
+/// + u.udaminc + dbon()`에서 피해 보너스 아이템 효과만 뺐다.
+fn effort_bonus(world: &GameWorld, rng: &mut GameRng, item: EntityId) -> u16 {
+    let strength = world.effective_strength();
+    let bonus = 10
+        + rn2(rng, 5)
+        + i32::from(strength_hit_bonus(strength))
+        + i32::from(strength_damage_bonus(strength))
+        + i32::from(world.entities.item_enchantment(item))
+        - i32::from(world.entities.item_erosion(item));
+    bonus.clamp(1, i32::from(u16::MAX)) as u16
+}
+
+fn message(text: &str) -> GameEvent {
+    GameEvent::Message {
+        priority: MessagePriority::Info,
+        text: text.to_string(),
+    }
+}
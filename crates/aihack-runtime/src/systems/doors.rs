@@ -1,16 +1,40 @@
+//! 문 열고 닫기, 걷어차기, 자물쇠 따기와 `#force`다.
+//!
+//! 판정은 NetHack 3.6.7 `lock.c`의 `pick_lock`/`doforce`/`doopen`/`doclose`,
+//! `dokick.c`의 문 차기, `mklev.c`의 `dosdoor`를 따른다. 자물쇠 작업은 NetHack처럼
+//! 여러 턴에 걸친 occupation이 아니라 한 턴에 한 번 굴리는 시도다. 이 게임에는 건강
+//! 능력치가 없으므로 걷어차기의 평균 능력치는 힘과 민첩만으로 구한다.
+
 use aihack_core::{
-    domain::tile::{DoorState, TileKind},
+    domain::{
+        entity::EntityLocation,
+        item::{ItemClass, ItemKind},
+        tile::{DoorState, LockMethod, TileKind},
+    },
     error::GameError,
-    event::GameEvent,
-    position::Direction,
+    event::{GameEvent, MessagePriority},
+    ids::{EntityId, LevelId},
+    position::{Direction, Pos},
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{combat::rn2, luck, vision},
+    world::GameWorld,
 };
 
-use crate::world::GameWorld;
+/// 무작위 문 상태를 굴리는 층 생성 난수의 시드 보정값이다. 게임 진행 난수와 섞이지 않게 한다.
+const DOOR_GENERATION_SALT: u64 = 0x646f_6f72_5f67_656e;
 
 pub fn open_door(
     world: &mut GameWorld,
     direction: Direction,
 ) -> Result<(DoorState, DoorState), GameError> {
+    if door_state_in_direction(world, direction) == Some(DoorState::Locked) {
+        return Err(GameError::CommandRejected(
+            "This door is locked.".to_string(),
+        ));
+    }
     change_door(world, direction, DoorState::Closed, DoorState::Open)
 }
 
@@ -18,12 +42,182 @@ pub fn close_door(
     world: &mut GameWorld,
     direction: Direction,
 ) -> Result<(DoorState, DoorState), GameError> {
-    change_door(world, direction, DoorState::Open, DoorState::Closed)
+    match door_state_in_direction(world, direction) {
+        Some(DoorState::Broken) => Err(GameError::CommandRejected(
+            "This door is broken.".to_string(),
+        )),
+        Some(DoorState::NoDoor) => Err(GameError::CommandRejected(
+            "This doorway has no door.".to_string(),
+        )),
+        _ => change_door(world, direction, DoorState::Open, DoorState::Closed),
+    }
 }
 
-pub fn kick_door(world: &mut GameWorld, direction: Direction) -> Result<Vec<GameEvent>, GameError> {
+/// 닫히거나 잠긴 문, 숨은 문을 걷어찬다. 성공하면 문이 부서지고 힘이 아주 세면 산산조각 난다.
+pub fn kick_door(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    direction: Direction,
+) -> Result<Vec<GameEvent>, GameError> {
     let pos = world.player_pos().offset(direction.delta());
-    aihack_core::doors::kick_door(world.current_map_mut(), pos)
+    let average = (i16::from(world.effective_strength()) + i16::from(world.dexterity)) / 2;
+    match world.current_map().tile(pos)? {
+        TileKind::HiddenDoor => {
+            if rn2(rng, 30) >= i32::from(average) {
+                return Ok(vec![
+                    GameEvent::DoorKicked { pos },
+                    message("Ouch!  That hurts!"),
+                ]);
+            }
+            world
+                .current_map_mut()
+                .set_tile(pos, TileKind::Door(DoorState::Open))?;
+            Ok(vec![
+                GameEvent::TileRevealed {
+                    pos,
+                    tile: TileKind::Door(DoorState::Closed),
+                },
+                GameEvent::DoorKicked { pos },
+                message("Crash!  You kick open a secret door!"),
+                GameEvent::DoorChanged {
+                    pos,
+                    from: DoorState::Closed,
+                    to: DoorState::Open,
+                },
+            ])
+        }
+        TileKind::Door(from) if from.is_closed() => {
+            let mut events = vec![GameEvent::DoorKicked { pos }];
+            if luck::rnl(world, rng, 35) >= average {
+                events.push(message("WHAMM!!"));
+                return Ok(events);
+            }
+            let to = if world.effective_strength() > 18 && rn2(rng, 5) == 0 {
+                events.push(message("As you kick the door, it shatters to pieces!"));
+                DoorState::NoDoor
+            } else {
+                events.push(message("As you kick the door, it crashes open!"));
+                DoorState::Broken
+            };
+            world.current_map_mut().set_tile(pos, TileKind::Door(to))?;
+            events.push(GameEvent::DoorChanged { pos, from, to });
+            Ok(events)
+        }
+        tile => Err(GameError::NoDoor { pos, tile }),
+    }
+}
+
+/// 열쇠, 자물쇠 따개, 신용카드로 잠긴 문을 열거나 닫힌 문을 잠근다.
+pub fn pick_lock(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    direction: Direction,
+) -> Result<Vec<GameEvent>, String> {
+    if !world.inventory.contains(item) {
+        return Err("item is not in inventory".to_string());
+    }
+    let (tool, unlock) = world
+        .entities
+        .item_data(item)
+        .and_then(|data| Some((data.kind, data.unlock?)))
+        .ok_or_else(|| "item cannot open locks".to_string())?;
+    let pos = world.player_pos().offset(direction.delta());
+    let (method, next) = match lockable_door(world, pos)? {
+        DoorState::Locked => (LockMethod::Unlock, DoorState::Closed),
+        _ if !unlock.can_lock => {
+            return Err("You can't lock a door with a credit card.".to_string());
+        }
+        _ => (LockMethod::Lock, DoorState::Locked),
+    };
+    let success = rn2(rng, 100) < i32::from(unlock.chance(world.dexterity));
+    let mut events = vec![GameEvent::LockWorked {
+        pos,
+        tool,
+        method,
+        success,
+    }];
+    let text = match (method, success) {
+        (LockMethod::Unlock, true) if tool == ItemKind::SkeletonKey => {
+            "You succeed in unlocking the door."
+        }
+        (LockMethod::Unlock, true) => "You succeed in picking the lock.",
+        (LockMethod::Unlock, false) => "You fail to unlock the door.",
+        (_, true) => "You succeed in locking the door.",
+        (_, false) => "You fail to lock the door.",
+    };
+    events.push(message(text));
+    if success {
+        events.extend(set_door(world, pos, next).map_err(|error| format!("{error}"))?);
+    }
+    Ok(events)
+}
+
+/// `#force`: 휘두르는 무기로 잠긴 문을 비틀어 연다. 실패하면 드물게 무기가 부러진다.
+pub fn force_lock(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    direction: Direction,
+) -> Result<Vec<GameEvent>, String> {
+    let (weapon, data) = world
+        .inventory
+        .equipped_melee
+        .and_then(|weapon| Some((weapon, world.entities.item_data(weapon)?)))
+        .filter(|(_, data)| data.class == ItemClass::Weapon)
+        .ok_or_else(|| "You can't force anything without a weapon.".to_string())?;
+    let pos = world.player_pos().offset(direction.delta());
+    if lockable_door(world, pos)? != DoorState::Locked {
+        return Err("This door is not locked.".to_string());
+    }
+    let sides = data
+        .attack_profile
+        .map_or(0, |profile| profile.damage.sides);
+    let success = rn2(rng, 100) < i32::from(sides) * 2;
+    let mut events = vec![GameEvent::LockWorked {
+        pos,
+        tool: data.kind,
+        method: LockMethod::Force,
+        success,
+    }];
+    if success {
+        events.push(message("You succeed in forcing the lock."));
+        events.extend(set_door(world, pos, DoorState::Closed).map_err(|error| format!("{error}"))?);
+    } else if rn2(rng, 1000 - sides.clamp(0, 999)) > 992 {
+        world.inventory.remove(weapon);
+        world
+            .entities
+            .set_item_location(weapon, EntityLocation::Consumed);
+        events.push(message("Your weapon broke!"));
+        events.push(GameEvent::ItemDestroyed { item: weapon });
+    } else {
+        events.push(message("You fail to force the lock."));
+    }
+    Ok(events)
+}
+
+/// 층마다 `random` 상태로 선언된 문의 상태를 정한다. 세션을 만들 때 한 번만 부른다.
+///
+/// NetHack `dosdoor`처럼 1/3은 문짝이 달려 1/5 열림, 나머지 중 1/6 잠김, 그 밖은 닫힘이고
+/// 2/3은 문짝 없는 출입구다. 같은 시드면 같은 문이 나오도록 시드에서 만든 별도 난수를 쓴다.
+pub fn generate_doors(world: &mut GameWorld, seed: u64) {
+    let mut rng = GameRng::new(seed ^ DOOR_GENERATION_SALT);
+    for level in &mut world.levels.levels {
+        let map = &mut level.map;
+        for pos in map.take_random_doors() {
+            let state = if rn2(&mut rng, 3) == 0 {
+                if rn2(&mut rng, 5) == 0 {
+                    DoorState::Open
+                } else if rn2(&mut rng, 6) == 0 {
+                    DoorState::Locked
+                } else {
+                    DoorState::Closed
+                }
+            } else {
+                DoorState::NoDoor
+            };
+            let _ = map.set_tile(pos, TileKind::Door(state));
+        }
+    }
 }
 
 pub fn door_state_in_direction(world: &GameWorld, direction: Direction) -> Option<DoorState> {
@@ -34,6 +228,25 @@ pub fn door_state_in_direction(world: &GameWorld, direction: Direction) -> Optio
     }
 }
 
+/// 자물쇠를 다룰 수 있는 문인지 확인한다. NetHack `pick_lock`의 문 상태 검사와 같다.
+fn lockable_door(world: &GameWorld, pos: Pos) -> Result<DoorState, String> {
+    match world.current_map().tile(pos) {
+        Ok(TileKind::Door(state @ (DoorState::Closed | DoorState::Locked))) => Ok(state),
+        Ok(TileKind::Door(DoorState::Open)) => Err("This door is open.".to_string()),
+        Ok(TileKind::Door(DoorState::Broken)) => Err("This door is broken.".to_string()),
+        Ok(TileKind::Door(DoorState::NoDoor)) => Err("This doorway has no door.".to_string()),
+        _ => Err("You see no door there.".to_string()),
+    }
+}
+
+fn set_door(world: &mut GameWorld, pos: Pos, to: DoorState) -> Result<Vec<GameEvent>, GameError> {
+    let TileKind::Door(from) = world.current_map().tile(pos)? else {
+        return Ok(Vec::new());
+    };
+    aihack_core::doors::change_door(world.current_map_mut(), pos, from, to)?;
+    Ok(vec![GameEvent::DoorChanged { pos, from, to }])
+}
+
 fn change_door(
     world: &mut GameWorld,
     direction: Direction,
@@ -43,3 +256,28 @@ fn change_door(
     let pos = world.player_pos().offset(direction.delta());
     aihack_core::doors::change_door(world.current_map_mut(), pos, expected, next)
 }
+
+/// 문을 여는 몬스터가 `level`의 `pos`에 있는 닫힌 문을 열고 지나간다. NetHack `m_move`처럼
+/// 문이 보이면 "You see a door open."을 알린다.
+pub fn monster_open_door(world: &mut GameWorld, level: LevelId, pos: Pos) -> Vec<GameEvent> {
+    let Ok((from, to)) = aihack_core::doors::change_door(
+        world.map_mut(level),
+        pos,
+        DoorState::Closed,
+        DoorState::Open,
+    ) else {
+        return Vec::new();
+    };
+    let mut events = vec![GameEvent::DoorChanged { pos, from, to }];
+    if level == world.current_level() && vision::is_visible(world, pos) {
+        events.push(message("You see a door open."));
+    }
+    events
+}
+
+fn message(text: &str) -> GameEvent {
+    GameEvent::Message {
+        priority: MessagePriority::Info,
+        text: text.to_string(),
+    }
+}
@@ -0,0 +1,1022 @@
+//! 물약, 두루마리, 증기가 공유하는 소모품 효과 엔진이다.
+//!
+//! 효과 선택은 content의 `ConsumableEffect`가 결정하고, 전달 방식과 축복 상태는
+//! 강도만 바꾼다. 호출자는 아이템 소모와 turn 처리를 맡는다.
+
+use aihack_core::{
+    domain::{
+        combat::DeathCause,
+        entity::EntityLocation,
+        item::ItemClass,
+        item::{BucStatus, ConsumableEffect, ItemKind},
+        memory::RememberedGlyph,
+        monster::MonsterKind,
+        status::StatusEffect,
+        tile::TileKind,
+    },
+    event::{GameEvent, MessagePriority},
+    ids::{EntityId, LevelId},
+    position::Pos,
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{
+        combat::{rn2, roll_die},
+        death, generation, morale, traps,
+    },
+    world::GameWorld,
+};
+
+/// 경험 레벨의 상한이다. NetHack `MAXULEV`와 같다.
+pub const MAX_EXPERIENCE_LEVEL: u8 = 30;
+
+/// 같은 효과가 몸에 닿는 경로다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    Quaff,
+    Read,
+    /// 깨진 물약의 증기를 들이마신 경우다. 마실 때보다 훨씬 약하다.
+    Vapor,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffectOutcome {
+    pub events: Vec<GameEvent>,
+    /// 효과가 눈에 띄어 물약 종류를 식별할 수 있는지 여부다.
+    pub obvious: bool,
+}
+
+impl EffectOutcome {
+    pub(crate) fn new(obvious: bool) -> Self {
+        Self {
+            events: Vec::new(),
+            obvious,
+        }
+    }
+
+    pub(crate) fn message(&mut self, priority: MessagePriority, text: &str) {
+        self.events.push(GameEvent::Message {
+            priority,
+            text: text.to_string(),
+        });
+    }
+}
+
+/// 플레이어에게 효과를 적용한다. `source`는 사망 원인과 식별 대상이다.
+pub fn apply_to_player(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    source: ItemKind,
+    effect: ConsumableEffect,
+    buc: BucStatus,
+    delivery: Delivery,
+) -> EffectOutcome {
+    let sign = buc.sign();
+    let player_id = world.player_id;
+    let mut outcome = EffectOutcome::new(true);
+    match (effect, delivery) {
+        (ConsumableEffect::Heal { .. }, Delivery::Vapor) => {
+            heal_player(world, &mut outcome, 1, false);
+        }
+        (ConsumableEffect::Heal { dice, sides, bonus }, _) => {
+            let dice = (dice + 2 * sign).max(1);
+            let raw = (0..dice).map(|_| roll_die(rng, sides)).sum::<i16>() + bonus;
+            heal_player(world, &mut outcome, raw, buc == BucStatus::Blessed);
+            if buc != BucStatus::Cursed {
+                cure_blindness(world, &mut outcome);
+            }
+        }
+        (ConsumableEffect::GainLevel, Delivery::Vapor) => outcome.obvious = false,
+        (ConsumableEffect::GainLevel, _) if buc == BucStatus::Cursed => {
+            rise_through_ceiling(world, &mut outcome);
+        }
+        (ConsumableEffect::GainLevel, _) => gain_level(world, rng, &mut outcome),
+        (ConsumableEffect::Confusion, delivery) => {
+            if !world.conditions.is_confused() {
+                outcome.message(
+                    MessagePriority::Warning,
+                    if delivery == Delivery::Vapor {
+                        "You feel somewhat dizzy."
+                    } else {
+                        "Huh, What?  Where am I?"
+                    },
+                );
+            } else {
+                outcome.obvious = false;
+            }
+            let turns = match delivery {
+                Delivery::Vapor => roll_die(rng, 5),
+                _ => 16 - 8 * sign + roll_die(rng, 7) - 1,
+            } as u16;
+            world.conditions.confusion_turns =
+                world.conditions.confusion_turns.saturating_add(turns);
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::Confusion,
+                turns: world.conditions.confusion_turns,
+            });
+        }
+        (ConsumableEffect::Blindness, delivery) => {
+            if !world.conditions.is_blind() {
+                outcome.message(MessagePriority::Warning, "It suddenly gets dark.");
+            } else {
+                outcome.obvious = false;
+            }
+            let turns = match delivery {
+                Delivery::Vapor => roll_die(rng, 5),
+                _ => 250 - 125 * sign + roll_die(rng, 200) - 1,
+            } as u16;
+            world.conditions.blindness_turns =
+                world.conditions.blindness_turns.saturating_add(turns);
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::Blindness,
+                turns: world.conditions.blindness_turns,
+            });
+        }
+        (ConsumableEffect::Sleeping, delivery) => {
+            let turns = match delivery {
+                Delivery::Vapor => {
+                    outcome.message(MessagePriority::Warning, "You feel rather tired.");
+                    roll_die(rng, 5)
+                }
+                _ => {
+                    outcome.message(MessagePriority::Danger, "You suddenly fall asleep!");
+                    25 - 12 * sign + roll_die(rng, 10) - 1
+                }
+            };
+            world.paralysis_turns = world.paralysis_turns.max(turns.clamp(0, 255) as u8);
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::Sleep,
+                turns: u16::from(world.paralysis_turns),
+            });
+        }
+        (ConsumableEffect::SeeInvisible, Delivery::Vapor) => outcome.obvious = false,
+        (ConsumableEffect::SeeInvisible, _) => {
+            outcome.obvious = false;
+            if buc == BucStatus::Cursed {
+                outcome.message(MessagePriority::Info, "Yecch!  This tastes rotten.");
+            } else {
+                outcome.message(MessagePriority::Info, "This tastes like fruit juice.");
+                cure_blindness(world, &mut outcome);
+            }
+            if buc == BucStatus::Blessed {
+                world.conditions.see_invisible = true;
+            } else {
+                let turns = 750 + roll_die(rng, 100) as u16 - 1;
+                world.conditions.see_invisible_turns =
+                    world.conditions.see_invisible_turns.saturating_add(turns);
+            }
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::SeeInvisible,
+                turns: world.conditions.see_invisible_turns,
+            });
+        }
+        (ConsumableEffect::Speed, delivery) => {
+            let turns = match delivery {
+                Delivery::Vapor => {
+                    outcome.message(MessagePriority::Info, "Your knees seem more flexible now.");
+                    roll_die(rng, 5)
+                }
+                _ => {
+                    if world.conditions.very_fast_turns > 0 {
+                        outcome.obvious = false;
+                        outcome.message(MessagePriority::Info, "Your legs get new energy.");
+                    } else {
+                        outcome.message(
+                            MessagePriority::Info,
+                            "You are suddenly moving much faster.",
+                        );
+                    }
+                    100 + 60 * sign + roll_die(rng, 10) - 1
+                }
+            } as u16;
+            world.conditions.very_fast_turns =
+                world.conditions.very_fast_turns.saturating_add(turns);
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::VeryFast,
+                turns: world.conditions.very_fast_turns,
+            });
+        }
+        (ConsumableEffect::Sickness, Delivery::Vapor) => {
+            let hp = world
+                .entities
+                .actor_stats(player_id)
+                .map(|stats| stats.hp)
+                .unwrap_or_default();
+            let amount = if hp <= 5 { hp - 1 } else { 5 };
+            damage_player(world, &mut outcome, source, amount.max(0));
+        }
+        (ConsumableEffect::Sickness, _) => {
+            outcome.message(
+                MessagePriority::Warning,
+                "Yecch!  This stuff tastes like poison.",
+            );
+            let amount = if buc == BucStatus::Blessed {
+                outcome.message(
+                    MessagePriority::Info,
+                    "(But in fact it was mildly stale fruit juice.)",
+                );
+                1
+            } else {
+                roll_die(rng, 10) + if buc == BucStatus::Cursed { 5 } else { 0 }
+            };
+            if world.hallucinating {
+                world.hallucinating = false;
+                outcome.message(
+                    MessagePriority::Info,
+                    "You are shocked back to your senses!",
+                );
+            }
+            damage_player(world, &mut outcome, source, amount);
+        }
+        (ConsumableEffect::Water, Delivery::Vapor) => outcome.obvious = false,
+        (ConsumableEffect::Water, _) => match buc {
+            BucStatus::Uncursed => {
+                outcome.message(MessagePriority::Info, "This tastes like water.");
+                world.nutrition = world.nutrition.saturating_add(roll_die(rng, 10));
+            }
+            BucStatus::Blessed => outcome.message(MessagePriority::Info, "You feel full of awe."),
+            BucStatus::Cursed => outcome.message(MessagePriority::Info, "You feel full of dread."),
+        },
+        (ConsumableEffect::RevealLevel, _) => {
+            outcome.events.extend(traps::reveal_all_hidden_tiles(world));
+        }
+        (ConsumableEffect::IdentifySingle, _) => {
+            if let Some((target, kind)) = world.inventory.entries.iter().find_map(|entry| {
+                world
+                    .entities
+                    .get(entry.item)
+                    .and_then(|entity| entity.item())
+                    .map(|(kind, _, _, _, _)| (entry.item, kind))
+            }) {
+                world.identify_item_kind(kind);
+                outcome.events.push(GameEvent::ItemIdentified {
+                    entity: player_id,
+                    item: target,
+                });
+            }
+        }
+        (ConsumableEffect::IdentifySource, _) => {
+            outcome.message(
+                MessagePriority::Info,
+                "You identify this as an identify scroll.",
+            );
+        }
+        (ConsumableEffect::Teleport, _) if buc == BucStatus::Cursed => {
+            level_teleport(world, rng, &mut outcome);
+        }
+        (ConsumableEffect::Teleport, _) => teleport_within_level(world, rng, &mut outcome),
+        (ConsumableEffect::LevelTeleport, _) => level_teleport(world, rng, &mut outcome),
+        (ConsumableEffect::EnchantWeapon, _) => enchant_weapon(world, rng, buc, &mut outcome),
+        (ConsumableEffect::EnchantArmor, _) => enchant_armor(world, rng, buc, &mut outcome),
+        (ConsumableEffect::ErodeproofWeapon, _) => {
+            erodeproof(world, world.inventory.equipped_melee, buc, &mut outcome);
+        }
+        (ConsumableEffect::ErodeproofArmor, _) => {
+            erodeproof(world, world.inventory.equipped_body, buc, &mut outcome);
+        }
+        (ConsumableEffect::RemoveCurse, _) => remove_curse(world, rng, buc, false, &mut outcome),
+        (ConsumableEffect::ScrambleCurses, _) => {
+            remove_curse(world, rng, buc, true, &mut outcome);
+        }
+        (
+            ConsumableEffect::Fire {
+                dice,
+                sides,
+                radius,
+            },
+            _,
+        ) => {
+            scroll_fire(world, rng, source, buc, (dice, sides, radius), &mut outcome);
+        }
+        (ConsumableEffect::Light { radius, lit }, _) => {
+            light_area(world, radius, lit && buc != BucStatus::Cursed, &mut outcome);
+        }
+        (ConsumableEffect::GoldDetection, _) if buc == BucStatus::Cursed => {
+            detect_traps(world, &mut outcome);
+        }
+        (ConsumableEffect::GoldDetection, _) => detect_gold(world, &mut outcome),
+        (ConsumableEffect::TrapDetection, _) => detect_traps(world, &mut outcome),
+        (ConsumableEffect::CreateMonster { count }, _) => {
+            let count = if buc == BucStatus::Cursed {
+                count.saturating_add(roll_die(rng, 4) as u8)
+            } else {
+                count
+            };
+            create_monsters(world, rng, count, &mut outcome);
+        }
+        (ConsumableEffect::ScareMonsters, _) => {
+            scare_monsters(world, buc == BucStatus::Cursed, &mut outcome);
+        }
+        (ConsumableEffect::RallyMonsters, _) => scare_monsters(world, true, &mut outcome),
+    }
+    outcome
+}
+
+/// 겁주는 두루마리다. 보이는 몬스터가 있으면 가까이서, 없으면 멀리서 웃음소리(`rally`이면
+/// 울음소리)가 들린다. NetHack `seffects`의 `SCR_SCARE_MONSTER`와 같다.
+fn scare_monsters(world: &mut GameWorld, rally: bool, outcome: &mut EffectOutcome) {
+    let (events, seen) = morale::scare_seen_monsters(world, rally);
+    outcome.events.extend(events);
+    let sound = if rally {
+        "sad wailing"
+    } else {
+        "maniacal laughter"
+    };
+    let place = if seen == 0 {
+        "in the distance"
+    } else {
+        "close by"
+    };
+    outcome.message(MessagePriority::Info, &format!("You hear {sound} {place}."));
+}
+
+/// 던진 물약이 몬스터 머리에서 깨졌을 때의 효과다. NetHack `potionhit`을 따르되, 이 게임의
+/// 몬스터는 마법 저항과 눈 유무가 없으므로 `resist`와 `haseyes` 판정은 하지 않는다.
+pub fn apply_to_monster(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    target: EntityId,
+    effect: ConsumableEffect,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    let crash = roll_die(rng, 5) > 1;
+    let Some(stats) = world.entities.actor_stats_mut(target) else {
+        return events;
+    };
+    if crash && stats.hp > 1 {
+        stats.hp -= 1;
+        events.push(GameEvent::EntityDamaged {
+            entity: target,
+            amount: 1,
+            hp_after: stats.hp,
+        });
+    }
+    match effect {
+        ConsumableEffect::Heal { .. } => {
+            let amount = stats.max_hp - stats.hp;
+            stats.hp = stats.max_hp;
+            events.push(GameEvent::EntityHealed {
+                entity: target,
+                amount,
+                hp_after: stats.hp,
+            });
+        }
+        ConsumableEffect::Sickness => {
+            if stats.max_hp > 3 {
+                stats.max_hp /= 2;
+            }
+            if stats.hp > 2 {
+                let before = stats.hp;
+                stats.hp = (stats.hp / 2).min(stats.max_hp);
+                events.push(GameEvent::EntityDamaged {
+                    entity: target,
+                    amount: before - stats.hp,
+                    hp_after: stats.hp,
+                });
+            }
+        }
+        ConsumableEffect::Confusion => stats.confused = true,
+        ConsumableEffect::Blindness => {
+            let turns = 64 + rn2(rng, 32) + rn2(rng, 32) + i32::from(stats.blinded_turns);
+            stats.blinded_turns = turns.min(127) as u8;
+        }
+        ConsumableEffect::Sleeping => {
+            // `sleep_monst(mon, rnd(12))`: 이미 굳어 있으면 남은 턴에 더한다.
+            let turns = i16::from(stats.frozen_turns) + roll_die(rng, 12);
+            stats.frozen_turns = turns.min(127) as u8;
+        }
+        _ => {}
+    }
+    events
+}
+
+fn heal_player(world: &mut GameWorld, outcome: &mut EffectOutcome, raw: i16, raise_max: bool) {
+    let player_id = world.player_id;
+    let Some(stats) = world.entities.actor_stats_mut(player_id) else {
+        return;
+    };
+    let before = stats.hp;
+    if raise_max && stats.hp + raw > stats.max_hp {
+        stats.max_hp += 1;
+    }
+    stats.hp = stats.max_hp.min(stats.hp + raw);
+    outcome.events.push(GameEvent::EntityHealed {
+        entity: player_id,
+        amount: stats.hp - before,
+        hp_after: stats.hp,
+    });
+}
+
+pub(crate) fn damage_player(
+    world: &mut GameWorld,
+    outcome: &mut EffectOutcome,
+    source: ItemKind,
+    amount: i16,
+) {
+    let player_id = world.player_id;
+    let Some(stats) = world.entities.actor_stats_mut(player_id) else {
+        return;
+    };
+    stats.hp -= amount;
+    outcome.events.push(GameEvent::EntityDamaged {
+        entity: player_id,
+        amount,
+        hp_after: stats.hp,
+    });
+    outcome
+        .events
+        .extend(death::collect_death_events_if_hp_depleted(
+            world,
+            player_id,
+            DeathCause::Consumable { item: source },
+        ));
+}
+
+fn cure_blindness(world: &mut GameWorld, outcome: &mut EffectOutcome) {
+    if world.conditions.is_blind() {
+        world.conditions.blindness_turns = 0;
+        outcome.message(MessagePriority::Info, "You can see again.");
+        outcome.events.push(GameEvent::StatusChanged {
+            entity: world.player_id,
+            effect: StatusEffect::Blindness,
+            turns: 0,
+        });
+    }
+}
+
+fn gain_level(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    if world.experience_level >= MAX_EXPERIENCE_LEVEL {
+        outcome.obvious = false;
+        return;
+    }
+    let gain = roll_die(rng, 8);
+    let player_id = world.player_id;
+    let Some(stats) = world.entities.actor_stats_mut(player_id) else {
+        return;
+    };
+    stats.max_hp += gain;
+    stats.hp += gain;
+    let max_hp_after = stats.max_hp;
+    world.experience_level += 1;
+    outcome.message(MessagePriority::Info, "You feel more experienced.");
+    outcome.events.push(GameEvent::ExperienceLevelChanged {
+        entity: player_id,
+        level: world.experience_level,
+        max_hp_after,
+    });
+}
+
+/// 경험 레벨 하나와 그만큼의 최대 체력을 잃는다. 1레벨이면 아무 일도 없다. NetHack `losexp`와
+/// 같지만 1레벨에서 죽지는 않는다.
+pub(crate) fn lose_level(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    if world.experience_level <= 1 {
+        return;
+    }
+    outcome.message(
+        MessagePriority::Danger,
+        &format!("Goodbye level {}.", world.experience_level),
+    );
+    world.experience_level -= 1;
+    let loss = roll_die(rng, 8);
+    let player_id = world.player_id;
+    let Some(stats) = world.entities.actor_stats_mut(player_id) else {
+        return;
+    };
+    stats.max_hp = (stats.max_hp - loss).max(1);
+    stats.hp = stats.hp.min(stats.max_hp);
+    let max_hp_after = stats.max_hp;
+    outcome.events.push(GameEvent::ExperienceLevelChanged {
+        entity: player_id,
+        level: world.experience_level,
+        max_hp_after,
+    });
+}
+
+/// 저주받은 경험 물약은 위층으로 천장을 뚫고 올라가게 한다.
+fn rise_through_ceiling(world: &mut GameWorld, outcome: &mut EffectOutcome) {
+    let from = world.current_level();
+    let to = LevelId {
+        branch: from.branch,
+        depth: from.depth - 1,
+    };
+    let Some(landing) = (from.depth > 1)
+        .then(|| world.levels.stairs_down_pos(to))
+        .flatten()
+    else {
+        outcome.message(MessagePriority::Info, "You have an uneasy feeling.");
+        return;
+    };
+    outcome.message(MessagePriority::Info, "You rise up, through the ceiling!");
+    world.set_player_location(to, landing);
+    outcome.events.push(GameEvent::LevelChanged {
+        entity: world.player_id,
+        from,
+        to,
+    });
+}
+
+/// 현재 층의 무작위 안전 지점으로 순간이동한다.
+pub(crate) fn teleport_within_level(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    outcome: &mut EffectOutcome,
+) {
+    let from = world.player_pos();
+    let Some(to) = random_safe_pos(world, rng, world.current_level(), Some(from)) else {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "You feel disoriented for a moment.");
+        return;
+    };
+    world.set_player_pos(to);
+    outcome.events.push(GameEvent::EntityTeleported {
+        entity: world.player_id,
+        from,
+        to,
+    });
+}
+
+/// 현재 층이 아닌 무작위 층의 안전 지점으로 이동한다.
+fn level_teleport(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    let from = world.current_level();
+    let candidates = world
+        .levels
+        .levels
+        .iter()
+        .map(|level| level.id)
+        .filter(|id| *id != from)
+        .collect::<Vec<_>>();
+    let landing = (!candidates.is_empty())
+        .then(|| candidates[(roll_die(rng, candidates.len() as i16) - 1) as usize])
+        .and_then(|to| random_safe_pos(world, rng, to, None).map(|pos| (to, pos)));
+    let Some((to, pos)) = landing else {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "You shudder for a moment.");
+        return;
+    };
+    world.set_player_location(to, pos);
+    outcome.events.push(GameEvent::LevelChanged {
+        entity: world.player_id,
+        from,
+        to,
+    });
+}
+
+/// 함정과 다른 actor가 없고 지나갈 수 있는 타일 중 하나를 고른다.
+pub(crate) fn random_safe_pos(
+    world: &GameWorld,
+    rng: &mut GameRng,
+    level: LevelId,
+    exclude: Option<Pos>,
+) -> Option<Pos> {
+    let map = world.levels.map(level)?;
+    let candidates = (0..map.height)
+        .flat_map(|y| (0..map.width).map(move |x| Pos { x, y }))
+        .filter(|pos| Some(*pos) != exclude)
+        .filter(|pos| {
+            map.tile(*pos).is_ok_and(|tile| {
+                tile.is_movement_passable()
+                    && !matches!(tile, TileKind::Trap(_) | TileKind::HiddenTrap(_))
+            })
+        })
+        .filter(|pos| world.entities.alive_actor_at(level, *pos).is_none())
+        .collect::<Vec<_>>();
+    (!candidates.is_empty())
+        .then(|| candidates[(roll_die(rng, candidates.len() as i16) - 1) as usize])
+}
+
+/// NetHack `chwepon`처럼 장비한 무기의 강화치를 바꾼다.
+fn enchant_weapon(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    buc: BucStatus,
+    outcome: &mut EffectOutcome,
+) {
+    let Some(weapon) = world.inventory.equipped_melee.filter(|item| {
+        world
+            .entities
+            .item_data(*item)
+            .is_some_and(|data| data.class == ItemClass::Weapon)
+    }) else {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "Your hands twitch.");
+        return;
+    };
+    let current = world.entities.item_enchantment(weapon);
+    let amount = enchantment_amount(rng, current, buc);
+    if amount > 0 && current > 5 && roll_die(rng, 3) > 1 {
+        outcome.message(
+            MessagePriority::Warning,
+            "Your weapon violently glows blue for a while and then evaporates.",
+        );
+        destroy_inventory_item(world, weapon, outcome);
+        return;
+    }
+    if amount == 0 {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "Your weapon vibrates briefly.");
+        return;
+    }
+    let next = current.saturating_add(amount);
+    world.entities.set_item_enchantment(weapon, next);
+    outcome.message(
+        MessagePriority::Info,
+        match amount {
+            ..=-1 => "Your weapon glows black for a moment.",
+            1 => "Your weapon glows blue for a moment.",
+            _ => "Your weapon glows blue for a while.",
+        },
+    );
+    outcome.events.push(GameEvent::ItemEnchanted {
+        item: weapon,
+        enchantment: next,
+    });
+    if amount > 0 && world.entities.item_buc(weapon) == Some(BucStatus::Cursed) {
+        set_buc(world, weapon, BucStatus::Uncursed, outcome);
+    }
+    if next > 5 && roll_die(rng, 3) == 1 {
+        outcome.message(
+            MessagePriority::Warning,
+            "Your weapon suddenly vibrates unexpectedly.",
+        );
+    }
+}
+
+/// 착용한 갑옷의 강화치를 바꾸고 두루마리의 축복 상태를 옮긴다.
+fn enchant_armor(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    buc: BucStatus,
+    outcome: &mut EffectOutcome,
+) {
+    let Some(armor) = world.inventory.equipped_body else {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "Your skin glows then fades.");
+        return;
+    };
+    let current = world.entities.item_enchantment(armor);
+    let amount = enchantment_amount(rng, current, buc);
+    let player_id = world.player_id;
+    if amount > 0 && current > 3 && roll_die(rng, i16::from(current)) > 1 {
+        outcome.message(
+            MessagePriority::Warning,
+            "Your armor violently glows silver for a while, then evaporates.",
+        );
+        let worn_bonus = world
+            .entities
+            .item_data(armor)
+            .map(|data| data.ac_bonus)
+            .unwrap_or_default()
+            + i16::from(current);
+        if let Some(stats) = world.entities.actor_stats_mut(player_id) {
+            stats.ac += worn_bonus;
+        }
+        destroy_inventory_item(world, armor, outcome);
+        return;
+    }
+    let next = current.saturating_add(amount);
+    world.entities.set_item_enchantment(armor, next);
+    if let Some(stats) = world.entities.actor_stats_mut(player_id) {
+        stats.ac -= i16::from(next - current);
+    }
+    outcome.message(
+        MessagePriority::Info,
+        match amount {
+            ..=-1 => "Your armor glows black for a moment.",
+            0 | 1 => "Your armor glows silver for a moment.",
+            _ => "Your armor glows silver for a while.",
+        },
+    );
+    outcome.events.push(GameEvent::ItemEnchanted {
+        item: armor,
+        enchantment: next,
+    });
+    let next_buc = match (buc, world.entities.item_buc(armor).unwrap_or_default()) {
+        (BucStatus::Blessed, _) => BucStatus::Blessed,
+        (BucStatus::Cursed, _) => BucStatus::Cursed,
+        (BucStatus::Uncursed, BucStatus::Cursed) => BucStatus::Uncursed,
+        (BucStatus::Uncursed, current) => current,
+    };
+    set_buc(world, armor, next_buc, outcome);
+}
+
+/// NetHack의 강화량 규칙이다. 저주는 -1, +9 이상은 거의 오르지 않는다.
+fn enchantment_amount(rng: &mut GameRng, current: i8, buc: BucStatus) -> i8 {
+    match buc {
+        BucStatus::Cursed => -1,
+        _ if current >= 9 => i8::from(roll_die(rng, i16::from(current)) == 1),
+        BucStatus::Blessed => roll_die(rng, i16::from(3 - current.max(0) / 3)) as i8,
+        BucStatus::Uncursed => 1,
+    }
+}
+
+fn erodeproof(
+    world: &mut GameWorld,
+    target: Option<EntityId>,
+    buc: BucStatus,
+    outcome: &mut EffectOutcome,
+) {
+    let Some(item) = target else {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "Your skin glows then fades.");
+        return;
+    };
+    let protect = buc != BucStatus::Cursed;
+    world.entities.set_item_erodeproof(item, protect);
+    if protect {
+        outcome.message(
+            MessagePriority::Info,
+            "Your item is covered by a shimmering golden shield!",
+        );
+        outcome.events.push(GameEvent::ItemErodeproofed { item });
+    } else {
+        outcome.message(
+            MessagePriority::Info,
+            "Your item is covered by a mottled black glow.",
+        );
+    }
+}
+
+/// 축복된 두루마리는 모든 소지품, 그 외에는 장비 중인 아이템만 대상으로 한다.
+fn remove_curse(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    buc: BucStatus,
+    scramble: bool,
+    outcome: &mut EffectOutcome,
+) {
+    outcome.obvious = false;
+    if buc == BucStatus::Cursed {
+        outcome.message(MessagePriority::Info, "The scroll disintegrates.");
+        return;
+    }
+    outcome.message(
+        MessagePriority::Info,
+        if scramble {
+            "You feel like you need some help."
+        } else {
+            "You feel like someone is helping you."
+        },
+    );
+    let targets = if buc == BucStatus::Blessed {
+        world
+            .inventory
+            .entries
+            .iter()
+            .map(|entry| entry.item)
+            .collect::<Vec<_>>()
+    } else {
+        [
+            world.inventory.equipped_melee,
+            world.inventory.equipped_body,
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    };
+    for item in targets {
+        let current = world.entities.item_buc(item).unwrap_or_default();
+        let next = match (scramble, current) {
+            (false, BucStatus::Cursed) => BucStatus::Uncursed,
+            (true, BucStatus::Uncursed) if roll_die(rng, 2) == 1 => {
+                if roll_die(rng, 2) == 1 {
+                    BucStatus::Cursed
+                } else {
+                    BucStatus::Blessed
+                }
+            }
+            _ => continue,
+        };
+        set_buc(world, item, next, outcome);
+    }
+}
+
+fn set_buc(world: &mut GameWorld, item: EntityId, buc: BucStatus, outcome: &mut EffectOutcome) {
+    if world.entities.item_buc(item) != Some(buc) {
+        world.entities.set_item_buc(item, buc);
+        outcome.events.push(GameEvent::ItemBucChanged { item, buc });
+    }
+}
+
+fn destroy_inventory_item(world: &mut GameWorld, item: EntityId, outcome: &mut EffectOutcome) {
+    world.inventory.remove(item);
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Consumed);
+    outcome.events.push(GameEvent::ItemDestroyed { item });
+}
+
+/// 불의 두루마리다. 반경 안의 플레이어와 몬스터가 같은 피해를 입는다.
+fn scroll_fire(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    source: ItemKind,
+    buc: BucStatus,
+    (dice, sides, radius): (i16, i16, i16),
+    outcome: &mut EffectOutcome,
+) {
+    let damage = ((0..dice).map(|_| roll_die(rng, sides)).sum::<i16>() + buc.sign()).max(1);
+    if radius == 0 {
+        outcome.message(
+            MessagePriority::Warning,
+            "The scroll catches fire and you burn your hands.",
+        );
+        damage_player(world, outcome, source, damage);
+        return;
+    }
+    outcome.message(
+        MessagePriority::Danger,
+        "The scroll erupts in a tower of flame!",
+    );
+    let center = world.player_pos();
+    for monster in world.current_level_hostile_monsters() {
+        let Some((_, pos)) = world.entities.actor_location(monster) else {
+            continue;
+        };
+        if center.chebyshev_distance(pos) > radius {
+            continue;
+        }
+        let Some(stats) = world.entities.actor_stats_mut(monster) else {
+            continue;
+        };
+        stats.hp -= damage;
+        outcome.events.push(GameEvent::EntityDamaged {
+            entity: monster,
+            amount: damage,
+            hp_after: stats.hp,
+        });
+        outcome
+            .events
+            .extend(death::collect_death_events_if_hp_depleted(
+                world,
+                monster,
+                DeathCause::Consumable { item: source },
+            ));
+    }
+    damage_player(world, outcome, source, damage);
+}
+
+/// 반경 안의 타일 조명을 바꾼다. 눈이 멀었으면 무엇이 바뀌었는지 알 수 없다.
+fn light_area(world: &mut GameWorld, radius: i16, lit: bool, outcome: &mut EffectOutcome) {
+    let center = world.player_pos();
+    let map = world.current_map_mut();
+    for dy in -radius..=radius {
+        for dx in -radius..=radius {
+            if dx * dx + dy * dy <= radius * radius {
+                let _ = map.set_lit(
+                    Pos {
+                        x: center.x + dx,
+                        y: center.y + dy,
+                    },
+                    lit,
+                );
+            }
+        }
+    }
+    outcome.events.push(GameEvent::AreaLit {
+        center,
+        radius,
+        lit,
+    });
+    if world.conditions.is_blind() {
+        outcome.obvious = false;
+    } else if lit {
+        outcome.message(MessagePriority::Info, "A lit field surrounds you!");
+    } else {
+        outcome.message(MessagePriority::Info, "Darkness surrounds you.");
+    }
+}
+
+/// 현재 층 바닥의 금화 더미를 모두 지도 기억에 남긴다. NetHack `gold_detect`와 같고, 금화가
+/// 없으면 아무것도 드러나지 않는다.
+fn detect_gold(world: &mut GameWorld, outcome: &mut EffectOutcome) {
+    let level = world.current_level();
+    let piles = world
+        .entities
+        .entities()
+        .iter()
+        .filter_map(|entity| match entity.item()? {
+            (kind, data, EntityLocation::OnMap { level: at, pos }, _, _)
+                if at == level && data.class == ItemClass::Coin =>
+            {
+                let tile = world.current_map().tile(pos).ok()?.observation_equivalent();
+                Some((pos, kind, tile))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    if piles.is_empty() {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "You feel materially poor.");
+        return;
+    }
+    let (width, height) = (world.current_map().width, world.current_map().height);
+    let memory = world.map_memory.level_mut(level, width, height);
+    for (pos, kind, tile) in piles {
+        let remembered = memory.glyph(pos);
+        memory.remember(
+            pos,
+            RememberedGlyph {
+                tile: remembered.map_or(tile, |glyph| glyph.tile),
+                object: Some(kind),
+                monster: remembered.and_then(|glyph| glyph.monster),
+                lit: remembered.is_some_and(|glyph| glyph.lit),
+            },
+        );
+    }
+    outcome.message(
+        MessagePriority::Info,
+        "You feel very greedy, and sense gold!",
+    );
+}
+
+fn detect_traps(world: &mut GameWorld, outcome: &mut EffectOutcome) {
+    let revealed = aihack_core::traps::reveal_hidden_traps(world.current_map_mut());
+    if revealed.is_empty() {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "Your toes stop itching.");
+    } else {
+        outcome.message(MessagePriority::Info, "Your toes itch.");
+    }
+    outcome.events.extend(revealed);
+}
+
+/// 플레이어 주변 빈 칸에 무작위 몬스터를 만든다. 무리 짓는 몬스터는 NetHack `makemon`처럼 무리와
+/// 함께 나온다. 빈 칸이 없으면 멈춘다.
+pub(crate) fn create_monsters(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    count: u8,
+    outcome: &mut EffectOutcome,
+) {
+    let mut created = 0;
+    for _ in 0..count {
+        let Some(event) = spawn_near_player(world, rng, None) else {
+            break;
+        };
+        let leader = match &event {
+            GameEvent::MonsterCreated { kind, .. } => Some(*kind),
+            _ => None,
+        };
+        outcome.events.push(event);
+        created += 1;
+        let Some(kind) = leader else {
+            continue;
+        };
+        for _ in 0..generation::group_size(world, rng, kind) {
+            let Some(event) = spawn_near_player(world, rng, Some(kind)) else {
+                break;
+            };
+            outcome.events.push(event);
+        }
+    }
+    if created == 0 {
+        outcome.obvious = false;
+        outcome.message(MessagePriority::Info, "You hear a faint rustling.");
+    }
+}
+
+/// 플레이어 주변 빈 칸 하나에 몬스터를 만든다. `kind`가 없으면 현재 층에 맞는 몬스터를 고르며,
+/// 나올 몬스터가 없으면 만들지 않는다.
+pub(crate) fn spawn_near_player(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    kind: Option<MonsterKind>,
+) -> Option<GameEvent> {
+    let level = world.current_level();
+    let center = world.player_pos();
+    let free = (-1..=1)
+        .flat_map(|dy| {
+            (-1..=1).map(move |dx| Pos {
+                x: center.x + dx,
+                y: center.y + dy,
+            })
+        })
+        .filter(|pos| {
+            world
+                .current_map()
+                .tile(*pos)
+                .is_ok_and(|tile| tile.is_movement_passable())
+                && world.entities.alive_actor_at(level, *pos).is_none()
+        })
+        .collect::<Vec<_>>();
+    if free.is_empty() {
+        return None;
+    }
+    let pos = free[(roll_die(rng, free.len() as i16) - 1) as usize];
+    let kind = match kind {
+        Some(kind) => kind,
+        None => generation::random_monster(world, rng)?,
+    };
+    let template = world.monster_template(kind).ok()?;
+    let entity = world
+        .entities
+        .spawn_monster_with_template(kind, template, pos);
+    world.entities.set_actor_location(entity, level, pos);
+    Some(GameEvent::MonsterCreated { entity, kind, pos })
+}
@@ -0,0 +1,120 @@
+//! `Explore` 자동 탐험이다.
+//!
+//! NetHack에는 없는 명령이라 걸음은 `_` travel의 길 찾기와 같은 규칙을 쓰고, 목적지만 기억하는
+//! 지도에서 아직 모르는 칸과 맞닿은 가장 가까운 칸(frontier)으로 정한다. 닫힌 문은 열고
+//! 지나가며, 발밑 물건은 autopickup 규칙에 맞으면 줍는다. 걸음마다 목적지를 다시 고른다.
+
+use aihack_core::{
+    domain::{
+        item::ItemClass,
+        player::InterruptReason,
+        tile::{DoorState, TileKind},
+    },
+    event::GameEvent,
+    position::{Direction, Pos},
+};
+
+use crate::{
+    systems::{items, movement, travel},
+    world::{GameWorld, UNENCUMBERED_CAPACITY},
+};
+
+/// 탐험하며 저절로 줍는 물건 종류다. NetHack에서 흔히 쓰는 `pickup_types:?!/="`와 같다.
+pub const AUTOPICKUP_CLASSES: [ItemClass; 4] = [
+    ItemClass::Scroll,
+    ItemClass::Potion,
+    ItemClass::Wand,
+    ItemClass::Ring,
+];
+
+/// 탐험의 다음 한 걸음이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Walk(Direction),
+    /// 길을 막은 닫힌 문을 연다.
+    Open(Direction),
+}
+
+/// 가장 가까운 frontier로 가는 길이다. 닫힌 문은 열고 지나갈 수 있는 칸으로 본다.
+pub fn frontier_path(world: &GameWorld) -> Option<Vec<Direction>> {
+    let known = travel::known_tiles(world);
+    let openable = |pos| match known(pos) {
+        Some(TileKind::Door(DoorState::Closed)) => Some(TileKind::Door(DoorState::Open)),
+        tile => tile,
+    };
+    let here = world.player_pos();
+    aihack_core::movement::find_path_to(here, openable, |pos| {
+        pos != here
+            && openable(pos).is_some_and(TileKind::is_movement_passable)
+            && Direction::ALL.into_iter().any(|direction| {
+                let next = pos.offset(direction.delta());
+                world.current_map().tile(next).is_ok() && known(next).is_none()
+            })
+    })
+}
+
+/// 다음 걸음이다. 더 갈 frontier가 없으면 `None`이다.
+pub fn next_step(world: &GameWorld) -> Option<Step> {
+    let direction = *frontier_path(world)?.first()?;
+    let ahead = world.player_pos().offset(direction.delta());
+    Some(
+        if world.current_map().tile(ahead) == Ok(TileKind::Door(DoorState::Closed)) {
+            Step::Open(direction)
+        } else {
+            Step::Walk(direction)
+        },
+    )
+}
+
+/// 방금 걸음의 사건 `events`를 보고 탐험을 멈출 까닭을 찾는다.
+pub fn stop_reason(world: &GameWorld, events: &[GameEvent]) -> Option<InterruptReason> {
+    let (hp, max_hp) = world
+        .entities
+        .actor_stats(world.player_id)
+        .map_or((0, 0), |stats| (stats.hp, stats.max_hp));
+    if hp.saturating_mul(2) <= max_hp {
+        return Some(InterruptReason::LowHitPoints);
+    }
+    if events.iter().any(|event| {
+        matches!(event, GameEvent::TrapTriggered { entity, .. } if *entity == world.player_id)
+    }) {
+        return Some(InterruptReason::Trap);
+    }
+    if !world
+        .entities
+        .items_at(world.current_level(), world.player_pos())
+        .is_empty()
+    {
+        return Some(InterruptReason::ItemsHere);
+    }
+    match next_step(world) {
+        None => Some(InterruptReason::Explored),
+        Some(Step::Walk(direction)) if !movement::can_keep_moving(world, direction) => {
+            Some(InterruptReason::Blocked)
+        }
+        Some(_) => None,
+    }
+}
+
+/// 발밑 물건 가운데 autopickup 종류이고 짐 부담이 생기지 않는 것을 줍는다.
+pub fn autopickup(world: &mut GameWorld) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    for item in world
+        .entities
+        .items_at(world.current_level(), world.player_pos())
+    {
+        let wanted = world.entities.item_data(item).is_some_and(|data| {
+            AUTOPICKUP_CLASSES.contains(&data.class)
+                && world.carried_weight() + data.weight <= UNENCUMBERED_CAPACITY
+        });
+        if wanted {
+            events.extend(items::pickup_item(world, item).ok());
+        }
+    }
+    events
+}
+
+/// `pos`가 플레이어가 아는 칸인지 본다. 관측과 테스트가 탐험 결과를 확인할 때 쓴다.
+pub fn is_known(world: &GameWorld, pos: Pos) -> bool {
+    travel::known_tiles(world)(pos).is_some()
+}
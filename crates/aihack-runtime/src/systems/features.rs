@@ -0,0 +1,780 @@
+//! 제단, 분수, 싱크대, 왕좌, 무덤 같은 던전 특수 지형과의 상호작용이다.
+//!
+//! 판정은 NetHack 3.6.7 `pray.c`의 `dosacrifice`, `fountain.c`의 `drinkfountain`/
+//! `dipfountain`/`dosinkring`, `dokick.c`의 싱크대 차기, `sit.c`의 `dosit`,
+//! `dig.c`의 `dig_up_grave`를 따른다. 제단은 언제나 플레이어가 섬기는 신의 것이다.
+//! 이 게임에 없는 결과(물의 님프, 보석, 학살 등)는 아무 일도 일어나지 않은 것으로 처리한다.
+
+use aihack_core::{
+    domain::{
+        combat::DeathCause,
+        entity::{EntityKind, EntityLocation},
+        item::{BucStatus, ItemClass, ItemKind},
+        monster::MonsterKind,
+        status::{LuckCause, StatusEffect},
+        tile::TileKind,
+    },
+    event::{GameEvent, MessagePriority},
+    ids::EntityId,
+    position::Pos,
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{
+        combat::{rn2, roll_die},
+        death,
+        effects::{self, EffectOutcome},
+        generation, items, luck,
+        prayer::{self, GOD_NAME},
+        traps,
+    },
+    world::GameWorld,
+};
+
+/// 제물 가치의 상한이다. NetHack `MAXVALUE`와 같다.
+pub const MAX_SACRIFICE_VALUE: i16 = 24;
+/// 이 나이가 된 시체는 제물로 받아들여지지 않는다.
+pub const SACRIFICE_MAX_AGE: u16 = 50;
+/// 무덤에서 나온 시체는 이만큼 오래된 것으로 본다.
+const GRAVE_CORPSE_AGE: u16 = 100;
+/// 힘은 이 값 아래로 내려가거나 위로 올라가지 않는다.
+const MIN_STRENGTH: u8 = 3;
+const MAX_STRENGTH: u8 = 18;
+
+/// 아이템을 떨어뜨린 자리의 특수 지형 반응이다. 제단은 축복/저주를 드러내고
+/// 싱크대는 반지를 삼키며 종류를 알려 준다.
+pub fn after_drop(world: &mut GameWorld, rng: &mut GameRng, item: EntityId) -> Vec<GameEvent> {
+    let mut outcome = EffectOutcome::new(true);
+    match world.current_map().tile(world.player_pos()) {
+        Ok(TileKind::Altar) => drop_on_altar(world, item, &mut outcome),
+        Ok(TileKind::Sink) if item_class(world, item) == Some(ItemClass::Ring) => {
+            drop_down_sink(world, rng, item, &mut outcome);
+        }
+        _ => {}
+    }
+    outcome.events
+}
+
+/// 발밑 제단에 시체를 바친다. 오래된 시체는 받아들여지지 않는다.
+pub fn offer(world: &mut GameWorld, item: EntityId, turn: u64) -> Result<Vec<GameEvent>, String> {
+    if world.current_map().tile(world.player_pos()) != Ok(TileKind::Altar) {
+        return Err("there is no altar here".to_string());
+    }
+    if !world.inventory.contains(item) && !world.item_is_underfoot(item) {
+        return Err("item is not in reach".to_string());
+    }
+    let Some(EntityKind::Item(kind)) = world.entities.get(item).map(|entity| entity.kind()) else {
+        return Err("entity is not an item".to_string());
+    };
+    let Some(monster) = kind.corpse_of() else {
+        return Err("only corpses can be sacrificed".to_string());
+    };
+    let mut outcome = EffectOutcome::new(true);
+    if world.entities.item_age(item) >= SACRIFICE_MAX_AGE {
+        outcome.message(MessagePriority::Info, "Nothing happens.");
+        return Ok(outcome.events);
+    }
+    world.inventory.remove(item);
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Consumed);
+    outcome.events.push(GameEvent::ItemConsumed {
+        entity: world.player_id,
+        item,
+    });
+    outcome.message(
+        MessagePriority::Info,
+        "Your sacrifice is consumed in a flash of fire!",
+    );
+    let difficulty = world
+        .monster_template(monster)
+        .map_or(0, |template| template.difficulty);
+    let value = (difficulty as i16 + 1).min(MAX_SACRIFICE_VALUE);
+    if world.god_anger > 0 {
+        let before = world.god_anger;
+        world.god_anger = world
+            .god_anger
+            .saturating_sub((value * 3 / MAX_SACRIFICE_VALUE) as u8);
+        if world.god_anger == before {
+            outcome.message(MessagePriority::Info, "You have a feeling of inadequacy.");
+        } else if world.god_anger > 0 {
+            outcome.message(
+                MessagePriority::Info,
+                &format!("{GOD_NAME} seems slightly mollified."),
+            );
+            improve_bad_luck(world, &mut outcome);
+        } else {
+            outcome.message(
+                MessagePriority::Info,
+                &format!("{GOD_NAME} seems mollified."),
+            );
+            clear_bad_luck(world, &mut outcome);
+        }
+    } else if world.alignment < 0 {
+        prayer::adjust_alignment(world, value.min(-world.alignment), turn);
+        outcome.message(MessagePriority::Info, "You feel partially absolved.");
+    } else if world.prayer_cooldown > 0 {
+        world.prayer_cooldown = world
+            .prayer_cooldown
+            .saturating_sub((value * 300 / MAX_SACRIFICE_VALUE) as u16);
+        if world.prayer_cooldown > 0 {
+            outcome.message(MessagePriority::Info, "You have a hopeful feeling.");
+            improve_bad_luck(world, &mut outcome);
+        } else {
+            outcome.message(
+                MessagePriority::Info,
+                "You have a feeling of reconciliation.",
+            );
+            clear_bad_luck(world, &mut outcome);
+        }
+    } else {
+        let before = world.luck;
+        let gain = value * luck::LUCK_MAX / (MAX_SACRIFICE_VALUE * 2);
+        if gain != 0 {
+            let event = luck::change_luck(world, gain, LuckCause::Sacrificed);
+            outcome.events.push(event);
+        }
+        world.luck = world.luck.max(0);
+        if world.luck != before {
+            outcome.message(
+                MessagePriority::Info,
+                if world.conditions.is_blind() {
+                    "You think something brushed your foot."
+                } else {
+                    "You glimpse a four-leaf clover at your feet."
+                },
+            );
+        }
+    }
+    Ok(outcome.events)
+}
+
+/// 발밑 분수의 물을 마신다. 마신 뒤 1/3 확률로 분수가 마른다.
+pub fn quaff_fountain(world: &mut GameWorld, rng: &mut GameRng) -> Result<Vec<GameEvent>, String> {
+    if world.current_map().tile(world.player_pos()) != Ok(TileKind::Fountain) {
+        return Err("there is no fountain here".to_string());
+    }
+    let mut outcome = EffectOutcome::new(true);
+    match roll_die(rng, 30) {
+        fate if fate < 10 => {
+            outcome.message(MessagePriority::Info, "The cool draught refreshes you.");
+            world.nutrition = world.nutrition.saturating_add(roll_die(rng, 10));
+        }
+        20 => {
+            outcome.message(
+                MessagePriority::Warning,
+                "The water is foul!  You gag and vomit.",
+            );
+            world.nutrition -= 10 + roll_die(rng, 20);
+        }
+        22 => water_snakes(world, rng, &mut outcome),
+        23 => water_demon(world, rng, &mut outcome),
+        24 => {
+            outcome.message(MessagePriority::Warning, "This water's no good!");
+            world.nutrition -= 10 + roll_die(rng, 20);
+            let items = inventory_items(world);
+            for item in items {
+                if rn2(rng, 5) == 0 {
+                    curse(world, item, &mut outcome);
+                }
+            }
+        }
+        25 => {
+            outcome.message(
+                MessagePriority::Info,
+                "You see an image of someone stalking you.",
+            );
+            outcome.message(MessagePriority::Info, "But it disappears.");
+            world.conditions.see_invisible = true;
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: world.player_id,
+                effect: StatusEffect::SeeInvisible,
+                turns: 0,
+            });
+        }
+        _ => outcome.message(MessagePriority::Info, "This tepid water is tasteless."),
+    }
+    dry_up(world, rng, &mut outcome);
+    Ok(outcome.events)
+}
+
+/// 인벤토리 아이템을 발밑 분수에 담근다. 물약은 먼저 묽어진다.
+pub fn dip_fountain(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+) -> Result<Vec<GameEvent>, String> {
+    if world.current_map().tile(world.player_pos()) != Ok(TileKind::Fountain) {
+        return Err("there is no fountain here".to_string());
+    }
+    if !world.inventory.contains(item) {
+        return Err("item is not in player inventory".to_string());
+    }
+    let Some(EntityKind::Item(kind)) = world.entities.get(item).map(|entity| entity.kind()) else {
+        return Err("entity is not an item".to_string());
+    };
+    let mut outcome = EffectOutcome::new(true);
+    outcome.events.push(GameEvent::ItemDipped {
+        entity: world.player_id,
+        item,
+        into: EntityId(0),
+    });
+    if item_class(world, item) == Some(ItemClass::Potion) && kind != ItemKind::PotionWater {
+        items::dilute(world, item, kind, &mut outcome.events);
+        if rn2(rng, 2) == 0 {
+            dry_up(world, rng, &mut outcome);
+            return Ok(outcome.events);
+        }
+    }
+    match roll_die(rng, 30) {
+        16 => curse(world, item, &mut outcome),
+        17..=20 => {
+            if world.entities.item_buc(item) == Some(BucStatus::Cursed) {
+                outcome.message(MessagePriority::Info, "The water glows for a moment.");
+                set_buc(world, item, BucStatus::Uncursed, &mut outcome);
+            } else {
+                outcome.message(MessagePriority::Info, "A feeling of loss comes over you.");
+            }
+        }
+        21 => water_demon(world, rng, &mut outcome),
+        23 => water_snakes(world, rng, &mut outcome),
+        26 => outcome.message(
+            MessagePriority::Info,
+            "A strange tingling runs up your arm.",
+        ),
+        27 => outcome.message(MessagePriority::Info, "You feel a sudden chill."),
+        28 => {
+            outcome.message(
+                MessagePriority::Info,
+                "An urge to take a bath overwhelms you.",
+            );
+            if world.gold > 10 {
+                world.gold -= world.gold / 10;
+                outcome.message(
+                    MessagePriority::Warning,
+                    "You lost some of your money in the fountain!",
+                );
+            }
+        }
+        _ => {}
+    }
+    dry_up(world, rng, &mut outcome);
+    Ok(outcome.events)
+}
+
+/// `pos`의 싱크대를 찬다. 대개 파이프만 울리고, 가끔 한 번뿐인 반지가 솟아오른다.
+pub fn kick_sink(world: &mut GameWorld, rng: &mut GameRng, pos: Pos) -> Vec<GameEvent> {
+    let mut outcome = EffectOutcome::new(true);
+    if rn2(rng, 5) != 0 {
+        outcome.message(MessagePriority::Info, "Klunk!  The pipes vibrate noisily.");
+        return outcome.events;
+    }
+    if rn2(rng, 3) == 0 {
+        outcome.message(
+            MessagePriority::Info,
+            if world.conditions.is_blind() {
+                "Flupp!  You hear a sloshing sound."
+            } else {
+                "Flupp!  Muddy waste pops up from the drain."
+            },
+        );
+        if !world.current_map().is_looted(pos) {
+            if let Some((kind, data)) =
+                generation::random_item_of_class(world, rng, ItemClass::Ring)
+                    .and_then(|kind| Some((kind, world.item_kind_data(kind).ok()?)))
+            {
+                let level = world.current_level();
+                world.entities.spawn_item_with_data(
+                    kind,
+                    data,
+                    EntityLocation::OnMap { level, pos },
+                );
+            }
+            world.current_map_mut().set_looted(pos);
+        }
+        return outcome.events;
+    }
+    outcome.message(MessagePriority::Warning, "Ouch!  That hurts!");
+    let damage = roll_die(rng, 5);
+    damage_player(world, TileKind::Sink, damage, &mut outcome);
+    outcome.events
+}
+
+/// 발밑 지형에 앉는다. 왕좌에서는 `rnd(6) > 4`일 때 13가지 효과 중 하나가 일어난다.
+pub fn sit(world: &mut GameWorld, rng: &mut GameRng) -> Vec<GameEvent> {
+    let mut outcome = EffectOutcome::new(true);
+    let pos = world.player_pos();
+    let tile = world.current_map().tile(pos).unwrap_or(TileKind::Floor);
+    let seat = match tile {
+        TileKind::Throne => {
+            sit_on_throne(world, rng, pos, &mut outcome);
+            return outcome.events;
+        }
+        TileKind::Altar => "altar",
+        TileKind::Fountain => "fountain",
+        TileKind::Sink => "sink",
+        TileKind::Grave => "grave",
+        TileKind::StairsDown | TileKind::StairsUp => "stairs",
+        _ => {
+            outcome.message(MessagePriority::Info, "Having fun sitting on the floor?");
+            return outcome.events;
+        }
+    };
+    outcome.message(MessagePriority::Info, &format!("You sit on the {seat}."));
+    if tile == TileKind::Sink {
+        outcome.message(MessagePriority::Info, "Your rump gets wet.");
+    }
+    outcome.events
+}
+
+/// 남은 소원 하나를 써서 `kind` 아이템을 인벤토리에 받는다. 빈 칸이 없으면 발밑에 놓인다.
+pub fn grant_wish(world: &mut GameWorld, kind: ItemKind) -> Result<Vec<GameEvent>, String> {
+    if world.pending_wishes == 0 {
+        return Err("no wish to make".to_string());
+    }
+    if kind.corpse_of().is_some() {
+        return Err("cannot wish for a corpse".to_string());
+    }
+    let data = world
+        .item_kind_data(kind)
+        .map_err(|error| error.to_string())?;
+    world.pending_wishes -= 1;
+    let level = world.current_level();
+    let pos = world.player_pos();
+    let item =
+        world
+            .entities
+            .spawn_item_with_data(kind, data, EntityLocation::OnMap { level, pos });
+    let mut events = vec![GameEvent::WishGranted {
+        entity: world.player_id,
+        item,
+        kind,
+    }];
+    if let Some(letter) = world.inventory.add_existing_with_next_letter(item) {
+        let owner = world.player_id;
+        world
+            .entities
+            .set_item_location(item, EntityLocation::Inventory { owner });
+        world.entities.set_item_letter(item, letter);
+        events.push(GameEvent::ItemPickedUp {
+            entity: owner,
+            item,
+            letter,
+        });
+    }
+    Ok(events)
+}
+
+/// 발밑 무덤을 파헤친다. 오래된 시체나 성난 무덤 주인이 나오고 무덤은 바닥이 된다.
+pub fn dig_up_grave(world: &mut GameWorld, rng: &mut GameRng, turn: u64) -> Vec<GameEvent> {
+    let pos = world.player_pos();
+    let mut outcome = EffectOutcome::new(true);
+    if world.current_map().tile(pos) != Ok(TileKind::Grave) {
+        return outcome.events;
+    }
+    if world.alignment > -10 {
+        outcome.message(
+            MessagePriority::Warning,
+            "You have violated the sanctity of this grave!",
+        );
+        prayer::adjust_alignment(world, -1, turn);
+    }
+    match rn2(rng, 5) {
+        0 | 1 => {
+            outcome.message(MessagePriority::Info, "You unearth a corpse.");
+            if let Some((kind, data)) = generation::random_monster(world, rng)
+                .and_then(MonsterKind::corpse_kind)
+                .and_then(|kind| Some((kind, world.item_kind_data(kind).ok()?)))
+            {
+                let level = world.current_level();
+                let corpse = world.entities.spawn_item_with_data(
+                    kind,
+                    data,
+                    EntityLocation::OnMap { level, pos },
+                );
+                world.entities.set_item_age(corpse, GRAVE_CORPSE_AGE);
+            }
+        }
+        2 | 3 => {
+            if !world.conditions.is_blind() {
+                outcome.message(MessagePriority::Warning, "The grave's owner is very upset!");
+            }
+            outcome
+                .events
+                .extend(effects::spawn_near_player(world, rng, None));
+        }
+        _ => outcome.message(
+            MessagePriority::Info,
+            "The grave seems unused.  Strange....",
+        ),
+    }
+    change_feature(world, pos, TileKind::Floor, &mut outcome);
+    outcome.events
+}
+
+fn drop_on_altar(world: &mut GameWorld, item: EntityId, outcome: &mut EffectOutcome) {
+    outcome.message(
+        MessagePriority::Info,
+        match world.entities.item_buc(item).unwrap_or_default() {
+            BucStatus::Blessed => "There is an amber flash as it hits the altar.",
+            BucStatus::Cursed => "There is a black flash as it hits the altar.",
+            BucStatus::Uncursed => "It lands on the altar.",
+        },
+    );
+}
+
+fn drop_down_sink(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    outcome: &mut EffectOutcome,
+) {
+    let Some(EntityKind::Item(kind)) = world.entities.get(item).map(|entity| entity.kind()) else {
+        return;
+    };
+    outcome.message(MessagePriority::Info, "You drop it down the drain.");
+    match (
+        world.conditions.is_blind(),
+        sink_ring_message(world, item, kind),
+    ) {
+        (false, Some(text)) => {
+            outcome.message(MessagePriority::Info, text);
+            world.identify_item_kind(kind);
+            outcome.events.push(GameEvent::ItemIdentified {
+                entity: world.player_id,
+                item,
+            });
+        }
+        _ => outcome.message(
+            MessagePriority::Info,
+            "You hear the ring bouncing down the drainpipe.",
+        ),
+    }
+    if rn2(rng, 20) == 0 {
+        outcome.message(
+            MessagePriority::Info,
+            "The sink backs up, leaving the ring.",
+        );
+        return;
+    }
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Consumed);
+    outcome.events.push(GameEvent::ItemDestroyed { item });
+}
+
+/// 싱크대에 빠뜨린 반지가 남기는 흔적이다. NetHack `dosinkring`의 문구와 같다.
+fn sink_ring_message(world: &GameWorld, item: EntityId, kind: ItemKind) -> Option<&'static str> {
+    match kind {
+        ItemKind::RingAdornment => Some("The faucets flash brightly for a moment."),
+        ItemKind::RingProtection => Some(if world.entities.item_enchantment(item) < 0 {
+            "The sink glows black for a moment."
+        } else {
+            "The sink glows silver for a moment."
+        }),
+        _ => None,
+    }
+}
+
+fn sit_on_throne(world: &mut GameWorld, rng: &mut GameRng, pos: Pos, outcome: &mut EffectOutcome) {
+    outcome.message(MessagePriority::Info, "You sit on the opulent throne.");
+    if roll_die(rng, 6) <= 4 {
+        outcome.message(MessagePriority::Info, "You feel somehow out of place...");
+    } else {
+        throne_effect(world, rng, outcome);
+    }
+    if world.player_alive()
+        && world.current_map().tile(pos) == Ok(TileKind::Throne)
+        && rn2(rng, 3) == 0
+    {
+        outcome.message(
+            MessagePriority::Info,
+            "The throne vanishes in a puff of logic.",
+        );
+        change_feature(world, pos, TileKind::Floor, outcome);
+    }
+}
+
+fn throne_effect(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    let player_id = world.player_id;
+    match roll_die(rng, 13) {
+        1 => {
+            let loss = 3 + rn2(rng, 4) as u8;
+            world.strength = world.strength.saturating_sub(loss).max(MIN_STRENGTH);
+            outcome.message(MessagePriority::Warning, "You feel weak!");
+            let damage = roll_die(rng, 10);
+            damage_player(world, TileKind::Throne, damage, outcome);
+        }
+        2 => {
+            if world.strength < MAX_STRENGTH {
+                world.strength += 1;
+                outcome.message(MessagePriority::Info, "You feel strong!");
+            }
+        }
+        3 => {
+            outcome.message(
+                MessagePriority::Danger,
+                "A massive electric shock shoots through your body!",
+            );
+            let damage = roll_die(rng, 30);
+            damage_player(world, TileKind::Throne, damage, outcome);
+        }
+        4 => {
+            outcome.message(MessagePriority::Info, "You feel much, much better!");
+            if let Some(stats) = world.entities.actor_stats_mut(player_id) {
+                let amount = stats.max_hp - stats.hp;
+                stats.hp = stats.max_hp;
+                outcome.events.push(GameEvent::EntityHealed {
+                    entity: player_id,
+                    amount,
+                    hp_after: stats.hp,
+                });
+            }
+            world.conditions.blindness_turns = 0;
+            world.conditions.food_poisoning_turns = 0;
+        }
+        5 => {
+            if world.gold == 0 {
+                outcome.message(MessagePriority::Info, "You feel a strange sensation.");
+            } else {
+                world.gold = 0;
+                outcome.message(MessagePriority::Warning, "You notice you have no money!");
+            }
+        }
+        6 => {
+            if world.luck + (rn2(rng, 5) as i16) < 0 {
+                outcome.message(MessagePriority::Info, "You feel your luck is changing.");
+                let event = luck::change_luck(world, 1, LuckCause::SatOnThrone);
+                outcome.events.push(event);
+            } else {
+                world.pending_wishes = world.pending_wishes.saturating_add(1);
+                outcome.message(MessagePriority::Info, "You may make a wish.");
+            }
+        }
+        7 => {
+            outcome.message(MessagePriority::Info, "A voice echoes:");
+            outcome.message(
+                MessagePriority::Warning,
+                "\"Thy audience hath been summoned, Sire!\"",
+            );
+            for _ in 0..roll_die(rng, 10) {
+                let Some(event) = effects::spawn_near_player(world, rng, None) else {
+                    break;
+                };
+                outcome.events.push(event);
+            }
+        }
+        8 => {
+            outcome.message(MessagePriority::Info, "A voice echoes:");
+            outcome.message(
+                MessagePriority::Info,
+                "\"By thine Imperious order, Sire...\"",
+            );
+        }
+        9 => {
+            outcome.message(MessagePriority::Info, "A voice echoes:");
+            outcome.message(
+                MessagePriority::Danger,
+                "\"A curse upon thee for sitting upon this most holy throne!\"",
+            );
+            let current = luck::effective_luck(world);
+            if current > 0 {
+                let turns = 250 + roll_die(rng, 100) - 1;
+                world.conditions.blindness_turns = world
+                    .conditions
+                    .blindness_turns
+                    .saturating_add(turns as u16);
+                outcome.events.push(GameEvent::StatusChanged {
+                    entity: player_id,
+                    effect: StatusEffect::Blindness,
+                    turns: world.conditions.blindness_turns,
+                });
+                let delta = if current > 1 { -1 } else { -2 };
+                let event = luck::change_luck(world, delta, LuckCause::SatOnThrone);
+                outcome.events.push(event);
+            } else {
+                prayer::curse_random_items(world, rng, outcome);
+            }
+        }
+        10 => {
+            if luck::effective_luck(world) < 0 || world.conditions.see_invisible {
+                outcome.message(MessagePriority::Info, "An image forms in your mind.");
+                outcome.events.extend(traps::reveal_all_hidden_tiles(world));
+            } else {
+                outcome.message(MessagePriority::Info, "Your vision becomes clear.");
+                world.conditions.see_invisible = true;
+                outcome.events.push(GameEvent::StatusChanged {
+                    entity: player_id,
+                    effect: StatusEffect::SeeInvisible,
+                    turns: 0,
+                });
+            }
+        }
+        11 => {
+            if luck::effective_luck(world) < 0 {
+                outcome.message(MessagePriority::Warning, "You feel threatened.");
+            } else {
+                outcome.message(MessagePriority::Info, "You feel a wrenching sensation.");
+                effects::teleport_within_level(world, rng, outcome);
+            }
+        }
+        12 => {
+            outcome.message(MessagePriority::Info, "You are granted an insight.");
+            for item in inventory_items(world) {
+                let Some(EntityKind::Item(kind)) =
+                    world.entities.get(item).map(|entity| entity.kind())
+                else {
+                    continue;
+                };
+                if !world.is_item_identified(kind) {
+                    world.identify_item_kind(kind);
+                    outcome.events.push(GameEvent::ItemIdentified {
+                        entity: player_id,
+                        item,
+                    });
+                }
+            }
+        }
+        _ => {
+            outcome.message(MessagePriority::Warning, "Your mind turns into a pretzel!");
+            let turns = 16 + rn2(rng, 7) as u16;
+            world.conditions.confusion_turns =
+                world.conditions.confusion_turns.saturating_add(turns);
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::Confusion,
+                turns: world.conditions.confusion_turns,
+            });
+        }
+    }
+}
+
+/// NetHack `dowatersnakes`처럼 물뱀 2~6마리를 풀어 놓는다.
+fn water_snakes(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    outcome.message(
+        MessagePriority::Danger,
+        if world.conditions.is_blind() {
+            "You hear something hissing!"
+        } else {
+            "An endless stream of snakes pours forth!"
+        },
+    );
+    for _ in 0..2 + rn2(rng, 5) {
+        let Some(event) = effects::spawn_near_player(world, rng, Some(MonsterKind::WaterMoccasin))
+        else {
+            break;
+        };
+        outcome.events.push(event);
+    }
+}
+
+/// NetHack `dowaterdemon`이다. 얕은 층일수록 풀려난 악마가 소원을 들어줄 확률이 높다.
+fn water_demon(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    outcome.message(
+        MessagePriority::Danger,
+        if world.conditions.is_blind() {
+            "You feel the presence of evil."
+        } else {
+            "You unleash a water demon!"
+        },
+    );
+    if roll_die(rng, 100) > 80 + world.current_level().depth {
+        outcome.message(
+            MessagePriority::Info,
+            "Grateful for his release, he grants you a wish!",
+        );
+        world.pending_wishes = world.pending_wishes.saturating_add(1);
+        return;
+    }
+    outcome.events.extend(effects::spawn_near_player(
+        world,
+        rng,
+        Some(MonsterKind::WaterDemon),
+    ));
+}
+
+/// 분수를 쓴 뒤 1/3 확률로 바닥으로 바꾼다. NetHack `dryup`과 같다.
+fn dry_up(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    let pos = world.player_pos();
+    if world.current_map().tile(pos) != Ok(TileKind::Fountain) || rn2(rng, 3) != 0 {
+        return;
+    }
+    outcome.message(MessagePriority::Info, "The fountain dries up!");
+    change_feature(world, pos, TileKind::Floor, outcome);
+}
+
+fn change_feature(world: &mut GameWorld, pos: Pos, to: TileKind, outcome: &mut EffectOutcome) {
+    let Ok(from) = world.current_map().tile(pos) else {
+        return;
+    };
+    if world.current_map_mut().set_tile(pos, to).is_ok() {
+        outcome
+            .events
+            .push(GameEvent::FeatureChanged { pos, from, to });
+    }
+}
+
+/// 신의 호의로 나쁜 luck을 한 칸 되돌린다.
+fn improve_bad_luck(world: &mut GameWorld, outcome: &mut EffectOutcome) {
+    if world.luck < 0 {
+        let event = luck::change_luck(world, 1, LuckCause::Sacrificed);
+        outcome.events.push(event);
+    }
+}
+
+/// 신과 화해하면 나쁜 luck이 모두 사라진다.
+fn clear_bad_luck(world: &mut GameWorld, outcome: &mut EffectOutcome) {
+    if world.luck < 0 {
+        let event = luck::change_luck(world, -world.luck, LuckCause::Sacrificed);
+        outcome.events.push(event);
+    }
+}
+
+fn damage_player(world: &mut GameWorld, tile: TileKind, amount: i16, outcome: &mut EffectOutcome) {
+    let player_id = world.player_id;
+    let Some(stats) = world.entities.actor_stats_mut(player_id) else {
+        return;
+    };
+    stats.hp -= amount;
+    outcome.events.push(GameEvent::EntityDamaged {
+        entity: player_id,
+        amount,
+        hp_after: stats.hp,
+    });
+    outcome
+        .events
+        .extend(death::collect_death_events_if_hp_depleted(
+            world,
+            player_id,
+            DeathCause::Feature { tile },
+        ));
+}
+
+fn curse(world: &mut GameWorld, item: EntityId, outcome: &mut EffectOutcome) {
+    let buc = match world.entities.item_buc(item).unwrap_or_default() {
+        BucStatus::Blessed => BucStatus::Uncursed,
+        _ => BucStatus::Cursed,
+    };
+    set_buc(world, item, buc, outcome);
+}
+
+fn set_buc(world: &mut GameWorld, item: EntityId, buc: BucStatus, outcome: &mut EffectOutcome) {
+    if world.entities.item_buc(item) != Some(buc) {
+        world.entities.set_item_buc(item, buc);
+        outcome.events.push(GameEvent::ItemBucChanged { item, buc });
+    }
+}
+
+fn inventory_items(world: &GameWorld) -> Vec<EntityId> {
+    world
+        .inventory
+        .entries
+        .iter()
+        .map(|entry| entry.item)
+        .collect()
+}
+
+fn item_class(world: &GameWorld, item: EntityId) -> Option<ItemClass> {
+    world.entities.item_data(item).map(|data| data.class)
+}
@@ -0,0 +1,54 @@
+//! 무작위 몬스터와 아이템 생성이다.
+//!
+//! 무엇이 얼마나 자주 나오는지는 world가 쓰는 content registry의 생성 표(`aihack_content::generation`)가
+//! 정하고, 여기서는 현재 층 깊이와 플레이어 경험 레벨로 난이도를 정해 넘긴다.
+
+use aihack_content::generation::LevelDifficulty;
+use aihack_core::{
+    domain::{
+        item::{ItemClass, ItemKind},
+        monster::MonsterKind,
+    },
+    ids::BranchId,
+    rng::GameRng,
+};
+
+use crate::world::GameWorld;
+
+/// 현재 층의 몬스터 생성 난이도다. 게헨놈 가지가 아직 없으므로 언제나 게헨놈 밖이다.
+pub fn level_difficulty(world: &GameWorld) -> LevelDifficulty {
+    let level = world.current_level();
+    LevelDifficulty {
+        depth: level.depth,
+        experience_level: world.experience_level,
+        in_hell: match level.branch {
+            BranchId::Main => false,
+        },
+    }
+}
+
+/// 현재 층에 맞는 무작위 몬스터다. NetHack `rndmonst`와 같고 나올 몬스터가 없으면 `None`이다.
+pub fn random_monster(world: &GameWorld, rng: &mut GameRng) -> Option<MonsterKind> {
+    world.content().select_monster(rng, level_difficulty(world))
+}
+
+/// 무작위로 만든 `kind` 몬스터와 함께 나올 무리의 수다.
+pub fn group_size(world: &GameWorld, rng: &mut GameRng, kind: MonsterKind) -> u8 {
+    world
+        .content()
+        .monster_group_size(rng, kind, world.experience_level)
+}
+
+/// 종류를 가리지 않은 무작위 아이템이다. NetHack `mkobj(RANDOM_CLASS)`와 같다.
+pub fn random_item(world: &GameWorld, rng: &mut GameRng) -> Option<ItemKind> {
+    world.content().select_item(rng)
+}
+
+/// 한 종류 안의 무작위 아이템이다. NetHack `mkobj(class)`와 같다.
+pub fn random_item_of_class(
+    world: &GameWorld,
+    rng: &mut GameRng,
+    class: ItemClass,
+) -> Option<ItemKind> {
+    world.content().select_item_of_class(rng, class)
+}
@@ -0,0 +1,146 @@
+use aihack_core::{
+    domain::{
+        combat::DeathCause,
+        item::ItemKind,
+        status::{HungerState, StatusEffect},
+    },
+    event::{GameEvent, MessagePriority},
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{combat::roll_die, death},
+    world::GameWorld,
+};
+
+/// 이 값보다 nutrition이 낮으면 굶어 죽는다. NetHack `-(200 + 20 * Con)`의 Con 10 기준이다.
+pub const STARVATION_NUTRITION: i16 = -400;
+/// 먹는 중에 이 값에 닿으면 배가 너무 부르다고 경고한다.
+pub const FULL_WARNING_NUTRITION: i16 = 1500;
+/// 배부른 상태에서 먹기 시작해 이 값에 닿으면 목이 막힌다.
+pub const CHOKING_NUTRITION: i16 = 2000;
+/// 목이 막혔을 때 토해내고 살아남을 확률의 분모다.
+pub const VOMIT_CHANCE: i16 = 20;
+
+/// 턴마다 nutrition을 1 소모하고 허기 단계에 따른 결과를 적용한다.
+/// NetHack `gethungry`/`newuhs`처럼 Fainting에서는 기절하거나 굶어 죽는다.
+pub fn digest(world: &mut GameWorld, rng: &mut GameRng) -> Vec<GameEvent> {
+    let before = world.hunger_state();
+    world.nutrition = world.nutrition.saturating_sub(1);
+    let mut events = hunger_changed_events(world, before);
+    if world.hunger_state() != HungerState::Fainting {
+        return events;
+    }
+
+    let nutrition = world.nutrition;
+    if before != HungerState::Fainting || roll_die(rng, 20 - nutrition / 10) > 19 {
+        if world.paralysis_turns == 0 {
+            let turns = (10 - nutrition / 10).clamp(1, i16::from(u8::MAX)) as u8;
+            world.paralysis_turns = turns;
+            events.push(message(
+                MessagePriority::Danger,
+                "You faint from lack of food.",
+            ));
+            events.push(GameEvent::StatusChanged {
+                entity: world.player_id,
+                effect: StatusEffect::Sleep,
+                turns: u16::from(turns),
+            });
+        }
+    } else if nutrition < STARVATION_NUTRITION {
+        events.push(message(MessagePriority::Danger, "You die from starvation."));
+        events.extend(death::kill_player(world, DeathCause::Starvation));
+    }
+    events
+}
+
+/// 한 입 먹은 직후 호출한다. `before`는 그 입을 먹기 전 nutrition이다. NetHack `lesshungry`처럼
+/// 배부른 상태에서 먹기 시작한 식사(`can_choke`)만 목이 막힐 수 있고, 경고는 한 끼에 한 번 난다.
+pub fn after_eating(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: ItemKind,
+    before: i16,
+    can_choke: bool,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    if world.nutrition >= CHOKING_NUTRITION {
+        if can_choke {
+            if roll_die(rng, VOMIT_CHANCE) == 1 {
+                world.nutrition -= 1000;
+                world.paralysis_turns = world.paralysis_turns.max(2);
+                events.push(message(
+                    MessagePriority::Warning,
+                    "You stuff yourself and then vomit voluminously.",
+                ));
+            } else {
+                events.push(message(
+                    MessagePriority::Danger,
+                    "You choke over your food.",
+                ));
+                events.extend(death::kill_player(world, DeathCause::Choked { item }));
+                return events;
+            }
+        }
+    } else if world.nutrition >= FULL_WARNING_NUTRITION && before < FULL_WARNING_NUTRITION {
+        events.push(message(
+            MessagePriority::Warning,
+            "You're having a hard time getting all of it down.",
+        ));
+    }
+    events.extend(hunger_changed_events(
+        world,
+        HungerState::from_nutrition(before),
+    ));
+    events
+}
+
+/// 허기 단계가 바뀌었으면 상태 이벤트와 NetHack `newuhs` 메시지를 만든다.
+fn hunger_changed_events(world: &GameWorld, before: HungerState) -> Vec<GameEvent> {
+    let after = world.hunger_state();
+    if after == before {
+        return Vec::new();
+    }
+    let mut events = vec![GameEvent::HungerChanged {
+        entity: world.player_id,
+        from: before,
+        to: after,
+    }];
+    let hungrier = severity(after) > severity(before);
+    let text = match (after, hungrier) {
+        (HungerState::Hungry, true) if world.nutrition < 145 => Some("You feel hungry."),
+        (HungerState::Hungry, true) => Some("You are beginning to feel hungry."),
+        (HungerState::Hungry, false) => Some("You only feel hungry now."),
+        (HungerState::Weak, true) if world.nutrition < 45 => Some("You feel weak."),
+        (HungerState::Weak, true) => Some("You are beginning to feel weak."),
+        (HungerState::Weak, false) => Some("You feel weak now."),
+        _ => None,
+    };
+    if let Some(text) = text {
+        let priority = if after == HungerState::Weak {
+            MessagePriority::Warning
+        } else {
+            MessagePriority::Info
+        };
+        events.push(message(priority, text));
+    }
+    events
+}
+
+fn severity(state: HungerState) -> u8 {
+    match state {
+        HungerState::Satiated | HungerState::Oversatiated => 0,
+        HungerState::NotHungry => 1,
+        HungerState::Hungry => 2,
+        HungerState::Weak => 3,
+        HungerState::Fainting => 4,
+        _ => 1,
+    }
+}
+
+fn message(priority: MessagePriority, text: &str) -> GameEvent {
+    GameEvent::Message {
+        priority,
+        text: text.to_string(),
+    }
+}
@@ -2,19 +2,21 @@ use aihack_core::{
     domain::{
         entity::EntityLocation,
         inventory::InventoryLetter,
-        item::{ConsumableEffect, EquipmentSlot, ItemClass},
-        level::{
-            PHASE5_LEVEL1_ID, PHASE5_LEVEL1_STAIRS_DOWN, PHASE5_LEVEL2_ID,
-            PHASE5_LEVEL2_STAIRS_UP_POS,
-        },
+        item::{BucStatus, ConsumableEffect, EquipmentSlot, ItemClass, ItemData, ItemKind},
     },
-    event::GameEvent,
+    event::{GameEvent, MessagePriority},
     ids::EntityId,
     rng::GameRng,
 };
 
 use crate::{
-    systems::{combat::roll_die, traps},
+    domain::item::item_kind_from_id,
+    systems::{
+        combat::{rn2, roll_die},
+        corpses,
+        effects::{self, Delivery, EffectOutcome},
+        generation, hunger,
+    },
     world::GameWorld,
 };
 
@@ -24,6 +26,17 @@ pub fn pickup(world: &mut GameWorld) -> Result<GameEvent, String> {
         .entities
         .item_at(world.current_level(), pos)
         .ok_or_else(|| "no item at player position".to_string())?;
+    pickup_item(world, item)
+}
+
+/// 발밑 물건 `item`을 하나 줍는다. 자동 탐험의 autopickup도 이 길을 지난다.
+pub fn pickup_item(world: &mut GameWorld, item: EntityId) -> Result<GameEvent, String> {
+    if world.entities.item_data(item).map(|data| data.class) == Some(ItemClass::Coin) {
+        return Ok(pickup_gold(world, item));
+    }
+    if !world.can_lift(item) {
+        return Err("item is too heavy to lift".to_string());
+    }
     let letter = world
         .inventory
         .add_existing_with_next_letter(item)
@@ -41,6 +54,21 @@ pub fn pickup(world: &mut GameWorld) -> Result<GameEvent, String> {
     })
 }
 
+/// 금화 더미는 소지품 글자 없이 소지금에 더해지고 바닥에서 사라진다. NetHack `pickup_object`가
+/// 금화를 `u.ugold`로 옮기는 것과 같다.
+fn pickup_gold(world: &mut GameWorld, item: EntityId) -> GameEvent {
+    let amount = world.entities.item_coins(item);
+    world.gold = world.gold.saturating_add(amount);
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Consumed);
+    GameEvent::GoldPickedUp {
+        entity: world.player_id,
+        item,
+        amount,
+    }
+}
+
 pub fn wield(world: &mut GameWorld, item: EntityId) -> Result<Option<GameEvent>, String> {
     if world.inventory.equipped_melee == Some(item) {
         return Ok(None);
@@ -63,7 +91,8 @@ pub fn wield(world: &mut GameWorld, item: EntityId) -> Result<Option<GameEvent>,
     }))
 }
 
-pub fn wear(world: &mut GameWorld, item: EntityId) -> Result<Option<GameEvent>, String> {
+/// 갑옷을 입는 데 첫 행동 뒤로 더 걸리는 행동 수다. 이미 입고 있으면 `None`이다.
+pub fn donning_delay(world: &GameWorld, item: EntityId) -> Result<Option<u8>, String> {
     if world.inventory.equipped_body == Some(item) {
         return Ok(None);
     }
@@ -77,7 +106,17 @@ pub fn wear(world: &mut GameWorld, item: EntityId) -> Result<Option<GameEvent>,
     if data.class != ItemClass::Armor {
         return Err("item is not armor".to_string());
     }
-    let ac_bonus = data.ac_bonus;
+    Ok(Some(data.delay))
+}
+
+pub fn wear(world: &mut GameWorld, item: EntityId) -> Result<Option<GameEvent>, String> {
+    if donning_delay(world, item)?.is_none() {
+        return Ok(None);
+    }
+    let Some(data) = world.entities.item_data(item) else {
+        return Err("entity is not an item".to_string());
+    };
+    let ac_bonus = data.ac_bonus + i16::from(world.entities.item_enchantment(item));
     world.inventory.equip_body(item);
     let player_id = world.player_id;
     if let Some(stats) = world.entities.actor_stats_mut(player_id) {
@@ -115,47 +154,240 @@ pub fn quaff(
     if !world.inventory.contains(item) {
         return Err("item is not in player inventory".to_string());
     }
-    let data = *world
-        .entities
-        .item_data(item)
-        .ok_or_else(|| "entity is not an item".to_string())?;
-    let Some(ConsumableEffect::Heal { dice, sides, bonus }) = data.consumable_effect else {
+    let (kind, data) = item_kind_and_data(world, item)?;
+    let (ItemClass::Potion, Some(effect)) = (data.class, data.consumable_effect) else {
         return Err("item is not a potion".to_string());
     };
+    let buc = world.entities.item_buc(item).unwrap_or_default();
 
-    let raw_heal = (0..dice).map(|_| roll_die(rng, sides)).sum::<i16>() + bonus;
-    let player_id = world.player_id;
-    let stats = world
+    world.inventory.remove(item);
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Consumed);
+
+    let mut events = vec![GameEvent::ItemConsumed {
+        entity: world.player_id,
+        item,
+    }];
+    let outcome = effects::apply_to_player(world, rng, kind, effect, buc, Delivery::Quaff);
+    if outcome.obvious {
+        world.identify_item_kind(kind);
+    }
+    events.extend(outcome.events);
+    Ok(events)
+}
+
+/// `#dip`: 물약에 아이템을 담가 희석, 성수 축복/저주, 물약 혼합을 처리한다.
+pub fn dip(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    into: EntityId,
+) -> Result<Vec<GameEvent>, String> {
+    if item == into {
+        return Err("cannot dip an item into itself".to_string());
+    }
+    if !world.inventory.contains(item) || !world.inventory.contains(into) {
+        return Err("item is not in player inventory".to_string());
+    }
+    let (kind, data) = item_kind_and_data(world, item)?;
+    let (potion_kind, potion_data) = item_kind_and_data(world, into)?;
+    if potion_data.class != ItemClass::Potion {
+        return Err("can only dip into a potion".to_string());
+    }
+    let mut events = vec![GameEvent::ItemDipped {
+        entity: world.player_id,
+        item,
+        into,
+    }];
+    let changed = if potion_data.consumable_effect == Some(ConsumableEffect::Water) {
+        dip_into_water(world, item, kind, data.class, into, &mut events)
+    } else if data.class == ItemClass::Potion && kind != potion_kind {
+        mix_potions(world, rng, item, kind, into, potion_kind, &mut events)?;
+        true
+    } else {
+        false
+    };
+    if changed {
+        consume(world, into);
+    } else {
+        events.push(message("Interesting..."));
+    }
+    Ok(events)
+}
+
+fn dip_into_water(
+    world: &mut GameWorld,
+    item: EntityId,
+    kind: ItemKind,
+    class: ItemClass,
+    water: EntityId,
+    events: &mut Vec<GameEvent>,
+) -> bool {
+    let item_buc = world.entities.item_buc(item).unwrap_or_default();
+    let next = match (world.entities.item_buc(water).unwrap_or_default(), item_buc) {
+        (BucStatus::Blessed, BucStatus::Cursed) => Some((BucStatus::Uncursed, "amber")),
+        (BucStatus::Blessed, BucStatus::Uncursed) => Some((BucStatus::Blessed, "light blue")),
+        (BucStatus::Cursed, BucStatus::Blessed) => Some((BucStatus::Uncursed, "brown")),
+        (BucStatus::Cursed, BucStatus::Uncursed) => Some((BucStatus::Cursed, "black")),
+        (BucStatus::Uncursed, _) if class == ItemClass::Potion && kind != ItemKind::PotionWater => {
+            dilute(world, item, kind, events);
+            return true;
+        }
+        _ => None,
+    };
+    let Some((buc, color)) = next else {
+        return false;
+    };
+    world.entities.set_item_buc(item, buc);
+    world.identify_item_kind(ItemKind::PotionWater);
+    events.push(message(&format!("Your item softly glows {color}.")));
+    events.push(GameEvent::ItemBucChanged { item, buc });
+    true
+}
+
+/// 물에 닿은 물약을 묽힌다. 이미 묽었던 물약은 보통 물이 된다.
+pub(crate) fn dilute(
+    world: &mut GameWorld,
+    item: EntityId,
+    kind: ItemKind,
+    events: &mut Vec<GameEvent>,
+) {
+    if world.entities.item_diluted(item) {
+        transform(world, item, kind, ItemKind::PotionWater, events);
+        world.entities.set_item_buc(item, BucStatus::Uncursed);
+        world.entities.set_item_diluted(item, false);
+        events.push(message("Your potion dilutes further."));
+    } else {
+        world.entities.set_item_diluted(item, true);
+        events.push(message("Your potion dilutes."));
+    }
+}
+
+/// NetHack `dodip`의 물약 혼합이다. 두 물약 중 하나라도 저주받았거나 `!rn2(10)`이면
+/// 함께 터져 증기를 들이마시고 `losehp(amt + rnd(9))`만큼 다친다. 물약은 한 개씩 있으므로
+/// `amt`는 1이다.
+fn mix_potions(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    kind: ItemKind,
+    into: EntityId,
+    potion_kind: ItemKind,
+    events: &mut Vec<GameEvent>,
+) -> Result<(), String> {
+    let buc = world.entities.item_buc(item).unwrap_or_default();
+    let into_buc = world.entities.item_buc(into).unwrap_or_default();
+    if buc == BucStatus::Cursed || into_buc == BucStatus::Cursed || rn2(rng, 10) == 0 {
+        events.push(GameEvent::Message {
+            priority: MessagePriority::Danger,
+            text: "BOOM!  They explode!".to_string(),
+        });
+        consume(world, item);
+        let mut outcome = match world
+            .item_kind_data(kind)
+            .ok()
+            .and_then(|data| data.consumable_effect)
+        {
+            Some(effect) => {
+                effects::apply_to_player(world, rng, kind, effect, buc, Delivery::Vapor)
+            }
+            None => EffectOutcome::new(true),
+        };
+        let amount = 1 + roll_die(rng, 9);
+        effects::damage_player(world, &mut outcome, kind, amount);
+        events.extend(outcome.events);
+        return Ok(());
+    }
+
+    world.entities.set_item_buc(item, BucStatus::Uncursed);
+    let mixture = aihack_content::potion_mix_result(
+        world.content(),
+        kind.content_id(),
+        potion_kind.content_id(),
+    )
+    .map(item_kind_from_id)
+    .transpose()
+    .map_err(|error| error.to_string())?;
+    let result = match mixture {
+        Some(result) => result,
+        None if world.entities.item_diluted(item) => ItemKind::PotionWater,
+        None => match roll_die(rng, 8) {
+            1 => ItemKind::PotionWater,
+            2 | 3 => ItemKind::PotionSickness,
+            4 => generation::random_item_of_class(world, rng, ItemClass::Potion)
+                .unwrap_or(ItemKind::PotionWater),
+            _ => {
+                consume(world, item);
+                events.push(message("The mixture glows brightly and evaporates."));
+                return Ok(());
+            }
+        },
+    };
+    transform(world, item, kind, result, events);
+    world
         .entities
-        .actor_stats_mut(player_id)
-        .ok_or_else(|| "player actor stats are missing".to_string())?;
-    let before = stats.hp;
-    stats.hp = stats.max_hp.min(stats.hp + raw_heal);
-    let effective = stats.hp - before;
-    let hp_after = stats.hp;
+        .set_item_diluted(item, result != ItemKind::PotionWater);
+    events.push(message(if result == ItemKind::PotionWater {
+        "The mixture bubbles, then clears."
+    } else {
+        "The mixture changes color."
+    }));
+    Ok(())
+}
 
+fn transform(
+    world: &mut GameWorld,
+    item: EntityId,
+    from: ItemKind,
+    to: ItemKind,
+    events: &mut Vec<GameEvent>,
+) {
+    if from == to {
+        return;
+    }
+    // world의 content가 모르는 종류로는 바꾸지 않는다.
+    if let Ok(data) = world.item_kind_data(to) {
+        world.entities.transform_item(item, to, data);
+        events.push(GameEvent::ItemTransformed { item, from, to });
+    }
+}
+
+fn consume(world: &mut GameWorld, item: EntityId) {
     world.inventory.remove(item);
     world
         .entities
         .set_item_location(item, EntityLocation::Consumed);
+}
 
-    Ok(vec![
-        GameEvent::ItemConsumed {
-            entity: player_id,
-            item,
-        },
-        GameEvent::EntityHealed {
-            entity: player_id,
-            amount: effective,
-            hp_after,
-        },
-    ])
+fn message(text: &str) -> GameEvent {
+    GameEvent::Message {
+        priority: MessagePriority::Info,
+        text: text.to_string(),
+    }
+}
+
+fn item_kind_and_data(world: &GameWorld, item: EntityId) -> Result<(ItemKind, ItemData), String> {
+    world
+        .entities
+        .get(item)
+        .and_then(|entity| entity.item())
+        .map(|(kind, data, _, _, _)| (kind, *data))
+        .ok_or_else(|| "entity is not an item".to_string())
 }
 
 /// 음식과 시체의 콘텐츠 영양값을 월드 허기 상태로 전달한다.
-pub fn eat(world: &mut GameWorld, item: EntityId) -> Result<Vec<GameEvent>, String> {
-    if !world.inventory.contains(item) {
-        return Err("item is not in player inventory".to_string());
+/// 소지품이나 발밑 바닥의 음식을 먹을 수 있고, 배부른 상태에서 먹으면 목이 막혀 죽을 수 있다.
+/// 시체는 `corpses`가 한 입씩 먹는다.
+/// 음식을 먹기 시작해 한 입 먹는다. 나머지는 먹기 occupation이 [`take_bite`]로 이어 먹는다.
+pub fn eat(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    can_choke: bool,
+) -> Result<Vec<GameEvent>, String> {
+    if !world.inventory.contains(item) && !world.item_is_underfoot(item) {
+        return Err("item is neither carried nor underfoot".to_string());
     }
     let data = *world
         .entities
@@ -164,86 +396,103 @@ pub fn eat(world: &mut GameWorld, item: EntityId) -> Result<Vec<GameEvent>, Stri
     if !matches!(data.class, ItemClass::Food | ItemClass::Corpse) {
         return Err("item is not edible".to_string());
     }
-    let nutrition = data
-        .nutrition
+    data.nutrition
         .filter(|nutrition| *nutrition > 0)
         .ok_or_else(|| "edible item has no positive nutrition".to_string())?;
+    if data.class == ItemClass::Corpse {
+        return Ok(corpses::eat_corpse(world, rng, item, can_choke));
+    }
 
-    world.nutrition = world.nutrition.saturating_add(nutrition);
-    world.inventory.remove(item);
-    world
-        .entities
-        .set_item_location(item, EntityLocation::Consumed);
+    let mut events = Vec::new();
+    if world.entities.item_bites(item) > 0 {
+        events.push(GameEvent::Message {
+            priority: MessagePriority::Info,
+            text: "You resume your meal.".to_string(),
+        });
+    }
+    events.extend(take_bite(world, rng, item, can_choke));
+    Ok(events)
+}
 
-    Ok(vec![GameEvent::ItemConsumed {
-        entity: world.player_id,
-        item,
-    }])
+/// 먹던 음식을 한 입 더 먹는다. 음식은 content `delay`번, 시체는 무게에 따른 횟수에 나눠 먹고
+/// 마지막 입에 없어진다.
+pub fn take_bite(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    can_choke: bool,
+) -> Vec<GameEvent> {
+    let Some(data) = world.entities.item_data(item).copied() else {
+        return Vec::new();
+    };
+    if data.class == ItemClass::Corpse {
+        return corpses::bite_corpse(world, rng, item, can_choke);
+    }
+    let rounds = data.delay.max(1);
+    let bites = world.entities.item_bites(item);
+    let before = world.nutrition;
+    world.nutrition = world.nutrition.saturating_add(corpses::bite_nutrition(
+        data.nutrition.unwrap_or_default(),
+        rounds,
+        bites,
+    ));
+    world.entities.set_item_bites(item, bites + 1);
+
+    let mut events = Vec::new();
+    if bites + 1 >= rounds {
+        world.inventory.remove(item);
+        world
+            .entities
+            .set_item_location(item, EntityLocation::Consumed);
+        events.push(GameEvent::ItemConsumed {
+            entity: world.player_id,
+            item,
+        });
+        if rounds > 1 {
+            events.push(GameEvent::Message {
+                priority: MessagePriority::Info,
+                text: "You finish your meal.".to_string(),
+            });
+        }
+    }
+    events.extend(hunger::after_eating(
+        world, rng, data.kind, before, can_choke,
+    ));
+    events
 }
 
-pub fn read(world: &mut GameWorld, item: EntityId) -> Result<Vec<GameEvent>, String> {
+pub fn read(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+) -> Result<Vec<GameEvent>, String> {
     if !world.inventory.contains(item) {
         return Err("item is not in player inventory".to_string());
     }
-    let data = *world
-        .entities
-        .item_data(item)
-        .ok_or_else(|| "entity is not an item".to_string())?;
-    let Some(effect) = data.consumable_effect else {
+    let (kind, data) = item_kind_and_data(world, item)?;
+    let (ItemClass::Scroll, Some(effect)) = (data.class, data.consumable_effect) else {
         return Err("item is not a scroll".to_string());
     };
+    if world.conditions.is_blind() {
+        return Err("cannot see to read while blind".to_string());
+    }
+    let effect = data
+        .confused_effect
+        .filter(|_| world.conditions.is_confused())
+        .unwrap_or(effect);
+    let buc = world.entities.item_buc(item).unwrap_or_default();
 
-    world.inventory.remove(item);
-    world
-        .entities
-        .set_item_location(item, EntityLocation::Consumed);
+    consume(world, item);
 
     let mut events = vec![GameEvent::ScrollRead {
         entity: world.player_id,
         item,
     }];
-    match effect {
-        ConsumableEffect::RevealLevel => {
-            events.extend(traps::reveal_all_hidden_tiles(world));
-        }
-        ConsumableEffect::IdentifySingle => {
-            if let Some(target) = world
-                .inventory
-                .entries
-                .iter()
-                .map(|entry| entry.item)
-                .find(|candidate| *candidate != item)
-            {
-                if let Some(kind) = world
-                    .entities
-                    .get(target)
-                    .and_then(|entity| entity.item())
-                    .map(|(kind, _, _, _, _)| kind)
-                {
-                    world.identify_item_kind(kind);
-                    events.push(GameEvent::ItemIdentified {
-                        entity: world.player_id,
-                        item: target,
-                    });
-                }
-            }
-        }
-        ConsumableEffect::LevelTeleport => {
-            let from = world.current_level();
-            let (to_level, to_pos) = if from == PHASE5_LEVEL1_ID {
-                (PHASE5_LEVEL2_ID, PHASE5_LEVEL2_STAIRS_UP_POS)
-            } else {
-                (PHASE5_LEVEL1_ID, PHASE5_LEVEL1_STAIRS_DOWN)
-            };
-            world.set_player_location(to_level, to_pos);
-            events.push(GameEvent::LevelChanged {
-                entity: world.player_id,
-                from,
-                to: to_level,
-            });
-        }
-        ConsumableEffect::Heal { .. } => return Err("item is not a scroll".to_string()),
+    let outcome = effects::apply_to_player(world, rng, kind, effect, buc, Delivery::Read);
+    if outcome.obvious {
+        world.identify_item_kind(kind);
     }
+    events.extend(outcome.events);
     Ok(events)
 }
 
@@ -0,0 +1,106 @@
+//! 등불 같은 이동 광원을 켜고 끄며 기름을 태우는 판정이다.
+//!
+//! NetHack 3.6.7 `apply.c`의 `use_lamp`, `timeout.c`의 `burn_object`, `light.c`의
+//! 광원 목록을 따른다. 소지품의 광원은 플레이어 칸에서, 바닥의 광원은 놓인 칸에서 빛난다.
+
+use aihack_core::{
+    domain::entity::EntityLocation,
+    event::{GameEvent, MessagePriority},
+    ids::{EntityId, LevelId},
+    vision::LightSource,
+};
+
+use crate::world::GameWorld;
+
+/// 기름 등불 하나가 탈 수 있는 턴 수다. NetHack 새 기름 등불의 `age`와 같다.
+pub const LAMP_FUEL: u16 = 1500;
+
+/// `#apply`로 등불을 켜거나 끈다. 기름이 다한 등불은 켜지지 않는다.
+pub fn apply_lamp(world: &mut GameWorld, item: EntityId) -> Result<Vec<GameEvent>, String> {
+    if !world.inventory.contains(item) {
+        return Err("item is not in inventory".to_string());
+    }
+    if world
+        .entities
+        .item_data(item)
+        .is_none_or(|data| data.light_radius <= 0)
+    {
+        return Err("item cannot be applied".to_string());
+    }
+    let lit = !world.entities.item_lit(item);
+    if lit && world.entities.item_age(item) >= LAMP_FUEL {
+        return Ok(vec![message("This lamp has no oil.")]);
+    }
+    world.entities.set_item_lit(item, lit);
+    let text = if lit {
+        "Your lamp is now on."
+    } else {
+        "Your lamp is now off."
+    };
+    Ok(vec![
+        message(text),
+        GameEvent::LightSourceChanged { item, lit },
+    ])
+}
+
+/// 켜진 등불마다 기름을 한 턴 태우고 다 탄 등불을 끈다.
+pub fn burn_lamps(world: &mut GameWorld) -> Vec<GameEvent> {
+    let lamps = world
+        .entities
+        .entities()
+        .iter()
+        .filter(|entity| world.entities.item_lit(entity.id))
+        .map(|entity| entity.id)
+        .collect::<Vec<_>>();
+    let mut events = Vec::new();
+    for item in lamps {
+        let age = world.entities.item_age(item).saturating_add(1);
+        world.entities.set_item_age(item, age);
+        if age < LAMP_FUEL {
+            continue;
+        }
+        world.entities.set_item_lit(item, false);
+        if world.inventory.contains(item) {
+            events.push(message("Your lamp has run out of oil."));
+        }
+        events.push(GameEvent::LightSourceChanged { item, lit: false });
+    }
+    events
+}
+
+/// `level`을 비추는 켜진 광원 목록이다.
+pub fn light_sources(world: &GameWorld, level: LevelId) -> Vec<LightSource> {
+    let (player_level, player_pos) = world.player_location();
+    world
+        .entities
+        .entities()
+        .iter()
+        .filter(|entity| world.entities.item_lit(entity.id))
+        .filter_map(|entity| {
+            let (_, data, location, _, _) = entity.item()?;
+            let pos = match location {
+                EntityLocation::Inventory { owner }
+                    if owner == world.player_id && player_level == level =>
+                {
+                    player_pos
+                }
+                EntityLocation::OnMap {
+                    level: item_level,
+                    pos,
+                } if item_level == level => pos,
+                _ => return None,
+            };
+            Some(LightSource {
+                pos,
+                radius: data.light_radius,
+            })
+        })
+        .collect()
+}
+
+fn message(text: &str) -> GameEvent {
+    GameEvent::Message {
+        priority: MessagePriority::Info,
+        text: text.to_string(),
+    }
+}
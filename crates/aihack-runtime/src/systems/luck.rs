@@ -0,0 +1,103 @@
+//! luck, luckstone 보너스와 luck timeout이다.
+//!
+//! 판정은 NetHack 3.6.7 `attrib.c`의 `change_luck`/`stone_luck`/`set_moreluck`,
+//! `timeout.c`의 luck timeout, `rnd.c`의 `rnl`을 따른다. 보름달과 Amulet은 없으므로
+//! 기준 luck은 언제나 0이다.
+
+use aihack_core::{
+    domain::{item::BucStatus, status::LuckCause},
+    event::GameEvent,
+    rng::GameRng,
+};
+
+use crate::{systems::combat::rn2, world::GameWorld};
+
+/// 기본 luck의 범위다. NetHack `LUCKMIN`/`LUCKMAX`와 같다.
+pub const LUCK_MIN: i16 = -10;
+pub const LUCK_MAX: i16 = 10;
+/// luck을 주는 돌을 가졌을 때 더해지는 보너스다. NetHack `LUCKADD`와 같다.
+pub const LUCKSTONE_BONUS: i16 = 3;
+/// luck이 0으로 한 칸 돌아가는 주기다. 신이 화나 있으면 `ANGRY_LUCK_TIMEOUT`을 쓴다.
+pub const LUCK_TIMEOUT: u64 = 600;
+pub const ANGRY_LUCK_TIMEOUT: u64 = 300;
+
+/// 기본 luck을 `delta`만큼 바꾸고 원인을 담은 이벤트를 돌려준다.
+pub fn change_luck(world: &mut GameWorld, delta: i16, cause: LuckCause) -> GameEvent {
+    world.luck = (world.luck + delta).clamp(LUCK_MIN, LUCK_MAX);
+    GameEvent::LuckChanged {
+        entity: world.player_id,
+        cause,
+    }
+}
+
+/// 소지한 luck 아이템의 방향이다. 축복받은 것은 +1, 저주받은 것은 -1로 세어 부호만 남긴다.
+/// 보통 아이템은 `count_uncursed`일 때만 +1이다. NetHack `stone_luck(parameter)`와 같아서
+/// 보너스는 `TRUE`로, luck timeout은 `FALSE`로 센다.
+pub fn stone_luck(world: &GameWorld, count_uncursed: bool) -> i16 {
+    luck_items(world)
+        .map(|buc| match buc {
+            BucStatus::Blessed => 1,
+            BucStatus::Cursed => -1,
+            BucStatus::Uncursed => i16::from(count_uncursed),
+        })
+        .sum::<i16>()
+        .signum()
+}
+
+/// 기본 luck에 luckstone 보너스를 더한 실제 판정용 luck이다. NetHack `Luck`과 같다.
+pub fn effective_luck(world: &GameWorld) -> i16 {
+    let bonus = if luck_items(world).next().is_none() {
+        0
+    } else if stone_luck(world, true) >= 0 {
+        LUCKSTONE_BONUS
+    } else {
+        -LUCKSTONE_BONUS
+    };
+    world.luck + bonus
+}
+
+/// 주기마다 기본 luck을 0 쪽으로 한 칸 되돌린다. 축복받은 luck 아이템은 좋은 luck을,
+/// 저주받은 luck 아이템은 나쁜 luck을 붙잡아 두고, 보통 luck 아이템은 둘 다 붙잡아 둔다.
+pub fn tick_luck(world: &mut GameWorld, turn: u64) {
+    let period = if world.god_anger > 0 {
+        ANGRY_LUCK_TIMEOUT
+    } else {
+        LUCK_TIMEOUT
+    };
+    if world.luck == 0 || !turn.is_multiple_of(period) {
+        return;
+    }
+    let no_stone = luck_items(world).next().is_none();
+    let stone = stone_luck(world, false);
+    if world.luck > 0 && (no_stone || stone < 0) {
+        world.luck -= 1;
+    } else if world.luck < 0 && (no_stone || stone > 0) {
+        world.luck += 1;
+    }
+}
+
+/// NetHack `rnl(x)`다. `0..x`를 굴리되 좋은 luck은 작은 값 쪽으로, 나쁜 luck은
+/// 큰 값 쪽으로 치우친다. `rnl(x) == 0`을 성공으로 쓰는 판정에 쓴다.
+pub fn rnl(world: &GameWorld, rng: &mut GameRng, x: i16) -> i16 {
+    let luck = effective_luck(world);
+    let adjustment = if x <= 15 {
+        (luck.abs() + 1) / 3 * luck.signum()
+    } else {
+        luck
+    };
+    let mut i = rn2(rng, x) as i16;
+    if adjustment != 0 && rn2(rng, 37 + adjustment.abs()) != 0 {
+        i = (i - adjustment).clamp(0, x - 1);
+    }
+    i
+}
+
+fn luck_items(world: &GameWorld) -> impl Iterator<Item = BucStatus> + '_ {
+    world.inventory.entries.iter().filter_map(|entry| {
+        world
+            .entities
+            .item_data(entry.item)
+            .filter(|data| data.confers_luck)
+            .map(|_| world.entities.item_buc(entry.item).unwrap_or_default())
+    })
+}
@@ -1,11 +1,28 @@
 pub mod combat;
+pub mod corpses;
 pub mod death;
+pub mod dig;
 pub mod doors;
+pub mod effects;
+pub mod explore;
+pub mod features;
+pub mod generation;
+pub mod hunger;
 pub mod items;
+pub mod light;
+pub mod luck;
 pub mod monster_ai;
+pub mod monster_attacks;
+pub mod morale;
 pub mod movement;
+pub mod occupation;
+pub mod prayer;
 pub mod projectiles;
+pub mod run;
 pub mod score;
+pub mod speed;
 pub mod stairs;
+pub mod terrain;
 pub mod traps;
+pub mod travel;
 pub mod vision;
@@ -1,5 +1,20 @@
+//! 몬스터의 행동 선택과 적용이다.
+//!
+//! 쫓는 몬스터는 NetHack 3.6.7 `monmove.c` `m_move`의 탐욕 접근 대신 벽을 돌아가는 길을 찾는다.
+//! 플레이어가 보이면 행동 바퀴마다 한 번 잰 플레이어까지의 걸음 수([`ChaseFields`])를 따라가고,
+//! 놓치면 `track.c` `gettrack`처럼 플레이어의 최근 자취를 밟아 온다. 닫힌 문은 content의
+//! `opens_doors` 몬스터만 열고 지나간다. 달아나는 몬스터는 `m_move`의 `appr = -1`처럼
+//! 플레이어와의 거리 제곱이 커지는 칸으로 가고, 물러설 곳이 없을 때만 맞서 싸운다.
+//! 원거리 공격이 있는 몬스터는 곁에 없는 플레이어가 한 줄로 늘어서면 다가가는 대신 쏜다.
+//! 혼란에 빠진 몬스터는 아무 쪽으로나 걷고, 눈먼 몬스터는 플레이어를 보지 못해 자취만 밟는다.
+
+use std::{cell::OnceCell, collections::HashMap};
+
 use aihack_core::{
-    domain::monster::MonsterAiKind,
+    domain::{
+        monster::MonsterAiKind,
+        tile::{DoorState, TileKind},
+    },
     event::GameEvent,
     ids::EntityId,
     position::{Direction, Pos},
@@ -8,7 +23,12 @@ use aihack_core::{
 };
 
 use crate::{
-    systems::{combat, death, movement, vision},
+    systems::{
+        combat::{self, rn2, roll_die},
+        death, doors, monster_attacks, morale, movement,
+        projectiles::{self, RangedWeapon},
+        speed, traps, vision,
+    },
     world::GameWorld,
 };
 
@@ -23,6 +43,11 @@ pub enum MonsterIntent {
         attacker: EntityId,
         defender: EntityId,
     },
+    RangedAttack {
+        attacker: EntityId,
+        direction: Direction,
+        weapon: RangedWeapon,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -30,32 +55,61 @@ pub struct MonsterTurnPlan {
     pub intents: Vec<MonsterIntent>,
 }
 
+/// 이동력이 남은 현재 층 몬스터가 한 번씩 행동한다. NetHack `movemon`의 한 바퀴다.
+/// 빠른 몬스터는 한 게임 턴에 이 바퀴를 여러 번 돈다.
 pub fn run_monster_turn(
     world: &mut GameWorld,
     rng: &mut GameRng,
     state: &mut RunState,
     turn: u64,
 ) -> Vec<GameEvent> {
-    let plan = collect_monster_turn_at(world, rng, turn);
-    let events = apply_monster_turn(world, rng, &plan);
+    let movers = monsters_that_can_act(world);
+    let mut events = Vec::new();
+    for actor in &movers {
+        events.extend(morale::before_move(world, rng, *actor));
+    }
+    let fields = ChaseFields::default();
+    let plan = MonsterTurnPlan {
+        intents: movers
+            .iter()
+            .map(|actor| decide_monster_intent(world, rng, &fields, *actor))
+            .collect(),
+    };
+    for actor in &movers {
+        speed::spend_move(world, *actor);
+    }
+    thaw_frozen_monsters(world, &movers);
+    calm_confused_monsters(world, rng, &movers);
+    rest_breath(world, &movers);
+    events.extend(apply_monster_turn(world, rng, &plan));
     *state = death::state_after_deaths_at(world, turn);
     events
 }
 
-pub fn collect_monster_turn(world: &GameWorld, rng: &mut GameRng) -> MonsterTurnPlan {
-    collect_monster_turn_at(world, rng, 1)
+/// 이번 턴에 아직 행동할 이동력이 남은 몬스터가 있는지 본다.
+pub fn any_monster_can_act(world: &GameWorld) -> bool {
+    !monsters_that_can_act(world).is_empty()
 }
 
-pub fn collect_monster_turn_at(world: &GameWorld, rng: &mut GameRng, turn: u64) -> MonsterTurnPlan {
+pub fn collect_monster_turn(world: &GameWorld, rng: &mut GameRng) -> MonsterTurnPlan {
+    let fields = ChaseFields::default();
     MonsterTurnPlan {
         intents: world
             .current_level_hostile_monsters()
             .into_iter()
-            .map(|actor| decide_monster_intent(world, rng, actor, turn))
+            .map(|actor| decide_monster_intent(world, rng, &fields, actor))
             .collect(),
     }
 }
 
+fn monsters_that_can_act(world: &GameWorld) -> Vec<EntityId> {
+    world
+        .current_level_hostile_monsters()
+        .into_iter()
+        .filter(|actor| speed::can_act(world, *actor))
+        .collect()
+}
+
 pub fn apply_monster_turn(
     world: &mut GameWorld,
     rng: &mut GameRng,
@@ -69,9 +123,18 @@ pub fn apply_monster_turn(
         match *intent {
             MonsterIntent::Wait => {}
             MonsterIntent::Move { entity, direction } => {
-                let Some((_, from)) = world.entities.actor_location(entity) else {
+                let Some((level, from)) = world.entities.actor_location(entity) else {
                     continue;
                 };
+                if traps::monster_still_trapped(world, rng, entity) {
+                    continue;
+                }
+                let to = from.offset(direction.delta());
+                if opens_doors(world, entity)
+                    && world.map(level).tile(to) == Ok(TileKind::Door(DoorState::Closed))
+                {
+                    events.extend(doors::monster_open_door(world, level, to));
+                }
                 if movement::move_actor(world, entity, direction).is_ok() {
                     let to = world
                         .entities
@@ -79,12 +142,17 @@ pub fn apply_monster_turn(
                         .expect("monster move succeeded so location must exist")
                         .1;
                     events.push(GameEvent::EntityMoved { entity, from, to });
+                    events.extend(traps::trigger_monster_trap(world, rng, entity));
                 }
             }
             MonsterIntent::MeleeAttack { attacker, defender } => {
                 if !melee_intent_is_still_valid(world, attacker, defender) {
                     continue;
                 }
+                if defender == world.player_id {
+                    events.extend(monster_attacks::attack_player(world, rng, attacker));
+                    continue;
+                }
                 let Some(resolution) = combat::resolve_attack(world, rng, attacker, defender)
                 else {
                     continue;
@@ -94,16 +162,86 @@ pub fn apply_monster_turn(
                     world, attacker, defender,
                 ));
             }
+            MonsterIntent::RangedAttack {
+                attacker,
+                direction,
+                weapon,
+            } => {
+                if world
+                    .entities
+                    .get(attacker)
+                    .and_then(|entity| entity.actor())
+                    .is_some_and(|(.., alive)| alive)
+                {
+                    events.extend(projectiles::monster_fire(
+                        world, rng, attacker, direction, weapon,
+                    ));
+                }
+            }
+        }
+    }
+    events
+}
+
+/// 큰 소리에 주변의 잠든 몬스터가 깬다. NetHack `wake_nearby`처럼 거리 제곱이
+/// 경험 레벨×20 미만인 몬스터만 깬다.
+pub fn wake_nearby(world: &mut GameWorld) -> Vec<GameEvent> {
+    let level = world.current_level();
+    let player = world.player_pos();
+    let radius = i32::from(world.experience_level.max(1)) * 20;
+    let mut events = Vec::new();
+    for entity in world.entities.hostile_monsters_on_level(level) {
+        let Some((_, pos)) = world.entities.actor_location(entity) else {
+            continue;
+        };
+        let (dx, dy) = (i32::from(pos.x - player.x), i32::from(pos.y - player.y));
+        if dx * dx + dy * dy >= radius {
+            continue;
+        }
+        if let Some(stats) = world.entities.actor_stats_mut(entity) {
+            if stats.asleep {
+                stats.asleep = false;
+                events.push(GameEvent::MonsterWokeUp { entity });
+            }
         }
     }
     events
 }
 
+/// 한 행동 바퀴 동안 플레이어는 움직이지 않으므로, 플레이어까지의 걸음 수를 처음 묻는 쫓는
+/// 몬스터가 한 번 재고 나머지가 함께 쓴다. 문을 여는 몬스터는 닫힌 문을 지나는 길로 따로 잰다.
+#[derive(Debug, Default)]
+struct ChaseFields {
+    walkers: OnceCell<HashMap<Pos, u16>>,
+    openers: OnceCell<HashMap<Pos, u16>>,
+}
+
+impl ChaseFields {
+    fn get(&self, world: &GameWorld, opens_doors: bool) -> &HashMap<Pos, u16> {
+        let cell = if opens_doors {
+            &self.openers
+        } else {
+            &self.walkers
+        };
+        cell.get_or_init(|| {
+            let map = world.current_map();
+            aihack_core::movement::distance_field(world.player_pos(), |pos| {
+                map.tile(pos).ok().map(|tile| match tile {
+                    TileKind::Door(DoorState::Closed) if opens_doors => {
+                        TileKind::Door(DoorState::Open)
+                    }
+                    tile => tile,
+                })
+            })
+        })
+    }
+}
+
 fn decide_monster_intent(
     world: &GameWorld,
     rng: &mut GameRng,
+    fields: &ChaseFields,
     actor: EntityId,
-    turn: u64,
 ) -> MonsterIntent {
     let Some(entity) = world.entities.get(actor) else {
         return MonsterIntent::Wait;
@@ -114,19 +252,31 @@ fn decide_monster_intent(
     let Some((_, actor_pos)) = world.entities.actor_location(actor) else {
         return MonsterIntent::Wait;
     };
-    let speed = world
-        .entities
-        .actor_stats(actor)
-        .map(|stats| stats.speed)
-        .unwrap_or_default();
-    if !acts_on_turn(speed, turn) {
+    let Some(stats) = world.entities.actor_stats(actor) else {
         return MonsterIntent::Wait;
-    }
-    if ai_kind == MonsterAiKind::Stationary {
+    };
+    if stats.frozen_turns > 0 || stats.asleep {
         return MonsterIntent::Wait;
     }
+    if stats.confused {
+        return match ai_kind {
+            MonsterAiKind::Stationary => MonsterIntent::Wait,
+            _ => choose_wander_intent(world, rng, actor),
+        };
+    }
     let player_id = world.player_id;
     let player_pos = world.player_pos();
+    if !stats.fleeing && actor_pos.chebyshev_distance(player_pos) > 1 {
+        if let Some(intent) = choose_ranged_intent(world, rng, actor, actor_pos) {
+            return intent;
+        }
+    }
+    if ai_kind == MonsterAiKind::Stationary {
+        return MonsterIntent::Wait;
+    }
+    if stats.fleeing {
+        return choose_flee_intent(world, actor, actor_pos);
+    }
     if actor_pos.chebyshev_distance(player_pos) <= 1 {
         return MonsterIntent::MeleeAttack {
             attacker: actor,
@@ -135,19 +285,102 @@ fn decide_monster_intent(
     }
     match ai_kind {
         MonsterAiKind::Wander => choose_wander_intent(world, rng, actor),
-        MonsterAiKind::ChaseVisiblePlayer => {
-            choose_chase_intent(world, actor, actor_pos, player_pos)
-        }
+        MonsterAiKind::ChaseVisiblePlayer => choose_chase_intent(world, fields, actor, actor_pos),
         MonsterAiKind::Stationary => MonsterIntent::Wait,
     }
 }
 
-fn acts_on_turn(speed: i16, turn: u64) -> bool {
-    if speed <= 0 || turn == 0 {
-        return false;
+/// 수면 가스 등으로 굳은 몬스터는 행동할 차례를 흘려보낼 때마다 남은 턴이 준다.
+fn thaw_frozen_monsters(world: &mut GameWorld, movers: &[EntityId]) {
+    for &monster in movers {
+        if let Some(stats) = world
+            .entities
+            .actor_stats_mut(monster)
+            .filter(|stats| stats.frozen_turns > 0)
+        {
+            stats.frozen_turns -= 1;
+        }
     }
-    let speed = speed as u64;
-    turn.saturating_mul(speed) / 12 > (turn - 1).saturating_mul(speed) / 12
+}
+
+/// 혼란에 빠진 몬스터는 행동할 차례마다 1/50로 정신을 차린다. NetHack `dochug`과 같다.
+fn calm_confused_monsters(world: &mut GameWorld, rng: &mut GameRng, movers: &[EntityId]) {
+    for &monster in movers {
+        if let Some(stats) = world
+            .entities
+            .actor_stats_mut(monster)
+            .filter(|stats| stats.confused)
+        {
+            stats.confused = rn2(rng, 50) != 0;
+        }
+    }
+}
+
+/// 게임 턴마다 현재 층 눈먼 몬스터의 남은 턴을 줄인다. NetHack `mcalcdistress`의
+/// `mblinded` 처리와 같다.
+pub fn tick_blindness(world: &mut GameWorld) {
+    for monster in world.current_level_hostile_monsters() {
+        if let Some(stats) = world
+            .entities
+            .actor_stats_mut(monster)
+            .filter(|stats| stats.blinded_turns > 0)
+        {
+            stats.blinded_turns -= 1;
+        }
+    }
+}
+
+/// 몬스터가 플레이어를 볼 수 있는지 본다. 눈먼 몬스터는 시야가 트여 있어도 보지 못한다.
+fn sees_player(world: &GameWorld, actor: EntityId) -> bool {
+    world
+        .entities
+        .actor_stats(actor)
+        .is_some_and(|stats| stats.blinded_turns == 0)
+        && vision::monster_has_line_of_sight_to_player(world, actor)
+}
+
+/// 숨을 쉰 몬스터는 행동할 차례마다 다시 쉴 수 있을 때까지 남은 행동 수가 준다.
+fn rest_breath(world: &mut GameWorld, movers: &[EntityId]) {
+    for &monster in movers {
+        if let Some(stats) = world
+            .entities
+            .actor_stats_mut(monster)
+            .filter(|stats| stats.ranged_cooldown > 0)
+        {
+            stats.ranged_cooldown -= 1;
+        }
+    }
+}
+
+/// 한 줄로 늘어선 플레이어에게 원거리 공격을 한다. 독은 NetHack `spitmu`처럼
+/// `1/(BOLT_LIM - 거리)`, 숨은 `breamu`처럼 2/3 확률로만 쓰고, 무기와 지팡이는 늘 쓴다.
+fn choose_ranged_intent(
+    world: &GameWorld,
+    rng: &mut GameRng,
+    actor: EntityId,
+    actor_pos: Pos,
+) -> Option<MonsterIntent> {
+    let weapon = projectiles::monster_ranged_weapon(world, actor)?;
+    if !sees_player(world, actor) {
+        return None;
+    }
+    let player_pos = world.player_pos();
+    let direction = projectiles::lined_up(world, actor_pos, player_pos)?;
+    let willing = match weapon {
+        RangedWeapon::Spit(..) => {
+            roll_die(
+                rng,
+                projectiles::BOLT_LIM - actor_pos.chebyshev_distance(player_pos),
+            ) == 1
+        }
+        RangedWeapon::Breath(..) => roll_die(rng, 3) != 1,
+        RangedWeapon::Wand(_) | RangedWeapon::Missile(_) => true,
+    };
+    willing.then_some(MonsterIntent::RangedAttack {
+        attacker: actor,
+        direction,
+        weapon,
+    })
 }
 
 fn choose_wander_intent(world: &GameWorld, rng: &mut GameRng, actor: EntityId) -> MonsterIntent {
@@ -164,38 +397,99 @@ fn choose_wander_intent(world: &GameWorld, rng: &mut GameRng, actor: EntityId) -
     MonsterIntent::Wait
 }
 
+/// 플레이어가 보이면 걸음 수가 줄어드는 곁 칸으로, 놓쳤으면 곁의 가장 새 자취로 간다.
 fn choose_chase_intent(
     world: &GameWorld,
+    fields: &ChaseFields,
     actor: EntityId,
     actor_pos: Pos,
-    player_pos: Pos,
 ) -> MonsterIntent {
-    if !vision::monster_has_line_of_sight_to_player(world, actor) {
-        return MonsterIntent::Wait;
-    }
-    let current = (
-        actor_pos.chebyshev_distance(player_pos),
-        manhattan_distance(actor_pos, player_pos),
-    );
-    for direction in Direction::ALL {
-        if !movement::is_passable_for_actor(world, actor, direction) {
-            continue;
-        }
-        let to = actor_pos.offset(direction.delta());
-        let next = (
-            to.chebyshev_distance(player_pos),
-            manhattan_distance(to, player_pos),
+    let opens_doors = opens_doors(world, actor);
+    let direction = if sees_player(world, actor) {
+        let distances = fields.get(world, opens_doors);
+        let current = distances.get(&actor_pos).copied().unwrap_or(u16::MAX);
+        Direction::ALL
+            .into_iter()
+            .filter(|direction| can_step(world, actor, opens_doors, *direction))
+            .filter_map(|direction| {
+                distances
+                    .get(&actor_pos.offset(direction.delta()))
+                    .map(|distance| (*distance, direction))
+            })
+            .filter(|(distance, _)| *distance < current)
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, direction)| direction)
+    } else {
+        world
+            .player_track
+            .next_from(actor_pos)
+            .and_then(|step| {
+                Direction::ALL
+                    .into_iter()
+                    .find(|direction| actor_pos.offset(direction.delta()) == step)
+            })
+            .filter(|direction| can_step(world, actor, opens_doors, *direction))
+    };
+    direction.map_or(MonsterIntent::Wait, |direction| MonsterIntent::Move {
+        entity: actor,
+        direction,
+    })
+}
+
+/// 플레이어와의 거리 제곱이 가장 커지는 곁 칸으로 물러선다. 물러설 칸이 없으면 곁의 플레이어를
+/// 치되, 플레이어가 겁주는 두루마리 위에 서 있으면 그러지 못한다.
+fn choose_flee_intent(world: &GameWorld, actor: EntityId, actor_pos: Pos) -> MonsterIntent {
+    let player_pos = world.player_pos();
+    let dist2 = |pos: Pos| {
+        let (dx, dy) = (
+            i32::from(pos.x - player_pos.x),
+            i32::from(pos.y - player_pos.y),
         );
-        if next < current {
-            return MonsterIntent::Move {
-                entity: actor,
-                direction,
-            };
-        }
+        dx * dx + dy * dy
+    };
+    let current = dist2(actor_pos);
+    let away = Direction::ALL
+        .into_iter()
+        .filter(|direction| movement::is_passable_for_actor(world, actor, *direction))
+        .map(|direction| (dist2(actor_pos.offset(direction.delta())), direction))
+        .filter(|(distance, _)| *distance > current)
+        .max_by_key(|(distance, _)| *distance);
+    if let Some((_, direction)) = away {
+        return MonsterIntent::Move {
+            entity: actor,
+            direction,
+        };
+    }
+    if actor_pos.chebyshev_distance(player_pos) <= 1 && !morale::onscary(world, player_pos) {
+        return MonsterIntent::MeleeAttack {
+            attacker: actor,
+            defender: world.player_id,
+        };
     }
     MonsterIntent::Wait
 }
 
+fn opens_doors(world: &GameWorld, actor: EntityId) -> bool {
+    world
+        .entities
+        .actor_stats(actor)
+        .is_some_and(|stats| stats.opens_doors)
+}
+
+/// 걸어 들어갈 수 있거나, 문을 여는 몬스터 곧바로 앞의 닫힌 문이면 한 걸음 갈 수 있다.
+fn can_step(world: &GameWorld, actor: EntityId, opens_doors: bool, direction: Direction) -> bool {
+    if movement::is_passable_for_actor(world, actor, direction) {
+        return true;
+    }
+    let Some((level, pos)) = world.entities.actor_location(actor) else {
+        return false;
+    };
+    opens_doors
+        && !direction.is_diagonal()
+        && world.map(level).tile(pos.offset(direction.delta()))
+            == Ok(TileKind::Door(DoorState::Closed))
+}
+
 fn melee_intent_is_still_valid(world: &GameWorld, attacker: EntityId, defender: EntityId) -> bool {
     let Some((attacker_level, attacker_pos)) = world.entities.actor_location(attacker) else {
         return false;
@@ -205,8 +499,3 @@ fn melee_intent_is_still_valid(world: &GameWorld, attacker: EntityId, defender:
     };
     attacker_level == defender_level && attacker_pos.chebyshev_distance(defender_pos) <= 1
 }
-
-fn manhattan_distance(from: Pos, to: Pos) -> i16 {
-    let delta = from.delta_to(to);
-    delta.dx.abs() + delta.dy.abs()
-}
@@ -0,0 +1,355 @@
+//! 몬스터가 곁의 플레이어에게 쓰는 공격과 플레이어가 받는 수동 반격이다.
+//!
+//! NetHack 3.6.7 `mhitu.c`의 `mattacku`/`hitmu`와 `uhitm.c`의 `passive`를 따른다. 공격 목록과
+//! 수동 반격은 monster content의 `attacks`/`passive`에서 오고, 맞으면 피해 유형마다 피해 뒤에
+//! 추가 효과를 준다. 추가 효과의 주사위는 맞았을 때만 굴려 물리 공격만 가진 몬스터의 난수
+//! 흐름은 예전과 같다.
+
+use aihack_core::{
+    domain::{
+        combat::{roll_damage, AttackProfile, DamageRoll},
+        entity::EntityLocation,
+        monster::{AttackType, DamageType, MonsterAttack},
+        status::Intrinsic,
+    },
+    event::{GameEvent, MessagePriority},
+    ids::EntityId,
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{
+        combat::{self, rn2, roll_die},
+        death,
+        effects::{self, EffectOutcome},
+        morale,
+    },
+    world::GameWorld,
+};
+
+/// 독에 힘을 잃어도 이 아래로는 내려가지 않는다.
+const MIN_STRENGTH: u8 = 3;
+
+/// 곁의 플레이어에게 근접 공격 목록을 차례로 쓴다. i번째 공격은 `rnd(20 + i)`로 명중을
+/// 굴리고, 어느 쪽이든 죽으면 멈춘다. 공격 목록이 없는 이전 저장본의 몬스터는 `damage`로 한
+/// 번 친다. NetHack `mattacku`와 같다.
+pub fn attack_player(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    monster: EntityId,
+) -> Vec<GameEvent> {
+    let player = world.player_id;
+    let Some(stats) = world.entities.actor_stats(monster).copied() else {
+        return Vec::new();
+    };
+    if stats.attacks.is_empty() {
+        let Some(resolution) = combat::resolve_attack(world, rng, monster, player) else {
+            return Vec::new();
+        };
+        let mut events = vec![combat::attack_event(&resolution)];
+        events.extend(death::collect_death_events_after_attack(
+            world, monster, player,
+        ));
+        return events;
+    }
+    let mut events = Vec::new();
+    let melee = stats
+        .attacks
+        .iter()
+        .filter(|attack| attack.attack_type.is_melee());
+    for (index, attack) in melee.enumerate() {
+        if !world.player_alive() || !is_alive(world, monster) {
+            break;
+        }
+        if attack.attack_type == AttackType::Gaze && world.conditions.is_blind() {
+            continue;
+        }
+        let resisted = attack.damage_type == DamageType::Fire
+            && world.conditions.has_intrinsic(Intrinsic::FireResistance);
+        let profile = AttackProfile::natural(
+            attack.attack_type.name(),
+            if resisted {
+                DamageRoll::none()
+            } else {
+                attack.damage
+            },
+        );
+        let Some(resolution) = combat::resolve_attack_with_to_hit_die(
+            world,
+            rng,
+            monster,
+            player,
+            profile,
+            20 + index as i16,
+        ) else {
+            break;
+        };
+        events.push(combat::attack_event(&resolution));
+        if resolution.hit {
+            if resisted {
+                message(&mut events, "The fire doesn't feel hot!");
+            }
+            if attack.attack_type == AttackType::Engulf {
+                hold(world, monster, true, &mut events);
+            }
+            events.extend(afflict(world, rng, monster, attack));
+        }
+        events.extend(death::collect_death_events_after_attack(
+            world, monster, player,
+        ));
+    }
+    events
+}
+
+/// 맞은 플레이어에게 피해 유형의 추가 효과를 준다. 원거리 공격과 수동 반격도 같은 효과를 쓴다.
+pub fn afflict(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    monster: EntityId,
+    attack: MonsterAttack,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    let name = monster_name(world, monster);
+    match attack.damage_type {
+        DamageType::Physical | DamageType::Fire => {}
+        DamageType::Poison if rn2(rng, 8) == 0 => {
+            message(
+                &mut events,
+                &format!("The {name}'s {} was poisoned!", attack.attack_type.name()),
+            );
+            poisoned(world, rng, monster, &mut events);
+        }
+        DamageType::Poison => {}
+        DamageType::Drain if rn2(rng, 3) == 0 => {
+            let mut outcome = EffectOutcome::new(true);
+            effects::lose_level(world, rng, &mut outcome);
+            events.extend(outcome.events);
+        }
+        DamageType::Drain => {}
+        DamageType::Stick => hold(world, monster, false, &mut events),
+        DamageType::Steal => steal(world, rng, monster, &mut events),
+        DamageType::Paralyze if world.paralysis_turns == 0 && rn2(rng, 3) == 0 => {
+            world.paralysis_turns = roll_die(rng, 10) as u8;
+            message(&mut events, &format!("You are frozen by the {name}!"));
+        }
+        DamageType::Paralyze => {}
+    }
+    events
+}
+
+/// 플레이어가 근접 공격한 몬스터의 수동 반격이다. 빗나가도 일어나지만 죽은 몬스터는 반격하지
+/// 않는다. 마비는 몬스터를 볼 수 있어야 통하고 `damage`를 굴린 턴 동안 굳는다. 다른 피해
+/// 유형은 굴린 만큼 다친 뒤 같은 추가 효과를 받는다. NetHack `passive`와 같다.
+pub fn passive(world: &mut GameWorld, rng: &mut GameRng, monster: EntityId) -> Vec<GameEvent> {
+    let Some(passive) = world
+        .entities
+        .get(monster)
+        .and_then(|entity| entity.monster_passive())
+    else {
+        return Vec::new();
+    };
+    if world
+        .entities
+        .actor_stats(monster)
+        .is_none_or(|stats| stats.hp <= 0)
+    {
+        return Vec::new();
+    }
+    let player = world.player_id;
+    let mut events = vec![GameEvent::PassiveAttackTriggered {
+        source: monster,
+        target: player,
+    }];
+    match passive.damage_type {
+        DamageType::Paralyze => {
+            if !world.conditions.is_blind() {
+                let turns = roll_damage(rng, passive.damage, 0, 0).clamp(0, 127) as u8;
+                world.paralysis_turns = world.paralysis_turns.max(turns);
+                let name = monster_name(world, monster);
+                message(&mut events, &format!("You are frozen by the {name}!"));
+            }
+        }
+        DamageType::Fire if world.conditions.has_intrinsic(Intrinsic::FireResistance) => {
+            message(&mut events, "You feel mildly warm.");
+        }
+        damage_type => {
+            if damage_type == DamageType::Fire {
+                message(&mut events, "You are suddenly very hot!");
+            }
+            let amount = roll_damage(rng, passive.damage, 0, 0);
+            damage_player(world, monster, amount, &mut events);
+            if world.player_alive() {
+                events.extend(afflict(
+                    world,
+                    rng,
+                    monster,
+                    MonsterAttack {
+                        attack_type: AttackType::Touch,
+                        damage_type,
+                        damage: passive.damage,
+                    },
+                ));
+            }
+        }
+    }
+    events
+}
+
+/// 플레이어를 붙잡고 있는 몬스터다. 죽었거나 곁을 떠났으면 `None`이다.
+pub fn holder(world: &GameWorld) -> Option<EntityId> {
+    let holder = world.held_by?;
+    let (level, pos) = world.entities.actor_location(holder)?;
+    (is_alive(world, holder)
+        && level == world.current_level()
+        && pos.chebyshev_distance(world.player_pos()) <= 1)
+        .then_some(holder)
+}
+
+/// 붙잡던 몬스터가 죽거나 떨어졌으면 플레이어를 놓아준다.
+pub fn release_if_apart(world: &mut GameWorld) -> Vec<GameEvent> {
+    match world.held_by {
+        Some(holder) if self::holder(world).is_none() => {
+            world.held_by = None;
+            vec![GameEvent::PlayerReleased { holder }]
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// 붙잡힌 플레이어가 붙잡은 몬스터가 아닌 곳으로 가려 하면 한 턴을 써서 몸부림친다. 붙잡은
+/// 몬스터가 움직이지 못하면 3/8, 아니면 3/40 확률로 빠져나온다. 붙잡혀 있지 않으면 `None`이다.
+/// NetHack `domove`의 `u.ustuck` 처리와 같다.
+pub fn struggle_free(world: &mut GameWorld, rng: &mut GameRng) -> Option<Vec<GameEvent>> {
+    let holder = holder(world)?;
+    let helpless = world
+        .entities
+        .actor_stats(holder)
+        .is_some_and(|stats| stats.asleep || stats.frozen_turns > 0);
+    let name = monster_name(world, holder);
+    let mut events = Vec::new();
+    if rn2(rng, if helpless { 8 } else { 40 }) < 3 {
+        world.held_by = None;
+        events.push(GameEvent::PlayerReleased { holder });
+        message(&mut events, &format!("You pull free from the {name}."));
+    } else {
+        message(&mut events, &format!("You cannot escape from the {name}!"));
+    }
+    Some(events)
+}
+
+/// NetHack `poisoned`: 독 저항이 있으면 멀쩡하고, 아니면 1/5로 힘을 3~5 잃고 나머지는
+/// 6~15만큼 더 다친다. NetHack의 1/30 즉사는 이 게임에 없다.
+fn poisoned(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    monster: EntityId,
+    events: &mut Vec<GameEvent>,
+) {
+    if world.conditions.has_intrinsic(Intrinsic::PoisonResistance) {
+        message(events, "The poison doesn't seem to affect you.");
+        return;
+    }
+    if rn2(rng, 30) <= 5 {
+        let loss = 3 + rn2(rng, 3) as u8;
+        world.strength = world.strength.saturating_sub(loss).max(MIN_STRENGTH);
+        message(events, "You feel weaker!");
+    } else {
+        let amount = 6 + rn2(rng, 10) as i16;
+        damage_player(world, monster, amount, events);
+    }
+}
+
+/// 붙잡힌 플레이어는 이미 붙잡혀 있지 않을 때만 새로 붙잡힌다.
+fn hold(world: &mut GameWorld, monster: EntityId, engulf: bool, events: &mut Vec<GameEvent>) {
+    if world.held_by.is_some() {
+        return;
+    }
+    world.held_by = Some(monster);
+    events.push(GameEvent::PlayerHeld { holder: monster });
+    if engulf {
+        let name = monster_name(world, monster);
+        message(events, &format!("The {name} engulfs you!"));
+    }
+}
+
+/// 몸에 걸치거나 손에 들지 않은 물건 하나를 훔쳐 같은 층 어딘가로 사라진 뒤 달아난다.
+/// NetHack `steal`과 같다.
+fn steal(world: &mut GameWorld, rng: &mut GameRng, thief: EntityId, events: &mut Vec<GameEvent>) {
+    let inventory = &world.inventory;
+    let loose = inventory
+        .entries
+        .iter()
+        .map(|entry| entry.item)
+        .filter(|item| {
+            inventory.equipped_melee != Some(*item) && inventory.equipped_body != Some(*item)
+        })
+        .collect::<Vec<_>>();
+    if loose.is_empty() {
+        return;
+    }
+    let item = loose[rn2(rng, loose.len() as i16) as usize];
+    world.inventory.remove(item);
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Inventory { owner: thief });
+    let name = monster_name(world, thief);
+    events.push(GameEvent::ItemStolen { thief, item });
+    message(events, &format!("The {name} stole something!"));
+    if let Some((level, from)) = world.entities.actor_location(thief) {
+        if let Some(to) = effects::random_safe_pos(world, rng, level, Some(from)) {
+            world.entities.set_actor_location(thief, level, to);
+            events.push(GameEvent::EntityTeleported {
+                entity: thief,
+                from,
+                to,
+            });
+        }
+    }
+    events.extend(morale::monflee(world, thief, 0, false, false));
+    events.extend(release_if_apart(world));
+}
+
+fn damage_player(
+    world: &mut GameWorld,
+    monster: EntityId,
+    amount: i16,
+    events: &mut Vec<GameEvent>,
+) {
+    let player = world.player_id;
+    let Some(stats) = world.entities.actor_stats_mut(player) else {
+        return;
+    };
+    stats.hp -= amount;
+    events.push(GameEvent::EntityDamaged {
+        entity: player,
+        amount,
+        hp_after: stats.hp,
+    });
+    events.extend(death::collect_death_events_after_attack(
+        world, monster, player,
+    ));
+}
+
+fn is_alive(world: &GameWorld, entity: EntityId) -> bool {
+    world
+        .entities
+        .get(entity)
+        .is_some_and(|entity| entity.is_alive_actor())
+}
+
+fn monster_name(world: &GameWorld, monster: EntityId) -> String {
+    world
+        .entities
+        .get(monster)
+        .and_then(|entity| entity.actor_kind())
+        .and_then(|kind| kind.monster_kind())
+        .map_or("monster", |kind| world.monster_name(kind))
+        .to_owned()
+}
+
+fn message(events: &mut Vec<GameEvent>, text: &str) {
+    events.push(GameEvent::Message {
+        priority: MessagePriority::Warning,
+        text: text.to_owned(),
+    });
+}
@@ -0,0 +1,245 @@
+//! 몬스터의 잠과 달아남이다.
+//!
+//! NetHack 3.6.7 `monmove.c`의 `disturb`, `monflee`, `distfleeck`와 `mon.c` `mcalcdistress`를
+//! 따른다. 상태는 몬스터의 `ActorStats.asleep`/`fleeing`/`flee_turns`에 남아 저장본과 함께 이어진다.
+//! 주사위는 다른 조건이 모두 맞을 때만 굴려 상태와 무관한 턴의 난수 흐름을 바꾸지 않는다.
+
+use aihack_core::{
+    domain::{item::ItemKind, status::Intrinsic},
+    event::{GameEvent, MessagePriority},
+    ids::EntityId,
+    position::Pos,
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{
+        combat::{rn2, roll_die},
+        vision,
+    },
+    world::GameWorld,
+};
+
+/// 잠든 몬스터가 플레이어를 알아챌 수 있는 거리 제곱 한계다. NetHack `disturb`의 `dist2 < 100`이다.
+pub const DISTURB_DISTANCE_SQ: i32 = 100;
+
+/// 몬스터가 행동하기 직전의 판정이다. 잠든 몬스터는 `disturb`로 깰 수 있고, 기한 없이
+/// 달아나던 몬스터는 체력이 가득하면 1/25로 용기를 되찾으며, 플레이어가 겁주는 두루마리 위에
+/// 서 있으면 곁의 몬스터가 달아난다(`distfleeck`).
+pub fn before_move(world: &mut GameWorld, rng: &mut GameRng, monster: EntityId) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    let Some(stats) = world.entities.actor_stats(monster).copied() else {
+        return events;
+    };
+    if stats.asleep {
+        if !disturbs(world, rng, monster) {
+            return events;
+        }
+        wake(world, monster, &mut events);
+    }
+    if stats.fleeing && stats.flee_turns == 0 && stats.hp == stats.max_hp && rn2(rng, 25) == 0 {
+        rally(world, monster, &mut events);
+    }
+    if is_adjacent_to_player(world, monster) && onscary(world, world.player_pos()) {
+        let turns = if rn2(rng, 7) == 0 {
+            roll_die(rng, 100)
+        } else {
+            roll_die(rng, 10)
+        };
+        events.extend(monflee(world, monster, turns as u8, true, true));
+    }
+    events
+}
+
+/// 플레이어가 근접 공격한 몬스터다. 빗나가도 잠은 깨고, 맞아서 체력이 절반 아래면 1/25로
+/// 달아난다. 달아나는 기간은 2/3 확률로 `rnd(100)`이고 나머지는 기한이 없다. NetHack
+/// `hmon_hitmon`과 같다.
+pub fn after_player_attack(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    monster: EntityId,
+    hit: bool,
+) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    let Some(stats) = world.entities.actor_stats(monster).copied() else {
+        return events;
+    };
+    if stats.hp <= 0 {
+        return events;
+    }
+    if stats.asleep {
+        wake(world, monster, &mut events);
+    }
+    if hit && stats.hp < stats.max_hp / 2 && rn2(rng, 25) == 0 {
+        let turns = if rn2(rng, 3) != 0 {
+            roll_die(rng, 100) as u8
+        } else {
+            0
+        };
+        events.extend(monflee(world, monster, turns, false, true));
+    }
+    events
+}
+
+/// 몬스터를 달아나게 한다. `turns`가 0이면 용기를 되찾을 때까지 달아난다. `first`이면 이미
+/// 달아나는 몬스터의 기간을 늘리지 않는다. 보이는 몬스터가 새로 달아나면 `message`에 따라
+/// "The X turns to flee."를 알린다. NetHack `monflee`와 같다.
+pub fn monflee(
+    world: &mut GameWorld,
+    monster: EntityId,
+    turns: u8,
+    first: bool,
+    message: bool,
+) -> Vec<GameEvent> {
+    let visible = monster_is_seen(world, monster);
+    let name = monster_name(world, monster);
+    let Some(stats) = world.entities.actor_stats_mut(monster) else {
+        return Vec::new();
+    };
+    if first && stats.fleeing {
+        return Vec::new();
+    }
+    let was_fleeing = stats.fleeing;
+    if turns == 0 {
+        stats.flee_turns = 0;
+    } else if !stats.fleeing || stats.flee_turns > 0 {
+        let total = u16::from(turns) + u16::from(stats.flee_turns);
+        stats.flee_turns = total.clamp(2, 127) as u8;
+    }
+    stats.fleeing = true;
+    let turns = stats.flee_turns;
+    if was_fleeing {
+        return Vec::new();
+    }
+    let mut events = vec![GameEvent::MonsterFled {
+        entity: monster,
+        turns,
+    }];
+    if message && visible {
+        events.push(GameEvent::Message {
+            priority: MessagePriority::Info,
+            text: format!("The {name} turns to flee."),
+        });
+    }
+    events
+}
+
+/// 게임 턴마다 현재 층 몬스터의 달아남 기간을 줄이고, 끝난 몬스터는 다시 덤빈다.
+/// NetHack `mcalcdistress`의 `mfleetim` 처리와 같다.
+pub fn tick(world: &mut GameWorld) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    for monster in world.current_level_hostile_monsters() {
+        let Some(stats) = world
+            .entities
+            .actor_stats_mut(monster)
+            .filter(|stats| stats.flee_turns > 0)
+        else {
+            continue;
+        };
+        stats.flee_turns -= 1;
+        if stats.flee_turns == 0 {
+            rally(world, monster, &mut events);
+        }
+    }
+    events
+}
+
+/// 플레이어가 볼 수 있는 몬스터에게 겁주는 두루마리를 읽은 효과를 준다. `rally_instead`이면 반대로
+/// 깨우고 달아나기를 멈추게 한다. 영향을 받은 몬스터 수를 돌려준다. 이 게임의 몬스터는 마법
+/// 저항이 없으므로 NetHack `resist` 판정은 하지 않는다.
+pub fn scare_seen_monsters(world: &mut GameWorld, rally_instead: bool) -> (Vec<GameEvent>, usize) {
+    let mut events = Vec::new();
+    let seen = world
+        .current_level_hostile_monsters()
+        .into_iter()
+        .filter(|monster| monster_is_seen(world, *monster))
+        .collect::<Vec<_>>();
+    for &monster in &seen {
+        if rally_instead {
+            if let Some(stats) = world.entities.actor_stats_mut(monster) {
+                stats.frozen_turns = 0;
+                stats.flee_turns = 0;
+            }
+            rally(world, monster, &mut events);
+            wake(world, monster, &mut events);
+        } else {
+            events.extend(monflee(world, monster, 0, false, false));
+        }
+    }
+    (events, seen.len())
+}
+
+/// `pos`에 몬스터가 다가오지 못하게 하는 것이 있는지 본다. NetHack `onscary`처럼 바닥의 겁주는
+/// 두루마리만 센다.
+pub fn onscary(world: &GameWorld, pos: Pos) -> bool {
+    world
+        .entities
+        .items_at(world.current_level(), pos)
+        .into_iter()
+        .filter_map(|item| world.entities.get(item)?.item())
+        .any(|(kind, ..)| kind == ItemKind::ScrollScareMonster)
+}
+
+/// NetHack `disturb`: 플레이어를 볼 수 있는 거리 제곱 100 미만의 몬스터는 플레이어가 은신하지
+/// 않으면 1/7 확률로 깬다.
+fn disturbs(world: &GameWorld, rng: &mut GameRng, monster: EntityId) -> bool {
+    let Some((_, pos)) = world.entities.actor_location(monster) else {
+        return false;
+    };
+    let player = world.player_pos();
+    let (dx, dy) = (i32::from(pos.x - player.x), i32::from(pos.y - player.y));
+    dx * dx + dy * dy < DISTURB_DISTANCE_SQ
+        && vision::monster_has_line_of_sight_to_player(world, monster)
+        && !world.conditions.has_intrinsic(Intrinsic::Stealth)
+        && rn2(rng, 7) == 0
+}
+
+fn wake(world: &mut GameWorld, monster: EntityId, events: &mut Vec<GameEvent>) {
+    if let Some(stats) = world
+        .entities
+        .actor_stats_mut(monster)
+        .filter(|stats| stats.asleep)
+    {
+        stats.asleep = false;
+        events.push(GameEvent::MonsterWokeUp { entity: monster });
+    }
+}
+
+fn rally(world: &mut GameWorld, monster: EntityId, events: &mut Vec<GameEvent>) {
+    if let Some(stats) = world
+        .entities
+        .actor_stats_mut(monster)
+        .filter(|stats| stats.fleeing)
+    {
+        stats.fleeing = false;
+        events.push(GameEvent::MonsterRallied { entity: monster });
+    }
+}
+
+fn is_adjacent_to_player(world: &GameWorld, monster: EntityId) -> bool {
+    world
+        .entities
+        .actor_location(monster)
+        .is_some_and(|(level, pos)| {
+            level == world.current_level() && pos.chebyshev_distance(world.player_pos()) <= 1
+        })
+}
+
+fn monster_is_seen(world: &GameWorld, monster: EntityId) -> bool {
+    world
+        .entities
+        .actor_location(monster)
+        .is_some_and(|(level, pos)| {
+            level == world.current_level() && vision::is_visible(world, pos)
+        })
+}
+
+fn monster_name(world: &GameWorld, monster: EntityId) -> String {
+    world
+        .entities
+        .get(monster)
+        .and_then(|entity| entity.actor_kind())
+        .and_then(|kind| kind.monster_kind())
+        .map_or("monster", |kind| world.monster_name(kind))
+        .to_owned()
+}
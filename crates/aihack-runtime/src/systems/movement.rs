@@ -3,12 +3,16 @@ use aihack_core::{
     error::GameError,
     ids::{EntityId, LevelId},
     position::{Direction, Pos},
+    rng::GameRng,
 };
 
-use crate::world::GameWorld;
+use crate::{
+    systems::combat,
+    world::{GameWorld, CARRY_CAPACITY},
+};
 
 pub fn move_player(world: &mut GameWorld, direction: Direction) -> Result<(), GameError> {
-    if world.carried_weight() > 80 {
+    if world.carried_weight() > CARRY_CAPACITY {
         return Err(GameError::CommandRejected(
             "movement blocked by encumbrance".to_string(),
         ));
@@ -16,6 +20,35 @@ pub fn move_player(world: &mut GameWorld, direction: Direction) -> Result<(), Ga
     move_actor(world, world.player_id, direction)
 }
 
+/// 혼란 상태에서는 1/5 확률로 의도와 다른 방향으로 움직인다.
+pub fn confused_direction(world: &GameWorld, rng: &mut GameRng, direction: Direction) -> Direction {
+    if !world.conditions.is_confused() || combat::roll_die(rng, 5) != 1 {
+        return direction;
+    }
+    Direction::ALL[(combat::roll_die(rng, 8) - 1) as usize]
+}
+
+/// 횟수를 붙인 이동이 `direction`으로 한 칸 더 걸을 수 있는지 본다. 덫에 걸렸거나, 막혔거나,
+/// 적대 몬스터나 물과 용암이 앞을 가로막으면 멈춘다.
+pub fn can_keep_moving(world: &GameWorld, direction: Direction) -> bool {
+    let to = world.player_pos().offset(direction.delta());
+    let trapped = world
+        .entities
+        .actor_stats(world.player_id)
+        .is_some_and(|stats| stats.trapped_turns > 0);
+    !trapped
+        && world.carried_weight() <= CARRY_CAPACITY
+        && is_passable_for_legal_action(world, direction)
+        && world
+            .entities
+            .alive_hostile_at(world.current_level(), to)
+            .is_none()
+        && !world
+            .current_map()
+            .tile(to)
+            .is_ok_and(|tile| tile.liquid_name().is_some())
+}
+
 pub fn move_actor(
     world: &mut GameWorld,
     actor: EntityId,
@@ -97,11 +130,15 @@ fn is_walkable_or_attackable_destination(
     to: Pos,
     direction: Direction,
 ) -> bool {
-    matches!(
-        world.map(level).tile(to),
-        Ok(TileKind::Floor
-            | TileKind::Door(DoorState::Open)
-            | TileKind::StairsDown
-            | TileKind::StairsUp)
-    ) && aihack_core::movement::validate_path(world, actor, level, from, direction).is_ok()
+    world.map(level).tile(to).is_ok_and(|tile| {
+        tile.is_feature()
+            || matches!(
+                tile,
+                TileKind::Floor
+                    | TileKind::Corridor
+                    | TileKind::Door(DoorState::Open | DoorState::Broken | DoorState::NoDoor)
+                    | TileKind::StairsDown
+                    | TileKind::StairsUp
+            )
+    }) && aihack_core::movement::validate_path(world, actor, level, from, direction).is_ok()
 }
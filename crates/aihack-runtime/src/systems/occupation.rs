@@ -0,0 +1,286 @@
+//! 여러 행동에 걸쳐 이어지는 플레이어의 일(occupation)과 그 일을 멈추게 하는 방해 규칙이다.
+//!
+//! NetHack 3.6.7 `allmain.c`의 occupation 처리와 `monster_nearby`/`stop_occupation`을 따른다.
+//! 명령이 첫 행동을 마치면 session이 [`perform`]으로 한 행동씩 이어 가고, 행동 사이마다
+//! [`interruption`]이 적대 몬스터 출현, 피해, 허기 변화를 살핀다.
+
+use aihack_core::{
+    domain::{
+        entity::EntityLocation,
+        player::{InterruptReason, Occupation, OccupationWatch},
+    },
+    event::{GameEvent, MessagePriority},
+    ids::EntityId,
+    position::{Direction, Pos},
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{
+        dig, doors,
+        explore::{self, Step},
+        hunger, items, movement, run, traps, travel, vision,
+    },
+    world::GameWorld,
+};
+
+/// 관측의 합법 행동이 제안하는 되풀이 찾기와 쉬기 횟수다. NetHack에서 흔히 쓰는 `n20s`와 같다.
+pub const SUGGESTED_COUNT: u16 = 20;
+
+/// 첫 행동 전에 방해를 살필 기준을 만든다. 그때 이미 보이던 적대 몬스터는 곁에 오기 전까지
+/// 방해하지 않는다.
+pub fn watch(world: &GameWorld) -> OccupationWatch {
+    OccupationWatch {
+        hp: player_hp(world),
+        seen: visible_hostiles(world),
+    }
+}
+
+/// 방금 한 행동의 사건 `events`를 보고 `occupation`을 멈춰야 하는지 판단한다. `watch`는
+/// 이번 행동 뒤의 체력과 본 몬스터로 갱신된다.
+pub fn interruption(
+    watch: &mut OccupationWatch,
+    world: &GameWorld,
+    occupation: Occupation,
+    events: &[GameEvent],
+) -> Option<InterruptReason> {
+    let hp = std::mem::replace(&mut watch.hp, player_hp(world));
+    let visible = visible_hostiles(world);
+    let appeared = visible
+        .iter()
+        .copied()
+        .find(|monster| !watch.seen.contains(monster) || is_threatening(world, *monster));
+    watch.seen.extend(visible);
+    if world.paralysis_turns > 0 {
+        return Some(InterruptReason::Helpless);
+    }
+    if player_hp(world) < hp {
+        return Some(InterruptReason::Damaged);
+    }
+    if let Some(monster) = appeared {
+        return Some(InterruptReason::MonsterAppeared { monster });
+    }
+    // NetHack에서 달리기는 메시지가 뜨면 `nomul(0)`로 멈춘다.
+    if matches!(occupation, Occupation::Running { .. })
+        && events
+            .iter()
+            .any(|event| matches!(event, GameEvent::Message { .. }))
+    {
+        return Some(InterruptReason::Message);
+    }
+    let eating = matches!(occupation, Occupation::Eating { .. });
+    if !eating
+            && events.iter().any(|event| {
+                matches!(event, GameEvent::HungerChanged { entity, .. } if *entity == world.player_id)
+            })
+        {
+            return Some(InterruptReason::HungerChanged);
+        }
+    if matches!(
+        occupation,
+        Occupation::Eating {
+            can_choke: true,
+            ..
+        }
+    ) && world.nutrition >= hunger::FULL_WARNING_NUTRITION
+    {
+        return Some(InterruptReason::TooFull);
+    }
+    if occupation == Occupation::Exploring {
+        return explore::stop_reason(world, events);
+    }
+    None
+}
+
+/// 지금 시야 안의 살아 있는 적대 몬스터다.
+pub fn visible_hostiles(world: &GameWorld) -> Vec<EntityId> {
+    let visible = vision::visible_positions(world);
+    world
+        .current_level_hostile_monsters()
+        .into_iter()
+        .filter(|monster| monster_pos(world, *monster).is_some_and(|pos| visible.contains(&pos)))
+        .collect()
+}
+
+/// `occupation`에 아직 할 일이 남았는지 본다.
+pub fn continues(world: &GameWorld, occupation: Occupation) -> bool {
+    match occupation {
+        Occupation::Eating { item, .. } => {
+            world.entities.item_location(item) != Some(EntityLocation::Consumed)
+                && (world.inventory.contains(item) || world.item_is_underfoot(item))
+        }
+        Occupation::Searching { remaining } | Occupation::Resting { remaining } => remaining > 0,
+        Occupation::Moving {
+            direction,
+            remaining,
+        } => remaining > 0 && movement::can_keep_moving(world, direction),
+        Occupation::Traveling { target } => {
+            world
+                .entities
+                .items_at(world.current_level(), world.player_pos())
+                .is_empty()
+                && travel::next_step(world, target)
+                    .is_some_and(|direction| movement::can_keep_moving(world, direction))
+        }
+        Occupation::Running { direction, mode } => run::next_direction(world, direction, mode)
+            .is_some_and(|direction| movement::can_keep_moving(world, direction)),
+        // 탐험은 더 갈 곳이 없을 때도 [`interruption`]으로 멈춰 까닭을 사건에 남긴다.
+        Occupation::Exploring => true,
+        Occupation::Donning { item, remaining } => remaining > 0 && world.inventory.contains(item),
+        Occupation::Digging { item, direction } => {
+            world.inventory.contains(item) && dig::is_digging(world, direction)
+        }
+    }
+}
+
+/// `occupation`을 한 행동만큼 하고 남은 일을 돌려준다. `turn`은 이 행동이 속할 게임 턴이다.
+pub fn perform(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    occupation: Occupation,
+    turn: u64,
+) -> (Vec<GameEvent>, Occupation) {
+    match occupation {
+        Occupation::Eating { item, can_choke } => {
+            (items::take_bite(world, rng, item, can_choke), occupation)
+        }
+        Occupation::Searching { remaining } => (
+            traps::search(world, rng),
+            Occupation::Searching {
+                remaining: remaining - 1,
+            },
+        ),
+        Occupation::Resting { remaining } => (
+            vec![GameEvent::Waited { turn }],
+            Occupation::Resting {
+                remaining: remaining - 1,
+            },
+        ),
+        Occupation::Moving {
+            direction,
+            remaining,
+        } => match walk(world, rng, direction) {
+            Some(events) => (
+                events,
+                Occupation::Moving {
+                    direction,
+                    remaining: remaining - 1,
+                },
+            ),
+            None => (
+                Vec::new(),
+                Occupation::Moving {
+                    direction,
+                    remaining: 0,
+                },
+            ),
+        },
+        Occupation::Traveling { target } => (
+            travel::next_step(world, target)
+                .and_then(|direction| walk(world, rng, direction))
+                .unwrap_or_default(),
+            occupation,
+        ),
+        Occupation::Running { direction, mode } => {
+            let direction = run::next_direction(world, direction, mode).unwrap_or(direction);
+            (
+                walk(world, rng, direction).unwrap_or_default(),
+                Occupation::Running { direction, mode },
+            )
+        }
+        Occupation::Exploring => (explore_step(world, rng).unwrap_or_default(), occupation),
+        Occupation::Donning { item, remaining } => {
+            let remaining = remaining - 1;
+            let mut events = Vec::new();
+            if remaining == 0 {
+                if let Ok(Some(event)) = items::wear(world, item) {
+                    events.push(event);
+                    events.push(message("You finish your dressing maneuver."));
+                }
+            }
+            (events, Occupation::Donning { item, remaining })
+        }
+        Occupation::Digging { item, direction } => (
+            dig::keep_digging(world, rng, item, direction).unwrap_or_default(),
+            occupation,
+        ),
+    }
+}
+
+/// 자동 탐험의 한 행동이다. 닫힌 문은 열고, 걸었으면 발밑 물건을 autopickup한다.
+/// 더 갈 곳이 없거나 걷지 못했으면 `None`이다.
+pub fn explore_step(world: &mut GameWorld, rng: &mut GameRng) -> Option<Vec<GameEvent>> {
+    match explore::next_step(world)? {
+        Step::Open(direction) => {
+            let pos = world.player_pos().offset(direction.delta());
+            let (from, to) = doors::open_door(world, direction).ok()?;
+            Some(vec![GameEvent::DoorChanged { pos, from, to }])
+        }
+        Step::Walk(direction) => {
+            let mut events = walk(world, rng, direction)?;
+            events.extend(explore::autopickup(world));
+            Some(events)
+        }
+    }
+}
+
+/// 한 칸 걷는다. 혼란으로 비틀거린 칸이 막혔으면 걷지 못하고 `None`이다.
+fn walk(world: &mut GameWorld, rng: &mut GameRng, direction: Direction) -> Option<Vec<GameEvent>> {
+    let from = world.player_pos();
+    let step = movement::confused_direction(world, rng, direction);
+    if !movement::can_keep_moving(world, step) || movement::move_player(world, step).is_err() {
+        return None;
+    }
+    let mut events = vec![GameEvent::EntityMoved {
+        entity: world.player_id,
+        from,
+        to: world.player_pos(),
+    }];
+    events.extend(traps::trigger_player_trap(world, rng));
+    Some(events)
+}
+
+/// NetHack `stop_occupation`의 "You stop ..." 메시지다. 걷기는 `nomul(0)`처럼 조용히 멈춘다.
+pub fn stop_message(occupation: Occupation) -> Option<GameEvent> {
+    let text = match occupation {
+        Occupation::Eating { .. } => "You stop eating.",
+        Occupation::Searching { .. } => "You stop searching.",
+        Occupation::Resting { .. } => "You stop waiting.",
+        Occupation::Moving { .. }
+        | Occupation::Traveling { .. }
+        | Occupation::Running { .. }
+        | Occupation::Exploring => return None,
+        Occupation::Donning { .. } => "You stop putting on your armor.",
+        Occupation::Digging { .. } => "You stop digging.",
+    };
+    Some(message(text))
+}
+
+/// NetHack `monster_nearby`처럼 곁에 있는 깨어 있는 적대 몬스터다.
+fn is_threatening(world: &GameWorld, monster: EntityId) -> bool {
+    let awake = world
+        .entities
+        .actor_stats(monster)
+        .is_some_and(|stats| !stats.asleep && stats.frozen_turns == 0);
+    awake
+        && monster_pos(world, monster)
+            .is_some_and(|pos| pos.chebyshev_distance(world.player_pos()) == 1)
+}
+
+fn monster_pos(world: &GameWorld, monster: EntityId) -> Option<Pos> {
+    world.entities.actor_location(monster).map(|(_, pos)| pos)
+}
+
+fn player_hp(world: &GameWorld) -> i16 {
+    world
+        .entities
+        .actor_stats(world.player_id)
+        .map_or(0, |stats| stats.hp)
+}
+
+fn message(text: &str) -> GameEvent {
+    GameEvent::Message {
+        priority: MessagePriority::Info,
+        text: text.to_string(),
+    }
+}
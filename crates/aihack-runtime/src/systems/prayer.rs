@@ -0,0 +1,492 @@
+//! 기도, 곤경 판정, 신의 분노와 alignment record다.
+//!
+//! 판정 순서는 NetHack 3.6.7 `pray.c`의 `in_trouble`, `can_pray`, `pleased`,
+//! `angrygods`를 따른다. 역할과 제단이 없으므로 언제나 같은 신에게 제단 밖에서 기도한다.
+
+use aihack_core::{
+    domain::{
+        combat::DeathCause,
+        entity::Faction,
+        item::{BucStatus, ItemClass},
+        status::{HungerState, LuckCause, StatusEffect, Trouble},
+    },
+    event::{GameEvent, MessagePriority},
+    ids::EntityId,
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{
+        combat::{rn2, roll_die},
+        death,
+        effects::{self, EffectOutcome},
+        luck,
+    },
+    world::GameWorld,
+};
+
+/// 새 캐릭터의 prayer timeout이다. NetHack `u.ublesscnt = 300`과 같다.
+pub const INITIAL_PRAYER_TIMEOUT: u16 = 300;
+/// 플레이어가 섬기는 신의 이름이다.
+pub const GOD_NAME: &str = "Mitra";
+/// NetHack alignment record 단계 경계다.
+pub const DEVOUT: i16 = 14;
+pub const STRIDENT: i16 = 4;
+/// trouble을 고칠 때 허기를 되돌리는 nutrition이다. NetHack `init_uhunger`와 같다.
+const RESTORED_NUTRITION: i16 = 900;
+
+/// 지금 플레이어가 처한 가장 심각한 곤경이다. major trouble이 minor보다 먼저다.
+pub fn worst_trouble(world: &GameWorld) -> Option<Trouble> {
+    let stats = world.entities.actor_stats(world.player_id);
+    let hunger = world.hunger_state();
+    [
+        (
+            Trouble::FoodPoisoning,
+            world.conditions.food_poisoning_turns > 0,
+        ),
+        (
+            Trouble::Starving,
+            matches!(hunger, HungerState::Weak | HungerState::Fainting),
+        ),
+        (
+            Trouble::LowHitPoints,
+            stats.is_some_and(|stats| stats.hp <= 5 || stats.hp * 7 <= stats.max_hp),
+        ),
+        (Trouble::CursedItems, worst_cursed_item(world).is_some()),
+        (
+            Trouble::TrappedInPit,
+            stats.is_some_and(|stats| stats.trapped_turns > 0),
+        ),
+        (Trouble::Hungry, hunger == HungerState::Hungry),
+        (Trouble::Blind, world.conditions.is_blind()),
+        (Trouble::Confused, world.conditions.is_confused()),
+        (Trouble::Hallucinating, world.hallucinating),
+    ]
+    .into_iter()
+    .find_map(|(trouble, present)| present.then_some(trouble))
+}
+
+/// 플레이어가 기도한다. 곤경의 무게에 맞는 timeout이 지났고 luck, 신의 분노,
+/// alignment가 모두 나쁘지 않아야 신이 기뻐하며 곤경을 고친다.
+pub fn pray(world: &mut GameWorld, rng: &mut GameRng) -> Vec<GameEvent> {
+    let mut outcome = EffectOutcome::new(true);
+    outcome.message(
+        MessagePriority::Info,
+        &format!("You begin praying to {GOD_NAME}."),
+    );
+    let trouble = worst_trouble(world);
+    let too_soon = match trouble {
+        Some(trouble) if trouble.is_major() => world.prayer_cooldown > 200,
+        Some(_) => world.prayer_cooldown > 100,
+        None => world.prayer_cooldown > 0,
+    };
+    let naughty = luck::effective_luck(world) < 0 || world.god_anger > 0 || world.alignment < 0;
+    if !too_soon && !naughty {
+        outcome.message(
+            MessagePriority::Info,
+            "You are surrounded by a shimmering light.",
+        );
+    }
+    outcome.message(MessagePriority::Info, "You finish your prayer.");
+
+    if too_soon {
+        let event = luck::change_luck(world, -3, LuckCause::PrayedTooSoon);
+        outcome.events.push(event);
+        world.god_anger = world.god_anger.saturating_add(1);
+        outcome.events.push(GameEvent::GodAngered {
+            entity: world.player_id,
+            anger: world.god_anger,
+        });
+        angry_gods(world, rng, &mut outcome);
+    } else if naughty {
+        angry_gods(world, rng, &mut outcome);
+    } else {
+        pleased(world, rng, trouble, &mut outcome);
+    }
+    outcome.events.push(GameEvent::PrayerOffered {
+        entity: world.player_id,
+        cooldown_after: world.prayer_cooldown,
+    });
+    outcome.events
+}
+
+/// 이번 턴에 플레이어가 죽인 몬스터마다 alignment record를 1 올린다.
+/// 몬스터 alignment가 없으므로 NetHack `malign` 대신 언제나 1을 쓴다.
+/// 평화로운 몬스터를 죽이면 NetHack `xkilled`처럼 record가 5 깎이고 절반 확률로 luck도 5 잃는다.
+pub fn record_kills(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    events: &[GameEvent],
+    turn: u64,
+) -> Vec<GameEvent> {
+    let kills = events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::EntityDied {
+                entity,
+                cause: DeathCause::Combat { attacker },
+            } if *attacker == world.player_id && *entity != world.player_id => Some(*entity),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let mut outcome = EffectOutcome::new(true);
+    for victim in kills {
+        let faction = world
+            .entities
+            .get(victim)
+            .and_then(|entity| entity.faction());
+        if faction != Some(Faction::Neutral) {
+            adjust_alignment(world, 1, turn);
+            continue;
+        }
+        adjust_alignment(world, -5, turn);
+        outcome.message(MessagePriority::Warning, "That was probably a bad idea...");
+        if rn2(rng, 2) == 0 {
+            let event = luck::change_luck(world, -5, LuckCause::KilledPeaceful);
+            outcome.events.push(event);
+        }
+    }
+    outcome.events
+}
+
+/// NetHack `adjalign`처럼 record를 바꾼다. 올릴 때는 `10 + turn / 200`을 넘지 않는다.
+pub fn adjust_alignment(world: &mut GameWorld, delta: i16, turn: u64) {
+    if delta < 0 {
+        world.alignment = world.alignment.saturating_add(delta);
+        return;
+    }
+    let limit = i16::try_from(10 + turn / 200).unwrap_or(i16::MAX);
+    if world.alignment < limit {
+        world.alignment = world.alignment.saturating_add(delta).min(limit);
+    }
+}
+
+/// NetHack `rnz(i)`다. 평균이 `i` 근처인 넓은 분포의 timeout을 굴린다.
+pub fn rnz(rng: &mut GameRng, i: u16) -> u16 {
+    let mut x = i64::from(i);
+    let mut tmp = 1000 + i64::from(rn2(rng, 1000));
+    tmp *= rne(rng, 4);
+    if rn2(rng, 2) != 0 {
+        x = x * tmp / 1000;
+    } else {
+        x = x * 1000 / tmp;
+    }
+    u16::try_from(x).unwrap_or(u16::MAX)
+}
+
+fn rne(rng: &mut GameRng, x: i16) -> i64 {
+    let mut tmp = 1;
+    while tmp < 5 && rn2(rng, x) == 0 {
+        tmp += 1;
+    }
+    tmp
+}
+
+fn pleased(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    trouble: Option<Trouble>,
+    outcome: &mut EffectOutcome,
+) {
+    let text = if trouble.is_some() && world.alignment < STRIDENT {
+        "You feel a hopeful feeling.".to_string()
+    } else {
+        let mood = if world.alignment >= DEVOUT {
+            "well-pleased"
+        } else if world.alignment >= STRIDENT {
+            "pleased"
+        } else {
+            "satisfied"
+        };
+        format!("You feel that {GOD_NAME} is {mood}.")
+    };
+    outcome.message(MessagePriority::Info, &text);
+    if world.alignment < 2 && !trouble.is_some_and(Trouble::is_major) {
+        world.alignment += 1;
+    }
+
+    if trouble.is_none() && world.alignment >= DEVOUT {
+        pat_on_head(world, rng, outcome);
+    } else {
+        let prayer_luck = luck::effective_luck(world).max(-1);
+        let mut action = (rn2(rng, prayer_luck + 2) + 1).min(3);
+        if world.alignment < STRIDENT {
+            action = i32::from(world.alignment > 0 || rn2(rng, 2) == 0);
+        }
+        if action == 3 {
+            if let Some(trouble) = worst_trouble(world) {
+                fix_trouble(world, rng, trouble, outcome);
+            }
+        }
+        if action >= 2 {
+            while let Some(trouble) = worst_trouble(world).filter(|trouble| trouble.is_major()) {
+                fix_trouble(world, rng, trouble, outcome);
+            }
+        } else if action == 1 {
+            if let Some(trouble) = worst_trouble(world).filter(|trouble| trouble.is_major()) {
+                fix_trouble(world, rng, trouble, outcome);
+            }
+        }
+    }
+    world.prayer_cooldown = rnz(rng, 350);
+}
+
+/// 곤경이 없고 신심이 깊을 때 받는 덤이다.
+fn pat_on_head(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    match rn2(rng, (luck::effective_luck(world) + 6) >> 1) {
+        0 => {}
+        1 => {
+            let Some(weapon) = world.inventory.equipped_melee else {
+                return;
+            };
+            let (buc, text) = match world.entities.item_buc(weapon).unwrap_or_default() {
+                BucStatus::Cursed => (BucStatus::Uncursed, "Your weapon softly glows amber."),
+                BucStatus::Uncursed => (
+                    BucStatus::Blessed,
+                    "Your weapon softly glows with a light blue aura.",
+                ),
+                BucStatus::Blessed => return,
+            };
+            world.entities.set_item_buc(weapon, buc);
+            outcome.message(MessagePriority::Info, text);
+            outcome
+                .events
+                .push(GameEvent::ItemBucChanged { item: weapon, buc });
+        }
+        2 | 3 => {
+            outcome.message(
+                MessagePriority::Info,
+                "You are surrounded by a golden glow.",
+            );
+            let player_id = world.player_id;
+            if let Some(stats) = world.entities.actor_stats_mut(player_id) {
+                stats.max_hp += 5;
+                stats.hp = stats.max_hp;
+            }
+            world.luck = world.luck.max(0);
+            world.nutrition = world.nutrition.max(RESTORED_NUTRITION);
+            if world.conditions.is_blind() {
+                fix_trouble(world, rng, Trouble::Blind, outcome);
+            }
+        }
+        _ => {
+            outcome.message(
+                MessagePriority::Info,
+                "You are surrounded by a light blue aura.",
+            );
+            let items = world
+                .inventory
+                .entries
+                .iter()
+                .map(|entry| entry.item)
+                .collect::<Vec<_>>();
+            for item in items {
+                if world.entities.item_buc(item) == Some(BucStatus::Cursed) {
+                    world.entities.set_item_buc(item, BucStatus::Uncursed);
+                    outcome.events.push(GameEvent::ItemBucChanged {
+                        item,
+                        buc: BucStatus::Uncursed,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn fix_trouble(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    trouble: Trouble,
+    outcome: &mut EffectOutcome,
+) {
+    let player_id = world.player_id;
+    match trouble {
+        Trouble::FoodPoisoning => {
+            world.conditions.food_poisoning_turns = 0;
+            outcome.message(MessagePriority::Info, "You feel better.");
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::FoodPoisoning,
+                turns: 0,
+            });
+        }
+        Trouble::Starving | Trouble::Hungry => {
+            let from = world.hunger_state();
+            world.nutrition = RESTORED_NUTRITION;
+            outcome.message(MessagePriority::Info, "Your stomach feels content.");
+            outcome.events.push(GameEvent::HungerChanged {
+                entity: player_id,
+                from,
+                to: world.hunger_state(),
+            });
+        }
+        Trouble::LowHitPoints => {
+            let level = i16::from(world.experience_level);
+            let gain = if world
+                .entities
+                .actor_stats(player_id)
+                .is_some_and(|stats| stats.max_hp < level * 5 + 11)
+            {
+                roll_die(rng, 5)
+            } else {
+                0
+            };
+            if let Some(stats) = world.entities.actor_stats_mut(player_id) {
+                stats.max_hp = (stats.max_hp + gain).max(6);
+                stats.hp = stats.max_hp;
+            }
+            outcome.message(MessagePriority::Info, "You feel much better.");
+        }
+        Trouble::CursedItems => {
+            let Some((item, class)) = worst_cursed_item(world) else {
+                return;
+            };
+            world.entities.set_item_buc(item, BucStatus::Uncursed);
+            if !world.conditions.is_blind() {
+                let name = if class == ItemClass::Armor {
+                    "armor"
+                } else {
+                    "weapon"
+                };
+                outcome.message(
+                    MessagePriority::Info,
+                    &format!("Your {name} softly glows amber."),
+                );
+            }
+            outcome.events.push(GameEvent::ItemBucChanged {
+                item,
+                buc: BucStatus::Uncursed,
+            });
+        }
+        Trouble::TrappedInPit => {
+            if let Some(stats) = world.entities.actor_stats_mut(player_id) {
+                stats.trapped_turns = 0;
+            }
+            outcome.message(MessagePriority::Info, "You float gently out of the pit.");
+        }
+        Trouble::Blind => {
+            world.conditions.blindness_turns = 0;
+            outcome.message(MessagePriority::Info, "Your eyes feel better.");
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::Blindness,
+                turns: 0,
+            });
+        }
+        Trouble::Confused => {
+            world.conditions.confusion_turns = 0;
+            outcome.message(MessagePriority::Info, "You feel less confused now.");
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::Confusion,
+                turns: 0,
+            });
+        }
+        Trouble::Hallucinating => {
+            world.hallucinating = false;
+            outcome.message(MessagePriority::Info, "Looks like you are back in Kansas.");
+        }
+    }
+    outcome.events.push(GameEvent::TroubleFixed {
+        entity: player_id,
+        trouble,
+    });
+}
+
+/// NetHack `angrygods`다. 분노와 나쁜 luck이 클수록 벌이 무거워진다.
+fn angry_gods(world: &mut GameWorld, rng: &mut GameRng, outcome: &mut EffectOutcome) {
+    let luck = luck::effective_luck(world);
+    let luck_anger = if luck > 0 || world.alignment >= STRIDENT {
+        -luck / 3
+    } else {
+        -luck
+    };
+    let max_anger = (3 * i16::from(world.god_anger) + luck_anger).clamp(1, 15);
+    match rn2(rng, max_anger) {
+        0 | 1 => outcome.message(
+            MessagePriority::Warning,
+            &format!("You feel that {GOD_NAME} is displeased."),
+        ),
+        2 | 3 => {
+            let sin = if world.god_anger > 0 {
+                "hast strayed from the path"
+            } else {
+                "art arrogant"
+            };
+            outcome.message(MessagePriority::Danger, &format!("\"Thou {sin}, mortal.\""));
+            outcome.message(
+                MessagePriority::Danger,
+                "\"Thou must relearn thy lessons!\"",
+            );
+            effects::lose_level(world, rng, outcome);
+        }
+        4..=6 => {
+            outcome.message(MessagePriority::Danger, "\"Thou hast angered me.\"");
+            if !world.conditions.is_blind() {
+                outcome.message(MessagePriority::Danger, "A black glow surrounds you.");
+            }
+            curse_random_items(world, rng, outcome);
+        }
+        7 | 8 => {
+            outcome.message(MessagePriority::Danger, "\"Thou durst call upon me?\"");
+            outcome.message(MessagePriority::Danger, "\"Then die, mortal!\"");
+            effects::create_monsters(world, rng, 1, outcome);
+        }
+        _ => {
+            outcome.message(
+                MessagePriority::Danger,
+                "Suddenly, a bolt of lightning strikes you!",
+            );
+            outcome.message(MessagePriority::Danger, "You fry to a crisp.");
+            outcome
+                .events
+                .extend(death::kill_player(world, DeathCause::DivineWrath));
+        }
+    }
+    world.prayer_cooldown = rnz(rng, 300);
+}
+
+/// NetHack `rndcurse`처럼 소지품 몇 개를 저주하거나 축복을 벗긴다.
+pub(crate) fn curse_random_items(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    outcome: &mut EffectOutcome,
+) {
+    let items = world
+        .inventory
+        .entries
+        .iter()
+        .map(|entry| entry.item)
+        .collect::<Vec<_>>();
+    if items.is_empty() {
+        return;
+    }
+    for _ in 0..roll_die(rng, 6) {
+        let item = items[(roll_die(rng, items.len() as i16) - 1) as usize];
+        let buc = match world.entities.item_buc(item).unwrap_or_default() {
+            BucStatus::Blessed => BucStatus::Uncursed,
+            _ => BucStatus::Cursed,
+        };
+        if world.entities.item_buc(item) != Some(buc) {
+            world.entities.set_item_buc(item, buc);
+            outcome.events.push(GameEvent::ItemBucChanged { item, buc });
+        }
+    }
+}
+
+/// 저주받아 곤경이 되는 장비다. 든 무기가 입은 갑옷보다 먼저다.
+fn worst_cursed_item(world: &GameWorld) -> Option<(EntityId, ItemClass)> {
+    [
+        world.inventory.equipped_melee,
+        world.inventory.equipped_body,
+    ]
+    .into_iter()
+    .flatten()
+    .find(|item| world.entities.item_buc(*item) == Some(BucStatus::Cursed))
+    .and_then(|item| {
+        world
+            .entities
+            .item_data(item)
+            .map(|data| (item, data.class))
+    })
+}
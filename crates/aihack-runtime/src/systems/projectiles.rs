@@ -1,21 +1,36 @@
 use aihack_core::{
     domain::{
-        combat::{AttackProfile, DamageRoll},
+        combat::{AttackProfile, DamageRoll, Missile},
         entity::EntityLocation,
-        item::{ItemKind, WandEffect},
+        item::{ItemClass, ItemKind, WandEffect},
+        monster::{AttackType, DamageType, MonsterAttack, RangedAttack},
+        status::{Intrinsic, LuckCause},
         tile::{DoorState, TileKind},
     },
-    event::GameEvent,
+    event::{GameEvent, MessagePriority},
     ids::EntityId,
     position::{Direction, Pos},
     rng::GameRng,
 };
 
 use crate::{
-    systems::{combat, death},
+    systems::{
+        combat::{self, roll_die},
+        death, dig,
+        effects::{self, Delivery},
+        luck, monster_attacks, terrain, vision,
+    },
     world::GameWorld,
 };
 
+/// 빈 지팡이에서 마지막 충전을 짜낼 `rnl` 범위다(NetHack `rn2(121)`).
+pub const WREST_CHANCE: i16 = 121;
+
+/// 몬스터 원거리 공격이 닿는 거리다. NetHack `BOLT_LIM`과 같다.
+pub const BOLT_LIM: i16 = 8;
+
+const MAGIC_MISSILE: AttackProfile = AttackProfile::natural("magic missile", DamageRoll::new(1, 6));
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ProjectileOutcome {
     pub landing: Pos,
@@ -36,12 +51,18 @@ pub fn throw_item(
     if !world.inventory.contains(item) {
         return Err("item is not in player inventory".to_string());
     }
+    if data.class == ItemClass::Potion {
+        return throw_potion(world, rng, item, kind, direction);
+    }
+    if data.break_luck != 0 {
+        return Ok(throw_fragile(world, item, direction, data.break_luck));
+    }
     if data.attack_profile.is_none() {
         return Err("item cannot be thrown in phase 7".to_string());
     }
 
     let from = world.player_pos();
-    let outcome = trace_path(world, from, direction);
+    let outcome = trace_path(world, from, direction, data.class == ItemClass::Rock);
     world.inventory.remove(item);
     let level = world.current_level();
     world.entities.set_item_location(
@@ -58,8 +79,16 @@ pub fn throw_item(
         from,
         to: outcome.landing,
     }];
+    events.extend(terrain::item_lands(world, item, outcome.landing));
     if let Some(target) = outcome.hit_target {
-        let profile = projectile_profile(kind, data.attack_profile);
+        let fired = data.launcher.is_some_and(|launcher| {
+            world
+                .inventory
+                .equipped_melee
+                .and_then(|weapon| world.entities.get(weapon)?.item())
+                .is_some_and(|(wielded, ..)| wielded == launcher)
+        });
+        let profile = missile_profile(world, item, kind, fired);
         if let Some(resolution) =
             combat::resolve_attack_with_profile(world, rng, world.player_id, target, profile)
         {
@@ -74,6 +103,83 @@ pub fn throw_item(
     Ok(events)
 }
 
+/// 거울처럼 깨지는 물건은 떨어진 자리에서 부서지고 던진 플레이어의 luck을 바꾼다.
+fn throw_fragile(
+    world: &mut GameWorld,
+    item: EntityId,
+    direction: Direction,
+    break_luck: i16,
+) -> Vec<GameEvent> {
+    let from = world.player_pos();
+    let outcome = trace_path(world, from, direction, false);
+    world.inventory.remove(item);
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Consumed);
+    vec![
+        GameEvent::ItemThrown {
+            entity: world.player_id,
+            item,
+            from,
+            to: outcome.landing,
+        },
+        GameEvent::Message {
+            priority: MessagePriority::Warning,
+            text: "It shatters!".to_string(),
+        },
+        luck::change_luck(world, break_luck, LuckCause::BrokeMirror),
+    ]
+}
+
+/// 던진 물약은 항상 깨진다. 맞은 몬스터는 물약 효과를 받고,
+/// 깨진 자리가 플레이어와 인접하면 플레이어가 증기를 들이마신다.
+fn throw_potion(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    item: EntityId,
+    kind: ItemKind,
+    direction: Direction,
+) -> Result<Vec<GameEvent>, String> {
+    let from = world.player_pos();
+    let outcome = trace_path(world, from, direction, false);
+    let buc = world.entities.item_buc(item).unwrap_or_default();
+    let effect = world
+        .entities
+        .item_data(item)
+        .and_then(|data| data.consumable_effect);
+    world.inventory.remove(item);
+    world
+        .entities
+        .set_item_location(item, EntityLocation::Consumed);
+
+    let mut events = vec![
+        GameEvent::ItemThrown {
+            entity: world.player_id,
+            item,
+            from,
+            to: outcome.landing,
+        },
+        GameEvent::PotionShattered {
+            item,
+            pos: outcome.landing,
+            target: outcome.hit_target,
+        },
+    ];
+    let Some(effect) = effect else {
+        return Ok(events);
+    };
+    if let Some(target) = outcome.hit_target {
+        events.extend(effects::apply_to_monster(world, rng, target, effect));
+    }
+    if outcome.landing.chebyshev_distance(from) <= 1 {
+        let vapors = effects::apply_to_player(world, rng, kind, effect, buc, Delivery::Vapor);
+        events.extend(vapors.events);
+    }
+    Ok(events)
+}
+
+/// 지팡이를 쏜다. 충전이 없으면 대개 아무 일도 없지만, `rnl(WREST_CHANCE)`가 0이면
+/// 마지막 충전을 짜내 쏘고 지팡이는 가루가 된다.
 pub fn zap_wand(
     world: &mut GameWorld,
     rng: &mut GameRng,
@@ -87,29 +193,48 @@ pub fn zap_wand(
     if !world.inventory.contains(item) {
         return Err("item is not in player inventory".to_string());
     }
-    if data.wand_effect != Some(WandEffect::MagicMissile) {
+    let Some(effect) = data.wand_effect else {
         return Err("item is not a wand".to_string());
-    }
+    };
     let Some(charges_before) = charges else {
         return Err("wand has no charge state".to_string());
     };
-    if charges_before == 0 {
-        return Err("wand has no charges".to_string());
+    let mut events = Vec::new();
+    let wrested = charges_before == 0;
+    if wrested {
+        if luck::rnl(world, rng, WREST_CHANCE) != 0 {
+            events.push(GameEvent::Message {
+                priority: MessagePriority::Info,
+                text: "Nothing happens.".to_string(),
+            });
+            return Ok(events);
+        }
+        events.push(GameEvent::Message {
+            priority: MessagePriority::Info,
+            text: "You wrest one last charge from the worn-out wand.".to_string(),
+        });
     }
 
-    let charges_after = charges_before - 1;
+    let charges_after = charges_before.saturating_sub(1);
     world.entities.set_item_charges(item, Some(charges_after));
-    let outcome = trace_path(world, world.player_pos(), direction);
-    let mut events = vec![GameEvent::WandZapped {
+    events.push(GameEvent::WandZapped {
         entity: world.player_id,
         item,
         direction,
         charges_after,
-    }];
-    if let Some(target) = outcome.hit_target {
-        let profile = AttackProfile::natural("magic missile", DamageRoll::new(1, 6));
+    });
+    let target = match effect {
+        WandEffect::MagicMissile => {
+            trace_path(world, world.player_pos(), direction, true).hit_target
+        }
+        WandEffect::Digging => {
+            events.extend(dig::zap_dig(world, rng, direction));
+            None
+        }
+    };
+    if let Some(target) = target {
         if let Some(resolution) =
-            combat::resolve_attack_with_profile(world, rng, world.player_id, target, profile)
+            combat::resolve_attack_with_profile(world, rng, world.player_id, target, MAGIC_MISSILE)
         {
             events.push(combat::attack_event(&resolution));
             events.extend(death::collect_death_events_after_attack(
@@ -119,17 +244,288 @@ pub fn zap_wand(
             ));
         }
     }
+    if wrested {
+        world.inventory.remove(item);
+        world
+            .entities
+            .set_item_location(item, EntityLocation::Consumed);
+        events.push(GameEvent::Message {
+            priority: MessagePriority::Info,
+            text: "The wand turns to dust.".to_string(),
+        });
+        events.push(GameEvent::ItemConsumed {
+            entity: world.player_id,
+            item,
+        });
+    }
     Ok(events)
 }
 
-fn projectile_profile(kind: ItemKind, base: Option<AttackProfile>) -> AttackProfile {
-    match kind {
-        ItemKind::Rock => AttackProfile::natural("rock", DamageRoll::new(1, 3)),
-        _ => base.unwrap_or(AttackProfile::natural("thrown item", DamageRoll::new(1, 2))),
+/// 몬스터가 고른 원거리 공격 수단이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangedWeapon {
+    /// 충전이 남은 공격 지팡이를 쏜다.
+    Wand(EntityId),
+    /// 지닌 무기를 던지거나 활로 쏜다.
+    Missile(EntityId),
+    Spit(DamageRoll, DamageType),
+    Breath(DamageRoll, DamageType),
+}
+
+/// `from`에서 `to`가 여덟 방향 한 줄로 `BOLT_LIM` 칸 안에 있고 시야를 가리는 지형이 없으면
+/// 그 방향이다. NetHack `linedup`과 같다.
+pub fn lined_up(world: &GameWorld, from: Pos, to: Pos) -> Option<Direction> {
+    let (dx, dy) = (to.x - from.x, to.y - from.y);
+    if (dx, dy) == (0, 0) || !(dx == 0 || dy == 0 || dx.abs() == dy.abs()) {
+        return None;
+    }
+    if from.chebyshev_distance(to) >= BOLT_LIM {
+        return None;
+    }
+    if !vision::has_line_of_sight(world, from, to) {
+        return None;
     }
+    Direction::ALL.into_iter().find(|direction| {
+        let delta = direction.delta();
+        (delta.dx, delta.dy) == (dx.signum(), dy.signum())
+    })
 }
 
-fn trace_path(world: &GameWorld, from: Pos, direction: Direction) -> ProjectileOutcome {
+/// 몬스터가 지금 쓸 수 있는 원거리 공격 수단이다. 무기를 쓰는 몬스터는 NetHack
+/// `find_offensive`처럼 충전이 남은 공격 지팡이를 먼저 쏘고, 없으면 `select_rwep` 순서대로
+/// 창, 활이 있을 때 화살, 단검, 돌을 던진다. 숨은 `ranged_cooldown`이 0일 때만 쓴다.
+pub fn monster_ranged_weapon(world: &GameWorld, monster: EntityId) -> Option<RangedWeapon> {
+    let stats = world.entities.actor_stats(monster)?;
+    match stats.ranged? {
+        RangedAttack::Spit {
+            damage,
+            damage_type,
+        } => Some(RangedWeapon::Spit(damage, damage_type)),
+        RangedAttack::Breath {
+            damage,
+            damage_type,
+        } => (stats.ranged_cooldown == 0).then_some(RangedWeapon::Breath(damage, damage_type)),
+        RangedAttack::Weapon => {
+            let carried = world
+                .entities
+                .inventory_items(monster)
+                .into_iter()
+                .filter_map(|item| {
+                    let (kind, data, _, _, charges) = world.entities.get(item)?.item()?;
+                    Some((item, kind, data.wand_effect, charges))
+                })
+                .collect::<Vec<_>>();
+            if let Some((wand, ..)) = carried.iter().find(|(_, _, effect, charges)| {
+                *effect == Some(WandEffect::MagicMissile) && charges.is_some_and(|c| c > 0)
+            }) {
+                return Some(RangedWeapon::Wand(*wand));
+            }
+            let has = |wanted| carried.iter().any(|(_, kind, ..)| *kind == wanted);
+            [
+                ItemKind::Spear,
+                ItemKind::Arrow,
+                ItemKind::Dagger,
+                ItemKind::Rock,
+            ]
+            .into_iter()
+            .filter(|kind| *kind != ItemKind::Arrow || has(ItemKind::Bow))
+            .find_map(|wanted| {
+                carried
+                    .iter()
+                    .find(|(_, kind, ..)| *kind == wanted)
+                    .map(|(item, ..)| RangedWeapon::Missile(*item))
+            })
+        }
+    }
+}
+
+/// 몬스터가 `direction`으로 원거리 공격을 한다. 던진 무기는 멈춘 칸에 떨어지고, 지팡이는
+/// 충전이 하나 줄며, 숨을 쉰 몬스터는 NetHack `breamu`처럼 `10 + rn2(20)` 행동 동안 쉰다.
+pub fn monster_fire(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    attacker: EntityId,
+    direction: Direction,
+    weapon: RangedWeapon,
+) -> Vec<GameEvent> {
+    let Some((level, from)) = world.entities.actor_location(attacker) else {
+        return Vec::new();
+    };
+    let seen = level == world.current_level() && vision::is_visible(world, from);
+    let name = world
+        .entities
+        .get(attacker)
+        .and_then(|entity| entity.actor_kind())
+        .and_then(|kind| kind.monster_kind())
+        .map_or("monster", |kind| world.monster_name(kind))
+        .to_owned();
+    let (missile, profile, passes_bars, verb) = match weapon {
+        RangedWeapon::Wand(wand) => {
+            let charges = world.entities.item_charges(wand).unwrap_or_default();
+            world
+                .entities
+                .set_item_charges(wand, Some(charges.saturating_sub(1)));
+            (
+                Missile::MagicMissile,
+                MAGIC_MISSILE,
+                true,
+                "zaps a wand".to_owned(),
+            )
+        }
+        RangedWeapon::Missile(item) => {
+            let Some(kind) = world
+                .entities
+                .get(item)
+                .and_then(|entity| entity.item())
+                .map(|(kind, ..)| kind)
+            else {
+                return Vec::new();
+            };
+            let fired = kind == ItemKind::Arrow;
+            let profile = missile_profile(world, item, kind, fired);
+            let verb = if fired { "shoots" } else { "throws" };
+            (
+                Missile::Item(kind),
+                profile,
+                kind == ItemKind::Rock,
+                format!(
+                    "{verb} {}",
+                    with_article(missile_name(world, kind, profile))
+                ),
+            )
+        }
+        RangedWeapon::Spit(damage, _) => (
+            Missile::Venom,
+            AttackProfile::natural("venom", fire_resisted(world, weapon, damage)),
+            true,
+            "spits venom".to_owned(),
+        ),
+        RangedWeapon::Breath(damage, _) => {
+            if let Some(stats) = world.entities.actor_stats_mut(attacker) {
+                stats.ranged_cooldown = 10 + (roll_die(rng, 20) - 1) as u8;
+            }
+            (
+                Missile::Breath,
+                AttackProfile::natural("breath", fire_resisted(world, weapon, damage)),
+                true,
+                "breathes".to_owned(),
+            )
+        }
+    };
+    let outcome = trace_path(world, from, direction, passes_bars);
+    let mut events = vec![GameEvent::MissileFired {
+        attacker,
+        missile,
+        from,
+        to: outcome.landing,
+    }];
+    if seen {
+        events.push(GameEvent::Message {
+            priority: MessagePriority::Warning,
+            text: format!("The {name} {verb}!"),
+        });
+    }
+    if let RangedWeapon::Missile(item) = weapon {
+        world.entities.set_item_location(
+            item,
+            EntityLocation::OnMap {
+                level,
+                pos: outcome.landing,
+            },
+        );
+        events.extend(terrain::item_lands(world, item, outcome.landing));
+    }
+    if let Some(target) = outcome.hit_target {
+        if let Some(resolution) =
+            combat::resolve_attack_with_profile(world, rng, attacker, target, profile)
+        {
+            events.push(combat::attack_event(&resolution));
+            if let Some(attack) = ranged_attack(weapon) {
+                if resolution.hit && target == world.player_id {
+                    events.extend(monster_attacks::afflict(world, rng, attacker, attack));
+                }
+            }
+            events.extend(death::collect_death_events_after_attack(
+                world, attacker, target,
+            ));
+        }
+    }
+    events
+}
+
+/// 침과 숨결을 피해 유형이 붙은 공격으로 본다. 맞은 플레이어는 근접 공격과 같은 추가 효과를 받는다.
+fn ranged_attack(weapon: RangedWeapon) -> Option<MonsterAttack> {
+    let (attack_type, damage, damage_type) = match weapon {
+        RangedWeapon::Spit(damage, damage_type) => (AttackType::Spit, damage, damage_type),
+        RangedWeapon::Breath(damage, damage_type) => (AttackType::Breath, damage, damage_type),
+        RangedWeapon::Wand(_) | RangedWeapon::Missile(_) => return None,
+    };
+    Some(MonsterAttack {
+        attack_type,
+        damage_type,
+        damage,
+    })
+}
+
+/// 불 저항이 있는 플레이어는 불 숨결과 불 침에 피해를 받지 않는다. 몬스터끼리 맞는 경우는 없다.
+fn fire_resisted(world: &GameWorld, weapon: RangedWeapon, damage: DamageRoll) -> DamageRoll {
+    let fiery = ranged_attack(weapon).is_some_and(|attack| attack.damage_type == DamageType::Fire);
+    if fiery && world.conditions.has_intrinsic(Intrinsic::FireResistance) {
+        DamageRoll::none()
+    } else {
+        damage
+    }
+}
+
+fn with_article(name: &str) -> String {
+    let article = if name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    };
+    format!("{article} {name}")
+}
+
+/// 던지거나 쏜 무기의 공격이다. 발사기 없이 던진 화살 같은 탄환은 NetHack `hmon`처럼
+/// `rnd(2)` 피해만 준다. 강화와 부식은 손에 든 무기와 똑같이 더한다.
+/// 던진 물건의 이름이다. content 무기의 공격에는 이름이 없으므로 종류 이름을 쓴다.
+fn missile_name(world: &GameWorld, kind: ItemKind, profile: AttackProfile) -> &str {
+    if profile.name.is_empty() {
+        world.item_name(kind)
+    } else {
+        profile.name
+    }
+}
+
+fn missile_profile(
+    world: &GameWorld,
+    item: EntityId,
+    kind: ItemKind,
+    fired: bool,
+) -> AttackProfile {
+    let data = world.entities.item_data(item);
+    let mut profile = match kind {
+        ItemKind::Rock => AttackProfile::natural("rock", DamageRoll::new(1, 3)),
+        _ if data.is_some_and(|data| data.launcher.is_some()) && !fired => {
+            AttackProfile::natural("thrown item", DamageRoll::new(1, 2))
+        }
+        _ => data
+            .and_then(|data| data.attack_profile)
+            .unwrap_or(AttackProfile::natural("thrown item", DamageRoll::new(1, 2))),
+    };
+    let enchantment = i16::from(world.entities.item_enchantment(item));
+    profile.hit_bonus += enchantment;
+    profile.damage_bonus += enchantment - i16::from(world.entities.item_erosion(item));
+    profile
+}
+
+/// 벽, 닫힌 문, 나무에서 멈춘다. 쇠창살은 `passes_bars`인 작은 물건과 광선만 지나간다.
+fn trace_path(
+    world: &GameWorld,
+    from: Pos,
+    direction: Direction,
+    passes_bars: bool,
+) -> ProjectileOutcome {
     let mut current = from;
     loop {
         let next = current.offset(direction.delta());
@@ -140,12 +536,10 @@ fn trace_path(world: &GameWorld, from: Pos, direction: Direction) -> ProjectileO
             };
         }
         if let Some(target) = world.entities.alive_actor_at(world.current_level(), next) {
-            if target != world.player_id {
-                return ProjectileOutcome {
-                    landing: next,
-                    hit_target: Some(target),
-                };
-            }
+            return ProjectileOutcome {
+                landing: next,
+                hit_target: Some(target),
+            };
         }
         let Ok(tile) = world.current_map().tile(next) else {
             return ProjectileOutcome {
@@ -155,8 +549,12 @@ fn trace_path(world: &GameWorld, from: Pos, direction: Direction) -> ProjectileO
         };
         if matches!(
             tile,
-            TileKind::Wall | TileKind::Door(DoorState::Closed) | TileKind::HiddenDoor
-        ) {
+            TileKind::Wall
+                | TileKind::Door(DoorState::Closed | DoorState::Locked)
+                | TileKind::HiddenDoor
+                | TileKind::Tree
+        ) || (tile == TileKind::IronBars && !passes_bars)
+        {
             return ProjectileOutcome {
                 landing: current,
                 hit_target: None,
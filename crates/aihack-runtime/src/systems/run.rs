@@ -0,0 +1,101 @@
+//! `Shift`+방향 rush와 `G`+방향 run이 멈추거나 꺾는 규칙이다.
+//!
+//! NetHack 3.6.7 `hack.c`의 `lookaround`를 따른다. 걸음을 마칠 때마다 플레이어 주변 여덟 칸을
+//! 살펴 더 달릴지, 외길 통로를 따라 방향을 꺾을지 정한다. 규칙이 runtime에 있으므로 TUI,
+//! headless runner, bot이 같은 곳에서 멈춘다.
+
+use aihack_core::{
+    action::RunMode,
+    domain::tile::TileKind,
+    position::{Direction, Pos},
+};
+
+use crate::{systems::movement, world::GameWorld};
+
+/// `direction`으로 달려 온 플레이어가 다음에 걸을 방향이다. 멈춰야 하면 `None`이다.
+pub fn next_direction(world: &GameWorld, direction: Direction, mode: RunMode) -> Option<Direction> {
+    let here = world.player_pos();
+    let level = world.current_level();
+    // 물건이나 문, 계단 같은 지형 위에 막 올라섰으면 멈춘다. rush는 출입구를 지나친다.
+    let standing = known_tile(world, here)?;
+    if !world.entities.items_at(level, here).is_empty()
+        || is_interesting(standing)
+        || (mode == RunMode::Run && matches!(standing, TileKind::Door(_)))
+    {
+        return None;
+    }
+
+    let delta = direction.delta();
+    let ahead = here.offset(delta);
+    let mut ways = Vec::new();
+    for candidate in Direction::ALL {
+        let pos = here.offset(candidate.delta());
+        // 지나온 칸과 그 옆은 돌아보지 않는다.
+        if (pos.x == here.x - delta.dx && pos.y != here.y + delta.dy)
+            || (pos.y == here.y - delta.dy && pos.x != here.x + delta.dx)
+        {
+            continue;
+        }
+        if world.entities.alive_actor_at(level, pos).is_some() {
+            if mode == RunMode::Run || pos == ahead {
+                return None;
+            }
+            continue;
+        }
+        let Some(tile) = known_tile(world, pos) else {
+            continue;
+        };
+        if mode == RunMode::Run {
+            let beside_door =
+                matches!(tile, TileKind::Door(_)) && !candidate.is_diagonal() && pos != ahead;
+            if beside_door || is_interesting(tile) || world.entities.item_at(level, pos).is_some() {
+                return None;
+            }
+        }
+        if pos.chebyshev_distance(ahead) <= 1
+            && tile.is_movement_passable()
+            && movement::is_passable_for_legal_action(world, candidate)
+        {
+            ways.push(candidate);
+        }
+    }
+
+    if standing != TileKind::Corridor {
+        return ways.contains(&direction).then_some(direction);
+    }
+    // 통로에서는 외길이면 따라 꺾고, 길이 갈라지면 멈춘다. 대각선 길은 같은 쪽 곧은 길이
+    // 있으면 모퉁이를 깎는 것일 뿐이므로 세지 않는다.
+    let straight = ways
+        .iter()
+        .copied()
+        .filter(|way| !way.is_diagonal())
+        .collect::<Vec<_>>();
+    let ways = ways
+        .into_iter()
+        .filter(|way| {
+            way.orthogonal_components()
+                .is_none_or(|(a, b)| !straight.contains(&a) && !straight.contains(&b))
+        })
+        .collect::<Vec<_>>();
+    match ways.as_slice() {
+        [way] => Some(*way),
+        _ => None,
+    }
+}
+
+fn known_tile(world: &GameWorld, pos: Pos) -> Option<TileKind> {
+    world
+        .current_map()
+        .tile(pos)
+        .ok()
+        .map(TileKind::observation_equivalent)
+}
+
+/// 곁에 보이면 달리기를 멈추게 하는 지형이다. 물과 용암은 바로 앞에 있을 때만 막는다.
+fn is_interesting(tile: TileKind) -> bool {
+    tile.is_feature()
+        || matches!(
+            tile,
+            TileKind::StairsDown | TileKind::StairsUp | TileKind::Trap(_)
+        )
+}
@@ -0,0 +1,89 @@
+//! 이동력으로 행동 순서를 정하는 속도 규칙이다.
+//!
+//! NetHack 3.6.7 `allmain.c`의 `moveloop`와 `mon.c`의 `mcalcmove`를 따른다. 모든 actor는
+//! 새 게임 턴마다 속도만큼 이동력을 받고, [`NORMAL_SPEED`] 이상 남아 있는 동안 한 번에
+//! 그만큼 쓰며 행동한다. 속도가 12의 배수가 아니면 나머지를 확률로 반올림하므로, 보통
+//! 속도에서는 난수를 쓰지 않는다.
+
+use aihack_core::{
+    domain::{entity::NORMAL_SPEED, status::Encumbrance},
+    ids::EntityId,
+    rng::GameRng,
+};
+
+use crate::{systems::combat::rn2, world::GameWorld};
+
+/// 새 턴에 플레이어가 받는 이동력이다. NetHack `moveloop`의 `moveamt` 계산과 같다.
+///
+/// 속도 물약 같은 외부 속도(`Very_fast`)는 평균 5/3배, intrinsic 속도(`Fast`)는 평균 4/3배이고
+/// 짐이 무거울수록 깎인다. 과적이면 어차피 움직일 수 없으므로 깎지 않는다.
+pub fn player_move_amount(world: &GameWorld, rng: &mut GameRng) -> i16 {
+    let mut amount = world
+        .entities
+        .actor_stats(world.player_id)
+        .map_or(NORMAL_SPEED, |stats| stats.speed);
+    if world.conditions.very_fast_turns > 0 {
+        amount += NORMAL_SPEED / 2;
+        if rn2(rng, 3) == 0 {
+            amount += NORMAL_SPEED / 2;
+        }
+    } else if world.conditions.fast && rn2(rng, 3) != 0 {
+        amount += NORMAL_SPEED / 2;
+    }
+    match world.encumbrance() {
+        Encumbrance::Unencumbered | Encumbrance::Overloaded => amount,
+        Encumbrance::Burdened => amount - amount / 4,
+        Encumbrance::Stressed => amount - amount / 2,
+        Encumbrance::Strained => amount - amount * 3 / 4,
+        Encumbrance::Overtaxed => amount - amount * 7 / 8,
+    }
+}
+
+/// 새 턴에 몬스터가 받는 이동력이다. NetHack `mcalcmove`처럼 12로 나눈 나머지를 확률로
+/// 반올림해 평균이 속도와 같게 한다.
+pub fn monster_move_amount(rng: &mut GameRng, speed: i16) -> i16 {
+    let speed = speed.max(0);
+    let remainder = speed % NORMAL_SPEED;
+    let amount = speed - remainder;
+    if remainder > 0 && rn2(rng, NORMAL_SPEED) < i32::from(remainder) {
+        amount + NORMAL_SPEED
+    } else {
+        amount
+    }
+}
+
+/// 새 게임 턴을 시작하며 플레이어와 현재 층 몬스터에게 이동력을 준다.
+pub fn grant_movement(world: &mut GameWorld, rng: &mut GameRng) {
+    for monster in world.current_level_hostile_monsters() {
+        let speed = world
+            .entities
+            .actor_stats(monster)
+            .map_or(0, |stats| stats.speed);
+        let amount = monster_move_amount(rng, speed);
+        add_movement(world, monster, amount);
+    }
+    let amount = player_move_amount(world, rng);
+    add_movement(world, world.player_id, amount);
+    world.turn_actions = 0;
+}
+
+/// 행동 한 번의 이동력을 쓴다.
+pub fn spend_move(world: &mut GameWorld, actor: EntityId) {
+    if let Some(stats) = world.entities.actor_stats_mut(actor) {
+        stats.movement -= NORMAL_SPEED;
+    }
+}
+
+/// 이번 턴에 한 번 더 행동할 이동력이 남았는지 본다.
+pub fn can_act(world: &GameWorld, actor: EntityId) -> bool {
+    world
+        .entities
+        .actor_stats(actor)
+        .is_some_and(|stats| stats.movement >= NORMAL_SPEED)
+}
+
+fn add_movement(world: &mut GameWorld, actor: EntityId, amount: i16) {
+    if let Some(stats) = world.entities.actor_stats_mut(actor) {
+        stats.movement = (stats.movement + amount).max(0);
+    }
+}
@@ -0,0 +1,212 @@
+//! 물, 해자, 용암 같은 위험 지형에 빠지거나 물건이 떨어질 때의 판정이다.
+//!
+//! NetHack 3.6.7 `trap.c`의 `drown`/`lava_effect`, `do.c`의 `flooreffects`,
+//! `lava_damage`를 따른다. 이 게임에는 수영, 부양, 화염 저항이 없으므로
+//! 용암에 빠지면 언제나 죽고, 물에서는 곁의 마른 땅으로 기어 나와야 산다.
+
+use aihack_core::{
+    domain::{
+        combat::DeathCause,
+        entity::{EntityKind, EntityLocation},
+        item::{ItemClass, ItemKind, Material},
+        tile::TileKind,
+    },
+    error::GameError,
+    event::{GameEvent, MessagePriority},
+    ids::EntityId,
+    position::{Direction, Pos},
+    rng::GameRng,
+};
+
+use crate::{
+    systems::{combat::roll_die, death, effects::EffectOutcome, items},
+    world::GameWorld,
+};
+
+/// 헛디딘 플레이어를 `direction` 쪽 액체 지형으로 옮기고 빠진 결과를 굴린다.
+pub fn stumble_into_liquid(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    direction: Direction,
+) -> Result<Vec<GameEvent>, GameError> {
+    let level = world.current_level();
+    let from = world.player_pos();
+    let to = from.offset(direction.delta());
+    let tile = world.current_map().tile(to)?;
+    if !tile.is_liquid() {
+        return Err(GameError::BlockedMovement { pos: to, tile });
+    }
+    if world.entities.alive_actor_at(level, to).is_some() {
+        return Err(GameError::CommandRejected(format!(
+            "movement blocked by living entity at {to:?}"
+        )));
+    }
+    aihack_core::movement::validate_path(world, world.player_id, level, from, direction)?;
+    world.set_player_location(level, to);
+    let mut events = vec![GameEvent::EntityMoved {
+        entity: world.player_id,
+        from,
+        to,
+    }];
+    events.extend(fall_into_liquid(world, rng, tile));
+    Ok(events)
+}
+
+/// 액체 지형에 빠진 플레이어를 처리한다. 용암은 즉사, 물은 기어 나오거나 익사한다.
+pub fn fall_into_liquid(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    tile: TileKind,
+) -> Vec<GameEvent> {
+    let mut outcome = EffectOutcome::new(true);
+    let name = tile.liquid_name().unwrap_or("water");
+    outcome.message(
+        MessagePriority::Danger,
+        &format!("You fall into the {name}!"),
+    );
+    if tile == TileKind::Lava {
+        burn_inventory(world, &mut outcome);
+        outcome.message(MessagePriority::Danger, "You burn to a crisp...");
+        outcome
+            .events
+            .extend(death::kill_player(world, DeathCause::Lava));
+        return outcome.events;
+    }
+    outcome.message(MessagePriority::Warning, "You sink like a rock.");
+    soak_inventory(world, &mut outcome.events);
+    match crawl_destination(world, rng) {
+        Some(to) => {
+            outcome.message(
+                MessagePriority::Info,
+                &format!("You try to crawl out of the {name}."),
+            );
+            outcome.message(MessagePriority::Info, "Pheew!  That was close.");
+            let from = world.player_pos();
+            let level = world.current_level();
+            world.set_player_location(level, to);
+            outcome.events.push(GameEvent::EntityMoved {
+                entity: world.player_id,
+                from,
+                to,
+            });
+        }
+        None => {
+            outcome.message(MessagePriority::Danger, "You drown.");
+            outcome
+                .events
+                .extend(death::kill_player(world, DeathCause::Drowned { tile }));
+        }
+    }
+    outcome.events
+}
+
+/// 던지거나 떨어뜨린 물건이 `pos` 지형에 닿은 결과다. 물에서는 가라앉고,
+/// 용암에서는 타는 재질이면 사라진다.
+pub fn item_lands(world: &mut GameWorld, item: EntityId, pos: Pos) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    match world.current_map().tile(pos) {
+        Ok(TileKind::Lava) if burns_in_lava(world, item) => {
+            world
+                .entities
+                .set_item_location(item, EntityLocation::Consumed);
+            events.push(message(MessagePriority::Info, "It burns up in the lava."));
+            events.push(GameEvent::ItemDestroyed { item });
+        }
+        Ok(tile) if tile.is_liquid() => {
+            let name = tile.liquid_name().unwrap_or("water");
+            events.push(message(
+                MessagePriority::Info,
+                &format!("It sinks into the {name}."),
+            ));
+        }
+        _ => {}
+    }
+    events
+}
+
+/// NetHack `crawl_dest_ok`처럼 곁의 빈 마른 땅을 고른다. 무작위로 100번 찾은 뒤
+/// 차례로 한 번 더 훑는다.
+fn crawl_destination(world: &GameWorld, rng: &mut GameRng) -> Option<Pos> {
+    let origin = world.player_pos();
+    let free = |pos: Pos| {
+        pos != origin
+            && world
+                .current_map()
+                .tile(pos)
+                .is_ok_and(|tile| tile.is_movement_passable())
+            && world
+                .entities
+                .alive_actor_at(world.current_level(), pos)
+                .is_none()
+    };
+    for _ in 0..100 {
+        let pos = Pos {
+            x: origin.x + roll_die(rng, 3) - 2,
+            y: origin.y + roll_die(rng, 3) - 2,
+        };
+        if free(pos) {
+            return Some(pos);
+        }
+    }
+    Direction::ALL
+        .into_iter()
+        .map(|direction| origin.offset(direction.delta()))
+        .find(|pos| free(*pos))
+}
+
+/// 물에 젖은 소지품을 처리한다. 물약은 묽어진다.
+fn soak_inventory(world: &mut GameWorld, events: &mut Vec<GameEvent>) {
+    let items = world
+        .inventory
+        .entries
+        .iter()
+        .map(|entry| entry.item)
+        .collect::<Vec<_>>();
+    for item in items {
+        let Some(EntityKind::Item(kind)) = world.entities.get(item).map(|entity| entity.kind())
+        else {
+            continue;
+        };
+        if kind != ItemKind::PotionWater
+            && world.entities.item_data(item).map(|data| data.class) == Some(ItemClass::Potion)
+        {
+            items::dilute(world, item, kind, events);
+        }
+    }
+}
+
+/// 용암에 빠지며 타는 소지품을 없앤다.
+fn burn_inventory(world: &mut GameWorld, outcome: &mut EffectOutcome) {
+    let items = world
+        .inventory
+        .entries
+        .iter()
+        .map(|entry| entry.item)
+        .filter(|item| burns_in_lava(world, *item))
+        .collect::<Vec<_>>();
+    for item in items {
+        world.inventory.remove(item);
+        world
+            .entities
+            .set_item_location(item, EntityLocation::Consumed);
+        outcome.events.push(GameEvent::ItemDestroyed { item });
+    }
+}
+
+/// NetHack `lava_damage`처럼 가죽, 종이, 살, 음식과 끓어 넘치는 물약은 용암에 타 없어진다.
+fn burns_in_lava(world: &GameWorld, item: EntityId) -> bool {
+    world.entities.item_data(item).is_some_and(|data| {
+        data.material == Some(Material::Leather)
+            || matches!(
+                data.class,
+                ItemClass::Food | ItemClass::Corpse | ItemClass::Scroll | ItemClass::Potion
+            )
+    })
+}
+
+fn message(priority: MessagePriority, text: &str) -> GameEvent {
+    GameEvent::Message {
+        priority,
+        text: text.to_string(),
+    }
+}
@@ -1,14 +1,61 @@
-use aihack_core::{domain::tile::TileKind, event::GameEvent, position::Pos};
+use aihack_core::{
+    domain::{
+        combat::DeathCause,
+        item::MAX_EROSION,
+        status::StatusEffect,
+        tile::{TileKind, TrapKind},
+    },
+    event::{GameEvent, MessagePriority},
+    ids::{EntityId, LevelId},
+    position::Pos,
+    rng::GameRng,
+};
 
-use crate::world::GameWorld;
+use crate::{
+    systems::{
+        combat::{rn2, roll_die},
+        death,
+        effects::{self, EffectOutcome},
+        luck, vision,
+    },
+    world::GameWorld,
+};
 
-pub const PHASE7_PIT_DAMAGE: i16 = 3;
+/// 이미 본 함정을 밟았을 때 플레이어가 피할 확률의 분모다(NetHack `!rn2(5)`).
+/// 판정은 `rnl`로 굴려 luck이 좋을수록 잘 피한다.
+pub const PLAYER_ESCAPE_CHANCE: i16 = 5;
+/// 드러난 함정을 몬스터가 피하지 못할 확률의 분모다(NetHack `rn2(4)`).
+pub const MONSTER_BLUNDER_CHANCE: i16 = 4;
+/// 붙잡힌 몬스터가 움직이려 할 때 빠져나올 확률의 분모다(NetHack `mintrap`의 `!rn2(40)`).
+pub const MONSTER_ESCAPE_CHANCE: i16 = 40;
+/// 나쁜 luck으로 검색할 때 쓰는 `rnl` 범위다(NetHack `rnl(7)`).
+pub const SEARCH_CHANCE: i16 = 7;
+/// 마법 함정이 폭발하고 사라질 확률의 분모다.
+pub const MAGIC_EXPLOSION_CHANCE: i16 = 20;
 
-/// 주변의 숨겨진 타일을 결정적으로 공개한다.
-pub fn search(world: &mut GameWorld) -> Vec<GameEvent> {
+const MAGIC_FLAVOR: [&str; 6] = [
+    "A shiver runs up and down your spine!",
+    "You hear distant howling.",
+    "You suddenly yearn for your distant homeland.",
+    "Your pack shakes violently!",
+    "You smell charred flesh.",
+    "You feel tired.",
+];
+
+/// 주변의 숨겨진 타일을 공개한다. luck이 0 이상이면 결정적으로 찾고,
+/// 나쁜 luck이면 타일마다 `rnl(SEARCH_CHANCE)`가 최댓값일 때 놓친다.
+pub fn search(world: &mut GameWorld, rng: &mut GameRng) -> Vec<GameEvent> {
     let origin = world.player_pos();
+    let unlucky = luck::effective_luck(world) < 0;
     let mut events = Vec::new();
     for pos in adjacent_and_current(origin) {
+        let hidden = world
+            .current_map()
+            .tile(pos)
+            .is_ok_and(|tile| tile.is_hidden());
+        if hidden && unlucky && luck::rnl(world, rng, SEARCH_CHANCE) == SEARCH_CHANCE - 1 {
+            continue;
+        }
         if let Some(event) = reveal_tile(world, pos) {
             events.push(event);
         }
@@ -27,35 +74,465 @@ pub fn reveal_all_hidden_tiles(world: &mut GameWorld) -> Vec<GameEvent> {
 }
 
 /// 이동 직후 플레이어가 밟은 함정을 발동한다.
-pub fn trigger_player_trap(world: &mut GameWorld) -> Vec<GameEvent> {
+/// 이미 드러난 함정은 일정 확률로 피하고, 사망하면 실제 함정 종류를 원인으로 남긴다.
+pub fn trigger_player_trap(world: &mut GameWorld, rng: &mut GameRng) -> Vec<GameEvent> {
     let pos = world.player_pos();
-    let Some(tile) = world.current_map().tile(pos).ok() else {
+    let Some((tile, trap)) = trap_at(world, world.current_level(), pos) else {
         return Vec::new();
     };
-    let trap = match tile {
-        TileKind::Trap(kind) | TileKind::HiddenTrap(kind) => kind,
-        _ => return Vec::new(),
-    };
 
-    let mut events = Vec::new();
-    if matches!(tile, TileKind::HiddenTrap(_)) {
-        if let Some(event) = reveal_tile(world, pos) {
-            events.push(event);
-        }
+    let mut outcome = EffectOutcome::new(true);
+    if tile.is_hidden() {
+        outcome.events.extend(reveal_tile(world, pos));
+    } else if luck::rnl(world, rng, PLAYER_ESCAPE_CHANCE) == 0 {
+        outcome.message(
+            MessagePriority::Info,
+            &format!("You escape {}.", with_article(trap)),
+        );
+        return outcome.events;
     }
+
     let player_id = world.player_id;
+    let ac = world
+        .entities
+        .actor_stats(player_id)
+        .map(|stats| stats.ac)
+        .unwrap_or_default();
+    let damage = match trap {
+        TrapKind::Pit => {
+            outcome.message(MessagePriority::Warning, "You fall into a pit!");
+            hold(world, player_id, pit_turns(rng));
+            roll_die(rng, 6)
+        }
+        TrapKind::BearTrap => {
+            outcome.message(MessagePriority::Warning, "A bear trap closes on your foot!");
+            hold(world, player_id, bear_trap_turns(rng));
+            roll_die(rng, 4) + roll_die(rng, 4)
+        }
+        TrapKind::Arrow | TrapKind::Dart | TrapKind::RollingBoulder => {
+            let (alert, missile) = match trap {
+                TrapKind::Arrow => ("An arrow shoots out at you!", "an arrow"),
+                TrapKind::Dart => ("A little dart shoots out at you!", "a little dart"),
+                _ => ("Click! You trigger a rolling boulder trap!", "a boulder"),
+            };
+            outcome.message(MessagePriority::Warning, alert);
+            match missile_damage(rng, trap, ac) {
+                Some(damage) => {
+                    outcome.message(
+                        MessagePriority::Danger,
+                        &format!("You are hit by {missile}!"),
+                    );
+                    damage
+                }
+                None => {
+                    outcome.message(
+                        MessagePriority::Info,
+                        &format!("You are almost hit by {missile}."),
+                    );
+                    0
+                }
+            }
+        }
+        TrapKind::SleepingGas => {
+            outcome.message(MessagePriority::Danger, "A cloud of gas puts you to sleep!");
+            let turns = roll_die(rng, 25) as u8;
+            world.paralysis_turns = world.paralysis_turns.max(turns);
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: player_id,
+                effect: StatusEffect::Sleep,
+                turns: u16::from(world.paralysis_turns),
+            });
+            0
+        }
+        TrapKind::Rust => {
+            rust_wielded_weapon(world, &mut outcome);
+            0
+        }
+        TrapKind::Teleport => {
+            effects::teleport_within_level(world, rng, &mut outcome);
+            0
+        }
+        TrapKind::TrapDoor | TrapKind::Hole => {
+            fall_through(world, rng, player_id, trap, &mut outcome);
+            0
+        }
+        TrapKind::LandMine => {
+            outcome.message(
+                MessagePriority::Danger,
+                "KAABLAMM!!!  You triggered a land mine!",
+            );
+            outcome
+                .events
+                .extend(become_pit(world, world.current_level(), pos));
+            hold(world, player_id, pit_turns(rng));
+            roll_die(rng, 16)
+        }
+        TrapKind::Magic => magic_trap(world, rng, pos, &mut outcome),
+    };
+
     if let Some(stats) = world.entities.actor_stats_mut(player_id) {
-        stats.hp -= PHASE7_PIT_DAMAGE;
+        stats.hp -= damage;
+    }
+    outcome.events.push(GameEvent::TrapTriggered {
+        entity: player_id,
+        trap,
+        pos,
+        damage,
+    });
+    outcome
+        .events
+        .extend(death::collect_death_events_if_hp_depleted(
+            world,
+            player_id,
+            DeathCause::Trap { trap },
+        ));
+    outcome.events
+}
+
+/// 구덩이·곰덫에 붙잡힌 플레이어가 빠져나오려 애쓴다.
+/// 붙잡혀 있지 않으면 `None`이고, 붙잡혀 있으면 이번 턴을 소모하는 이벤트를 돌려준다.
+pub fn struggle_out_of_trap(world: &mut GameWorld) -> Option<Vec<GameEvent>> {
+    let player_id = world.player_id;
+    let stats = world.entities.actor_stats_mut(player_id)?;
+    if stats.trapped_turns == 0 {
+        return None;
+    }
+    stats.trapped_turns -= 1;
+    let freed = stats.trapped_turns == 0;
+    let bear_trap = matches!(
+        world.current_map().tile(world.player_pos()),
+        Ok(TileKind::Trap(TrapKind::BearTrap))
+    );
+    let text = match (bear_trap, freed) {
+        (true, true) => "You pull free from the bear trap.",
+        (true, false) => "You cannot pull your foot out of the bear trap.",
+        (false, true) => "You crawl to the edge of the pit.",
+        (false, false) => "You are still in a pit.",
+    };
+    Some(vec![GameEvent::Message {
+        priority: MessagePriority::Info,
+        text: text.to_string(),
+    }])
+}
+
+/// 구덩이·곰덫에 붙잡힌 몬스터가 움직이려 할 때 빠져나오려 애쓴다. NetHack `mintrap`처럼
+/// 함정이 사라졌으면 바로 풀려나고, 아니면 `MONSTER_ESCAPE_CHANCE`분의 1로 빠져나온다.
+/// 이번 턴에도 붙잡혀 있으면 `true`다.
+pub fn monster_still_trapped(world: &mut GameWorld, rng: &mut GameRng, monster: EntityId) -> bool {
+    let Some((level, pos)) = world.entities.actor_location(monster) else {
+        return false;
+    };
+    if world
+        .entities
+        .actor_stats(monster)
+        .is_none_or(|stats| stats.trapped_turns == 0)
+    {
+        return false;
+    }
+    let holding = matches!(
+        trap_at(world, level, pos),
+        Some((_, TrapKind::Pit | TrapKind::BearTrap))
+    );
+    let held = holding && rn2(rng, MONSTER_ESCAPE_CHANCE) != 0;
+    if !held {
+        if let Some(stats) = world.entities.actor_stats_mut(monster) {
+            stats.trapped_turns = 0;
+        }
+    }
+    held
+}
+
+/// 몬스터가 이동한 칸의 함정을 발동한다. 구덩이·곰덫은 NetHack `mtrapped`처럼 턴 수 없이
+/// 붙잡아 두고, 풀려나는 것은 `monster_still_trapped`가 매 턴 굴린다.
+/// 이미 드러난 함정은 대개 피하며, 플레이어가 보는 곳의 숨은 함정만 공개된다.
+pub fn trigger_monster_trap(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    monster: EntityId,
+) -> Vec<GameEvent> {
+    let Some((level, pos)) = world.entities.actor_location(monster) else {
+        return Vec::new();
+    };
+    let Some((tile, trap)) = trap_at(world, level, pos) else {
+        return Vec::new();
+    };
+    if !tile.is_hidden() && roll_die(rng, MONSTER_BLUNDER_CHANCE) != 1 {
+        return Vec::new();
+    }
+
+    let mut events = Vec::new();
+    let seen = level == world.current_level() && vision::is_visible(world, pos);
+    if tile.is_hidden() && seen {
+        events.extend(reveal_tile(world, pos));
+    }
+    let ac = world
+        .entities
+        .actor_stats(monster)
+        .map(|stats| stats.ac)
+        .unwrap_or_default();
+    let mut outcome = EffectOutcome::new(true);
+    let damage = match trap {
+        TrapKind::Pit => {
+            hold(world, monster, 1);
+            roll_die(rng, 6)
+        }
+        TrapKind::BearTrap => {
+            hold(world, monster, 1);
+            roll_die(rng, 4) + roll_die(rng, 4)
+        }
+        TrapKind::Arrow | TrapKind::Dart | TrapKind::RollingBoulder => {
+            missile_damage(rng, trap, ac).unwrap_or_default()
+        }
+        TrapKind::SleepingGas => {
+            let turns = roll_die(rng, 25) as u8;
+            if let Some(stats) = world.entities.actor_stats_mut(monster) {
+                stats.frozen_turns = stats.frozen_turns.max(turns);
+            }
+            0
+        }
+        TrapKind::Rust => 0,
+        TrapKind::Teleport => {
+            if let Some(to) = effects::random_safe_pos(world, rng, level, Some(pos)) {
+                world.entities.set_actor_location(monster, level, to);
+                outcome.events.push(GameEvent::EntityTeleported {
+                    entity: monster,
+                    from: pos,
+                    to,
+                });
+            }
+            0
+        }
+        TrapKind::TrapDoor | TrapKind::Hole => {
+            fall_through(world, rng, monster, trap, &mut outcome);
+            0
+        }
+        TrapKind::LandMine => {
+            outcome.events.extend(become_pit(world, level, pos));
+            hold(world, monster, 1);
+            roll_die(rng, 16)
+        }
+        TrapKind::Magic if roll_die(rng, MAGIC_EXPLOSION_CHANCE) == 1 => {
+            outcome.events.extend(remove_trap(world, level, pos));
+            roll_die(rng, 10)
+        }
+        TrapKind::Magic => 0,
+    };
+
+    if let Some(stats) = world.entities.actor_stats_mut(monster) {
+        stats.hp -= damage;
     }
     events.push(GameEvent::TrapTriggered {
-        entity: world.player_id,
+        entity: monster,
         trap,
         pos,
-        damage: PHASE7_PIT_DAMAGE,
+        damage,
     });
+    events.extend(outcome.events);
+    events.extend(death::collect_death_events_if_hp_depleted(
+        world,
+        monster,
+        DeathCause::Trap { trap },
+    ));
     events
 }
 
+fn trap_at(world: &GameWorld, level: LevelId, pos: Pos) -> Option<(TileKind, TrapKind)> {
+    match world.levels.map(level)?.tile(pos).ok()? {
+        tile @ (TileKind::Trap(kind) | TileKind::HiddenTrap(kind)) => Some((tile, kind)),
+        _ => None,
+    }
+}
+
+fn with_article(trap: TrapKind) -> String {
+    let name = trap.name();
+    let article = if name.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    };
+    format!("{article} {name}")
+}
+
+/// NetHack `rn1(6, 2)`처럼 구덩이에 2..=7턴 붙잡힌다.
+fn pit_turns(rng: &mut GameRng) -> u8 {
+    roll_die(rng, 6) as u8 + 1
+}
+
+/// NetHack `4 + rn2(4)`처럼 곰덫에 4..=7턴 붙잡힌다.
+fn bear_trap_turns(rng: &mut GameRng) -> u8 {
+    roll_die(rng, 4) as u8 + 3
+}
+
+fn hold(world: &mut GameWorld, actor: EntityId, turns: u8) {
+    if let Some(stats) = world.entities.actor_stats_mut(actor) {
+        stats.trapped_turns = stats.trapped_turns.max(turns);
+    }
+}
+
+/// NetHack `thitu`/`thitm`처럼 `ac + 함정 수준 > d20`이면 맞는다.
+fn missile_damage(rng: &mut GameRng, trap: TrapKind, ac: i16) -> Option<i16> {
+    let (level, sides) = match trap {
+        TrapKind::Arrow => (8, 6),
+        TrapKind::Dart => (7, 3),
+        _ => (9, 20),
+    };
+    (ac + level > roll_die(rng, 20)).then(|| roll_die(rng, sides))
+}
+
+/// 장비한 철제 무기를 한 단계 녹슬게 한다.
+fn rust_wielded_weapon(world: &mut GameWorld, outcome: &mut EffectOutcome) {
+    outcome.message(MessagePriority::Warning, "A gush of water hits you!");
+    let Some(weapon) = world.inventory.equipped_melee.filter(|item| {
+        world
+            .entities
+            .item_data(*item)
+            .and_then(|data| data.material)
+            .is_some_and(|material| material.is_rustprone())
+    }) else {
+        return;
+    };
+    if world.entities.item_erodeproof(weapon) {
+        outcome.message(
+            MessagePriority::Info,
+            "Somehow, your weapon is not affected.",
+        );
+        return;
+    }
+    let erosion = world.entities.item_erosion(weapon);
+    if erosion >= MAX_EROSION {
+        outcome.message(MessagePriority::Info, "Your weapon looks completely rusty.");
+        return;
+    }
+    world.entities.set_item_erosion(weapon, erosion + 1);
+    outcome.message(
+        MessagePriority::Warning,
+        if erosion == 0 {
+            "Your weapon rusts!"
+        } else {
+            "Your weapon rusts further!"
+        },
+    );
+    outcome.events.push(GameEvent::ItemEroded {
+        item: weapon,
+        erosion: erosion + 1,
+    });
+}
+
+/// 함정문·구멍을 통해 바로 아래층의 무작위 안전 지점으로 떨어진다.
+pub(crate) fn fall_through(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    actor: EntityId,
+    trap: TrapKind,
+    outcome: &mut EffectOutcome,
+) {
+    let Some((from, _)) = world.entities.actor_location(actor) else {
+        return;
+    };
+    let to = LevelId {
+        branch: from.branch,
+        depth: from.depth + 1,
+    };
+    let is_player = actor == world.player_id;
+    let landing = world
+        .levels
+        .contains(to)
+        .then(|| effects::random_safe_pos(world, rng, to, None))
+        .flatten();
+    let Some(landing) = landing else {
+        if is_player {
+            outcome.message(
+                MessagePriority::Info,
+                &format!("You float over the {}.", trap.name()),
+            );
+        }
+        return;
+    };
+    if is_player {
+        outcome.message(
+            MessagePriority::Warning,
+            if trap == TrapKind::Hole {
+                "You fall through a hole!"
+            } else {
+                "A trap door opens up under you!"
+            },
+        );
+        world.set_player_location(to, landing);
+    } else {
+        world.entities.set_actor_location(actor, to, landing);
+    }
+    outcome.events.push(GameEvent::LevelChanged {
+        entity: actor,
+        from,
+        to,
+    });
+}
+
+/// 터진 지뢰 자리는 드러난 구덩이가 된다.
+fn become_pit(world: &mut GameWorld, level: LevelId, pos: Pos) -> Option<GameEvent> {
+    set_trap_tile(world, level, pos, TileKind::Trap(TrapKind::Pit))
+}
+
+fn remove_trap(world: &mut GameWorld, level: LevelId, pos: Pos) -> Option<GameEvent> {
+    set_trap_tile(world, level, pos, TileKind::Floor)
+}
+
+/// 함정이 놓인 `level`의 지도를 바꾼다. 공개 이벤트는 플레이어가 있는 층에서만 낸다.
+fn set_trap_tile(
+    world: &mut GameWorld,
+    level: LevelId,
+    pos: Pos,
+    tile: TileKind,
+) -> Option<GameEvent> {
+    world.map_mut(level).set_tile(pos, tile).ok()?;
+    (level == world.current_level()).then_some(GameEvent::TileRevealed { pos, tile })
+}
+
+/// NetHack `domagictrap`을 줄인 것이다. 드물게 폭발해 사라지고,
+/// 나머지는 섬광과 함께 몬스터를 부르거나 기묘한 느낌만 남긴다.
+fn magic_trap(
+    world: &mut GameWorld,
+    rng: &mut GameRng,
+    pos: Pos,
+    outcome: &mut EffectOutcome,
+) -> i16 {
+    if roll_die(rng, MAGIC_EXPLOSION_CHANCE) == 1 {
+        outcome.message(
+            MessagePriority::Danger,
+            "You are caught in a magical explosion!",
+        );
+        outcome
+            .events
+            .extend(remove_trap(world, world.current_level(), pos));
+        return roll_die(rng, 10);
+    }
+    let fate = roll_die(rng, 20);
+    match fate {
+        ..=9 => {
+            outcome.message(
+                MessagePriority::Warning,
+                "You are momentarily blinded by a flash of light!",
+            );
+            outcome.message(MessagePriority::Warning, "You hear a deafening roar!");
+            let turns = roll_die(rng, 5) as u16 + 9;
+            world.conditions.blindness_turns = world.conditions.blindness_turns.max(turns);
+            outcome.events.push(GameEvent::StatusChanged {
+                entity: world.player_id,
+                effect: StatusEffect::Blindness,
+                turns: world.conditions.blindness_turns,
+            });
+            let count = roll_die(rng, 4) as u8;
+            effects::create_monsters(world, rng, count, outcome);
+        }
+        10 | 11 => {}
+        _ => outcome.message(
+            MessagePriority::Info,
+            MAGIC_FLAVOR[(fate - 12) as usize % MAGIC_FLAVOR.len()],
+        ),
+    }
+    0
+}
+
 fn adjacent_and_current(origin: Pos) -> [Pos; 9] {
     [
         Pos {
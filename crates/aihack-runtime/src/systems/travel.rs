@@ -0,0 +1,43 @@
+//! `_` travel 명령의 길 찾기다.
+//!
+//! NetHack 3.6.7 `hack.c`의 `findtravelpath`를 따른다. 플레이어가 기억하는 지도 칸만 밟으며,
+//! 걸음마다 현재 위치에서 길을 다시 찾으므로 문이 닫히거나 새 칸을 보면 바로 반영된다.
+
+use std::collections::HashSet;
+
+use aihack_core::{
+    domain::tile::TileKind,
+    position::{Direction, Pos},
+};
+
+use crate::{systems::vision, world::GameWorld};
+
+/// 플레이어 위치에서 `target`까지 보이거나 기억하는 칸을 따라가는 가장 짧은 길이다.
+pub fn travel_path(world: &GameWorld, target: Pos) -> Option<Vec<Direction>> {
+    aihack_core::movement::find_travel_path(world.player_pos(), target, known_tiles(world))
+}
+
+/// 플레이어가 아는 지형이다. 관측의 `Observation::travel_route`와 같은 칸을 보도록 보이는 칸은
+/// 지금 모습을, 시야 밖은 기억한 모습을 쓰고 모르는 칸은 `None`이다.
+pub fn known_tiles(world: &GameWorld) -> impl Fn(Pos) -> Option<TileKind> + '_ {
+    let visible = vision::visible_positions(world)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    let memory = world.map_memory.level(world.current_level());
+    move |pos| {
+        if visible.contains(&pos) {
+            world
+                .current_map()
+                .tile(pos)
+                .ok()
+                .map(TileKind::observation_equivalent)
+        } else {
+            memory.and_then(|memory| memory.glyph(pos).map(|glyph| glyph.tile))
+        }
+    }
+}
+
+/// `target`으로 가는 길의 첫 걸음이다. 이미 도착했거나 길이 없으면 `None`이다.
+pub fn next_step(world: &GameWorld, target: Pos) -> Option<Direction> {
+    travel_path(world, target)?.first().copied()
+}
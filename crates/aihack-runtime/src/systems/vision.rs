@@ -1,14 +1,21 @@
 use aihack_core::{
-    domain::entity::ActorKind,
+    domain::{
+        entity::{ActorKind, EntityKind},
+        memory::RememberedGlyph,
+    },
     ids::{EntityId, LevelId},
     position::Pos,
 };
 
-use crate::world::GameWorld;
+use crate::{systems::light, world::GameWorld};
 
 pub use aihack_core::vision::DEFAULT_VISION_RADIUS;
 
+/// 플레이어 시야다. 눈이 먼 동안에는 자기 칸만 인식한다. 어두운 칸은 곁에 있을 때만 보인다.
 pub fn visible_positions(world: &GameWorld) -> Vec<Pos> {
+    if world.conditions.is_blind() {
+        return vec![world.player_pos()];
+    }
     visible_positions_from(world, world.player_pos())
 }
 
@@ -17,7 +24,11 @@ pub fn visible_positions_from(world: &GameWorld, origin: Pos) -> Vec<Pos> {
 }
 
 pub fn visible_positions_on_level(world: &GameWorld, level: LevelId, origin: Pos) -> Vec<Pos> {
-    aihack_core::vision::visible_positions(world.map(level), origin)
+    aihack_core::vision::visible_positions(
+        world.map(level),
+        origin,
+        &light::light_sources(world, level),
+    )
 }
 
 pub fn has_line_of_sight(world: &GameWorld, from: Pos, to: Pos) -> bool {
@@ -41,7 +52,11 @@ pub fn monster_has_line_of_sight_to_player(world: &GameWorld, monster: EntityId)
     }
     let (player_level, player_pos) = world.player_location();
     monster_level == player_level
-        && monster_pos.chebyshev_distance(player_pos) <= DEFAULT_VISION_RADIUS
+        && aihack_core::vision::within_vision_radius(
+            world.map(monster_level),
+            monster_pos,
+            player_pos,
+        )
         && has_line_of_sight_on_level(world, monster_level, monster_pos, player_pos)
 }
 
@@ -50,5 +65,52 @@ pub fn is_visible(world: &GameWorld, pos: Pos) -> bool {
 }
 
 pub fn is_visible_from(world: &GameWorld, from: Pos, pos: Pos) -> bool {
-    from.chebyshev_distance(pos) <= DEFAULT_VISION_RADIUS && has_line_of_sight(world, from, pos)
+    let level = world.current_level();
+    aihack_core::vision::is_visible(
+        world.map(level),
+        from,
+        pos,
+        &light::light_sources(world, level),
+    )
+}
+
+/// 지도 조명이나 켜진 광원으로 `pos`가 밝은지 여부다.
+pub fn is_lit(world: &GameWorld, pos: Pos) -> bool {
+    let level = world.current_level();
+    aihack_core::vision::is_lit(world.map(level), pos, &light::light_sources(world, level))
+}
+
+/// 지금 보이는 칸의 지형, 맨 위 물건, 몬스터를 현재 층 기억에 새긴다.
+pub fn remember_visible(world: &mut GameWorld) {
+    let level = world.current_level();
+    let glyphs = visible_positions(world)
+        .into_iter()
+        .filter_map(|pos| {
+            let tile = world.current_map().tile(pos).ok()?.observation_equivalent();
+            let object = world.entities.item_at(level, pos).and_then(|item| {
+                match world.entities.get(item)?.kind() {
+                    EntityKind::Item(kind) => Some(kind),
+                    _ => None,
+                }
+            });
+            let monster = world
+                .entities
+                .alive_actor_at(level, pos)
+                .and_then(|actor| world.entities.get(actor)?.actor_kind()?.monster_kind());
+            Some((
+                pos,
+                RememberedGlyph {
+                    tile,
+                    object,
+                    monster,
+                    lit: is_lit(world, pos),
+                },
+            ))
+        })
+        .collect::<Vec<_>>();
+    let (width, height) = (world.current_map().width, world.current_map().height);
+    let memory = world.map_memory.level_mut(level, width, height);
+    for (pos, glyph) in glyphs {
+        memory.remember(pos, glyph);
+    }
 }
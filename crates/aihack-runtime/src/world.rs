@@ -1,13 +1,18 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
 
 use aihack_content::ContentRegistry;
 use aihack_core::{
     domain::{
         inventory::Inventory,
-        item::ItemKind,
+        item::{ItemData, ItemKind},
         level::LevelRegistry,
         map::GameMap,
-        status::{HungerState, Status},
+        monster::{MonsterKind, MonsterTemplate},
+        status::{Encumbrance, HungerState, Status},
+        tile::TileKind,
     },
     error::ContentError,
     ids::{EntityId, LevelId},
@@ -26,10 +31,16 @@ pub const PHASE3_JACKAL_START: Pos = Pos { x: 6, y: 5 };
 pub const PHASE3_GOBLIN_START: Pos = Pos { x: 20, y: 12 };
 pub const PHASE4_POTION_START: Pos = Pos { x: 8, y: 5 };
 pub const PHASE7_WAND_START_CHARGES: u8 = 3;
+/// 이 무게를 넘게 들면 움직일 수 없고 더 주울 수도 없다.
+pub const CARRY_CAPACITY: i16 = 80;
+/// 이 무게까지는 부담 없이 움직인다. 넘으면 `CARRY_CAPACITY`까지 네 단계로 느려진다.
+pub const UNENCUMBERED_CAPACITY: i16 = 60;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GameWorld {
     state: WorldState<EntityStore>,
+    /// world를 만든 content다. 종류의 자료와 이름은 process 전역 표가 아니라 여기서 찾는다.
+    content: Arc<ContentRegistry>,
 }
 
 impl Deref for GameWorld {
@@ -54,9 +65,12 @@ impl GameWorld {
     pub fn try_fixture_phase5_with_registry(
         registry: &ContentRegistry,
     ) -> Result<Self, ContentError> {
-        Ok(Self {
+        let mut world = Self {
             state: crate::bootstrap::initial_world(registry)?,
-        })
+            content: Arc::new(registry.clone()),
+        };
+        crate::systems::vision::remember_visible(&mut world);
+        Ok(world)
     }
 
     pub fn fixture_phase2() -> Self {
@@ -136,13 +150,18 @@ impl GameWorld {
             .expect("Phase 5 world는 항상 player actor 위치를 가진다")
     }
 
+    /// 플레이어를 옮기고 자취(`player_track`)에 남긴다. 다른 층으로 가면 자취를 새로 시작한다.
     pub fn set_player_location(&mut self, level: LevelId, pos: Pos) {
         let player_id = self.player_id;
         assert!(
             self.entities.set_actor_location(player_id, level, pos),
             "Phase 5 world는 항상 player actor를 가진다"
         );
+        if level != self.current_level {
+            self.player_track.clear();
+        }
         self.current_level = level;
+        self.player_track.record(pos);
     }
 
     pub fn player_pos(&self) -> Pos {
@@ -169,7 +188,7 @@ impl GameWorld {
     pub fn identify_item_kind(&mut self, kind: ItemKind) {
         if !self.identified_items.contains(&kind) {
             self.identified_items.push(kind);
-            self.identified_items.sort_by_key(|kind| *kind as u8);
+            self.identified_items.sort();
         }
     }
 
@@ -198,6 +217,38 @@ impl GameWorld {
             .sum()
     }
 
+    /// 짐 무게에 따른 부담 단계다. NetHack `near_capacity()`의 다섯 단계를
+    /// `UNENCUMBERED_CAPACITY`와 `CARRY_CAPACITY` 사이에 고르게 나누고, 그 위는 과적이다.
+    pub fn encumbrance(&self) -> Encumbrance {
+        let weight = self.carried_weight();
+        if weight <= UNENCUMBERED_CAPACITY {
+            return Encumbrance::Unencumbered;
+        }
+        if weight > CARRY_CAPACITY {
+            return Encumbrance::Overloaded;
+        }
+        match (weight - UNENCUMBERED_CAPACITY - 1) * 4 / (CARRY_CAPACITY - UNENCUMBERED_CAPACITY) {
+            0 => Encumbrance::Burdened,
+            1 => Encumbrance::Stressed,
+            2 => Encumbrance::Strained,
+            _ => Encumbrance::Overtaxed,
+        }
+    }
+
+    /// 바닥의 `item`을 주워도 `CARRY_CAPACITY`를 넘지 않는지 본다.
+    pub fn can_lift(&self, item: EntityId) -> bool {
+        self.entities
+            .item_data(item)
+            .is_some_and(|data| self.carried_weight() + data.weight <= CARRY_CAPACITY)
+    }
+
+    /// `item`이 플레이어 발밑 바닥에 놓여 있는지 본다.
+    pub fn item_is_underfoot(&self, item: EntityId) -> bool {
+        self.entities
+            .items_at(self.current_level(), self.player_pos())
+            .contains(&item)
+    }
+
     pub fn status(&self) -> Status {
         Status {
             nutrition: self.nutrition,
@@ -220,11 +271,98 @@ impl GameWorld {
         self.status().hunger_state()
     }
 
+    /// Weak 이하로 굶주리는 동안은 NetHack `ATEMP(A_STR) = -1`처럼 힘이 1 낮다.
+    pub fn effective_strength(&self) -> u8 {
+        let penalty = matches!(
+            self.hunger_state(),
+            HungerState::Weak | HungerState::Fainting
+        );
+        self.strength.saturating_sub(u8::from(penalty))
+    }
+
     pub fn from_saved_world(saved: SavedWorldV1) -> Self {
+        Self::from_saved_world_with_registry(
+            saved,
+            aihack_content::registry()
+                .expect("embedded content registry must validate for saved worlds"),
+        )
+    }
+
+    /// 저장한 world를 `registry` content로 되살린다. 종류 검증은 [`Self::unknown_kinds`]로 한다.
+    pub fn from_saved_world_with_registry(saved: SavedWorldV1, registry: &ContentRegistry) -> Self {
         Self {
             state: saved.into(),
+            content: Arc::new(registry.clone()),
         }
     }
+
+    /// world를 만든 content registry다.
+    pub fn content(&self) -> &ContentRegistry {
+        &self.content
+    }
+
+    /// 이 world의 content로 `kind` 아이템의 자료를 만든다. content가 모르는 종류면 오류다.
+    pub fn item_kind_data(&self, kind: ItemKind) -> Result<ItemData, ContentError> {
+        aihack_content::item_data_from_registry(kind, &self.content)
+    }
+
+    /// 이 world의 content로 `kind` 몬스터의 template을 만든다. content가 모르는 종류면 오류다.
+    pub fn monster_template(&self, kind: MonsterKind) -> Result<MonsterTemplate, ContentError> {
+        aihack_content::monster_template_from_registry(kind, &self.content)
+    }
+
+    /// 이 world의 content가 아는 모든 아이템 종류다. 소원과 무작위 반지·물약은 이 목록에서 고른다.
+    pub fn item_kinds(&self) -> Vec<ItemKind> {
+        aihack_content::item_kinds(&self.content)
+    }
+
+    /// content가 선언한 아이템 이름이다. content가 모르는 종류면 content id를 쓴다.
+    pub fn item_name(&self, kind: ItemKind) -> &str {
+        self.content.item_name(kind)
+    }
+
+    /// content가 선언한 몬스터 이름이다. content가 모르는 종류면 content id를 쓴다.
+    pub fn monster_name(&self, kind: MonsterKind) -> &str {
+        self.content.monster_name(kind)
+    }
+
+    /// 이 world의 content가 선언하지 않은 몬스터와 아이템 종류의 content id다. 물건과 몬스터,
+    /// 지도 기억, 식별 목록을 모두 본다. 다른 content로 만든 저장본이면 비어 있지 않다.
+    pub fn unknown_kinds(&self) -> Vec<&'static str> {
+        let remembered = self
+            .map_memory
+            .levels
+            .iter()
+            .flat_map(|level| level.remembered().map(|(_, glyph)| glyph))
+            .collect::<Vec<_>>();
+        let items = self
+            .entities
+            .entities()
+            .iter()
+            .filter_map(|entity| match entity.kind() {
+                EntityKind::Item(kind) => Some(kind),
+                _ => None,
+            })
+            .chain(remembered.iter().filter_map(|glyph| glyph.object))
+            .chain(self.identified_items.iter().copied())
+            .filter(|kind| self.content.item_info(*kind).is_none())
+            .map(ItemKind::content_id);
+        let monsters = self
+            .entities
+            .entities()
+            .iter()
+            .filter_map(|entity| match entity.kind() {
+                EntityKind::Monster(kind) => Some(kind),
+                _ => None,
+            })
+            .chain(remembered.iter().filter_map(|glyph| glyph.monster))
+            .filter(|kind| self.content.monster_info(*kind).is_none())
+            .map(MonsterKind::content_id);
+        let mut unknown = items.chain(monsters).collect::<Vec<_>>();
+        unknown.sort_unstable();
+        unknown.dedup();
+        unknown
+    }
 }
 
 impl WorldInvariantView for GameWorld {
@@ -249,6 +387,15 @@ impl WorldInvariantView for GameWorld {
     fn inventory_owner(&self) -> EntityId {
         self.inventory.owner
     }
+    fn level_ids(&self) -> Vec<LevelId> {
+        self.levels.levels.iter().map(|level| level.id).collect()
+    }
+    fn level_size(&self, level: LevelId) -> Option<(i16, i16)> {
+        self.levels.map(level).map(|map| (map.width, map.height))
+    }
+    fn tile_at(&self, level: LevelId, pos: Pos) -> Option<TileKind> {
+        self.levels.map(level).and_then(|map| map.tile(pos).ok())
+    }
 }
 
 impl MovementWorld for GameWorld {
@@ -1,4 +1,4 @@
-use aihack_core::position::Direction;
+use aihack_core::{position::Direction, rng::GameRng};
 use aihack_runtime::{
     systems::{doors, stairs, traps},
     world::GameWorld,
@@ -7,8 +7,9 @@ use aihack_runtime::{
 #[test]
 fn runtime_exposes_environment_interaction_systems() {
     let mut world = GameWorld::fixture_without_monsters();
+    let mut rng = GameRng::new(42);
 
-    let _ = traps::search(&mut world);
+    let _ = traps::search(&mut world, &mut rng);
     assert_eq!(
         doors::door_state_in_direction(&world, Direction::East),
         None
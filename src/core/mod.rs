@@ -14,13 +14,15 @@ pub mod transaction;
 pub mod turn;
 pub mod world;
 
-pub use action::{ActionIntent, CommandIntent, NarrativeTopic};
+pub use action::{
+    ActionIntent, ApplyDirection, CommandIntent, NarrativeTopic, RepeatIntent, RunMode,
+};
 pub use error::ContentError;
 pub use event::GameEvent;
 pub use ids::{BranchId, EntityId, LevelId};
 pub use invariant::{InvariantReport, WorldInvariantError, WORLD_INVARIANT_COUNT};
 pub use observation::{
-    ActionSpace, EntityObservation, ItemObservation, Observation, PlayerObservation,
+    ActionSpace, EntityObservation, ItemObservation, LuckFeeling, Observation, PlayerObservation,
     RunStateSummary, TileObservation,
 };
 pub use position::{Delta, Direction, Pos};
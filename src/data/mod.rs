@@ -4,3 +4,7 @@ pub use aihack_content::*;
 pub mod schema {
     pub use aihack_content::schema::*;
 }
+
+pub mod generation {
+    pub use aihack_content::generation::*;
+}
@@ -2,6 +2,7 @@ pub mod combat;
 pub mod entity;
 pub mod inventory;
 pub mod item;
+pub mod kind;
 pub mod level;
 pub mod map;
 pub mod monster;
@@ -0,0 +1 @@
+pub use aihack_runtime::systems::terrain::*;
@@ -5,8 +5,16 @@
 //! its persisted fixture representation instead of borrowing the live world.
 
 use crate::{
-    core::{ids::LevelId, position::Pos, save::SavedWorldV1, GameSession, RunState},
-    domain::{item::ItemKind, map::GameMap, status::Status},
+    core::{
+        ids::LevelId, position::Pos, save::SavedWorldV1, ActionIntent, CommandIntent, EntityId,
+        GameEvent, GameSession, RunState,
+    },
+    domain::{
+        entity::{ActorStats, EntityLocation},
+        item::{try_item_data, BucStatus, ItemKind},
+        map::GameMap,
+        status::Status,
+    },
 };
 
 type SavedWorldConfigurator = Box<dyn FnOnce(&mut SavedWorldV1)>;
@@ -64,10 +72,27 @@ impl FixtureWorld<'_> {
     pub fn identify_item_kind(&mut self, kind: ItemKind) {
         if !self.saved.identified_items.contains(&kind) {
             self.saved.identified_items.push(kind);
-            self.saved.identified_items.sort_by_key(|kind| *kind as u8);
+            self.saved.identified_items.sort();
         }
     }
 
+    /// content 기본값으로 만든 아이템을 다음 inventory letter에 넣는다.
+    pub fn give_item(&mut self, kind: ItemKind) -> EntityId {
+        let owner = self.saved.player_id;
+        let item = self.saved.entities.spawn_item_with_data(
+            kind,
+            try_item_data(kind).expect("fixture items come from the embedded content"),
+            EntityLocation::Inventory { owner },
+        );
+        let letter = self
+            .saved
+            .inventory
+            .add_existing_with_next_letter(item)
+            .expect("fixture inventory must have a free letter");
+        self.saved.entities.set_item_letter(item, letter);
+        item
+    }
+
     pub fn saved(&mut self) -> &mut SavedWorldV1 {
         self.saved
     }
@@ -117,3 +142,71 @@ impl SessionBuilder {
         result
     }
 }
+
+/// 몬스터를 모두 치운 `seed` 세션을 `configure`로 고친다. 규칙 하나만 보는 테스트가 처음 놓인
+/// 자칼과 고블린에 흔들리지 않게 한다.
+pub fn session_with<T>(
+    seed: u64,
+    configure: impl FnOnce(&mut FixtureWorld<'_>) -> T,
+) -> (GameSession, T) {
+    let mut session = GameSession::new_for_playing(seed);
+    let result = SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters();
+        configure(world)
+    });
+    (session, result)
+}
+
+/// 몬스터를 치운 세션의 소지품에 `buc` 상태의 `kind` 하나를 넣는다.
+pub fn session_with_item(seed: u64, kind: ItemKind, buc: BucStatus) -> (GameSession, EntityId) {
+    session_with(seed, |world| {
+        let item = world.give_item(kind);
+        world.saved().entities.set_item_buc(item, buc);
+        item
+    })
+}
+
+/// 몬스터를 치운 세션의 소지품에 `kinds`를 차례로 넣는다.
+pub fn session_with_items(seed: u64, kinds: &[ItemKind]) -> (GameSession, Vec<EntityId>) {
+    session_with(seed, |world| {
+        kinds
+            .iter()
+            .map(|kind| world.give_item(*kind))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// `events` 가운데 `expected`와 똑같은 메시지가 있는지 본다.
+pub fn has_message(events: &[GameEvent], expected: &str) -> bool {
+    events.iter().any(|event| {
+        matches!(
+            event,
+            GameEvent::Message { text, .. } if text == expected
+        )
+    })
+}
+
+/// 관측의 합법 명령에 `intent`가 있는지 본다.
+pub fn is_legal(session: &GameSession, intent: CommandIntent) -> bool {
+    session
+        .observation()
+        .action_space
+        .commands
+        .contains(&ActionIntent::Command(intent))
+}
+
+pub fn player_hp(session: &GameSession) -> i16 {
+    player_stats(session).hp
+}
+
+pub fn player_max_hp(session: &GameSession) -> i16 {
+    player_stats(session).max_hp
+}
+
+fn player_stats(session: &GameSession) -> &ActorStats {
+    session
+        .world()
+        .entities()
+        .actor_stats(session.world().player_id())
+        .expect("fixture session must retain the player actor")
+}
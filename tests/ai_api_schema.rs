@@ -1,12 +1,15 @@
 use serde_json::Value;
 
-use aihack::core::{save, ActionIntent, ActionSpace, CommandIntent, GameSession, Observation};
+use aihack::core::{
+    observation::OBSERVATION_SCHEMA_VERSION, save, ActionIntent, ActionSpace, CommandIntent,
+    GameSession, Observation,
+};
 
 #[test]
 fn observation_fixture_roundtrip() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
-    let fixture = include_str!("fixtures/observation_v1.json");
+    let fixture = include_str!("fixtures/observation_v2.json");
     let fixture_value: Value = serde_json::from_str(fixture).unwrap();
     let fixture_object = fixture_value.as_object().unwrap();
     let decoded: Observation =
@@ -41,6 +44,22 @@ fn observation_fixture_roundtrip() {
     );
     assert_eq!(fixture_object.get("seed"), object.get("seed"));
     assert_eq!(fixture_object.get("turn"), object.get("turn"));
+    assert_eq!(decoded.player.luck, observation.player.luck);
+    assert_eq!(
+        decoded.visible_entities[0].kind,
+        observation.visible_entities[0].kind
+    );
+    assert_eq!(decoded.inventory[0].kind, observation.inventory[0].kind);
+}
+
+#[test]
+fn observation_v1_fixture_is_not_read_as_the_current_schema() {
+    let fixture: Value =
+        serde_json::from_str(include_str!("fixtures/observation_v1.json")).unwrap();
+
+    assert_eq!(fixture["schema_version"], 1);
+    assert_ne!(OBSERVATION_SCHEMA_VERSION, 1);
+    assert!(serde_json::from_value::<Observation>(fixture).is_err());
 }
 
 #[test]
@@ -2,7 +2,7 @@ use aihack::{
     core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, RunState},
     domain::{
         entity::{EntityKind, EntityLocation},
-        item::{item_data, ItemKind},
+        item::{try_item_data, ItemKind},
         monster::MonsterAiKind,
         status::HungerState,
     },
@@ -155,7 +155,7 @@ fn monster_passive_content_changes_player_status() {
     .unwrap();
     let passive_monsters = MONSTERS_TOML.replacen(
         "difficulty=1",
-        "difficulty=1\npassive=\"paralyze_on_melee\"",
+        "difficulty=1\npassive={damage_type=\"paralyze\", damage=\"2d1\"}",
         1,
     );
     let passive_registry = aihack::data::ContentRegistry::from_toml_sources(
@@ -222,10 +222,10 @@ fn item_base_price_changes_actual_game_over_score() {
 }
 
 #[test]
-fn prayer_created_luck_changes_the_next_attack_roll() {
+fn premature_prayer_luck_penalty_changes_the_next_attack_roll() {
     let mut plain = GameSession::new_for_playing(1234);
-    let mut lucky = GameSession::new_for_playing(1234);
-    for session in [&mut plain, &mut lucky] {
+    let mut unlucky = GameSession::new_for_playing(1234);
+    for session in [&mut plain, &mut unlucky] {
         aihack::testing::SessionBuilder::mutate(session, |world| {
             world.saved().entities.set_alive(EntityId(3), false);
             let jackal = world
@@ -238,13 +238,16 @@ fn prayer_created_luck_changes_the_next_attack_roll() {
         });
     }
 
-    assert!(plain.submit(CommandIntent::Wait).accepted);
-    assert!(lucky.submit(CommandIntent::Pray).accepted);
+    // 두 세션이 같은 RNG 소비를 하도록 함께 기도한 뒤, 한쪽의 luck만 되돌린다.
+    for session in [&mut plain, &mut unlucky] {
+        assert!(session.submit(CommandIntent::Pray).accepted);
+    }
+    aihack::testing::SessionBuilder::mutate(&mut plain, |world| world.saved().luck = 0);
     assert_eq!(plain.snapshot().luck, 0);
-    assert_eq!(lucky.snapshot().luck, 1);
+    assert_eq!(unlucky.snapshot().luck, -3);
 
     let plain_attack = plain.submit(CommandIntent::Move(Direction::East));
-    let lucky_attack = lucky.submit(CommandIntent::Move(Direction::East));
+    let unlucky_attack = unlucky.submit(CommandIntent::Move(Direction::East));
     let attack_roll = |events: &[GameEvent]| {
         events
             .iter()
@@ -255,8 +258,8 @@ fn prayer_created_luck_changes_the_next_attack_roll() {
             .expect("bump attack must produce an attack resolution")
     };
     assert_eq!(
-        attack_roll(&lucky_attack.events),
-        attack_roll(&plain_attack.events) + 1
+        attack_roll(&unlucky_attack.events),
+        attack_roll(&plain_attack.events) - 3
     );
 }
 
@@ -302,7 +305,7 @@ fn armor_content_bonus_changes_player_defense_state() {
     );
     assert!(session.submit(CommandIntent::Pickup).accepted);
     let armor = inventory_item(&session, ItemKind::ArmorLeather);
-    let content_bonus = item_data(ItemKind::ArmorLeather).ac_bonus;
+    let content_bonus = try_item_data(ItemKind::ArmorLeather).unwrap().ac_bonus;
     let before_ac = session
         .world()
         .entities()
@@ -368,21 +371,42 @@ fn jackal_death_creates_an_edible_corpse_that_changes_hunger() {
             .submit(CommandIntent::Move(Direction::East))
             .accepted
     );
-    assert!(session.submit(CommandIntent::Pickup).accepted);
-    let corpse = inventory_item(&session, ItemKind::CorpseJackal);
+    let corpse = session
+        .world()
+        .entities()
+        .items_at(
+            session.world().current_level(),
+            session.world().player_pos(),
+        )
+        .into_iter()
+        .find(|item| {
+            session
+                .world()
+                .entities()
+                .item_data(*item)
+                .map(|data| data.kind)
+                == Some(ItemKind::CorpseJackal)
+        })
+        .expect("seed 42 jackal must leave a corpse");
+    let observation = session.observation();
+    assert!(!observation.legal_actions.contains(&CommandIntent::Pickup));
+    assert!(observation
+        .legal_actions
+        .contains(&CommandIntent::Eat { item: corpse }));
     let before_eat = session.snapshot();
     assert_eq!(session.world().hunger_state(), HungerState::Hungry);
 
-    let eaten = session.submit(CommandIntent::Eat { item: corpse });
+    let outcome = session.submit(CommandIntent::Eat { item: corpse });
     let after_eat = session.snapshot();
 
-    assert!(eaten.accepted);
-    assert_eq!(after_eat.nutrition, before_eat.nutrition + 49);
-    assert_eq!(session.world().hunger_state(), HungerState::NotHungry);
+    assert!(outcome.accepted);
     assert_eq!(
         session.world().entities().item_location(corpse),
         Some(EntityLocation::Consumed)
     );
+    assert_eq!(outcome.turns_elapsed, 7);
+    assert_eq!(after_eat.nutrition, before_eat.nutrition + 250 - 7);
+    assert_eq!(session.world().hunger_state(), HungerState::NotHungry);
     assert_ne!(before_eat.stable_hash(), after_eat.stable_hash());
 }
 
@@ -401,8 +425,9 @@ fn eating_food_changes_nutrition_hunger_and_item_lifecycle() {
 
     assert!(outcome.accepted);
     assert!(outcome.turn_advanced);
+    assert_eq!(outcome.turns_elapsed, 5);
     assert_eq!(before.nutrition, 100);
-    assert_eq!(after.nutrition, 899);
+    assert_eq!(after.nutrition, 895);
     assert_eq!(session.world().hunger_state(), HungerState::NotHungry);
     assert_eq!(
         session.world().entities().item_location(food),
@@ -3,12 +3,19 @@ use aihack::{
     domain::{
         combat::DamageRoll,
         entity::{ActorStats, EntityKind, EntityStore, Faction},
-        monster::{monster_template, MonsterKind},
+        monster::{try_monster_template, MonsterAttacks, MonsterKind, MonsterTemplate},
         player::adventurer_template,
     },
     systems::combat,
 };
 
+fn melee_damage(template: &MonsterTemplate) -> DamageRoll {
+    template
+        .attacks
+        .first_melee()
+        .map_or(DamageRoll::none(), |attack| attack.damage)
+}
+
 #[test]
 fn entity_store_assigns_stable_nonzero_ids() {
     let mut store = EntityStore::new();
@@ -62,16 +69,16 @@ fn player_factory_matches_phase3_spec() {
 
 #[test]
 fn monster_factories_match_phase3_spec() {
-    let jackal = monster_template(MonsterKind::Jackal);
-    let goblin = monster_template(MonsterKind::Goblin);
-    let floating_eye = monster_template(MonsterKind::FloatingEye);
+    let jackal = try_monster_template(MonsterKind::Jackal).unwrap();
+    let goblin = try_monster_template(MonsterKind::Goblin).unwrap();
+    let floating_eye = try_monster_template(MonsterKind::FloatingEye).unwrap();
 
     assert_eq!(
         (
             jackal.hp,
             jackal.ac,
             jackal.hit_bonus,
-            jackal.attack_profile.damage
+            melee_damage(&jackal)
         ),
         (4, 0, 0, DamageRoll { dice: 1, sides: 2 })
     );
@@ -80,7 +87,7 @@ fn monster_factories_match_phase3_spec() {
             goblin.hp,
             goblin.ac,
             goblin.hit_bonus,
-            goblin.attack_profile.damage
+            melee_damage(&goblin)
         ),
         (6, 1, 1, DamageRoll { dice: 1, sides: 4 })
     );
@@ -89,7 +96,7 @@ fn monster_factories_match_phase3_spec() {
             floating_eye.hp,
             floating_eye.ac,
             floating_eye.hit_bonus,
-            floating_eye.attack_profile.damage
+            melee_damage(&floating_eye)
         ),
         (8, 2, 0, DamageRoll { dice: 0, sides: 0 })
     );
@@ -111,10 +118,22 @@ fn hit_formula_uses_d20_bonuses_and_defense() {
             damage_reduction: 0,
             damage: DamageRoll { dice: 1, sides: 4 },
             weapon_hit_bonus: 1,
+            attacks: MonsterAttacks::default(),
             speed: 12,
             ai_kind: None,
             passive: None,
+            opens_doors: false,
+            ranged: None,
+            ranged_cooldown: 0,
             difficulty: 0,
+            trapped_turns: 0,
+            frozen_turns: 0,
+            asleep: false,
+            confused: false,
+            blinded_turns: 0,
+            fleeing: false,
+            flee_turns: 0,
+            movement: 12,
         },
     );
     let defender = store.spawn(
@@ -130,10 +149,22 @@ fn hit_formula_uses_d20_bonuses_and_defense() {
             damage_reduction: 0,
             damage: DamageRoll { dice: 1, sides: 2 },
             weapon_hit_bonus: 0,
+            attacks: MonsterAttacks::default(),
             speed: 12,
             ai_kind: None,
             passive: None,
+            opens_doors: false,
+            ranged: None,
+            ranged_cooldown: 0,
             difficulty: 0,
+            trapped_turns: 0,
+            frozen_turns: 0,
+            asleep: false,
+            confused: false,
+            blinded_turns: 0,
+            fleeing: false,
+            flee_turns: 0,
+            movement: 12,
         },
     );
     let a = store.get(attacker).unwrap();
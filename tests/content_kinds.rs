@@ -0,0 +1,200 @@
+use aihack::{
+    core::{error::GameError, ContentError, GameSession, Pos},
+    domain::{entity::EntityKind, item::ItemKind, kind::GlyphColor, monster::MonsterKind},
+};
+
+const ITEMS_TOML: &str = include_str!("../crates/aihack-content/src/data/items.toml");
+const MONSTERS_TOML: &str = include_str!("../crates/aihack-content/src/data/monsters.toml");
+const LEVEL_1_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_1.toml");
+const LEVEL_2_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_2.toml");
+
+const RUBY: &str = r#"
+[[item]]
+id="item.gem.ruby"
+name="ruby"
+kind="weapon"
+glyph="*"
+color="red"
+weight=1
+slot="melee"
+hit_bonus=0
+damage="1d3"
+base_price=3500
+material="mineral"
+"#;
+
+const NEWT: &str = r#"
+[[monster]]
+id="monster.newt"
+name="newt"
+glyph=":"
+color="yellow"
+hp=2
+ac=8
+hit_bonus=0
+ai="wander"
+speed=6
+difficulty=1
+attacks=[{type="bite", damage="1d3"}]
+[monster.corpse]
+weight=10
+nutrition=20
+chance=1
+"#;
+
+const SPAWNS: &str = r#"
+[[monster]]
+id="monster.newt"
+pos=[7,6]
+[[item]]
+id="item.gem.ruby"
+pos=[5,6]
+"#;
+
+fn registry(items: &str, monsters: &str) -> Result<aihack::data::ContentRegistry, ContentError> {
+    aihack::data::ContentRegistry::from_toml_sources(
+        1,
+        items,
+        monsters,
+        &[
+            ("main_1.toml", &format!("{LEVEL_1_TOML}{SPAWNS}")),
+            ("main_2.toml", LEVEL_2_TOML),
+        ],
+    )
+}
+
+fn newt_session() -> GameSession {
+    let registry = registry(
+        &format!("{ITEMS_TOML}{RUBY}"),
+        &format!("{MONSTERS_TOML}{NEWT}"),
+    )
+    .unwrap();
+    GameSession::try_new_for_playing_with_registry(42, &registry).unwrap()
+}
+
+#[test]
+fn toml_entry_alone_adds_a_monster_and_an_item() {
+    let session = newt_session();
+
+    let content = session.world().content();
+    let newt = MonsterKind::from_content_id("monster.newt").unwrap();
+    let info = content.monster_info(newt).unwrap();
+    assert_eq!(info.name, "newt");
+    assert_eq!(info.glyph, ':');
+    assert_eq!(info.color, GlyphColor::Yellow);
+    let ruby = ItemKind::from_content_id("item.gem.ruby").unwrap();
+    let info = content.item_info(ruby).unwrap();
+    assert_eq!(info.name, "ruby");
+    assert_eq!(info.color, GlyphColor::Red);
+    let corpse = newt.corpse_kind().unwrap();
+    assert_eq!(corpse.content_id(), "item.corpse.newt");
+    assert_eq!(content.item_name(corpse), "newt corpse");
+
+    let observation = session.observation();
+    assert!(observation.visible_entities.iter().any(|entity| {
+        entity.kind == EntityKind::Monster(newt) && entity.pos == Pos { x: 7, y: 6 }
+    }));
+    assert!(observation
+        .remembered_objects
+        .iter()
+        .any(|object| object.kind == ruby && object.pos == Pos { x: 5, y: 6 }));
+}
+
+#[test]
+fn observation_and_save_carry_content_ids() {
+    let session = newt_session();
+
+    let observation = serde_json::to_string(&session.observation()).unwrap();
+    assert!(observation.contains("\"monster.newt\""));
+    assert!(observation.contains("\"item.gem.ruby\""));
+
+    let save = serde_json::to_string(&session.to_save_data()).unwrap();
+    assert!(save.contains("\"monster.newt\""));
+    let restored = GameSession::from_save_data_with_registry(
+        serde_json::from_str(&save).unwrap(),
+        session.world().content(),
+    )
+    .unwrap();
+    assert_eq!(
+        restored.observation().visible_entities,
+        session.observation().visible_entities
+    );
+}
+
+#[test]
+fn save_with_kinds_outside_the_content_is_rejected() {
+    let save = newt_session().to_save_data();
+
+    assert!(matches!(
+        GameSession::from_save_data(save),
+        Err(GameError::Content(ContentError::UnknownReference { target, .. }))
+            if target == "item.corpse.newt" || target == "item.gem.ruby" || target == "monster.newt"
+    ));
+}
+
+#[test]
+fn unknown_content_id_is_rejected_on_deserialize() {
+    assert!(serde_json::from_str::<ItemKind>("\"item.nope\"").is_err());
+    assert!(serde_json::from_str::<MonsterKind>("\"monster.nope\"").is_err());
+}
+
+#[test]
+fn registries_keep_their_own_names_for_the_same_id() {
+    let red = registry(
+        &format!("{ITEMS_TOML}{RUBY}"),
+        &format!("{MONSTERS_TOML}{NEWT}"),
+    )
+    .unwrap();
+    let blue = registry(
+        &format!(
+            "{ITEMS_TOML}{}",
+            RUBY.replace("name=\"ruby\"", "name=\"sapphire\"")
+        ),
+        &format!("{MONSTERS_TOML}{NEWT}"),
+    )
+    .unwrap();
+
+    let ruby = ItemKind::from_content_id("item.gem.ruby").unwrap();
+    assert_eq!(red.item_name(ruby), "ruby");
+    assert_eq!(blue.item_name(ruby), "sapphire");
+    assert_eq!(aihack::data::registry().unwrap().item_info(ruby), None);
+}
+
+#[test]
+fn builtin_kinds_use_content_names_and_glyphs() {
+    let content = aihack::data::registry().unwrap();
+
+    assert_eq!(ItemKind::Dagger.content_id(), "item.weapon.dagger");
+    let dagger = content.item_info(ItemKind::Dagger).unwrap();
+    assert_eq!(dagger.name, "dagger");
+    assert_eq!(dagger.glyph, ')');
+    let jackal = content.monster_info(MonsterKind::Jackal).unwrap();
+    assert_eq!(jackal.glyph, 'd');
+    assert_eq!(jackal.color, GlyphColor::Brown);
+    assert_eq!(
+        serde_json::to_string(&MonsterKind::FloatingEye).unwrap(),
+        "\"monster.floating_eye\""
+    );
+}
+
+#[test]
+fn legacy_variant_names_still_load() {
+    let dagger: ItemKind = serde_json::from_str("\"Dagger\"").unwrap();
+    assert_eq!(dagger, ItemKind::Dagger);
+    let teleport: ItemKind = serde_json::from_str("\"ScrollLevelTeleport\"").unwrap();
+    assert_eq!(teleport, ItemKind::ScrollTeleportation);
+    let goblin: MonsterKind = serde_json::from_str("\"Goblin\"").unwrap();
+    assert_eq!(goblin, MonsterKind::Goblin);
+}
+
+#[test]
+fn unknown_color_and_wide_glyph_are_rejected() {
+    let monsters = format!("{MONSTERS_TOML}{}", NEWT.replace("yellow", "plaid"));
+    assert!(matches!(
+        registry(ITEMS_TOML, &monsters),
+        Err(ContentError::UnknownReference { target, .. }) if target == "plaid"
+    ));
+
+    let monsters = format!("{MONSTERS_TOML}{}", NEWT.replace("\":\"", "\"::\""));
+    assert!(registry(ITEMS_TOML, &monsters).is_err());
+}
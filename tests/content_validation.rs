@@ -6,6 +6,7 @@ use aihack::{
 const ITEMS: &str = r#"
 [[item]]
 id = "item.weapon.dagger"
+name = "dagger"
 kind = "weapon"
 glyph = ")"
 weight = 10
@@ -14,11 +15,12 @@ damage = "1d4"
 const MONSTERS: &str = r#"
 [[monster]]
 id = "monster.jackal"
+name = "jackal"
 glyph = "d"
 hp = 4
 ac = 0
 hit_bonus = 0
-damage = "1d2"
+attacks = [{ type = "bite", damage = "1d2" }]
 ai = "wander"
 speed = 12
 difficulty = 1
@@ -159,6 +161,108 @@ fn causal_numeric_content_rejects_invalid_ranges() {
     ));
 }
 
+#[test]
+fn scroll_effects_must_be_declared_as_typed_tables() {
+    let levels = [("one", LEVEL_1), ("two", LEVEL_2)];
+    let scroll = |body: &str| {
+        format!(
+            "{ITEMS}\n[[item]]\nid = \"item.scroll.fire\"\nname = \"fire scroll\"\nkind = \"scroll\"\nglyph = \"?\"\nweight = 5\n{body}"
+        )
+    };
+
+    assert!(registry(
+        &scroll("[item.scroll]\neffect = { type = \"fire\", damage = \"1d4\", radius = 1 }"),
+        MONSTERS,
+        &levels
+    )
+    .is_ok());
+    for invalid in [
+        "effect = \"fire\"",
+        "[item.scroll]\neffect = { type = \"meteor\" }",
+        "[item.scroll]\neffect = { type = \"fire\", damage = \"1d4\", radius = 40 }",
+        "[item.scroll]\neffect = { type = \"create_monster\", count = 0 }",
+    ] {
+        assert!(
+            matches!(
+                registry(&scroll(invalid), MONSTERS, &levels),
+                Err(ContentError::Parse { .. })
+            ),
+            "{invalid}"
+        );
+    }
+}
+
+#[test]
+fn monster_corpses_need_positive_weight_and_known_intrinsics() {
+    let levels = [("one", LEVEL_1), ("two", LEVEL_2)];
+    let corpse = |body: &str| format!("{MONSTERS}\n[monster.corpse]\n{body}");
+
+    assert!(registry(
+        ITEMS,
+        &corpse("weight = 300\nnutrition = 250\nchance = 2\neffects = [{ type = \"poisonous\" }]"),
+        &levels
+    )
+    .is_ok());
+    for invalid in [
+        "weight = 0\nnutrition = 250\nchance = 2",
+        "weight = 300\nnutrition = 250\nchance = 0",
+        "weight = 300\nnutrition = 250\nchance = 2\neffects = [{ type = \"radioactive\" }]",
+    ] {
+        assert!(
+            matches!(
+                registry(ITEMS, &corpse(invalid), &levels),
+                Err(ContentError::Parse { .. })
+            ),
+            "{invalid}"
+        );
+    }
+    assert!(matches!(
+        registry(
+            ITEMS,
+            &corpse("weight = 10\nnutrition = 10\nchance = 2\neffects = [{ type = \"intrinsic\", intrinsic = \"flight\", chance = 1 }]"),
+            &levels
+        ),
+        Err(ContentError::UnknownReference { .. })
+    ));
+}
+
+#[test]
+fn level_traps_must_be_known_and_holes_need_a_level_below() {
+    let with_trap = |level: &str, table: &str, trap: &str| {
+        format!("{level}\n[[{table}]]\npos = [3, 3]\ntrap = \"{trap}\"\ntile = \"floor\"")
+    };
+
+    assert!(registry(
+        ITEMS,
+        MONSTERS,
+        &[
+            ("one", &with_trap(LEVEL_1, "hidden_trap", "trap_door")),
+            ("two", &with_trap(LEVEL_2, "hidden_trap", "bear_trap")),
+        ]
+    )
+    .is_ok());
+    let visible = format!("{LEVEL_2}\n[[trap]]\npos = [3, 3]\ntrap = \"arrow\"");
+    assert!(registry(ITEMS, MONSTERS, &[("one", LEVEL_1), ("two", &visible)]).is_ok());
+    for (one, two) in [
+        (
+            with_trap(LEVEL_1, "hidden_trap", "spiked_pit"),
+            LEVEL_2.to_owned(),
+        ),
+        (
+            LEVEL_1.to_owned(),
+            with_trap(LEVEL_2, "hidden_trap", "hole"),
+        ),
+    ] {
+        assert!(
+            matches!(
+                registry(ITEMS, MONSTERS, &[("one", &one), ("two", &two)]),
+                Err(ContentError::UnknownReference { .. })
+            ),
+            "{one}\n{two}"
+        );
+    }
+}
+
 #[test]
 fn unsupported_schema_and_unpaired_stairs_are_typed_errors() {
     assert!(matches!(
@@ -0,0 +1,265 @@
+use aihack::{
+    core::{CommandIntent, EntityId, GameEvent, GameSession, Pos, RunState},
+    domain::{
+        combat::DeathCause,
+        entity::EntityLocation,
+        item::{try_item_data, CorpseEffects, ItemKind},
+        monster::{try_monster_template, MonsterKind},
+        status::Intrinsic,
+    },
+    testing::{has_message, session_with, SessionBuilder},
+};
+
+fn session_with_corpse(seed: u64, kind: ItemKind, age: u16) -> (GameSession, EntityId) {
+    session_with(seed, |world| {
+        let corpse = world.give_item(kind);
+        world.saved().entities.set_item_age(corpse, age);
+        corpse
+    })
+}
+
+fn session_with_effects(seed: u64, effects: CorpseEffects) -> (GameSession, EntityId) {
+    session_with(seed, |world| {
+        let mut data = try_item_data(ItemKind::CorpseGoblin).unwrap();
+        data.corpse = Some(effects);
+        let owner = world.saved().player_id;
+        let corpse = world.saved().entities.spawn_item_with_data(
+            ItemKind::CorpseGoblin,
+            data,
+            EntityLocation::Inventory { owner },
+        );
+        let letter = world
+            .saved()
+            .inventory
+            .add_existing_with_next_letter(corpse)
+            .unwrap();
+        world.saved().entities.set_item_letter(corpse, letter);
+        corpse
+    })
+}
+
+fn eat_until_consumed(session: &mut GameSession, corpse: EntityId) -> Vec<GameEvent> {
+    let mut events = Vec::new();
+    for _ in 0..40 {
+        if session.world().entities().item_location(corpse) == Some(EntityLocation::Consumed) {
+            break;
+        }
+        let outcome = session.submit(CommandIntent::Eat { item: corpse });
+        if !outcome.accepted {
+            assert!(session.submit(CommandIntent::Wait).accepted);
+        }
+        events.extend(outcome.events);
+    }
+    events
+}
+
+#[test]
+fn every_monster_declares_corpse_content() {
+    for kind in [
+        MonsterKind::Jackal,
+        MonsterKind::Goblin,
+        MonsterKind::FloatingEye,
+        MonsterKind::WaterMoccasin,
+    ] {
+        let corpse = try_monster_template(kind).unwrap().corpse.unwrap();
+        let corpse_kind = kind.corpse_kind().unwrap();
+        let data = try_item_data(corpse_kind).unwrap();
+        assert_eq!(data.weight, corpse.weight, "{kind:?}");
+        assert_eq!(data.nutrition, Some(corpse.nutrition), "{kind:?}");
+        assert_eq!(corpse_kind.corpse_of(), Some(kind));
+    }
+    let jackal = try_monster_template(MonsterKind::Jackal)
+        .unwrap()
+        .corpse
+        .unwrap();
+    assert_eq!((jackal.weight, jackal.nutrition), (300, 250));
+    let eye = try_monster_template(MonsterKind::FloatingEye)
+        .unwrap()
+        .corpse
+        .unwrap();
+    assert_eq!(
+        eye.effects.intrinsic.map(|grant| grant.intrinsic),
+        Some(Intrinsic::Telepathy)
+    );
+}
+
+#[test]
+fn carried_corpse_rots_away() {
+    let (mut session, corpse) = session_with_corpse(42, ItemKind::CorpseJackal, 249);
+
+    let outcome = session.submit(CommandIntent::Wait);
+
+    assert!(outcome
+        .events
+        .contains(&GameEvent::CorpseRotted { item: corpse }));
+    assert!(has_message(
+        &outcome.events,
+        "Your jackal corpse rots away."
+    ));
+    assert!(!session.world().inventory().contains(corpse));
+    assert_eq!(
+        session.world().entities().item_location(corpse),
+        Some(EntityLocation::Consumed)
+    );
+}
+
+#[test]
+fn tainted_corpse_causes_fatal_food_poisoning() {
+    let (mut session, corpse) = session_with_corpse(42, ItemKind::CorpseJackal, 200);
+
+    let outcome = session.submit(CommandIntent::Eat { item: corpse });
+
+    assert!(has_message(
+        &outcome.events,
+        "Ulch - that meat was tainted!"
+    ));
+    let turns = session.observation().player.food_poisoning_turns;
+    assert!((9..=19).contains(&turns), "{turns}");
+    for _ in 0..turns {
+        assert!(session.submit(CommandIntent::Wait).accepted);
+    }
+    match session.run_state() {
+        RunState::GameOver { cause, .. } => assert_eq!(cause, DeathCause::FoodPoisoning),
+        state => panic!("expected food poisoning, got {state:?}"),
+    }
+}
+
+#[test]
+fn corpse_takes_several_bites_and_is_observed_partly_eaten() {
+    let (mut session, corpse) = session_with_corpse(42, ItemKind::CorpseJackal, 0);
+    let pos = session.world().player_pos();
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.spawn_monster(
+            MonsterKind::FloatingEye,
+            Pos {
+                x: pos.x + 1,
+                y: pos.y,
+            },
+        );
+    });
+
+    let outcome = session.submit(CommandIntent::Eat { item: corpse });
+    assert!(outcome.accepted);
+    assert!(has_message(&outcome.events, "You stop eating."));
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters()
+    });
+    let observed = session
+        .observation()
+        .inventory
+        .into_iter()
+        .find(|item| item.item == corpse)
+        .unwrap();
+    assert!(observed.partly_eaten);
+
+    let outcome = session.submit(CommandIntent::Eat { item: corpse });
+    assert!(has_message(&outcome.events, "You resume your meal."));
+    assert!(has_message(
+        &outcome.events,
+        "You finish eating the jackal corpse."
+    ));
+    assert_eq!(
+        session.world().entities().item_location(corpse),
+        Some(EntityLocation::Consumed)
+    );
+}
+
+#[test]
+fn acidic_and_poisonous_corpses_hurt_on_the_first_bite() {
+    let (mut session, corpse) = session_with_effects(
+        42,
+        CorpseEffects {
+            acidic: true,
+            ..CorpseEffects::default()
+        },
+    );
+    let outcome = session.submit(CommandIntent::Eat { item: corpse });
+    assert!(has_message(
+        &outcome.events,
+        "You have a very bad case of stomach acid."
+    ));
+    assert!(outcome
+        .events
+        .iter()
+        .any(|event| matches!(event, GameEvent::EntityDamaged { .. })));
+
+    let mut poisoned = 0;
+    for seed in 0..10 {
+        let (mut session, corpse) = session_with_effects(
+            seed,
+            CorpseEffects {
+                poisonous: true,
+                ..CorpseEffects::default()
+            },
+        );
+        let outcome = session.submit(CommandIntent::Eat { item: corpse });
+        if has_message(&outcome.events, "Ecch - that must have been poisonous!") {
+            assert!(session.observation().player.strength < 14);
+            poisoned += 1;
+        }
+    }
+    assert!(poisoned > 0);
+}
+
+#[test]
+fn poison_resistance_blocks_poisonous_corpse_strength_loss() {
+    for seed in 0..10 {
+        let (mut session, corpse) = session_with_effects(
+            seed,
+            CorpseEffects {
+                poisonous: true,
+                ..CorpseEffects::default()
+            },
+        );
+        SessionBuilder::mutate(&mut session, |world| {
+            world.saved().conditions.poison_resistance = true;
+        });
+        let outcome = session.submit(CommandIntent::Eat { item: corpse });
+        if has_message(&outcome.events, "Ecch - that must have been poisonous!") {
+            assert!(has_message(
+                &outcome.events,
+                "You seem unaffected by the poison."
+            ));
+        }
+        assert_eq!(session.observation().player.strength, 14);
+    }
+}
+
+#[test]
+fn petrifying_corpse_kills_on_the_first_bite() {
+    let (mut session, corpse) = session_with_effects(
+        42,
+        CorpseEffects {
+            petrifying: true,
+            ..CorpseEffects::default()
+        },
+    );
+
+    let outcome = session.submit(CommandIntent::Eat { item: corpse });
+
+    assert!(has_message(&outcome.events, "You turn to stone."));
+    match session.run_state() {
+        RunState::GameOver { cause, .. } => assert_eq!(
+            cause,
+            DeathCause::Petrification {
+                item: ItemKind::CorpseGoblin
+            }
+        ),
+        state => panic!("expected petrification, got {state:?}"),
+    }
+}
+
+#[test]
+fn finishing_a_floating_eye_corpse_grants_telepathy() {
+    let (mut session, corpse) = session_with_corpse(42, ItemKind::CorpseFloatingEye, 0);
+    let player = session.world().player_id();
+
+    let events = eat_until_consumed(&mut session, corpse);
+
+    assert!(events.contains(&GameEvent::IntrinsicGained {
+        entity: player,
+        intrinsic: Intrinsic::Telepathy,
+    }));
+    assert!(has_message(&events, "You feel a strange mental acuity."));
+    assert!(session.observation().player.telepathic);
+}
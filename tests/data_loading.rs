@@ -52,7 +52,9 @@ fn load_monsters_includes_jackal() {
     assert!(jackal.is_some(), "jackal이 monsters.toml에 있어야 한다.");
     let jackal = jackal.unwrap();
     assert_eq!(jackal.hp, 4);
-    assert_eq!(jackal.damage, "1d2");
+    assert_eq!(jackal.attacks.len(), 1);
+    assert_eq!(jackal.attacks[0].attack_type, "bite");
+    assert_eq!(jackal.attacks[0].damage, "1d2");
 }
 
 /// [v0.2.0] Phase 20: load_level이 main:1 레벨 데이터를 반환한다.
@@ -73,7 +75,7 @@ fn game_world_status_returns_status() {
     let status = session.world().status();
     assert_eq!(status.nutrition, 900);
     assert_eq!(status.luck, 0);
-    assert_eq!(status.prayer_cooldown, 0);
+    assert_eq!(status.prayer_cooldown, 300);
     assert_eq!(status.paralysis_turns, 0);
     assert!(!status.hallucinating);
 }
@@ -0,0 +1,273 @@
+use aihack::{
+    core::{ApplyDirection, CommandIntent, Direction, EntityId, GameEvent, GameSession, Pos},
+    domain::{
+        item::ItemKind,
+        level::PHASE5_LEVEL2_ID,
+        monster::MonsterKind,
+        player::InterruptReason,
+        tile::{DoorState, TileKind, TrapKind},
+    },
+    testing::{has_message, is_legal, session_with, SessionBuilder},
+};
+
+/// main:1 방 사이 벽의 한 칸이다.
+const WALL: Pos = Pos { x: 12, y: 4 };
+const JACKAL: Pos = Pos { x: 6, y: 5 };
+const GOBLIN: Pos = Pos { x: 20, y: 12 };
+
+fn session_holding(kind: ItemKind, pos: Pos) -> (GameSession, EntityId) {
+    session_with(42, |world| {
+        world.set_player_pos(pos);
+        world.give_item(kind)
+    })
+}
+
+#[test]
+fn a_pick_axe_tunnels_through_a_wall_over_several_turns() {
+    let (mut session, pick) = session_holding(ItemKind::PickAxe, Pos { x: 11, y: 4 });
+    let dig = CommandIntent::Apply {
+        item: pick,
+        direction: Some(ApplyDirection::Toward(Direction::East)),
+    };
+    assert!(is_legal(&session, dig));
+
+    let outcome = session.submit(dig);
+    assert!(outcome.turn_advanced);
+    assert!(outcome.turns_elapsed > 1);
+    assert!(has_message(&outcome.events, "You start digging."));
+    assert!(outcome.events.iter().any(|event| {
+        matches!(
+            event,
+            GameEvent::TileDug {
+                pos,
+                from: TileKind::Wall,
+                to: TileKind::Corridor,
+            } if *pos == WALL
+        )
+    }));
+
+    assert_eq!(session.turn(), outcome.turns_elapsed);
+    assert_eq!(session.world().digging, None);
+    assert!(!is_legal(&session, dig));
+    assert!(
+        session
+            .submit(CommandIntent::Move(Direction::East))
+            .accepted
+    );
+    assert_eq!(session.world().player_pos(), WALL);
+}
+
+#[test]
+fn applying_a_pick_axe_without_a_direction_asks_for_one() {
+    let (mut session, pick) = session_holding(ItemKind::PickAxe, Pos { x: 11, y: 4 });
+
+    let outcome = session.submit(CommandIntent::Apply {
+        item: pick,
+        direction: None,
+    });
+
+    assert!(!outcome.accepted);
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::CommandRejected { reason } if reason == "In what direction do you want to dig?"
+    )));
+    assert!(!is_legal(
+        &session,
+        CommandIntent::Apply {
+            item: pick,
+            direction: None,
+        }
+    ));
+}
+
+#[test]
+fn interrupted_dig_progress_is_saved_and_resumed() {
+    let (mut session, pick) = session_holding(ItemKind::PickAxe, Pos { x: 11, y: 4 });
+    let eye = SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .spawn_monster(MonsterKind::FloatingEye, Pos { x: 10, y: 4 })
+    });
+    let dig = CommandIntent::Apply {
+        item: pick,
+        direction: Some(ApplyDirection::Toward(Direction::East)),
+    };
+
+    let first = session.submit(dig);
+    assert!(first.events.iter().any(|event| matches!(
+        event,
+        GameEvent::OccupationInterrupted {
+            reason: InterruptReason::MonsterAppeared { monster },
+            ..
+        } if *monster == eye
+    )));
+    assert!(has_message(&first.events, "You stop digging."));
+    assert_eq!(
+        session.world().digging.map(|progress| progress.pos),
+        Some(WALL)
+    );
+    assert_eq!(session.world().current_map().tile(WALL), Ok(TileKind::Wall));
+
+    let json = serde_json::to_string(&session.to_save_data()).unwrap();
+    let mut restored = GameSession::from_save_data(serde_json::from_str(&json).unwrap()).unwrap();
+    assert_eq!(restored.world().digging, session.world().digging);
+
+    SessionBuilder::mutate(&mut restored, |world| {
+        world.saved().entities.clear_monsters()
+    });
+    let resumed = restored.submit(dig);
+    assert!(has_message(&resumed.events, "You continue digging."));
+    assert_eq!(
+        restored.world().current_map().tile(WALL),
+        Ok(TileKind::Corridor)
+    );
+}
+
+#[test]
+fn undiggable_walls_resist_picks_and_wands() {
+    let mut session = GameSession::new_for_playing(42);
+    let (pick, wand) = SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters();
+        world.set_player_location(PHASE5_LEVEL2_ID, Pos { x: 17, y: 4 });
+        (
+            world.give_item(ItemKind::PickAxe),
+            world.give_item(ItemKind::WandDigging),
+        )
+    });
+    let dig = CommandIntent::Apply {
+        item: pick,
+        direction: Some(ApplyDirection::Toward(Direction::East)),
+    };
+
+    assert!(!is_legal(&session, dig));
+    let outcome = session.submit(dig);
+    assert!(!outcome.accepted);
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::CommandRejected { reason } if reason.contains("This wall is too hard to dig into.")
+    )));
+
+    let outcome = session.submit(CommandIntent::Zap {
+        item: wand,
+        direction: Direction::East,
+    });
+    assert!(has_message(&outcome.events, "The wall glows then fades."));
+    assert_eq!(
+        session.world().current_map().tile(Pos { x: 18, y: 4 }),
+        Ok(TileKind::Wall)
+    );
+}
+
+#[test]
+fn digging_down_makes_a_pit_then_a_hole_to_the_next_level() {
+    let start = Pos { x: 5, y: 5 };
+    let (mut session, pick) = session_holding(ItemKind::PickAxe, start);
+    let dig = CommandIntent::Apply {
+        item: pick,
+        direction: Some(ApplyDirection::Down),
+    };
+    assert!(is_legal(&session, dig));
+
+    let mut pit = false;
+    let mut fell = false;
+    for _ in 0..40 {
+        let outcome = session.submit(dig);
+        pit |= has_message(&outcome.events, "You dig a pit in the floor.");
+        if outcome
+            .events
+            .iter()
+            .any(|event| matches!(event, GameEvent::LevelChanged { .. }))
+        {
+            assert!(has_message(
+                &outcome.events,
+                "You dig a hole through the floor."
+            ));
+            fell = true;
+            break;
+        }
+    }
+
+    assert!(pit);
+    assert!(fell);
+    assert_eq!(session.world().current_level(), PHASE5_LEVEL2_ID);
+    assert_eq!(
+        session
+            .world()
+            .map(aihack::core::LevelId::main(1))
+            .tile(start),
+        Ok(TileKind::Trap(TrapKind::Hole))
+    );
+    assert!(!is_legal(&session, dig));
+    let outcome = session.submit(dig);
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::CommandRejected { reason } if reason.contains("The floor here is too hard to dig in.")
+    )));
+}
+
+#[test]
+fn a_wand_of_digging_razes_doors_and_bores_through_walls() {
+    let (mut session, wand) = session_holding(ItemKind::WandDigging, Pos { x: 5, y: 5 });
+    let outcome = session.submit(CommandIntent::Zap {
+        item: wand,
+        direction: Direction::East,
+    });
+
+    assert!(outcome.accepted);
+    assert!(has_message(&outcome.events, "The door is razed!"));
+    let map = session.world().current_map();
+    assert_eq!(
+        map.tile(Pos { x: 10, y: 5 }),
+        Ok(TileKind::Door(DoorState::NoDoor))
+    );
+    assert!(session.world().validate_invariants().is_valid());
+}
+
+#[test]
+fn digging_wakes_nearby_sleeping_monsters() {
+    let mut session = GameSession::new_for_playing(42);
+    let (pick, jackal, goblin) = SessionBuilder::mutate(&mut session, |world| {
+        let monsters = world
+            .saved()
+            .entities
+            .hostile_monsters_on_level(aihack::core::LevelId::main(1));
+        let at = |world: &mut aihack::testing::FixtureWorld, pos: Pos| {
+            *monsters
+                .iter()
+                .find(|id| {
+                    world
+                        .saved()
+                        .entities
+                        .actor_location(**id)
+                        .is_some_and(|(_, at)| at == pos)
+                })
+                .unwrap()
+        };
+        let jackal = at(world, JACKAL);
+        let goblin = at(world, GOBLIN);
+        for id in [jackal, goblin] {
+            world.saved().entities.actor_stats_mut(id).unwrap().asleep = true;
+        }
+        (world.give_item(ItemKind::PickAxe), jackal, goblin)
+    });
+
+    let outcome = session.submit(CommandIntent::Apply {
+        item: pick,
+        direction: Some(ApplyDirection::Down),
+    });
+
+    assert!(outcome
+        .events
+        .iter()
+        .any(|event| matches!(event, GameEvent::MonsterWokeUp { entity } if *entity == jackal)));
+    let asleep = |id| {
+        session
+            .world()
+            .entities()
+            .actor_stats(id)
+            .is_some_and(|stats| stats.asleep)
+    };
+    assert!(!asleep(jackal));
+    assert!(asleep(goblin));
+}
@@ -0,0 +1,121 @@
+use aihack::{
+    core::{CommandIntent, GameEvent, GameSession, Pos},
+    domain::{
+        entity::EntityLocation,
+        item::ItemKind,
+        player::{InterruptReason, Occupation},
+        tile::{DoorState, TileKind},
+    },
+    systems::explore,
+    testing::{session_with, SessionBuilder},
+    ui::tui::{key_to_candidate, UiCommandCandidate},
+};
+
+/// main:1의 넓은 방 동쪽을 벽으로 채우고 (11,10)에서 동쪽으로 (28,10)까지 어두운 통로를 판다.
+/// 남은 방은 밝아서 다 보이므로 아직 모르는 곳은 통로뿐이다.
+fn corridor_session() -> GameSession {
+    session_with(42, |world| {
+        world.saved().nutrition = 500;
+        world.set_player_pos(Pos { x: 5, y: 10 });
+        let map = world.current_map_mut();
+        for y in 1..=18 {
+            for x in 12..=38 {
+                map.set_tile(Pos { x, y }, TileKind::Wall).unwrap();
+            }
+        }
+        for x in 12..=28 {
+            map.set_tile(Pos { x, y: 10 }, TileKind::Corridor).unwrap();
+        }
+    })
+    .0
+}
+
+fn stop_reasons(events: &[GameEvent]) -> Vec<InterruptReason> {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::OccupationInterrupted {
+                occupation: Occupation::Exploring,
+                reason,
+            } => Some(*reason),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn explore_walks_the_corridor_to_its_end_and_reports_why_it_stopped() {
+    let mut session = corridor_session();
+    assert!(session
+        .observation()
+        .action_space
+        .commands
+        .contains(&aihack::core::ActionIntent::Command(CommandIntent::Explore)));
+
+    let outcome = session.submit(CommandIntent::Explore);
+
+    assert!(outcome.accepted);
+    assert!(outcome.turns_elapsed > 1);
+    assert!(explore::is_known(session.world(), Pos { x: 28, y: 10 }));
+    assert_eq!(stop_reasons(&outcome.events), [InterruptReason::Explored]);
+
+    let again = session.submit(CommandIntent::Explore);
+    assert!(!again.accepted);
+}
+
+#[test]
+fn explore_opens_a_closed_door_on_the_way() {
+    let mut session = corridor_session();
+    let door = Pos { x: 20, y: 10 };
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .current_map_mut()
+            .set_tile(door, TileKind::Door(DoorState::Closed))
+            .unwrap();
+    });
+
+    let outcome = session.submit(CommandIntent::Explore);
+
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::DoorChanged { pos, to: DoorState::Open, .. } if *pos == door
+    )));
+    assert!(explore::is_known(session.world(), Pos { x: 28, y: 10 }));
+}
+
+#[test]
+fn explore_picks_up_autopickup_items_and_stops_on_others() {
+    let mut session = corridor_session();
+    let scroll_pos = Pos { x: 17, y: 10 };
+    let potion_pos = Pos { x: 22, y: 10 };
+    SessionBuilder::mutate(&mut session, |world| {
+        let entities = &mut world.saved().entities;
+        entities.spawn_item(
+            ItemKind::ScrollIdentify,
+            EntityLocation::on_main_level(scroll_pos),
+        );
+        // 시작 짐에 물약을 더하면 짐 부담이 생기므로 autopickup하지 않는다.
+        entities.spawn_item(
+            ItemKind::PotionHealing,
+            EntityLocation::on_main_level(potion_pos),
+        );
+    });
+
+    let outcome = session.submit(CommandIntent::Explore);
+
+    assert!(outcome
+        .events
+        .iter()
+        .any(|event| matches!(event, GameEvent::ItemPickedUp { .. })));
+    assert_eq!(session.world().player_pos(), potion_pos);
+    assert_eq!(stop_reasons(&outcome.events), [InterruptReason::ItemsHere]);
+}
+
+#[test]
+fn explore_is_bound_to_x_in_the_tui() {
+    let session = corridor_session();
+    assert_eq!(
+        key_to_candidate('X', &session.observation()),
+        Some(UiCommandCandidate::Command(CommandIntent::Explore))
+    );
+}
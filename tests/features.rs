@@ -0,0 +1,278 @@
+use aihack::{
+    core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, Pos},
+    domain::{
+        entity::{EntityKind, EntityLocation},
+        item::{BucStatus, ItemKind},
+        level::PHASE5_LEVEL2_ID,
+        tile::TileKind,
+    },
+    testing::{has_message, session_with, SessionBuilder},
+};
+
+const DAGGER: EntityId = EntityId(5);
+const HERE: Pos = Pos { x: 5, y: 5 };
+
+fn session_on(seed: u64, tile: TileKind) -> GameSession {
+    session_with(seed, |world| {
+        world.current_map_mut().set_tile(HERE, tile).unwrap();
+    })
+    .0
+}
+
+fn feature_removed(events: &[GameEvent], tile: TileKind) -> bool {
+    events.iter().any(|event| {
+        matches!(
+            event,
+            GameEvent::FeatureChanged { from, to: TileKind::Floor, .. } if *from == tile
+        )
+    })
+}
+
+#[test]
+fn second_level_places_one_of_each_feature() {
+    let session = GameSession::new_for_playing(42);
+    let tiles = session.world().map(PHASE5_LEVEL2_ID).tiles();
+
+    for feature in [
+        TileKind::Altar,
+        TileKind::Fountain,
+        TileKind::Sink,
+        TileKind::Throne,
+        TileKind::Grave,
+    ] {
+        assert_eq!(tiles.iter().filter(|tile| **tile == feature).count(), 1);
+    }
+}
+
+#[test]
+fn dropping_on_an_altar_reveals_curses() {
+    let mut session = session_on(42, TileKind::Altar);
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .set_item_buc(DAGGER, BucStatus::Cursed);
+    });
+
+    let outcome = session.submit(CommandIntent::Drop { item: DAGGER });
+
+    assert!(outcome.accepted);
+    assert!(has_message(
+        &outcome.events,
+        "There is a black flash as it hits the altar."
+    ));
+}
+
+#[test]
+fn sacrifice_absolves_a_negative_alignment() {
+    let mut session = session_on(42, TileKind::Altar);
+    let corpse = SessionBuilder::mutate(&mut session, |world| {
+        world.saved().alignment = -1;
+        world.give_item(ItemKind::CorpseJackal)
+    });
+
+    let outcome = session.submit(CommandIntent::Offer { item: corpse });
+
+    assert!(outcome.turn_advanced);
+    assert!(has_message(
+        &outcome.events,
+        "Your sacrifice is consumed in a flash of fire!"
+    ));
+    assert!(has_message(&outcome.events, "You feel partially absolved."));
+    assert_eq!(session.world().alignment, 0);
+    assert_eq!(
+        session.world().entities().item_location(corpse),
+        Some(EntityLocation::Consumed)
+    );
+}
+
+#[test]
+fn old_corpses_are_not_accepted_and_offering_needs_an_altar() {
+    let mut session = session_on(42, TileKind::Altar);
+    let corpse = SessionBuilder::mutate(&mut session, |world| {
+        let corpse = world.give_item(ItemKind::CorpseJackal);
+        world.saved().entities.set_item_age(corpse, 50);
+        corpse
+    });
+
+    let outcome = session.submit(CommandIntent::Offer { item: corpse });
+    assert!(has_message(&outcome.events, "Nothing happens."));
+    assert!(session.world().inventory().contains(corpse));
+
+    let mut session = session_on(42, TileKind::Floor);
+    let corpse = SessionBuilder::mutate(&mut session, |world| {
+        world.give_item(ItemKind::CorpseJackal)
+    });
+    assert!(
+        !session
+            .submit(CommandIntent::Offer { item: corpse })
+            .accepted
+    );
+}
+
+#[test]
+fn fountains_eventually_dry_up() {
+    let mut session = session_on(42, TileKind::Fountain);
+
+    let mut dried = false;
+    for _ in 0..30 {
+        let outcome = session.submit(CommandIntent::QuaffFountain);
+        assert!(outcome.accepted);
+        if feature_removed(&outcome.events, TileKind::Fountain) {
+            dried = true;
+            break;
+        }
+    }
+
+    assert!(dried);
+    assert_eq!(
+        session.world().current_map().tile(HERE),
+        Ok(TileKind::Floor)
+    );
+    assert!(!session.submit(CommandIntent::QuaffFountain).accepted);
+}
+
+#[test]
+fn a_pending_wish_creates_the_item_without_using_a_turn() {
+    let mut session = session_on(42, TileKind::Floor);
+    SessionBuilder::mutate(&mut session, |world| world.saved().pending_wishes = 1);
+    assert!(session
+        .observation()
+        .action_space
+        .commands
+        .iter()
+        .any(|action| matches!(
+            action,
+            aihack::core::ActionIntent::Command(CommandIntent::Wish {
+                kind: ItemKind::Luckstone
+            })
+        )));
+
+    let outcome = session.submit(CommandIntent::Wish {
+        kind: ItemKind::Luckstone,
+    });
+
+    assert!(outcome.accepted);
+    assert!(!outcome.turn_advanced);
+    let item = outcome
+        .events
+        .iter()
+        .find_map(|event| match event {
+            GameEvent::WishGranted { item, .. } => Some(*item),
+            _ => None,
+        })
+        .unwrap();
+    assert!(session.world().inventory().contains(item));
+    assert_eq!(session.world().pending_wishes, 0);
+    assert!(
+        !session
+            .submit(CommandIntent::Wish {
+                kind: ItemKind::Luckstone
+            })
+            .accepted
+    );
+}
+
+#[test]
+fn rings_dropped_down_a_sink_identify_themselves() {
+    let mut session = session_on(42, TileKind::Sink);
+    let ring = SessionBuilder::mutate(&mut session, |world| {
+        world.give_item(ItemKind::RingAdornment)
+    });
+
+    let outcome = session.submit(CommandIntent::Drop { item: ring });
+
+    assert!(has_message(&outcome.events, "You drop it down the drain."));
+    assert!(has_message(
+        &outcome.events,
+        "The faucets flash brightly for a moment."
+    ));
+    assert!(session.world().is_item_identified(ItemKind::RingAdornment));
+}
+
+#[test]
+fn kicking_a_sink_loots_at_most_one_ring() {
+    let mut session = session_on(42, TileKind::Floor);
+    let sink = Pos { x: 4, y: 5 };
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .current_map_mut()
+            .set_tile(sink, TileKind::Sink)
+            .unwrap();
+        world
+            .saved()
+            .entities
+            .actor_stats_mut(EntityId(1))
+            .unwrap()
+            .hp = 999;
+    });
+
+    let rings_at_sink = |session: &GameSession| {
+        session
+            .world()
+            .entities()
+            .items_at(session.world().current_level(), sink)
+            .into_iter()
+            .filter(|item| {
+                matches!(
+                    session
+                        .world()
+                        .entities()
+                        .get(*item)
+                        .map(|entity| entity.kind()),
+                    Some(EntityKind::Item(
+                        ItemKind::RingAdornment | ItemKind::RingProtection
+                    ))
+                )
+            })
+            .count()
+    };
+    for _ in 0..200 {
+        assert!(
+            session
+                .submit(CommandIntent::Kick(Direction::West))
+                .accepted
+        );
+    }
+
+    assert_eq!(rings_at_sink(&session), 1);
+    assert!(session.world().current_map().is_looted(sink));
+}
+
+#[test]
+fn thrones_can_vanish_after_being_sat_on() {
+    let vanished = (0..40)
+        .filter(|seed| {
+            let mut session = session_on(*seed, TileKind::Throne);
+            SessionBuilder::mutate(&mut session, |world| {
+                world
+                    .saved()
+                    .entities
+                    .actor_stats_mut(EntityId(1))
+                    .unwrap()
+                    .hp = 999;
+            });
+            let outcome = session.submit(CommandIntent::Sit);
+            assert!(has_message(
+                &outcome.events,
+                "You sit on the opulent throne."
+            ));
+            feature_removed(&outcome.events, TileKind::Throne)
+        })
+        .count();
+
+    assert!(vanished > 0 && vanished < 40, "{vanished}");
+}
+
+#[test]
+fn sitting_elsewhere_only_prints_a_message() {
+    let mut session = session_on(42, TileKind::Floor);
+
+    let outcome = session.submit(CommandIntent::Sit);
+
+    assert!(outcome.turn_advanced);
+    assert!(has_message(
+        &outcome.events,
+        "Having fun sitting on the floor?"
+    ));
+}
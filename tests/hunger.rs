@@ -0,0 +1,121 @@
+use aihack::{
+    core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, RunState},
+    domain::{combat::DeathCause, item::ItemKind, status::HungerState},
+    testing::{has_message, session_with},
+};
+
+const FOOD_RATION: EntityId = EntityId(6);
+
+fn session_with_nutrition(seed: u64, nutrition: i16) -> GameSession {
+    session_with(seed, |world| world.saved().nutrition = nutrition).0
+}
+
+#[test]
+fn crossing_a_hunger_threshold_emits_transition_and_message() {
+    let mut session = session_with_nutrition(42, 151);
+
+    let outcome = session.submit(CommandIntent::Wait);
+
+    assert!(outcome.events.contains(&GameEvent::HungerChanged {
+        entity: session.world().player_id(),
+        from: HungerState::NotHungry,
+        to: HungerState::Hungry,
+    }));
+    assert!(has_message(
+        &outcome.events,
+        "You are beginning to feel hungry."
+    ));
+
+    let mut weak = session_with_nutrition(42, 51);
+    let outcome = weak.submit(CommandIntent::Wait);
+    assert!(has_message(
+        &outcome.events,
+        "You are beginning to feel weak."
+    ));
+}
+
+#[test]
+fn fainting_from_hunger_leaves_the_player_helpless() {
+    let mut session = session_with_nutrition(42, 1);
+
+    let outcome = session.submit(CommandIntent::Wait);
+
+    assert!(has_message(&outcome.events, "You faint from lack of food."));
+    assert_eq!(session.world().status().paralysis_turns, 10);
+    assert!(
+        !session
+            .submit(CommandIntent::Move(Direction::East))
+            .accepted
+    );
+}
+
+#[test]
+fn starving_past_the_limit_kills_the_player() {
+    let mut session = session_with_nutrition(42, -400);
+
+    for _ in 0..500 {
+        if matches!(session.run_state(), RunState::GameOver { .. }) {
+            break;
+        }
+        assert!(session.submit(CommandIntent::Wait).accepted);
+    }
+
+    match session.run_state() {
+        RunState::GameOver { cause, .. } => assert_eq!(cause, DeathCause::Starvation),
+        state => panic!("expected starvation, got {state:?}"),
+    }
+}
+
+#[test]
+fn eating_while_satiated_chokes_or_vomits() {
+    let mut choked = 0;
+    for seed in 0..20 {
+        let mut session = session_with_nutrition(seed, 1950);
+
+        let outcome = session.submit(CommandIntent::Eat { item: FOOD_RATION });
+
+        assert!(outcome.accepted);
+        match session.run_state() {
+            RunState::GameOver { cause, .. } => {
+                assert_eq!(
+                    cause,
+                    DeathCause::Choked {
+                        item: ItemKind::FoodRation
+                    }
+                );
+                assert!(has_message(&outcome.events, "You choke over your food."));
+                choked += 1;
+            }
+            _ => {
+                assert!(has_message(
+                    &outcome.events,
+                    "You stuff yourself and then vomit voluminously."
+                ));
+                assert_eq!(session.world().status().nutrition, 1109);
+            }
+        }
+    }
+    assert!(choked > 0);
+}
+
+#[test]
+fn eating_while_satiated_below_the_choking_limit_only_warns() {
+    let mut session = session_with_nutrition(42, 1100);
+
+    let outcome = session.submit(CommandIntent::Eat { item: FOOD_RATION });
+
+    assert!(has_message(
+        &outcome.events,
+        "You're having a hard time getting all of it down."
+    ));
+    assert_eq!(session.run_state(), RunState::Playing);
+}
+
+#[test]
+fn weakness_from_hunger_lowers_observed_strength() {
+    let session = session_with_nutrition(42, 900);
+    assert_eq!(session.observation().player.strength, 14);
+
+    let weak = session_with_nutrition(42, 40);
+    assert_eq!(weak.observation().player.strength, 13);
+}
@@ -3,7 +3,7 @@ use aihack::{
     domain::{
         entity::{EntityKind, EntityLocation},
         inventory::InventoryLetter,
-        item::{item_data, ConsumableEffect, ItemClass, ItemKind, WandEffect},
+        item::{try_item_data, ConsumableEffect, ItemClass, ItemKind, WandEffect},
     },
 };
 
@@ -18,12 +18,12 @@ fn stand_on_potion(session: &mut GameSession) {
 
 #[test]
 fn item_factories_match_spec_data() {
-    let dagger = item_data(ItemKind::Dagger);
-    let food = item_data(ItemKind::FoodRation);
-    let potion = item_data(ItemKind::PotionHealing);
-    let wand = item_data(ItemKind::WandMagicMissile);
-    let scroll = item_data(ItemKind::ScrollReveal);
-    let rock = item_data(ItemKind::Rock);
+    let dagger = try_item_data(ItemKind::Dagger).unwrap();
+    let food = try_item_data(ItemKind::FoodRation).unwrap();
+    let potion = try_item_data(ItemKind::PotionHealing).unwrap();
+    let wand = try_item_data(ItemKind::WandMagicMissile).unwrap();
+    let scroll = try_item_data(ItemKind::ScrollReveal).unwrap();
+    let rock = try_item_data(ItemKind::Rock).unwrap();
 
     assert_eq!(dagger.class, ItemClass::Weapon);
     assert_eq!(dagger.glyph, ')');
@@ -142,6 +142,31 @@ fn pickup_assigns_next_letter_and_event_order() {
     );
 }
 
+#[test]
+fn picking_up_gold_adds_to_purse_without_a_letter() {
+    let mut session = GameSession::new_for_playing(42);
+    aihack::testing::SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters();
+        world.set_player_pos(Pos { x: 30, y: 3 });
+    });
+    let inventory = session.observation().inventory.len();
+
+    let outcome = session.submit(CommandIntent::Pickup);
+
+    assert!(outcome.accepted);
+    assert!(outcome
+        .events
+        .iter()
+        .any(|event| matches!(event, GameEvent::GoldPickedUp { amount: 25, .. })));
+    assert_eq!(session.world().gold(), 25);
+    assert_eq!(session.observation().inventory.len(), inventory);
+    assert!(session
+        .world()
+        .entities()
+        .items_at(session.world().current_level(), Pos { x: 30, y: 3 })
+        .is_empty());
+}
+
 #[test]
 fn pickup_without_item_is_rejected_without_turn() {
     let mut session = GameSession::new_for_playing(42);
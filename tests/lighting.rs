@@ -0,0 +1,123 @@
+use aihack::{
+    core::{ActionIntent, CommandIntent, EntityId, GameSession, Pos},
+    domain::{item::ItemKind, level::PHASE5_LEVEL2_ID, tile::TileKind},
+    systems::{light::LAMP_FUEL, vision::visible_positions},
+    testing::{has_message, session_with, SessionBuilder},
+};
+
+/// main:2의 어두운 영역 한가운데다.
+const DARK: Pos = Pos { x: 30, y: 3 };
+
+fn session_in_the_dark() -> GameSession {
+    session_with(42, |world| {
+        world.set_player_location(PHASE5_LEVEL2_ID, DARK);
+    })
+    .0
+}
+
+fn give_lamp(session: &mut GameSession) -> EntityId {
+    SessionBuilder::mutate(session, |world| world.give_item(ItemKind::OilLamp))
+}
+
+#[test]
+fn levels_declare_dark_regions_and_unlit_corridors() {
+    let session = GameSession::new_for_playing(42);
+    let map = session.world().map(PHASE5_LEVEL2_ID);
+
+    assert!(!map.is_lit(DARK));
+    assert!(map.is_lit(Pos { x: 24, y: 3 }));
+    assert_eq!(map.tile(Pos { x: 20, y: 18 }), Ok(TileKind::Corridor));
+    assert!(!map.is_lit(Pos { x: 20, y: 18 }));
+    assert_eq!(map.vision_radius(), None);
+    assert_eq!(session.world().current_map().vision_radius(), Some(8));
+}
+
+#[test]
+fn a_dark_room_shows_only_adjacent_squares_but_lit_floor_stays_visible_far_away() {
+    let session = session_in_the_dark();
+    let visible = visible_positions(session.world());
+
+    assert!(visible.contains(&Pos { x: 31, y: 3 }));
+    assert!(!visible.contains(&Pos { x: 33, y: 3 }));
+    assert!(visible.contains(&Pos { x: 24, y: 15 }));
+    assert!(session
+        .observation()
+        .visible_tiles
+        .iter()
+        .any(|tile| tile.pos == (Pos { x: 31, y: 3 }) && !tile.lit));
+}
+
+#[test]
+fn a_lit_lamp_lights_the_squares_around_the_player() {
+    let mut session = session_in_the_dark();
+    let lamp = give_lamp(&mut session);
+    assert!(session
+        .observation()
+        .action_space
+        .commands
+        .contains(&ActionIntent::Command(CommandIntent::Apply {
+            item: lamp,
+            direction: None
+        })));
+
+    let outcome = session.submit(CommandIntent::Apply {
+        item: lamp,
+        direction: None,
+    });
+
+    assert!(outcome.turn_advanced);
+    assert!(has_message(&outcome.events, "Your lamp is now on."));
+    let visible = visible_positions(session.world());
+    assert!(visible.contains(&Pos { x: 33, y: 3 }));
+    assert!(!visible.contains(&Pos { x: 34, y: 3 }));
+
+    let outcome = session.submit(CommandIntent::Apply {
+        item: lamp,
+        direction: None,
+    });
+    assert!(has_message(&outcome.events, "Your lamp is now off."));
+    assert!(!visible_positions(session.world()).contains(&Pos { x: 33, y: 3 }));
+}
+
+#[test]
+fn a_lamp_goes_out_when_its_oil_is_spent() {
+    let mut session = session_in_the_dark();
+    let lamp = give_lamp(&mut session);
+    session.submit(CommandIntent::Apply {
+        item: lamp,
+        direction: None,
+    });
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.set_item_age(lamp, LAMP_FUEL - 1);
+    });
+
+    let outcome = session.submit(CommandIntent::Wait);
+
+    assert!(has_message(
+        &outcome.events,
+        "Your lamp has run out of oil."
+    ));
+    assert!(!session.world().entities().item_lit(lamp));
+    let outcome = session.submit(CommandIntent::Apply {
+        item: lamp,
+        direction: None,
+    });
+    assert!(has_message(&outcome.events, "This lamp has no oil."));
+    assert!(!session.world().entities().item_lit(lamp));
+}
+
+#[test]
+fn reading_light_brightens_a_dark_room() {
+    let mut session = session_in_the_dark();
+    let scroll =
+        SessionBuilder::mutate(&mut session, |world| world.give_item(ItemKind::ScrollLight));
+
+    assert!(
+        session
+            .submit(CommandIntent::Read { item: scroll })
+            .accepted
+    );
+
+    assert!(session.world().current_map().is_lit(DARK));
+    assert!(visible_positions(session.world()).contains(&Pos { x: 33, y: 3 }));
+}
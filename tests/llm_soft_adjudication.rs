@@ -108,6 +108,7 @@ fn high_contrast_and_reduced_motion_keep_semantic_text_and_dismiss_is_ui_only()
     assert!(lines[0].contains("LLM_UNAVAILABLE"));
     let panel_lines = aihack::ui::tui::render_panels::inspect_lines(
         &app.observation(),
+        app.content(),
         None,
         aihack::ui::tui::UiPanel::Inspect,
         &lines,
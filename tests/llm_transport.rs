@@ -412,14 +412,14 @@ fn local_llm_service_round_trips_narrative_on_the_bounded_worker() {
 fn public_request_contract_exposes_versioned_projection_and_action_space() {
     let input = public_narrative_input();
 
-    assert_eq!(input.schema_version, 1);
+    assert_eq!(input.schema_version, LLM_SCHEMA_VERSION);
     assert_eq!(input.observation.turn, input.revision.turn);
     assert!(!input.action_space.commands.is_empty());
 }
 
 #[test]
 fn enqueue_rejects_unsupported_request_schema_before_external_work() {
-    for actual in [0, 2] {
+    for actual in [0, 1, 3] {
         let mut service =
             LocalLlmService::from_config(enabled_config("127.0.0.1:9".parse().unwrap(), 500))
                 .unwrap();
@@ -438,9 +438,9 @@ fn enqueue_rejects_unsupported_request_schema_before_external_work() {
 }
 
 #[test]
-fn response_schema_gate_rejects_versions_zero_and_two() {
+fn response_schema_gate_rejects_other_versions() {
     assert!(validate_response_schema(LLM_SCHEMA_VERSION).is_ok());
-    for actual in [0, 2] {
+    for actual in [0, 1, 3] {
         assert_eq!(
             validate_response_schema(actual),
             Err(LlmResponseError::UnsupportedSchema {
@@ -454,7 +454,7 @@ fn response_schema_gate_rejects_versions_zero_and_two() {
 #[test]
 fn observation_schema_is_not_rewritten_to_version_one_by_the_transport_adapter() {
     let mut request = narrative_request();
-    request.observation.schema_version = 2;
+    request.observation.schema_version = 1;
     let result = OpenAiNarrativeTransport::new(enabled_config("127.0.0.1:9".parse().unwrap(), 500))
         .unwrap()
         .complete(&request);
@@ -463,7 +463,7 @@ fn observation_schema_is_not_rewritten_to_version_one_by_the_transport_adapter()
         result,
         Err(LlmResponseError::UnsupportedSchema {
             expected: LLM_SCHEMA_VERSION,
-            actual: 2,
+            actual: 1,
         })
     );
 }
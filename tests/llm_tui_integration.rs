@@ -9,7 +9,10 @@ use std::{
 
 use aihack::{
     core::{CommandIntent, GameSession},
-    ui::tui::{key_to_candidate, LlmUiStatus, TuiApp, UiCommandCandidate, UiRuntimeConfig},
+    ui::tui::{
+        function_key_to_candidate, key_to_candidate, LlmUiStatus, TuiApp, UiCommandCandidate,
+        UiRuntimeConfig,
+    },
 };
 use aihack_llm::config::LlmRequestKind;
 use aihack_llm::{
@@ -24,25 +27,31 @@ fn unused() -> &'static Path {
 }
 
 #[test]
-fn uppercase_llm_ctas_do_not_replace_lowercase_roguelike_commands() {
+fn function_key_llm_ctas_do_not_replace_roguelike_commands() {
     let observation = GameSession::new_for_playing(42).observation();
 
     assert_eq!(
-        key_to_candidate('G', &observation),
+        function_key_to_candidate(2),
         Some(UiCommandCandidate::LlmNarrative)
     );
     assert_eq!(
-        key_to_candidate('A', &observation),
+        function_key_to_candidate(3),
         Some(UiCommandCandidate::LlmSuggest)
     );
     assert_eq!(
-        key_to_candidate('J', &observation),
-        Some(UiCommandCandidate::LlmJudge)
-    );
-    assert_ne!(
-        key_to_candidate('j', &observation),
+        function_key_to_candidate(4),
         Some(UiCommandCandidate::LlmJudge)
     );
+    for key in ['j', 'J', 'G'] {
+        assert_ne!(
+            key_to_candidate(key, &observation),
+            Some(UiCommandCandidate::LlmJudge)
+        );
+        assert_ne!(
+            key_to_candidate(key, &observation),
+            Some(UiCommandCandidate::LlmNarrative)
+        );
+    }
 }
 
 #[test]
@@ -138,9 +147,9 @@ fn displayed_llm_footer_ctas_have_the_same_mouse_candidates() {
     let layout = compute_layout(100, 32);
     let ready = aihack::ui::tui::render_panels::llm_footer_line(&LlmUiStatus::Ready, false, false);
     for (label, expected) in [
-        ("[G] Narrative", UiCommandCandidate::LlmNarrative),
-        ("[A] Suggest", UiCommandCandidate::LlmSuggest),
-        ("[J] Judge", UiCommandCandidate::LlmJudge),
+        ("[F2] Narrative", UiCommandCandidate::LlmNarrative),
+        ("[F3] Suggest", UiCommandCandidate::LlmSuggest),
+        ("[F4] Judge", UiCommandCandidate::LlmJudge),
     ] {
         let offset = ready.find(label).unwrap() as u16;
         assert_eq!(
@@ -155,12 +164,12 @@ fn displayed_llm_footer_ctas_have_the_same_mouse_candidates() {
     }
 
     let result = aihack::ui::tui::render_panels::llm_footer_line(&LlmUiStatus::Ready, true, true);
-    assert!(result.contains("[Y] Apply"));
+    assert!(result.contains("[F5] Apply"));
     assert!(result.contains("[N] Dismiss"));
 
     let failed =
         aihack::ui::tui::render_panels::llm_footer_line(&LlmUiStatus::Unavailable, false, true);
-    assert!(failed.contains("[R] Retry"));
+    assert!(failed.contains("[F7] Retry"));
     assert!(failed.contains("[N] Dismiss"));
 }
 
@@ -280,7 +289,7 @@ fn unsupported_response_schema_is_rejected_before_tui_payload_acceptance() {
         assert!(Instant::now() < deadline);
         thread::yield_now();
     };
-    envelope.schema_version = 2;
+    envelope.schema_version = 1;
     app.accept_llm_response(envelope);
 
     assert_eq!(app.llm_status(), &LlmUiStatus::Invalid);
@@ -0,0 +1,292 @@
+use aihack::{
+    core::{CommandIntent, Direction, GameEvent, GameSession, Pos},
+    domain::{
+        item::ItemKind,
+        level::PHASE5_LEVEL2_ID,
+        tile::{DoorState, LockMethod, TileKind},
+    },
+    testing::{has_message, is_legal, session_with, SessionBuilder},
+};
+
+/// main:2 벽에 박힌 잠긴 문이다.
+const LOCKED_DOOR: Pos = Pos { x: 18, y: 6 };
+/// main:2 벽에 박힌, 세션마다 상태를 굴리는 문이다.
+const RANDOM_DOOR: Pos = Pos { x: 18, y: 8 };
+
+fn session_at_locked_door(seed: u64) -> GameSession {
+    session_with(seed, |world| {
+        world.set_player_location(PHASE5_LEVEL2_ID, Pos { x: 17, y: 6 });
+    })
+    .0
+}
+
+fn door(session: &GameSession, pos: Pos) -> TileKind {
+    session.world().current_map().tile(pos).unwrap()
+}
+
+#[test]
+fn a_locked_door_cannot_be_opened() {
+    let mut session = session_at_locked_door(42);
+
+    let outcome = session.submit(CommandIntent::Open(Direction::East));
+
+    assert!(!outcome.accepted);
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::CommandRejected { reason } if reason.contains("This door is locked.")
+    )));
+    assert!(!is_legal(&session, CommandIntent::Open(Direction::East)));
+    assert_eq!(
+        door(&session, LOCKED_DOOR),
+        TileKind::Door(DoorState::Locked)
+    );
+}
+
+#[test]
+fn a_skeleton_key_unlocks_and_relocks_a_door() {
+    let mut session = session_at_locked_door(42);
+    let key = SessionBuilder::mutate(&mut session, |world| world.give_item(ItemKind::SkeletonKey));
+    let pick = CommandIntent::PickLock {
+        item: key,
+        direction: Direction::East,
+    };
+    assert!(is_legal(&session, pick));
+
+    let mut unlocked = false;
+    for _ in 0..20 {
+        let outcome = session.submit(pick);
+        assert!(outcome.turn_advanced);
+        if has_message(&outcome.events, "You succeed in unlocking the door.") {
+            unlocked = true;
+            break;
+        }
+    }
+
+    assert!(unlocked);
+    assert_eq!(
+        door(&session, LOCKED_DOOR),
+        TileKind::Door(DoorState::Closed)
+    );
+    assert!(
+        session
+            .submit(CommandIntent::Open(Direction::East))
+            .accepted
+    );
+    assert!(
+        session
+            .submit(CommandIntent::Close(Direction::East))
+            .accepted
+    );
+    let relocked = (0..20).any(|_| {
+        session.submit(pick).events.iter().any(|event| {
+            matches!(
+                event,
+                GameEvent::LockWorked {
+                    method: LockMethod::Lock,
+                    success: true,
+                    ..
+                }
+            )
+        })
+    });
+    assert!(relocked);
+    assert_eq!(
+        door(&session, LOCKED_DOOR),
+        TileKind::Door(DoorState::Locked)
+    );
+}
+
+#[test]
+fn a_credit_card_opens_locks_but_cannot_lock() {
+    let mut session = session_at_locked_door(42);
+    let card = SessionBuilder::mutate(&mut session, |world| {
+        world
+            .current_map_mut()
+            .set_tile(LOCKED_DOOR, TileKind::Door(DoorState::Closed))
+            .unwrap();
+        world.give_item(ItemKind::CreditCard)
+    });
+    let pick = CommandIntent::PickLock {
+        item: card,
+        direction: Direction::East,
+    };
+
+    assert!(!is_legal(&session, pick));
+    let outcome = session.submit(pick);
+    assert!(!outcome.accepted);
+    assert_eq!(session.turn(), 0);
+}
+
+#[test]
+fn lock_picks_succeed_more_often_than_credit_cards() {
+    let successes = |kind: ItemKind| {
+        (0..60)
+            .filter(|seed| {
+                let mut session = session_at_locked_door(*seed);
+                let item = SessionBuilder::mutate(&mut session, |world| world.give_item(kind));
+                session
+                    .submit(CommandIntent::PickLock {
+                        item,
+                        direction: Direction::East,
+                    })
+                    .events
+                    .iter()
+                    .any(|event| matches!(event, GameEvent::LockWorked { success: true, .. }))
+            })
+            .count()
+    };
+
+    assert!(successes(ItemKind::LockPick) > successes(ItemKind::CreditCard));
+}
+
+#[test]
+fn forcing_a_lock_needs_a_wielded_weapon() {
+    let mut session = session_at_locked_door(42);
+    let outcome = session.submit(CommandIntent::Force {
+        direction: Direction::East,
+    });
+    assert!(!outcome.accepted);
+    assert!(!is_legal(
+        &session,
+        CommandIntent::Force {
+            direction: Direction::East
+        }
+    ));
+
+    let dagger = session.world().inventory().entries[0].item;
+    assert!(
+        session
+            .submit(CommandIntent::Wield { item: dagger })
+            .accepted
+    );
+    assert!(is_legal(
+        &session,
+        CommandIntent::Force {
+            direction: Direction::East
+        }
+    ));
+    let forced = (0..200).any(|_| {
+        session
+            .submit(CommandIntent::Force {
+                direction: Direction::East,
+            })
+            .events
+            .iter()
+            .any(|event| {
+                matches!(
+                    event,
+                    GameEvent::LockWorked {
+                        tool: ItemKind::Dagger,
+                        method: LockMethod::Force,
+                        success: true,
+                        ..
+                    }
+                )
+            })
+    });
+    assert!(forced || !session.world().inventory().contains(dagger));
+}
+
+#[test]
+fn kicking_a_locked_door_eventually_breaks_it() {
+    let mut session = session_at_locked_door(42);
+    let mut broken = false;
+    for _ in 0..40 {
+        let outcome = session.submit(CommandIntent::Kick(Direction::East));
+        assert!(outcome
+            .events
+            .iter()
+            .any(|event| matches!(event, GameEvent::DoorKicked { pos } if *pos == LOCKED_DOOR)));
+        if has_message(&outcome.events, "As you kick the door, it crashes open!") {
+            broken = true;
+            break;
+        }
+    }
+
+    assert!(broken);
+    assert_eq!(
+        door(&session, LOCKED_DOOR),
+        TileKind::Door(DoorState::Broken)
+    );
+    assert!(
+        !session
+            .submit(CommandIntent::Close(Direction::East))
+            .accepted
+    );
+    assert!(
+        !session
+            .submit(CommandIntent::Kick(Direction::East))
+            .accepted
+    );
+    assert!(
+        session
+            .submit(CommandIntent::Move(Direction::East))
+            .accepted
+    );
+}
+
+#[test]
+fn intact_doorways_cannot_be_entered_or_left_diagonally() {
+    let doorway = Pos { x: 6, y: 10 };
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters();
+        world.set_player_pos(Pos { x: 5, y: 11 });
+        world
+            .current_map_mut()
+            .set_tile(doorway, TileKind::Door(DoorState::Open))
+            .unwrap();
+    });
+
+    let outcome = session.submit(CommandIntent::Move(Direction::NorthEast));
+    assert!(!outcome.accepted);
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::CommandRejected { reason }
+            if reason.contains("You can't move diagonally into an intact doorway.")
+    )));
+    assert!(!is_legal(
+        &session,
+        CommandIntent::Move(Direction::NorthEast)
+    ));
+
+    assert!(
+        session
+            .submit(CommandIntent::Move(Direction::East))
+            .accepted
+    );
+    assert!(
+        session
+            .submit(CommandIntent::Move(Direction::North))
+            .accepted
+    );
+    assert_eq!(session.world().player_pos(), doorway);
+    assert!(
+        !session
+            .submit(CommandIntent::Move(Direction::NorthEast))
+            .accepted
+    );
+
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .current_map_mut()
+            .set_tile(doorway, TileKind::Door(DoorState::NoDoor))
+            .unwrap();
+    });
+    assert!(
+        session
+            .submit(CommandIntent::Move(Direction::NorthEast))
+            .accepted
+    );
+}
+
+#[test]
+fn random_doors_are_rolled_once_per_seed() {
+    let state = |seed: u64| door(&session_at_locked_door(seed), RANDOM_DOOR);
+
+    assert_eq!(state(42), state(42));
+    let states = (0..40).map(state).collect::<Vec<_>>();
+    assert!(states.contains(&TileKind::Door(DoorState::NoDoor)));
+    assert!(states.iter().any(|tile| tile.is_intact_doorway()));
+    assert!(states.iter().all(|tile| matches!(tile, TileKind::Door(_))));
+}
@@ -1,6 +1,9 @@
-use aihack::core::{
-    policy::{run_to_turn, HeadlessPolicy},
-    GameSession, RunState,
+use aihack::{
+    core::{
+        policy::{run_to_turn, run_to_turn_with_trace, HeadlessPolicy},
+        GameSession, RunState,
+    },
+    domain::item::{try_item_data, ItemKind},
 };
 
 const SEEDS: [u64; 3] = [42, 7, 1234];
@@ -21,12 +24,27 @@ fn survival_policy_reaches_one_thousand_accepted_turns_for_required_seeds() {
         let mut session = GameSession::new_for_playing(seed);
         let initial_semantic_state = semantic_state(&session);
         let initial_nutrition = session.snapshot().nutrition;
-        let report = run_to_turn(&mut session, TARGET_TURN, HeadlessPolicy::survival_v1()).unwrap();
+        let (report, trace) =
+            run_to_turn_with_trace(&mut session, TARGET_TURN, HeadlessPolicy::survival_v1())
+                .unwrap();
 
         assert_eq!(report.accepted_turns, TARGET_TURN, "seed={seed}");
         assert_eq!(report.final_state, RunState::Playing, "seed={seed}");
-        assert!((report.accepted_turns..=report.accepted_turns * 16)
-            .contains(&report.submitted_commands));
+        assert!(report.submitted_commands <= report.accepted_turns * 16);
+        // 받아들여진 명령은 한 턴 이상을 넘기고, 여러 턴짜리 명령인 식사도 가장 긴 food ration
+        // 한 끼보다 길지 않다.
+        let accepted = trace.iter().filter(|line| line.outcome.accepted);
+        let accepted_commands = accepted.clone().count() as u64;
+        let longest_meal = u64::from(try_item_data(ItemKind::FoodRation).unwrap().delay);
+        assert!(
+            (accepted_commands..=accepted_commands * longest_meal).contains(&report.accepted_turns),
+            "seed={seed} accepted_commands={accepted_commands}"
+        );
+        assert_eq!(
+            accepted.map(|line| line.outcome.turns_elapsed).sum::<u64>(),
+            report.accepted_turns,
+            "seed={seed}"
+        );
         assert_ne!(
             semantic_state(&session),
             initial_semantic_state,
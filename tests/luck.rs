@@ -0,0 +1,253 @@
+use aihack::{
+    core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, LuckFeeling, Pos},
+    data::ContentRegistry,
+    domain::{
+        entity::{EntityLocation, Faction},
+        item::{BucStatus, ItemKind},
+        status::LuckCause,
+        tile::{TileKind, TrapKind},
+    },
+    testing::{has_message, session_with, SessionBuilder},
+};
+
+const ITEMS_TOML: &str = include_str!("../crates/aihack-content/src/data/items.toml");
+const MONSTERS_TOML: &str = include_str!("../crates/aihack-content/src/data/monsters.toml");
+const LEVEL_1_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_1.toml");
+const LEVEL_2_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_2.toml");
+
+const JACKAL: EntityId = EntityId(2);
+const WAND: EntityId = EntityId(7);
+
+fn session_with_luck(seed: u64, luck: i16) -> GameSession {
+    session_with(seed, |world| world.saved().luck = luck).0
+}
+
+fn give_luckstone(session: &mut GameSession, buc: BucStatus) {
+    SessionBuilder::mutate(session, |world| {
+        let stone = world.give_item(ItemKind::Luckstone);
+        world.saved().entities.set_item_buc(stone, buc);
+    });
+}
+
+fn wait_turns(session: &mut GameSession, turns: u32) {
+    for _ in 0..turns {
+        assert!(session.submit(CommandIntent::Wait).accepted);
+    }
+}
+
+#[test]
+fn luck_times_out_toward_zero_every_600_turns() {
+    let mut session = session_with_luck(42, 3);
+
+    wait_turns(&mut session, 599);
+    assert_eq!(session.snapshot().luck, 3);
+    wait_turns(&mut session, 1);
+    assert_eq!(session.snapshot().luck, 2);
+
+    let mut unlucky = session_with_luck(42, -3);
+    wait_turns(&mut unlucky, 600);
+    assert_eq!(unlucky.snapshot().luck, -2);
+}
+
+#[test]
+fn carried_luckstone_adds_a_bonus_and_keeps_good_luck() {
+    let mut session = session_with_luck(42, 0);
+    assert_eq!(session.observation().player.luck, LuckFeeling::Neutral);
+    give_luckstone(&mut session, BucStatus::Uncursed);
+    assert_eq!(session.observation().player.luck, LuckFeeling::Lucky);
+    assert_eq!(session.snapshot().luck, 0);
+
+    let mut kept = session_with_luck(42, 3);
+    give_luckstone(&mut kept, BucStatus::Blessed);
+    wait_turns(&mut kept, 600);
+    assert_eq!(kept.snapshot().luck, 3);
+}
+
+#[test]
+fn uncursed_luckstone_keeps_bad_luck_from_timing_out() {
+    let mut session = session_with_luck(42, -3);
+    give_luckstone(&mut session, BucStatus::Uncursed);
+    wait_turns(&mut session, 600);
+    assert_eq!(session.snapshot().luck, -3);
+
+    let mut angry = session_with(42, |world| {
+        world.saved().luck = -3;
+        world.saved().god_anger = 1;
+    })
+    .0;
+    give_luckstone(&mut angry, BucStatus::Uncursed);
+    wait_turns(&mut angry, 300);
+    assert_eq!(angry.snapshot().luck, -3);
+}
+
+#[test]
+fn cursed_luckstone_keeps_bad_luck_and_feels_unlucky() {
+    let mut session = session_with_luck(42, -3);
+    give_luckstone(&mut session, BucStatus::Cursed);
+
+    wait_turns(&mut session, 600);
+
+    assert_eq!(session.snapshot().luck, -3);
+    assert_eq!(session.observation().player.luck, LuckFeeling::Unlucky);
+
+    let mut cursed_only = session_with_luck(42, 2);
+    give_luckstone(&mut cursed_only, BucStatus::Cursed);
+    assert_eq!(cursed_only.observation().player.luck, LuckFeeling::Unlucky);
+}
+
+#[test]
+fn breaking_a_thrown_mirror_costs_luck() {
+    let mut session = session_with_luck(42, 0);
+    let mirror = SessionBuilder::mutate(&mut session, |world| world.give_item(ItemKind::Mirror));
+    let player = session.world().player_id();
+
+    let outcome = session.submit(CommandIntent::Throw {
+        item: mirror,
+        direction: Direction::East,
+    });
+
+    assert!(outcome.accepted);
+    assert!(has_message(&outcome.events, "It shatters!"));
+    assert!(outcome.events.contains(&GameEvent::LuckChanged {
+        entity: player,
+        cause: LuckCause::BrokeMirror,
+    }));
+    assert_eq!(
+        session.world().entities().item_location(mirror),
+        Some(EntityLocation::Consumed)
+    );
+    assert_eq!(session.snapshot().luck, -2);
+    assert_eq!(session.observation().player.luck, LuckFeeling::Unlucky);
+}
+
+#[test]
+fn killing_a_peaceful_monster_lowers_alignment_and_may_cost_luck() {
+    let (mut kills, mut unlucky) = (0, 0);
+    let level_1 = LEVEL_1_TOML.replacen("pos=[6,5]", "pos=[6,5]\npeaceful=true", 1);
+    let registry = ContentRegistry::from_toml_sources(
+        1,
+        ITEMS_TOML,
+        MONSTERS_TOML,
+        &[("main_1.toml", &level_1), ("main_2.toml", LEVEL_2_TOML)],
+    )
+    .unwrap();
+    let peaceful = JACKAL;
+    for seed in 0..30 {
+        let mut session = GameSession::try_new_for_playing_with_registry(seed, &registry).unwrap();
+        assert_eq!(
+            session.world().entities().get(peaceful).unwrap().faction(),
+            Some(Faction::Neutral)
+        );
+        SessionBuilder::mutate(&mut session, |world| {
+            world.saved().entities.actor_stats_mut(peaceful).unwrap().hp = 1;
+            world.saved().alignment = 10;
+        });
+
+        let outcome = session.submit(CommandIntent::Zap {
+            item: WAND,
+            direction: Direction::East,
+        });
+
+        if session
+            .world()
+            .entities()
+            .get(peaceful)
+            .unwrap()
+            .is_alive_actor()
+        {
+            continue;
+        }
+        kills += 1;
+        assert!(has_message(
+            &outcome.events,
+            "That was probably a bad idea..."
+        ));
+        assert_eq!(session.observation().player.alignment, 5);
+        if outcome
+            .events
+            .iter()
+            .any(|event| matches!(event, GameEvent::LuckChanged { .. }))
+        {
+            assert_eq!(session.snapshot().luck, -5);
+            unlucky += 1;
+        }
+    }
+    assert!(kills > 0);
+    assert!(unlucky > 0 && unlucky < kills, "{unlucky} / {kills}");
+}
+
+#[test]
+fn luck_changes_how_often_seen_traps_are_escaped() {
+    let escapes = |luck: i16| {
+        (0..40)
+            .filter(|seed| {
+                let mut session = session_with_luck(*seed, luck);
+                SessionBuilder::mutate(&mut session, |world| {
+                    world
+                        .current_map_mut()
+                        .set_tile(Pos { x: 6, y: 5 }, TileKind::Trap(TrapKind::BearTrap))
+                        .unwrap();
+                });
+                let outcome = session.submit(CommandIntent::Move(Direction::East));
+                has_message(&outcome.events, "You escape a bear trap.")
+            })
+            .count()
+    };
+
+    let (lucky, plain, unlucky) = (escapes(10), escapes(0), escapes(-10));
+    assert!(
+        lucky > plain && plain > unlucky,
+        "{lucky} {plain} {unlucky}"
+    );
+}
+
+#[test]
+fn bad_luck_can_make_a_search_miss_a_hidden_door() {
+    let mut missed = 0;
+    for seed in 0..20 {
+        let mut session = session_with_luck(seed, -10);
+        SessionBuilder::mutate(&mut session, |world| {
+            world.set_player_pos(Pos { x: 11, y: 5 })
+        });
+
+        let outcome = session.submit(CommandIntent::Search);
+
+        if !outcome
+            .events
+            .iter()
+            .any(|event| matches!(event, GameEvent::TileRevealed { .. }))
+        {
+            missed += 1;
+        }
+    }
+    assert!(missed > 0);
+}
+
+#[test]
+fn good_luck_can_wrest_a_last_charge_from_an_empty_wand() {
+    let mut wrested = 0;
+    for seed in 0..40 {
+        let mut session = session_with_luck(seed, 10);
+        SessionBuilder::mutate(&mut session, |world| {
+            world.saved().entities.set_item_charges(WAND, Some(0));
+        });
+
+        let outcome = session.submit(CommandIntent::Zap {
+            item: WAND,
+            direction: Direction::East,
+        });
+
+        assert!(outcome.accepted);
+        if has_message(
+            &outcome.events,
+            "You wrest one last charge from the worn-out wand.",
+        ) {
+            assert!(has_message(&outcome.events, "The wand turns to dust."));
+            assert!(!session.world().inventory().contains(WAND));
+            wrested += 1;
+        } else {
+            assert!(has_message(&outcome.events, "Nothing happens."));
+        }
+    }
+    assert!(wrested > 0);
+}
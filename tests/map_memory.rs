@@ -0,0 +1,136 @@
+use aihack::{
+    core::{CommandIntent, GameSession, Pos, SaveDataV1},
+    domain::{item::ItemKind, monster::MonsterKind, tile::TileKind},
+    testing::SessionBuilder,
+};
+
+const START: Pos = Pos { x: 5, y: 5 };
+const POTION: Pos = Pos { x: 8, y: 5 };
+const JACKAL: Pos = Pos { x: 6, y: 5 };
+const FAR_AWAY: Pos = Pos { x: 30, y: 16 };
+
+fn session_after_walking_away() -> GameSession {
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| world.set_player_pos(FAR_AWAY));
+    assert!(session.submit(CommandIntent::Wait).accepted);
+    session
+}
+
+#[test]
+fn the_starting_view_is_remembered_from_the_first_turn() {
+    let session = GameSession::new_for_playing(42);
+    let memory = session
+        .world()
+        .map_memory
+        .level(session.world().current_level())
+        .unwrap();
+
+    assert_eq!(
+        memory.glyph(START).map(|glyph| glyph.tile),
+        Some(TileKind::Floor)
+    );
+    assert_eq!(
+        memory.glyph(POTION).and_then(|glyph| glyph.object),
+        Some(ItemKind::PotionHealing)
+    );
+    assert!(memory.glyph(Pos { x: 34, y: 15 }).is_none());
+}
+
+#[test]
+fn explored_squares_stay_in_the_observation_after_leaving_view() {
+    let session = session_after_walking_away();
+    let observation = session.observation();
+
+    let start = observation
+        .remembered_tiles
+        .iter()
+        .find(|tile| tile.pos == START)
+        .unwrap();
+    assert!(!start.visible);
+    assert_eq!(start.tile, TileKind::Floor);
+    assert!(observation.visible_tiles.iter().all(|tile| !observation
+        .remembered_tiles
+        .iter()
+        .any(|old| old.pos == tile.pos)));
+    assert!(observation
+        .remembered_objects
+        .iter()
+        .any(|object| object.pos == POTION && object.kind == ItemKind::PotionHealing));
+    assert!(observation
+        .remembered_monsters
+        .iter()
+        .any(|monster| monster.pos == JACKAL && monster.kind == MonsterKind::Jackal));
+}
+
+#[test]
+fn hidden_doors_are_remembered_as_walls() {
+    let hidden_door = Pos { x: 12, y: 5 };
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters();
+        world.set_player_pos(Pos { x: 11, y: 5 });
+    });
+    assert!(session.submit(CommandIntent::Wait).accepted);
+
+    let memory = session
+        .world()
+        .map_memory
+        .level(session.world().current_level())
+        .unwrap();
+    assert_eq!(
+        memory.glyph(hidden_door).map(|glyph| glyph.tile),
+        Some(TileKind::Wall)
+    );
+}
+
+#[test]
+fn map_memory_survives_save_and_load() {
+    let session = session_after_walking_away();
+    let json = serde_json::to_string(&session.to_save_data()).unwrap();
+    let decoded: SaveDataV1 = serde_json::from_str(&json).unwrap();
+    let restored = GameSession::from_save_data(decoded).unwrap();
+
+    assert_eq!(restored.world().map_memory, session.world().map_memory);
+    assert_eq!(
+        restored.observation().remembered_tiles,
+        session.observation().remembered_tiles
+    );
+}
+
+#[test]
+fn saves_without_map_memory_still_load() {
+    let session = GameSession::new_for_playing(42);
+    let mut json = serde_json::to_value(session.to_save_data()).unwrap();
+    json["world"]
+        .as_object_mut()
+        .unwrap()
+        .remove("map_memory")
+        .unwrap();
+
+    let decoded: SaveDataV1 = serde_json::from_value(json).unwrap();
+
+    assert!(GameSession::from_save_data(decoded)
+        .unwrap()
+        .world()
+        .map_memory
+        .levels
+        .is_empty());
+}
+
+#[test]
+fn remembered_tiles_keep_the_lighting_they_were_seen_with() {
+    let mut session = session_after_walking_away();
+    SessionBuilder::mutate(&mut session, |world| {
+        world.current_map_mut().set_lit(START, false).unwrap();
+    });
+
+    let start = session
+        .observation()
+        .remembered_tiles
+        .into_iter()
+        .find(|tile| tile.pos == START)
+        .unwrap();
+
+    assert!(start.lit);
+    assert!(!session.world().current_map().is_lit(START));
+}
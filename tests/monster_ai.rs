@@ -1,6 +1,9 @@
 use aihack::{
     core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, LevelId, Pos, RunState},
-    domain::monster::{monster_ai_kind, MonsterAiKind, MonsterKind},
+    domain::{
+        monster::{monster_ai_kind, MonsterAiKind, MonsterKind},
+        tile::{DoorState, TileKind},
+    },
 };
 
 fn kill_fixture_monsters(session: &mut GameSession) {
@@ -433,3 +436,189 @@ fn movement_events_include_actor_identity() {
         }
     )));
 }
+
+/// 원래 몬스터를 지우고 `player`에 선 플레이어와 `monster` 한 마리만 남긴다.
+fn chase_session(player: Pos, kind: MonsterKind, monster: Pos) -> (GameSession, EntityId) {
+    let mut session = GameSession::new_for_playing(42);
+    kill_fixture_monsters(&mut session);
+    let id = aihack::testing::SessionBuilder::mutate(&mut session, |world| {
+        world.saved().nutrition = 900;
+        world.set_player_pos(player);
+        world.saved().entities.spawn_monster(kind, monster)
+    });
+    (session, id)
+}
+
+fn set_tiles(session: &mut GameSession, tiles: impl IntoIterator<Item = (Pos, TileKind)>) {
+    aihack::testing::SessionBuilder::mutate(session, |world| {
+        for (pos, tile) in tiles {
+            world.current_map_mut().set_tile(pos, tile).unwrap();
+        }
+    });
+}
+
+fn monster_pos(session: &GameSession, monster: EntityId) -> Pos {
+    session
+        .world()
+        .entities()
+        .actor_location(monster)
+        .unwrap()
+        .1
+}
+
+/// 플레이어가 쉬는 동안 `monster`가 곁에 붙을 때까지 기다린다. 붙지 못하면 `None`이다.
+fn wait_until_adjacent(session: &mut GameSession, monster: EntityId, turns: u32) -> Option<u32> {
+    (1..=turns).find(|_| {
+        session.submit(CommandIntent::Wait);
+        monster_pos(session, monster).chebyshev_distance(session.world().player_pos()) <= 1
+    })
+}
+
+#[test]
+fn goblin_walks_around_bars_it_can_see_through() {
+    let (mut session, goblin) = chase_session(
+        Pos { x: 20, y: 10 },
+        MonsterKind::Goblin,
+        Pos { x: 20, y: 5 },
+    );
+    // 돌아가는 길에 놓인 fixture의 숨은 구덩이는 걷어 낸다. 붙잡힌 몬스터는 1/40로만 빠져나온다.
+    set_tiles(
+        &mut session,
+        (15..=25)
+            .map(|x| (Pos { x, y: 8 }, TileKind::IronBars))
+            .chain([(Pos { x: 16, y: 5 }, TileKind::Floor)]),
+    );
+
+    // 곧장 다가가는 것만으로는 쇠창살 앞에 멈춘다. 길을 찾으면 끝을 돌아온다.
+    assert!(wait_until_adjacent(&mut session, goblin, 20).is_some());
+}
+
+#[test]
+fn goblin_follows_the_player_track_after_losing_sight() {
+    let (mut session, goblin) = chase_session(
+        Pos { x: 18, y: 10 },
+        MonsterKind::Goblin,
+        Pos { x: 16, y: 10 },
+    );
+    let gap = Pos { x: 20, y: 10 };
+    set_tiles(
+        &mut session,
+        (1..=18)
+            .map(|y| Pos { x: 20, y })
+            .filter(|pos| *pos != gap)
+            .map(|pos| (pos, TileKind::Wall)),
+    );
+    let player = session.world().player_id();
+    aihack::testing::SessionBuilder::mutate(&mut session, |world| {
+        let stats = world.saved().entities.actor_stats_mut(player).unwrap();
+        stats.hp = 200;
+        stats.max_hp = 200;
+    });
+
+    for direction in [Direction::East; 3]
+        .into_iter()
+        .chain([Direction::North; 5])
+    {
+        assert!(session.submit(CommandIntent::Move(direction)).accepted);
+    }
+    assert_eq!(session.world().player_pos(), Pos { x: 21, y: 5 });
+
+    // 벽 너머로 모습을 감춰도 고블린은 틈을 지난 자취를 밟아 따라온다.
+    assert!(wait_until_adjacent(&mut session, goblin, 20).is_some());
+    assert!(monster_pos(&session, goblin).x > gap.x);
+}
+
+#[test]
+fn only_door_opening_monsters_pass_closed_doors() {
+    let door = Pos { x: 20, y: 12 };
+    let wall = (1..=18).map(|y| {
+        let tile = match y {
+            ..=11 => TileKind::IronBars,
+            12 => TileKind::Door(DoorState::Closed),
+            _ => TileKind::Wall,
+        };
+        (Pos { x: 20, y }, tile)
+    });
+    let player = Pos { x: 22, y: 10 };
+    let start = Pos { x: 16, y: 10 };
+
+    let (mut session, goblin) = chase_session(player, MonsterKind::Goblin, start);
+    set_tiles(&mut session, wall.clone());
+    let mut opened = false;
+    for _ in 0..20 {
+        let outcome = session.submit(CommandIntent::Wait);
+        opened |= outcome.events.iter().any(|event| {
+            matches!(event, GameEvent::DoorChanged { pos, to: DoorState::Open, .. } if *pos == door)
+        });
+        if monster_pos(&session, goblin).chebyshev_distance(player) <= 1 {
+            break;
+        }
+    }
+    assert!(opened);
+    assert!(monster_pos(&session, goblin).chebyshev_distance(player) <= 1);
+
+    // 물뱀은 문을 열지 못하므로 쇠창살 너머로 플레이어를 보고도 건너오지 못한다.
+    let (mut session, snake) = chase_session(player, MonsterKind::WaterMoccasin, start);
+    set_tiles(&mut session, wall);
+    assert_eq!(wait_until_adjacent(&mut session, snake, 20), None);
+    assert!(monster_pos(&session, snake).x < door.x);
+    assert_eq!(
+        session.world().current_map().tile(door),
+        Ok(TileKind::Door(DoorState::Closed))
+    );
+}
+
+#[test]
+fn blind_goblin_does_not_see_the_player_until_its_eyes_clear() {
+    let (mut session, goblin) = chase_session(
+        Pos { x: 17, y: 12 },
+        MonsterKind::Goblin,
+        Pos { x: 22, y: 12 },
+    );
+    aihack::testing::SessionBuilder::mutate(&mut session, |world| {
+        let stats = world.saved().entities.actor_stats_mut(goblin).unwrap();
+        stats.movement = 12;
+        stats.blinded_turns = 3;
+    });
+
+    // 눈이 보였다면 첫 턴부터 다가왔을 것이다.
+    for turns_left in [2, 1] {
+        session.submit(CommandIntent::Wait);
+        assert_eq!(monster_pos(&session, goblin), Pos { x: 22, y: 12 });
+        assert_eq!(
+            session
+                .world()
+                .entities()
+                .actor_stats(goblin)
+                .unwrap()
+                .blinded_turns,
+            turns_left
+        );
+    }
+    assert!(wait_until_adjacent(&mut session, goblin, 20).is_some());
+}
+
+#[test]
+fn confused_monster_wanders_instead_of_attacking() {
+    let (mut session, jackal) =
+        chase_session(Pos { x: 9, y: 5 }, MonsterKind::Jackal, Pos { x: 8, y: 5 });
+    aihack::testing::SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .actor_stats_mut(jackal)
+            .unwrap()
+            .confused = true;
+    });
+
+    let outcome = session.submit(CommandIntent::Wait);
+
+    assert!(!outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::AttackResolved { attacker, .. } if *attacker == jackal
+    )));
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::EntityMoved { entity, .. } if *entity == jackal
+    )));
+}
@@ -0,0 +1,222 @@
+use aihack::{
+    core::{CommandIntent, ContentError, Direction, EntityId, GameEvent, GameSession},
+    domain::{entity::EntityLocation, item::ItemKind},
+    testing::{has_message, player_hp, SessionBuilder},
+};
+
+const ITEMS_TOML: &str = include_str!("../crates/aihack-content/src/data/items.toml");
+const MONSTERS_TOML: &str = include_str!("../crates/aihack-content/src/data/monsters.toml");
+const LEVEL_1_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_1.toml");
+const LEVEL_2_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_2.toml");
+
+const JACKAL: EntityId = EntityId(2);
+const GOBLIN: EntityId = EntityId(3);
+const JACKAL_ATTACKS: &str = "attacks=[{type=\"bite\", damage=\"1d2\"}]";
+
+fn registry(monsters: &str) -> Result<aihack::data::ContentRegistry, ContentError> {
+    aihack::data::ContentRegistry::from_toml_sources(
+        1,
+        ITEMS_TOML,
+        monsters,
+        &[("main_1.toml", LEVEL_1_TOML), ("main_2.toml", LEVEL_2_TOML)],
+    )
+}
+
+/// 자칼의 공격 목록을 `attacks` 줄로 바꾸고 반드시 맞게 한 뒤, 고블린을 치우고 양쪽 체력을
+/// 넉넉히 채운다. 자칼은 플레이어 바로 동쪽에 있다.
+fn jackal_session(attacks: &str) -> GameSession {
+    let monsters = MONSTERS_TOML.replacen(JACKAL_ATTACKS, attacks, 1).replacen(
+        "hit_bonus=0",
+        "hit_bonus=40",
+        1,
+    );
+    let mut session =
+        GameSession::try_new_for_playing_with_registry(42, &registry(&monsters).unwrap()).unwrap();
+    SessionBuilder::mutate(&mut session, |world| {
+        let player = world.saved().player_id;
+        let entities = &mut world.saved().entities;
+        entities.set_alive(GOBLIN, false);
+        for actor in [player, JACKAL] {
+            let stats = entities.actor_stats_mut(actor).unwrap();
+            stats.hp = 999;
+            stats.max_hp = 999;
+        }
+    });
+    session
+}
+
+fn attacks_by(events: &[GameEvent], attacker: EntityId) -> usize {
+    events
+        .iter()
+        .filter(|event| matches!(event, GameEvent::AttackResolved { attacker: who, .. } if *who == attacker))
+        .count()
+}
+
+#[test]
+fn monster_uses_every_attack_in_its_list_each_turn() {
+    let mut session = jackal_session(
+        "attacks=[{type=\"bite\", damage=\"1d1\"}, {type=\"claw\", damage=\"1d1\"}, {type=\"claw\", damage=\"1d1\"}]",
+    );
+
+    let events = session.submit(CommandIntent::Wait).events;
+
+    assert_eq!(attacks_by(&events, JACKAL), 3);
+}
+
+#[test]
+fn fire_resistance_blocks_fire_touch_damage() {
+    let mut session =
+        jackal_session("attacks=[{type=\"touch\", damage_type=\"fire\", damage=\"4d6\"}]");
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().conditions.fire_resistance = true;
+    });
+    let before = player_hp(&session);
+
+    let events = session.submit(CommandIntent::Wait).events;
+
+    assert!(has_message(&events, "The fire doesn't feel hot!"));
+    assert_eq!(player_hp(&session), before);
+}
+
+#[test]
+fn sticky_monster_holds_until_it_dies() {
+    let mut session =
+        jackal_session("attacks=[{type=\"touch\", damage_type=\"stick\", damage=\"1d1\"}]");
+
+    let events = session.submit(CommandIntent::Wait).events;
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, GameEvent::PlayerHeld { holder } if *holder == JACKAL)));
+    assert_eq!(session.world().held_by, Some(JACKAL));
+
+    let start = session.world().player_pos();
+    let events = session.submit(CommandIntent::Move(Direction::West)).events;
+    if has_message(&events, "You cannot escape from the jackal!") {
+        assert_eq!(session.world().player_pos(), start);
+    } else {
+        assert!(has_message(&events, "You pull free from the jackal."));
+    }
+
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().held_by = Some(JACKAL);
+        world.saved().entities.set_alive(JACKAL, false);
+    });
+    let events = session.submit(CommandIntent::Wait).events;
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, GameEvent::PlayerReleased { holder } if *holder == JACKAL)));
+    assert_eq!(session.world().held_by, None);
+}
+
+#[test]
+fn held_player_stays_held_across_save_and_load() {
+    let mut session =
+        jackal_session("attacks=[{type=\"touch\", damage_type=\"stick\", damage=\"1d1\"}]");
+    session.submit(CommandIntent::Wait);
+    assert_eq!(session.world().held_by, Some(JACKAL));
+
+    let json = serde_json::to_string(&session.to_save_data()).unwrap();
+    let restored = GameSession::from_save_data(serde_json::from_str(&json).unwrap()).unwrap();
+
+    assert_eq!(restored.world().held_by, Some(JACKAL));
+}
+
+#[test]
+fn thief_takes_a_loose_item_and_teleports_away() {
+    let mut session =
+        jackal_session("attacks=[{type=\"claw\", damage_type=\"steal\", damage=\"1d1\"}]");
+    let rock = SessionBuilder::mutate(&mut session, |world| {
+        let owner = world.saved().player_id;
+        let rock = world
+            .saved()
+            .entities
+            .spawn_item(ItemKind::Rock, EntityLocation::Inventory { owner });
+        let letter = world
+            .saved()
+            .inventory
+            .add_existing_with_next_letter(rock)
+            .unwrap();
+        world.saved().entities.set_item_letter(rock, letter);
+        rock
+    });
+    let before = session.world().entities().actor_location(JACKAL).unwrap();
+
+    let events = session.submit(CommandIntent::Wait).events;
+
+    assert!(events.iter().any(|event| matches!(
+        event,
+        GameEvent::ItemStolen { thief, .. } if *thief == JACKAL
+    )));
+    assert!(has_message(&events, "The jackal stole something!"));
+    let stolen = session
+        .world()
+        .entities()
+        .inventory_items(JACKAL)
+        .into_iter()
+        .next()
+        .unwrap();
+    assert!(!session.world().inventory().contains(stolen));
+    assert!(stolen == rock || session.world().inventory().contains(rock));
+    assert_ne!(
+        session.world().entities().actor_location(JACKAL).unwrap(),
+        before
+    );
+}
+
+#[test]
+fn level_drain_eventually_costs_an_experience_level() {
+    let mut session =
+        jackal_session("attacks=[{type=\"bite\", damage_type=\"drain\", damage=\"1d1\"}]");
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().experience_level = 5;
+    });
+
+    let drained = (0..30).any(|_| {
+        session.submit(CommandIntent::Wait);
+        session.world().experience_level < 5
+    });
+
+    assert!(drained);
+}
+
+#[test]
+fn passive_response_comes_from_content() {
+    let passive = "passive={damage_type=\"fire\", damage=\"1d1\"}";
+    let mut session = jackal_session(&format!("{JACKAL_ATTACKS}\n{passive}"));
+    let events = session.submit(CommandIntent::Move(Direction::East)).events;
+    assert!(events.iter().any(|event| matches!(
+        event,
+        GameEvent::PassiveAttackTriggered { source, .. } if *source == JACKAL
+    )));
+    assert!(has_message(&events, "You are suddenly very hot!"));
+
+    let mut session = jackal_session(&format!("{JACKAL_ATTACKS}\n{passive}"));
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().conditions.fire_resistance = true;
+    });
+    let events = session.submit(CommandIntent::Move(Direction::East)).events;
+    assert!(has_message(&events, "You feel mildly warm."));
+}
+
+#[test]
+fn unknown_attack_type_is_a_typed_error() {
+    let monsters = MONSTERS_TOML.replacen(
+        JACKAL_ATTACKS,
+        "attacks=[{type=\"sting\", damage=\"1d2\"}]",
+        1,
+    );
+    assert!(matches!(
+        registry(&monsters),
+        Err(ContentError::UnknownReference { target, .. }) if target == "sting"
+    ));
+
+    let monsters = MONSTERS_TOML.replacen(
+        JACKAL_ATTACKS,
+        "attacks=[{type=\"bite\", damage_type=\"acid\", damage=\"1d2\"}]",
+        1,
+    );
+    assert!(matches!(
+        registry(&monsters),
+        Err(ContentError::UnknownReference { target, .. }) if target == "acid"
+    ));
+}
@@ -0,0 +1,197 @@
+use std::collections::BTreeSet;
+
+use aihack::{
+    core::{CommandIntent, ContentError, GameEvent, GameRng, GameSession},
+    data::{generation::LevelDifficulty, ContentRegistry},
+    domain::{
+        item::{BucStatus, ItemClass, ItemKind},
+        monster::MonsterKind,
+    },
+    testing::SessionBuilder,
+};
+
+const ITEMS_TOML: &str = include_str!("../crates/aihack-content/src/data/items.toml");
+const MONSTERS_TOML: &str = include_str!("../crates/aihack-content/src/data/monsters.toml");
+const LEVEL_1_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_1.toml");
+const LEVEL_2_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_2.toml");
+
+const IMP: &str = r#"
+[[monster]]
+id="monster.imp"
+name="imp"
+glyph="i"
+color="red"
+hp=4
+ac=2
+hit_bonus=0
+ai="chase_on_sight"
+speed=12
+difficulty=1
+frequency=1
+generation=["hell_only"]
+attacks=[{type="claw", damage="1d4"}]
+"#;
+
+fn registry(items: &str, monsters: &str) -> Result<ContentRegistry, ContentError> {
+    ContentRegistry::from_toml_sources(
+        1,
+        items,
+        monsters,
+        &[("main_1.toml", LEVEL_1_TOML), ("main_2.toml", LEVEL_2_TOML)],
+    )
+}
+
+fn difficulty(depth: i16, experience_level: u8) -> LevelDifficulty {
+    LevelDifficulty {
+        depth,
+        experience_level,
+        in_hell: false,
+    }
+}
+
+fn selected_monsters(registry: &ContentRegistry, difficulty: LevelDifficulty) -> BTreeSet<String> {
+    let mut rng = GameRng::new(7);
+    (0..200)
+        .filter_map(|_| registry.select_monster(&mut rng, difficulty))
+        .map(|kind| kind.content_id().to_owned())
+        .collect()
+}
+
+#[test]
+fn level_difficulty_bounds_follow_depth_and_experience() {
+    let shallow = difficulty(1, 1);
+    assert_eq!(shallow.min_monster_difficulty(), 0);
+    assert_eq!(shallow.max_monster_difficulty(), 1);
+    let deep = difficulty(12, 8);
+    assert_eq!(deep.min_monster_difficulty(), 2);
+    assert_eq!(deep.max_monster_difficulty(), 10);
+}
+
+#[test]
+fn selection_stays_inside_the_difficulty_window_and_skips_no_gen() {
+    let registry = registry(ITEMS_TOML, MONSTERS_TOML).unwrap();
+
+    assert_eq!(
+        selected_monsters(&registry, difficulty(1, 1)),
+        BTreeSet::from(["monster.jackal".to_owned()])
+    );
+    assert_eq!(
+        selected_monsters(&registry, difficulty(12, 30)),
+        BTreeSet::from([
+            "monster.floating_eye".to_owned(),
+            "monster.goblin".to_owned()
+        ])
+    );
+    let mut rng = GameRng::new(7);
+    assert_eq!(registry.select_monster(&mut rng, difficulty(60, 1)), None);
+}
+
+#[test]
+fn hell_only_monsters_appear_only_in_gehennom() {
+    let registry = registry(ITEMS_TOML, &format!("{MONSTERS_TOML}{IMP}")).unwrap();
+
+    assert!(!selected_monsters(&registry, difficulty(1, 1)).contains("monster.imp"));
+    let hell = LevelDifficulty {
+        in_hell: true,
+        ..difficulty(1, 1)
+    };
+    assert!(selected_monsters(&registry, hell).contains("monster.imp"));
+}
+
+#[test]
+fn group_size_shrinks_for_low_experience_levels() {
+    let registry = registry(ITEMS_TOML, MONSTERS_TOML).unwrap();
+    let mut rng = GameRng::new(7);
+
+    let novice = (0..100)
+        .map(|_| registry.monster_group_size(&mut rng, MonsterKind::Jackal, 1))
+        .collect::<BTreeSet<_>>();
+    assert_eq!(novice, BTreeSet::from([0, 1]));
+    let veteran = (0..100)
+        .map(|_| registry.monster_group_size(&mut rng, MonsterKind::Jackal, 10))
+        .collect::<BTreeSet<_>>();
+    assert_eq!(veteran, BTreeSet::from([0, 1, 2, 3]));
+    assert!((0..20).all(|_| registry.monster_group_size(&mut rng, MonsterKind::Goblin, 10) == 0));
+}
+
+#[test]
+fn item_selection_uses_class_and_item_probabilities() {
+    let registry = registry(ITEMS_TOML, MONSTERS_TOML).unwrap();
+    let mut rng = GameRng::new(7);
+
+    let items = (0..500)
+        .filter_map(|_| registry.select_item(&mut rng))
+        .collect::<Vec<_>>();
+    assert_eq!(items.len(), 500);
+    assert!(!items.contains(&ItemKind::Rock));
+    assert!(items.iter().all(|kind| kind.corpse_of().is_none()));
+    let rations = items
+        .iter()
+        .filter(|kind| **kind == ItemKind::FoodRation)
+        .count();
+    assert!((50..150).contains(&rations), "{rations}");
+
+    let rings = (0..20)
+        .filter_map(|_| registry.select_item_of_class(&mut rng, ItemClass::Ring))
+        .collect::<BTreeSet<_>>();
+    assert_eq!(
+        rings,
+        BTreeSet::from([ItemKind::RingAdornment, ItemKind::RingProtection])
+    );
+    assert_eq!(
+        registry.select_item_of_class(&mut rng, ItemClass::Corpse),
+        None
+    );
+}
+
+#[test]
+fn invalid_generation_content_is_rejected() {
+    let monsters = MONSTERS_TOML.replacen("frequency=3", "frequency=8", 1);
+    assert!(matches!(
+        registry(ITEMS_TOML, &monsters),
+        Err(ContentError::Parse { message, .. }) if message.contains("frequency")
+    ));
+
+    let monsters = MONSTERS_TOML.replacen("[\"small_group\"]", "[\"unique\"]", 1);
+    assert!(matches!(
+        registry(ITEMS_TOML, &monsters),
+        Err(ContentError::UnknownReference { target, .. }) if target == "unique"
+    ));
+
+    let monsters =
+        MONSTERS_TOML.replacen("[\"small_group\"]", "[\"small_group\", \"large_group\"]", 1);
+    assert!(registry(ITEMS_TOML, &monsters).is_err());
+
+    let items = ITEMS_TOML.replacen(
+        "kind=\"ring\"\nprobability=3",
+        "kind=\"amulet\"\nprobability=3",
+        1,
+    );
+    assert!(matches!(
+        registry(&items, MONSTERS_TOML),
+        Err(ContentError::UnknownReference { target, .. }) if target == "amulet"
+    ));
+}
+
+#[test]
+fn create_monster_scroll_picks_monsters_for_the_current_level() {
+    for seed in 0..10 {
+        let mut session = GameSession::new_for_playing(seed);
+        let scroll = SessionBuilder::mutate(&mut session, |world| {
+            world.saved().entities.clear_monsters();
+            let scroll = world.give_item(ItemKind::ScrollCreateMonster);
+            world
+                .saved()
+                .entities
+                .set_item_buc(scroll, BucStatus::Uncursed);
+            scroll
+        });
+
+        let events = session.submit(CommandIntent::Read { item: scroll }).events;
+
+        assert!(events.iter().all(|event| !matches!(
+            event,
+            GameEvent::MonsterCreated { kind, .. } if *kind != MonsterKind::Jackal
+        )));
+    }
+}
@@ -0,0 +1,221 @@
+use aihack::{
+    core::{CommandIntent, EntityId, GameEvent, GameSession, Pos, SaveDataV1},
+    domain::{
+        entity::{ActorStats, EntityLocation},
+        item::{BucStatus, ItemKind},
+    },
+    testing::{has_message, player_hp, player_max_hp, SessionBuilder},
+    ui::tui::{render_panels::inspect_lines, UiPanel},
+};
+
+const ITEMS_TOML: &str = include_str!("../crates/aihack-content/src/data/items.toml");
+const MONSTERS_TOML: &str = include_str!("../crates/aihack-content/src/data/monsters.toml");
+const LEVEL_1_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_1.toml");
+const LEVEL_2_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_2.toml");
+
+const JACKAL: EntityId = EntityId(2);
+const GOBLIN: EntityId = EntityId(3);
+const JACKAL_START: Pos = Pos { x: 6, y: 5 };
+
+/// 고블린을 치우고 플레이어 곁 (6,5)의 자칼만 남긴다.
+fn jackal_session() -> GameSession {
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.set_alive(GOBLIN, false)
+    });
+    session
+}
+
+fn jackal(session: &GameSession) -> ActorStats {
+    *session.world().entities().actor_stats(JACKAL).unwrap()
+}
+
+fn jackal_pos(session: &GameSession) -> Pos {
+    session.world().entities().actor_location(JACKAL).unwrap().1
+}
+
+fn farlook(session: &GameSession, pos: Pos) -> String {
+    inspect_lines(
+        &session.observation(),
+        session.world().content(),
+        Some(pos),
+        UiPanel::Inspect,
+        &[],
+    )[2]
+    .clone()
+}
+
+fn read(session: &mut GameSession, buc: BucStatus) -> Vec<GameEvent> {
+    let scroll = SessionBuilder::mutate(session, |world| {
+        let scroll = world.give_item(ItemKind::ScrollScareMonster);
+        world.saved().entities.set_item_buc(scroll, buc);
+        scroll
+    });
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+    assert!(outcome.accepted);
+    outcome.events
+}
+
+#[test]
+fn level_content_places_a_monster_asleep_and_farlook_shows_it() {
+    let level_1 = LEVEL_1_TOML.replacen("pos=[6,5]", "pos=[6,5]\nasleep=true", 1);
+    let registry = aihack::data::ContentRegistry::from_toml_sources(
+        1,
+        ITEMS_TOML,
+        MONSTERS_TOML,
+        &[("main_1.toml", &level_1), ("main_2.toml", LEVEL_2_TOML)],
+    )
+    .unwrap();
+    let mut session = GameSession::try_new_for_playing_with_registry(42, &registry).unwrap();
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.set_alive(GOBLIN, false);
+        world.saved().conditions.stealth = true;
+    });
+
+    let observed = session
+        .observation()
+        .visible_entities
+        .into_iter()
+        .find(|entity| entity.entity == JACKAL)
+        .unwrap();
+    assert!(observed.asleep);
+    assert!(!observed.fleeing);
+    assert!(farlook(&session, JACKAL_START).ends_with("sleeping"));
+
+    for _ in 0..20 {
+        assert!(session.submit(CommandIntent::Wait).accepted);
+    }
+    assert!(jackal(&session).asleep);
+    assert_eq!(jackal_pos(&session), JACKAL_START);
+    let (hp, max_hp) = (player_hp(&session), player_max_hp(&session));
+    assert_eq!(hp, max_hp);
+}
+
+#[test]
+fn sleeping_monster_near_a_noisy_player_eventually_wakes() {
+    let mut session = jackal_session();
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .actor_stats_mut(JACKAL)
+            .unwrap()
+            .asleep = true;
+    });
+
+    let woke =
+        (0..60).any(|_| {
+            session.submit(CommandIntent::Wait).events.iter().any(
+                |event| matches!(event, GameEvent::MonsterWokeUp { entity } if *entity == JACKAL),
+            )
+        });
+
+    assert!(woke);
+    assert!(!jackal(&session).asleep);
+    assert!(!farlook(&session, jackal_pos(&session)).contains("sleeping"));
+}
+
+#[test]
+fn scare_monster_makes_seen_monsters_flee_until_their_timer_runs_out() {
+    let mut session = jackal_session();
+
+    let events = read(&mut session, BucStatus::Uncursed);
+
+    assert!(events.contains(&GameEvent::MonsterFled {
+        entity: JACKAL,
+        turns: 0,
+    }));
+    assert!(has_message(&events, "You hear maniacal laughter close by."));
+    assert!(jackal(&session).fleeing);
+    assert!(farlook(&session, jackal_pos(&session)).ends_with("fleeing"));
+
+    let player = session.world().player_pos();
+    for _ in 0..3 {
+        assert!(session.submit(CommandIntent::Wait).accepted);
+    }
+    assert!(jackal_pos(&session).chebyshev_distance(player) > 1);
+    let (hp, max_hp) = (player_hp(&session), player_max_hp(&session));
+    assert_eq!(hp, max_hp);
+
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .actor_stats_mut(JACKAL)
+            .unwrap()
+            .flee_turns = 2;
+    });
+    let mut events = Vec::new();
+    for _ in 0..2 {
+        events.extend(session.submit(CommandIntent::Wait).events);
+    }
+    assert!(events.contains(&GameEvent::MonsterRallied { entity: JACKAL }));
+    assert!(!jackal(&session).fleeing);
+}
+
+#[test]
+fn cursed_scare_monster_wakes_and_rallies_seen_monsters() {
+    let mut session = jackal_session();
+    SessionBuilder::mutate(&mut session, |world| {
+        let stats = world.saved().entities.actor_stats_mut(JACKAL).unwrap();
+        stats.asleep = true;
+        stats.fleeing = true;
+    });
+
+    let events = read(&mut session, BucStatus::Cursed);
+
+    assert!(has_message(&events, "You hear sad wailing close by."));
+    assert!(events.contains(&GameEvent::MonsterWokeUp { entity: JACKAL }));
+    assert!(events.contains(&GameEvent::MonsterRallied { entity: JACKAL }));
+    let stats = jackal(&session);
+    assert!(!stats.asleep && !stats.fleeing);
+}
+
+#[test]
+fn standing_on_scare_monster_keeps_adjacent_monsters_from_attacking() {
+    let mut session = jackal_session();
+    let player = session.world().player_pos();
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.spawn_item(
+            ItemKind::ScrollScareMonster,
+            EntityLocation::on_main_level(player),
+        );
+    });
+
+    let mut events = Vec::new();
+    for _ in 0..20 {
+        events.extend(session.submit(CommandIntent::Wait).events);
+    }
+
+    assert!(events
+        .iter()
+        .any(|event| matches!(event, GameEvent::MonsterFled { entity, .. } if *entity == JACKAL)));
+    assert!(has_message(&events, "The jackal turns to flee."));
+    assert!(!events.iter().any(|event| matches!(
+        event,
+        GameEvent::AttackResolved { attacker, .. } if *attacker == JACKAL
+    )));
+}
+
+#[test]
+fn sleep_and_flee_state_survive_save_and_load() {
+    let mut session = jackal_session();
+    SessionBuilder::mutate(&mut session, |world| {
+        let stats = world.saved().entities.actor_stats_mut(JACKAL).unwrap();
+        stats.asleep = true;
+        stats.fleeing = true;
+        stats.flee_turns = 30;
+    });
+
+    let json = serde_json::to_string(&session.to_save_data()).unwrap();
+    let decoded: SaveDataV1 = serde_json::from_str(&json).unwrap();
+    let restored = GameSession::from_save_data(decoded).unwrap();
+
+    let stats = jackal(&restored);
+    assert!(stats.asleep && stats.fleeing);
+    assert_eq!(stats.flee_turns, 30);
+    assert_eq!(
+        session.snapshot().stable_hash(),
+        restored.snapshot().stable_hash()
+    );
+}
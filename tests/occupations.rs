@@ -0,0 +1,224 @@
+use aihack::{
+    core::{
+        policy::{run_replay_to_turn, HeadlessPolicy},
+        CommandIntent, Direction, EntityId, GameEvent, GameSession, Pos, RepeatIntent,
+        ReplayLineV1,
+    },
+    domain::{
+        entity::EntityLocation,
+        item::ItemKind,
+        monster::MonsterKind,
+        player::{InterruptReason, Occupation},
+    },
+    testing::{has_message, session_with, SessionBuilder},
+};
+
+const FOOD_RATION: EntityId = EntityId(6);
+
+fn quiet_session(seed: u64) -> GameSession {
+    session_with(seed, |world| {
+        world.saved().nutrition = 500;
+    })
+    .0
+}
+
+fn interruption(events: &[GameEvent]) -> Option<(Occupation, InterruptReason)> {
+    events.iter().find_map(|event| match event {
+        GameEvent::OccupationInterrupted { occupation, reason } => Some((*occupation, *reason)),
+        _ => None,
+    })
+}
+
+#[test]
+fn a_food_ration_is_eaten_over_five_turns_in_one_command() {
+    let mut session = quiet_session(42);
+
+    let outcome = session.submit(CommandIntent::Eat { item: FOOD_RATION });
+
+    assert!(outcome.accepted);
+    assert_eq!(outcome.turns_elapsed, 5);
+    assert_eq!(session.turn(), 5);
+    assert!(has_message(&outcome.events, "You finish your meal."));
+    assert_eq!(
+        session.world().entities().item_location(FOOD_RATION),
+        Some(EntityLocation::Consumed)
+    );
+    assert_eq!(session.world().status().nutrition, 500 + 800 - 5);
+}
+
+#[test]
+fn counted_search_and_rest_take_one_turn_per_count() {
+    let mut session = quiet_session(42);
+
+    let search = session.submit(CommandIntent::Repeat {
+        count: 4,
+        intent: RepeatIntent::Search,
+    });
+    assert!(search.accepted);
+    assert_eq!(search.turns_elapsed, 4);
+
+    let rest = session.submit(CommandIntent::Repeat {
+        count: 6,
+        intent: RepeatIntent::Wait,
+    });
+    assert!(rest.accepted);
+    assert_eq!(rest.turns_elapsed, 6);
+    assert_eq!(
+        rest.events
+            .iter()
+            .filter(|event| matches!(event, GameEvent::Waited { .. }))
+            .count(),
+        6
+    );
+    assert_eq!(session.turn(), 10);
+
+    assert!(
+        !session
+            .submit(CommandIntent::Repeat {
+                count: 0,
+                intent: RepeatIntent::Wait,
+            })
+            .accepted
+    );
+}
+
+#[test]
+fn resting_stops_when_a_hostile_comes_into_view() {
+    let mut session = quiet_session(42);
+    let jackal = SessionBuilder::mutate(&mut session, |world| {
+        world.set_player_pos(Pos { x: 30, y: 15 });
+        world
+            .saved()
+            .entities
+            .spawn_monster(MonsterKind::Jackal, Pos { x: 30, y: 3 })
+    });
+
+    let outcome = session.submit(CommandIntent::Repeat {
+        count: 50,
+        intent: RepeatIntent::Wait,
+    });
+
+    assert!(outcome.accepted);
+    assert!(outcome.turns_elapsed < 50);
+    assert_eq!(
+        interruption(&outcome.events),
+        Some((
+            Occupation::Resting {
+                remaining: 50 - outcome.turns_elapsed as u16
+            },
+            InterruptReason::MonsterAppeared { monster: jackal }
+        ))
+    );
+    assert!(has_message(&outcome.events, "You stop waiting."));
+}
+
+#[test]
+fn a_meal_is_interrupted_by_a_nearby_hostile_and_can_be_resumed() {
+    let mut session = quiet_session(42);
+    let pos = session.world().player_pos();
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.spawn_monster(
+            MonsterKind::Jackal,
+            Pos {
+                x: pos.x + 1,
+                y: pos.y,
+            },
+        );
+    });
+
+    let outcome = session.submit(CommandIntent::Eat { item: FOOD_RATION });
+
+    assert!(outcome.accepted);
+    assert!(matches!(
+        interruption(&outcome.events),
+        Some((
+            Occupation::Eating {
+                item: FOOD_RATION,
+                ..
+            },
+            InterruptReason::MonsterAppeared { .. } | InterruptReason::Damaged
+        ))
+    ));
+    assert!(has_message(&outcome.events, "You stop eating."));
+    assert!(session.world().inventory().contains(FOOD_RATION));
+
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters()
+    });
+    let resumed = session.submit(CommandIntent::Eat { item: FOOD_RATION });
+    assert!(has_message(&resumed.events, "You resume your meal."));
+    assert!(has_message(&resumed.events, "You finish your meal."));
+}
+
+#[test]
+fn donning_body_armor_takes_its_delay_before_it_counts() {
+    let mut session = quiet_session(42);
+    let armor = SessionBuilder::mutate(&mut session, |world| {
+        world.give_item(ItemKind::ArmorLeather)
+    });
+
+    let outcome = session.submit(CommandIntent::Wear { item: armor });
+
+    assert!(outcome.accepted);
+    assert!(outcome.turns_elapsed > 1);
+    assert!(has_message(
+        &outcome.events,
+        "You finish your dressing maneuver."
+    ));
+    assert_eq!(session.world().inventory().equipped_body, Some(armor));
+}
+
+#[test]
+fn an_occupation_is_one_replay_line_that_replays_to_the_same_state() {
+    let mut source = quiet_session(42);
+    let command = CommandIntent::Repeat {
+        count: 7,
+        intent: RepeatIntent::Wait,
+    };
+    let turn_before = source.turn();
+    let outcome = source.submit(command);
+    let line = ReplayLineV1 {
+        turn_before,
+        command,
+        snapshot_hash_after: outcome.snapshot_hash.clone(),
+        outcome,
+    };
+    let mut replayed = quiet_session(42);
+
+    let report = run_replay_to_turn(&mut replayed, 7, &[line]).unwrap();
+
+    assert_eq!(report.policy, HeadlessPolicy::ReplayFile);
+    assert_eq!(report.submitted_commands, 1);
+    assert_eq!(report.accepted_turns, 7);
+    assert_eq!(
+        replayed.snapshot().stable_hash(),
+        source.snapshot().stable_hash()
+    );
+}
+
+#[test]
+fn a_counted_move_walks_until_the_count_or_an_obstacle() {
+    let mut session = quiet_session(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.set_player_pos(Pos { x: 5, y: 10 })
+    });
+    let walk = |direction| CommandIntent::Repeat {
+        count: 4,
+        intent: RepeatIntent::Move(direction),
+    };
+
+    let outcome = session.submit(walk(Direction::East));
+    assert!(outcome.accepted);
+    assert_eq!(outcome.turns_elapsed, 4);
+    assert_eq!(session.world().player_pos(), Pos { x: 9, y: 10 });
+
+    // main:1의 (10,5)는 닫힌 문이라 그 앞에서 조용히 멈춘다.
+    SessionBuilder::mutate(&mut session, |world| {
+        world.set_player_pos(Pos { x: 8, y: 5 })
+    });
+    let outcome = session.submit(walk(Direction::East));
+    assert!(outcome.accepted);
+    assert_eq!(outcome.turns_elapsed, 1);
+    assert_eq!(session.world().player_pos(), Pos { x: 9, y: 5 });
+    assert_eq!(interruption(&outcome.events), None);
+}
@@ -0,0 +1,340 @@
+use aihack::{
+    core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, Pos},
+    domain::{
+        entity::EntityLocation,
+        item::{try_item_data, BucStatus, ConsumableEffect, ItemClass, ItemKind},
+        status::StatusEffect,
+    },
+    testing::{player_max_hp, session_with_items, SessionBuilder},
+};
+
+const POTIONS: [ItemKind; 10] = [
+    ItemKind::PotionHealing,
+    ItemKind::PotionExtraHealing,
+    ItemKind::PotionGainLevel,
+    ItemKind::PotionConfusion,
+    ItemKind::PotionBlindness,
+    ItemKind::PotionSleeping,
+    ItemKind::PotionSeeInvisible,
+    ItemKind::PotionSpeed,
+    ItemKind::PotionSickness,
+    ItemKind::PotionWater,
+];
+
+#[test]
+fn potion_catalogue_is_loaded_from_content() {
+    for kind in POTIONS {
+        let data = try_item_data(kind).unwrap();
+        assert_eq!(data.class, ItemClass::Potion, "{kind:?}");
+        assert!(data.consumable_effect.is_some(), "{kind:?}");
+    }
+    assert_eq!(
+        try_item_data(ItemKind::PotionExtraHealing)
+            .unwrap()
+            .consumable_effect,
+        Some(ConsumableEffect::Heal {
+            dice: 6,
+            sides: 4,
+            bonus: 0,
+        })
+    );
+}
+
+#[test]
+fn confusion_potion_sets_timer_identifies_and_wears_off() {
+    let (mut session, items) = session_with_items(42, &[ItemKind::PotionConfusion]);
+
+    let outcome = session.submit(CommandIntent::Quaff { item: items[0] });
+
+    assert!(outcome.accepted);
+    let turns = session.world().conditions.confusion_turns;
+    assert!((8..=30).contains(&turns), "{turns}");
+    assert!(session
+        .world()
+        .is_item_identified(ItemKind::PotionConfusion));
+    assert_eq!(session.observation().player.confusion_turns, turns);
+    for _ in 0..turns {
+        assert!(session.submit(CommandIntent::Wait).accepted);
+    }
+    assert!(!session.world().conditions.is_confused());
+    assert!(session.event_log().iter().any(|event| matches!(
+        event,
+        GameEvent::StatusChanged {
+            effect: StatusEffect::Confusion,
+            turns: 0,
+            ..
+        }
+    )));
+}
+
+#[test]
+fn blindness_limits_observation_to_own_square() {
+    let (mut session, items) = session_with_items(42, &[ItemKind::PotionBlindness]);
+
+    assert!(
+        session
+            .submit(CommandIntent::Quaff { item: items[0] })
+            .accepted
+    );
+
+    let observation = session.observation();
+    assert!(observation.player.blindness_turns > 0);
+    assert_eq!(observation.visible_tiles.len(), 1);
+    assert_eq!(observation.visible_tiles[0].pos, observation.player_pos);
+}
+
+#[test]
+fn sleeping_potion_leaves_player_helpless() {
+    let (mut session, items) = session_with_items(42, &[ItemKind::PotionSleeping]);
+
+    assert!(
+        session
+            .submit(CommandIntent::Quaff { item: items[0] })
+            .accepted
+    );
+
+    assert!(session.world().paralysis_turns >= 13);
+    assert!(!session.submit(CommandIntent::Search).accepted);
+    assert!(session.submit(CommandIntent::Wait).accepted);
+}
+
+#[test]
+fn gain_level_raises_experience_and_max_hp() {
+    let (mut session, items) = session_with_items(42, &[ItemKind::PotionGainLevel]);
+    let max_before = player_max_hp(&session);
+
+    assert!(
+        session
+            .submit(CommandIntent::Quaff { item: items[0] })
+            .accepted
+    );
+
+    assert_eq!(session.world().experience_level, 2);
+    assert!(player_max_hp(&session) > max_before);
+    assert_eq!(session.observation().player.experience_level, 2);
+}
+
+#[test]
+fn sickness_potion_can_kill_with_consumable_cause() {
+    let (mut session, items) = session_with_items(42, &[ItemKind::PotionSickness]);
+    let player = session.world().player_id();
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.actor_stats_mut(player).unwrap().hp = 1;
+    });
+
+    let outcome = session.submit(CommandIntent::Quaff { item: items[0] });
+
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::EntityDied {
+            cause: aihack::domain::combat::DeathCause::Consumable {
+                item: ItemKind::PotionSickness
+            },
+            ..
+        }
+    )));
+}
+
+#[test]
+fn dipping_water_into_holy_water_makes_more_holy_water() {
+    let (mut session, items) =
+        session_with_items(42, &[ItemKind::PotionWater, ItemKind::PotionWater]);
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .set_item_buc(items[1], BucStatus::Blessed);
+    });
+
+    let outcome = session.submit(CommandIntent::Dip {
+        item: items[0],
+        into: items[1],
+    });
+
+    assert!(outcome.accepted);
+    let entities = session.world().entities();
+    assert_eq!(entities.item_buc(items[0]), Some(BucStatus::Blessed));
+    assert_eq!(
+        entities.item_location(items[1]),
+        Some(EntityLocation::Consumed)
+    );
+    assert!(session.world().is_item_identified(ItemKind::PotionWater));
+}
+
+#[test]
+fn uncursed_water_dilutes_then_turns_potion_to_water() {
+    let (mut session, items) = session_with_items(
+        42,
+        &[
+            ItemKind::PotionSpeed,
+            ItemKind::PotionWater,
+            ItemKind::PotionWater,
+        ],
+    );
+
+    assert!(
+        session
+            .submit(CommandIntent::Dip {
+                item: items[0],
+                into: items[1],
+            })
+            .accepted
+    );
+    assert!(session.world().entities().item_diluted(items[0]));
+
+    assert!(
+        session
+            .submit(CommandIntent::Dip {
+                item: items[0],
+                into: items[2],
+            })
+            .accepted
+    );
+    let (kind, _, _, _, _) = session
+        .world()
+        .entities()
+        .get(items[0])
+        .unwrap()
+        .item()
+        .unwrap();
+    assert_eq!(kind, ItemKind::PotionWater);
+    assert!(!session.world().entities().item_diluted(items[0]));
+}
+
+#[test]
+fn mixing_follows_content_table_or_explodes() {
+    let (mut session, items) =
+        session_with_items(42, &[ItemKind::PotionHealing, ItemKind::PotionSpeed]);
+
+    let outcome = session.submit(CommandIntent::Dip {
+        item: items[0],
+        into: items[1],
+    });
+
+    assert!(outcome.accepted);
+    let exploded = outcome
+        .events
+        .iter()
+        .any(|event| matches!(event, GameEvent::Message { text, .. } if text.starts_with("BOOM")));
+    if !exploded {
+        assert!(outcome.events.contains(&GameEvent::ItemTransformed {
+            item: items[0],
+            from: ItemKind::PotionHealing,
+            to: ItemKind::PotionExtraHealing,
+        }));
+        assert!(session.world().entities().item_diluted(items[0]));
+    }
+    assert_eq!(
+        session.world().entities().item_location(items[1]),
+        Some(EntityLocation::Consumed)
+    );
+}
+
+#[test]
+fn mixing_into_a_cursed_potion_always_explodes_for_alchemic_blast_damage() {
+    for seed in 0..8 {
+        let (mut session, items) =
+            session_with_items(seed, &[ItemKind::PotionSeeInvisible, ItemKind::PotionSpeed]);
+        let player = session.world().player_id();
+        SessionBuilder::mutate(&mut session, |world| {
+            world
+                .saved()
+                .entities
+                .set_item_buc(items[1], BucStatus::Cursed);
+        });
+
+        let outcome = session.submit(CommandIntent::Dip {
+            item: items[0],
+            into: items[1],
+        });
+
+        assert!(outcome.accepted);
+        assert!(outcome.events.iter().any(
+            |event| matches!(event, GameEvent::Message { text, .. } if text == "BOOM!  They explode!")
+        ));
+        // `losehp(amt + rnd(9))`: 물약 하나에 1d9를 더한다.
+        assert!(outcome.events.iter().any(|event| matches!(
+            event,
+            GameEvent::EntityDamaged { entity, amount, .. }
+                if *entity == player && (2..=10).contains(amount)
+        )));
+        for item in items {
+            assert_eq!(
+                session.world().entities().item_location(item),
+                Some(EntityLocation::Consumed)
+            );
+        }
+    }
+}
+
+#[test]
+fn thrown_confusion_blindness_and_sleeping_potions_affect_the_monster_hit() {
+    for kind in [
+        ItemKind::PotionConfusion,
+        ItemKind::PotionBlindness,
+        ItemKind::PotionSleeping,
+    ] {
+        let mut session = GameSession::new_for_playing(42);
+        let jackal = EntityId(2);
+        let potion = SessionBuilder::mutate(&mut session, |world| world.give_item(kind));
+
+        let outcome = session.submit(CommandIntent::Throw {
+            item: potion,
+            direction: Direction::East,
+        });
+
+        assert!(outcome.accepted);
+        let stats = *session.world().entities().actor_stats(jackal).unwrap();
+        match kind {
+            ItemKind::PotionConfusion => assert!(stats.confused),
+            // `64 + rn2(32) + rn2(32)`에서 한 턴이 지났다.
+            ItemKind::PotionBlindness => {
+                assert!((63..=125).contains(&stats.blinded_turns), "{stats:?}")
+            }
+            _ => assert!(!outcome.events.iter().any(|event| matches!(
+                event,
+                GameEvent::AttackResolved { attacker, .. } if *attacker == jackal
+            ))),
+        }
+    }
+}
+
+#[test]
+fn thrown_healing_potion_shatters_on_monster_and_vapors_reach_player() {
+    let mut session = GameSession::new_for_playing(42);
+    let player = session.world().player_id();
+    let potion = SessionBuilder::mutate(&mut session, |world| {
+        let jackal = EntityId(2);
+        world.saved().entities.actor_stats_mut(jackal).unwrap().hp = 1;
+        world.saved().entities.actor_stats_mut(player).unwrap().hp = 3;
+        world.give_item(ItemKind::PotionHealing)
+    });
+
+    let outcome = session.submit(CommandIntent::Throw {
+        item: potion,
+        direction: Direction::East,
+    });
+
+    assert!(outcome.accepted);
+    assert!(outcome.events.contains(&GameEvent::PotionShattered {
+        item: potion,
+        pos: Pos { x: 6, y: 5 },
+        target: Some(EntityId(2)),
+    }));
+    assert!(outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::EntityHealed {
+            entity: EntityId(2),
+            ..
+        }
+    )));
+    assert!(outcome.events.contains(&GameEvent::EntityHealed {
+        entity: player,
+        amount: 1,
+        hp_after: 4,
+    }));
+    assert_eq!(
+        session.world().entities().item_location(potion),
+        Some(EntityLocation::Consumed)
+    );
+}
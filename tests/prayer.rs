@@ -0,0 +1,213 @@
+use aihack::{
+    core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, LuckFeeling, RunState},
+    domain::{combat::DeathCause, item::BucStatus, status::Trouble},
+    testing::{has_message, session_with, SessionBuilder},
+};
+
+const JACKAL: EntityId = EntityId(2);
+const DAGGER: EntityId = EntityId(5);
+
+fn session_ready_to_pray(seed: u64) -> GameSession {
+    session_with(seed, |world| {
+        world.saved().prayer_cooldown = 0;
+    })
+    .0
+}
+
+fn set_player_hp(session: &mut GameSession, hp: i16) {
+    SessionBuilder::mutate(session, |world| {
+        let player = world.saved().player_id;
+        world.saved().entities.actor_stats_mut(player).unwrap().hp = hp;
+    });
+}
+
+fn fixed(events: &[GameEvent], trouble: Trouble) -> bool {
+    events.iter().any(|event| {
+        matches!(
+            event,
+            GameEvent::TroubleFixed { trouble: fixed, .. } if *fixed == trouble
+        )
+    })
+}
+
+#[test]
+fn prayer_heals_low_hit_points_and_resets_the_timeout() {
+    let mut session = session_ready_to_pray(42);
+    set_player_hp(&mut session, 2);
+
+    let outcome = session.submit(CommandIntent::Pray);
+
+    assert!(fixed(&outcome.events, Trouble::LowHitPoints));
+    assert!(has_message(&outcome.events, "You feel much better."));
+    let player = session.observation().player;
+    assert_eq!(player.hp, player.max_hp);
+    assert!(player.prayer_cooldown > 0);
+}
+
+#[test]
+fn major_trouble_can_be_fixed_before_the_timeout_runs_out() {
+    let mut session = session_ready_to_pray(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().prayer_cooldown = 150;
+        world.saved().nutrition = 40;
+    });
+
+    let outcome = session.submit(CommandIntent::Pray);
+
+    assert!(fixed(&outcome.events, Trouble::Starving));
+    assert!(has_message(&outcome.events, "Your stomach feels content."));
+    assert_eq!(session.world().status().nutrition, 899);
+}
+
+#[test]
+fn prayer_cures_food_poisoning() {
+    let mut session = session_ready_to_pray(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().conditions.food_poisoning_turns = 5;
+    });
+
+    let outcome = session.submit(CommandIntent::Pray);
+
+    assert!(fixed(&outcome.events, Trouble::FoodPoisoning));
+    assert_eq!(session.observation().player.food_poisoning_turns, 0);
+    for _ in 0..10 {
+        assert!(session.submit(CommandIntent::Wait).accepted);
+    }
+    assert_eq!(session.run_state(), RunState::Playing);
+}
+
+#[test]
+fn praying_too_soon_for_a_minor_trouble_angers_the_god() {
+    let mut session = session_ready_to_pray(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().prayer_cooldown = 150;
+        world.saved().conditions.confusion_turns = 10;
+    });
+    let player = session.world().player_id();
+
+    let outcome = session.submit(CommandIntent::Pray);
+
+    assert!(outcome.events.contains(&GameEvent::GodAngered {
+        entity: player,
+        anger: 1,
+    }));
+    assert!(!fixed(&outcome.events, Trouble::Confused));
+    assert_eq!(session.snapshot().luck, -3);
+    assert_eq!(session.observation().player.luck, LuckFeeling::Unlucky);
+    assert_eq!(session.observation().player.god_anger, 1);
+}
+
+#[test]
+fn lucky_prayer_can_uncurse_the_wielded_weapon() {
+    let mut uncursed = 0;
+    for seed in 0..10 {
+        let mut session = session_ready_to_pray(seed);
+        SessionBuilder::mutate(&mut session, |world| {
+            world.saved().luck = 3;
+            world
+                .saved()
+                .entities
+                .set_item_buc(DAGGER, BucStatus::Cursed);
+        });
+        assert!(
+            session
+                .submit(CommandIntent::Wield { item: DAGGER })
+                .accepted
+        );
+
+        let outcome = session.submit(CommandIntent::Pray);
+
+        if fixed(&outcome.events, Trouble::CursedItems) {
+            assert!(has_message(
+                &outcome.events,
+                "Your weapon softly glows amber."
+            ));
+            assert_eq!(
+                session.world().entities().item_buc(DAGGER),
+                Some(BucStatus::Uncursed)
+            );
+            uncursed += 1;
+        }
+    }
+    assert!(uncursed > 0);
+}
+
+#[test]
+fn low_alignment_prayer_only_brings_a_hopeful_feeling() {
+    let mut session = session_ready_to_pray(42);
+    SessionBuilder::mutate(&mut session, |world| world.saved().alignment = 1);
+    set_player_hp(&mut session, 2);
+
+    let outcome = session.submit(CommandIntent::Pray);
+
+    assert!(has_message(&outcome.events, "You feel a hopeful feeling."));
+    assert!(fixed(&outcome.events, Trouble::LowHitPoints));
+}
+
+#[test]
+fn negative_alignment_makes_the_god_refuse_to_help() {
+    let mut session = session_ready_to_pray(42);
+    SessionBuilder::mutate(&mut session, |world| world.saved().alignment = -1);
+    set_player_hp(&mut session, 2);
+
+    let outcome = session.submit(CommandIntent::Pray);
+
+    assert!(!fixed(&outcome.events, Trouble::LowHitPoints));
+    assert!(!has_message(
+        &outcome.events,
+        "You are surrounded by a shimmering light."
+    ));
+}
+
+#[test]
+fn a_furious_god_can_smite_the_player() {
+    let mut smitten = 0;
+    for seed in 0..10 {
+        let mut session = session_ready_to_pray(seed);
+        SessionBuilder::mutate(&mut session, |world| world.saved().god_anger = 5);
+
+        let outcome = session.submit(CommandIntent::Pray);
+
+        assert!(outcome.accepted);
+        if let RunState::GameOver { cause, .. } = session.run_state() {
+            assert_eq!(cause, DeathCause::DivineWrath);
+            assert!(has_message(
+                &outcome.events,
+                "Suddenly, a bolt of lightning strikes you!"
+            ));
+            smitten += 1;
+        }
+    }
+    assert!(smitten > 0);
+}
+
+#[test]
+fn killing_a_hostile_monster_raises_alignment() {
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().alignment = 5;
+        world.saved().entities.set_alive(EntityId(3), false);
+        world.saved().entities.actor_stats_mut(JACKAL).unwrap().hp = 1;
+        world.saved().inventory.equipped_melee = Some(DAGGER);
+    });
+
+    for _ in 0..20 {
+        if !session
+            .world()
+            .entities()
+            .get(JACKAL)
+            .unwrap()
+            .is_alive_actor()
+        {
+            break;
+        }
+        assert!(
+            session
+                .submit(CommandIntent::Move(Direction::East))
+                .accepted
+        );
+    }
+
+    assert_eq!(session.world().kill_count(), 1);
+    assert_eq!(session.observation().player.alignment, 6);
+}
@@ -114,7 +114,7 @@ fn wand_zap_hits_first_target_and_spends_charge() {
 }
 
 #[test]
-fn empty_wand_is_rejected() {
+fn empty_wand_usually_does_nothing_but_takes_a_turn() {
     let mut session = GameSession::new_for_playing(42);
     aihack::testing::SessionBuilder::mutate(&mut session, |world| {
         world.saved().entities.clear_monsters()
@@ -131,8 +131,13 @@ fn empty_wand_is_rejected() {
         direction: Direction::East,
     });
 
-    assert!(!outcome.accepted);
-    assert!(!outcome.turn_advanced);
+    assert!(outcome.accepted);
+    assert!(outcome.turn_advanced);
+    assert!(outcome.events.contains(&GameEvent::Message {
+        priority: aihack::core::event::MessagePriority::Info,
+        text: "Nothing happens.".to_string(),
+    }));
+    assert!(session.world().inventory().contains(EntityId(7)));
 }
 
 #[test]
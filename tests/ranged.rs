@@ -0,0 +1,204 @@
+use aihack::{
+    core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, Pos},
+    domain::{
+        combat::Missile,
+        entity::{ActorStats, EntityLocation},
+        item::ItemKind,
+    },
+    testing::{has_message, SessionBuilder},
+    ui::tui::render_panels::log_lines,
+};
+
+const ITEMS_TOML: &str = include_str!("../crates/aihack-content/src/data/items.toml");
+const MONSTERS_TOML: &str = include_str!("../crates/aihack-content/src/data/monsters.toml");
+const LEVEL_1_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_1.toml");
+const LEVEL_2_TOML: &str = include_str!("../crates/aihack-content/src/data/levels/main_2.toml");
+
+const JACKAL: EntityId = EntityId(2);
+const GOBLIN: EntityId = EntityId(3);
+/// 플레이어 (5,5)에서 남쪽으로 네 칸, 한 줄로 늘어선 자리다.
+const LINED_UP: Pos = Pos { x: 5, y: 9 };
+
+/// 자칼을 치우고 고블린을 `pos`에 세운 뒤 `kinds`를 쥐여 준다.
+fn goblin_session(pos: Pos, kinds: &[ItemKind]) -> (GameSession, Vec<EntityId>) {
+    let mut session = GameSession::new_for_playing(42);
+    let items = SessionBuilder::mutate(&mut session, |world| {
+        let level = aihack::core::LevelId::main(1);
+        let entities = &mut world.saved().entities;
+        entities.set_alive(JACKAL, false);
+        entities.set_actor_location(GOBLIN, level, pos);
+        kinds
+            .iter()
+            .map(|kind| entities.spawn_item(*kind, EntityLocation::Inventory { owner: GOBLIN }))
+            .collect()
+    });
+    (session, items)
+}
+
+/// 자칼의 공격 목록에 `ranged` 공격을 더한 내용으로 세션을 열고 고블린을 치운 뒤 자칼을
+/// `pos`에 세운다.
+fn jackal_session(ranged: &str, pos: Pos) -> GameSession {
+    let monsters = MONSTERS_TOML.replacen(
+        "attacks=[{type=\"bite\", damage=\"1d2\"}]",
+        &format!("attacks=[{{type=\"bite\", damage=\"1d2\"}}, {ranged}]"),
+        1,
+    );
+    let registry = aihack::data::ContentRegistry::from_toml_sources(
+        1,
+        ITEMS_TOML,
+        &monsters,
+        &[("main_1.toml", LEVEL_1_TOML), ("main_2.toml", LEVEL_2_TOML)],
+    )
+    .unwrap();
+    let mut session = GameSession::try_new_for_playing_with_registry(42, &registry).unwrap();
+    SessionBuilder::mutate(&mut session, |world| {
+        let level = aihack::core::LevelId::main(1);
+        world.saved().entities.set_alive(GOBLIN, false);
+        world
+            .saved()
+            .entities
+            .set_actor_location(JACKAL, level, pos);
+    });
+    session
+}
+
+fn stats(session: &GameSession, monster: EntityId) -> ActorStats {
+    *session.world().entities().actor_stats(monster).unwrap()
+}
+
+fn fired(events: &[GameEvent], attacker: EntityId) -> Option<Missile> {
+    events.iter().find_map(|event| match event {
+        GameEvent::MissileFired {
+            attacker: who,
+            missile,
+            ..
+        } if *who == attacker => Some(*missile),
+        _ => None,
+    })
+}
+
+#[test]
+fn lined_up_goblin_throws_a_dagger_that_lands_on_the_map() {
+    let (mut session, daggers) = goblin_session(LINED_UP, &[ItemKind::Dagger, ItemKind::Dagger]);
+
+    let events = session.submit(CommandIntent::Wait).events;
+
+    assert_eq!(
+        fired(&events, GOBLIN),
+        Some(Missile::Item(ItemKind::Dagger))
+    );
+    assert!(has_message(&events, "The goblin throws a dagger!"));
+    assert!(events.iter().any(|event| matches!(
+        event,
+        GameEvent::MissileFired { from, .. } if *from == LINED_UP
+    )));
+    let carried = session.world().entities().inventory_items(GOBLIN);
+    assert_eq!(carried.len(), 1);
+    let thrown = daggers
+        .iter()
+        .find(|dagger| !carried.contains(dagger))
+        .unwrap();
+    let Some(EntityLocation::OnMap { pos, .. }) = session.world().entities().item_location(*thrown)
+    else {
+        panic!("thrown dagger should land on the map");
+    };
+    assert_eq!(pos.x, LINED_UP.x);
+    assert!(pos.y <= LINED_UP.y && pos.y >= session.world().player_pos().y);
+    assert!(
+        log_lines(&session.observation(), session.world().content(), &[])
+            .iter()
+            .any(|line| line == "! incoming dagger")
+    );
+}
+
+#[test]
+fn goblin_out_of_line_approaches_instead_of_throwing() {
+    let (mut session, _) = goblin_session(Pos { x: 7, y: 9 }, &[ItemKind::Dagger]);
+
+    let events = session.submit(CommandIntent::Wait).events;
+
+    assert_eq!(fired(&events, GOBLIN), None);
+    assert_eq!(session.world().entities().inventory_items(GOBLIN).len(), 1);
+}
+
+#[test]
+fn arrows_are_only_shot_with_a_bow() {
+    let (mut session, _) = goblin_session(LINED_UP, &[ItemKind::Arrow]);
+    let events = session.submit(CommandIntent::Wait).events;
+    assert_eq!(fired(&events, GOBLIN), None);
+
+    let (mut session, _) = goblin_session(LINED_UP, &[ItemKind::Bow, ItemKind::Arrow]);
+    let events = session.submit(CommandIntent::Wait).events;
+    assert_eq!(fired(&events, GOBLIN), Some(Missile::Item(ItemKind::Arrow)));
+    assert!(has_message(&events, "The goblin shoots an arrow!"));
+}
+
+#[test]
+fn goblin_zaps_a_magic_missile_wand_and_uses_a_charge() {
+    let (mut session, items) = goblin_session(LINED_UP, &[ItemKind::WandMagicMissile]);
+    let wand = items[0];
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.set_item_charges(wand, Some(2))
+    });
+
+    let events = session.submit(CommandIntent::Wait).events;
+
+    assert_eq!(fired(&events, GOBLIN), Some(Missile::MagicMissile));
+    assert!(has_message(&events, "The goblin zaps a wand!"));
+    assert_eq!(session.world().entities().item_charges(wand), Some(1));
+}
+
+#[test]
+fn breathing_monster_rests_before_breathing_again() {
+    let mut session = jackal_session("{type=\"breath\", damage=\"1d1\"}", LINED_UP);
+
+    let mut breaths = 0;
+    for _ in 0..8 {
+        let events = session.submit(CommandIntent::Wait).events;
+        if fired(&events, JACKAL) == Some(Missile::Breath) {
+            breaths += 1;
+            assert!(stats(&session, JACKAL).ranged_cooldown >= 10);
+        }
+    }
+
+    assert_eq!(breaths, 1);
+}
+
+#[test]
+fn spitting_monster_eventually_spits_venom() {
+    let mut session = jackal_session("{type=\"spit\", damage=\"1d1\"}", LINED_UP);
+    let player = session.world().player_id();
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.actor_stats_mut(player).unwrap().hp = 999;
+    });
+
+    let spat = (0..40).any(|_| {
+        let events = session.submit(CommandIntent::Wait).events;
+        fired(&events, JACKAL) == Some(Missile::Venom)
+    });
+
+    assert!(spat);
+}
+
+#[test]
+fn dead_monster_drops_what_it_carried() {
+    let (mut session, daggers) = goblin_session(Pos { x: 6, y: 5 }, &[ItemKind::Dagger]);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.actor_stats_mut(GOBLIN).unwrap().hp = 1;
+    });
+
+    let killed = (0..20).any(|_| {
+        session.submit(CommandIntent::Move(Direction::East));
+        !session
+            .world()
+            .entities()
+            .get(GOBLIN)
+            .is_some_and(|goblin| goblin.is_alive_actor())
+    });
+
+    assert!(killed);
+    assert_eq!(
+        session.world().entities().item_location(daggers[0]),
+        Some(EntityLocation::on_main_level(Pos { x: 6, y: 5 }))
+    );
+}
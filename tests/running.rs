@@ -0,0 +1,252 @@
+use std::env;
+
+use aihack::{
+    core::{
+        policy::run_replay_to_turn, CommandIntent, Direction, GameEvent, GameSession, Pos,
+        ReplayLineV1, RunMode,
+    },
+    domain::{monster::MonsterKind, tile::TileKind},
+    testing::{session_with, SessionBuilder},
+    ui::tui::{key_to_candidate, TuiApp, UiCommandCandidate, UiRuntimeConfig},
+};
+
+fn quiet_session_at(pos: Pos) -> GameSession {
+    session_with(42, |world| {
+        world.saved().nutrition = 500;
+        world.set_player_pos(pos);
+    })
+    .0
+}
+
+fn run(direction: Direction, mode: RunMode) -> CommandIntent {
+    CommandIntent::Run { direction, mode }
+}
+
+fn count(events: &[GameEvent], pred: fn(&GameEvent) -> bool) -> usize {
+    events.iter().filter(|event| pred(event)).count()
+}
+
+/// 달리기를 걸고 런타임이 멈출 때까지 걸음을 이어 replay 줄로 남긴다.
+fn run_to_stop(
+    session: &mut GameSession,
+    direction: Direction,
+    mode: RunMode,
+) -> Vec<ReplayLineV1> {
+    let mut command = run(direction, mode);
+    let mut lines = Vec::new();
+    loop {
+        let turn_before = session.turn();
+        let outcome = session.submit(command);
+        lines.push(ReplayLineV1 {
+            turn_before,
+            command,
+            snapshot_hash_after: outcome.snapshot_hash.clone(),
+            outcome,
+        });
+        if !session
+            .observation()
+            .legal_actions
+            .contains(&CommandIntent::ContinueRun)
+        {
+            return lines;
+        }
+        command = CommandIntent::ContinueRun;
+    }
+}
+
+fn events(lines: &[ReplayLineV1]) -> Vec<GameEvent> {
+    lines
+        .iter()
+        .flat_map(|line| line.outcome.events.iter().cloned())
+        .collect()
+}
+
+/// main:1의 넓은 방 가운데를 벽으로 채우고 꺾인 통로 하나와 끝의 갈림길을 판다.
+fn corridor_session() -> GameSession {
+    let mut session = quiet_session_at(Pos { x: 15, y: 10 });
+    SessionBuilder::mutate(&mut session, |world| {
+        let map = world.current_map_mut();
+        for y in 9..=14 {
+            for x in 14..=30 {
+                map.set_tile(Pos { x, y }, TileKind::Wall).unwrap();
+            }
+        }
+        let corridor = (15..=20)
+            .map(|x| Pos { x, y: 10 })
+            .chain([Pos { x: 20, y: 11 }])
+            .chain((20..=27).map(|x| Pos { x, y: 12 }))
+            .chain([Pos { x: 25, y: 13 }]);
+        for pos in corridor {
+            map.set_tile(pos, TileKind::Corridor).unwrap();
+        }
+    });
+    session
+}
+
+#[test]
+fn rushing_crosses_the_room_one_turn_per_step_until_the_wall() {
+    let mut session = quiet_session_at(Pos { x: 20, y: 12 });
+
+    let lines = run_to_stop(&mut session, Direction::East, RunMode::Rush);
+
+    assert_eq!(session.world().player_pos(), Pos { x: 38, y: 12 });
+    // 걸음마다 따로 받아들인 턴이라 replay에 걸음 수만큼 줄이 남고, 모두 한 묶음 번호를 갖는다.
+    assert_eq!(lines.len(), 18);
+    let group = lines[0].outcome.run_group;
+    assert!(group.is_some());
+    for (step, line) in lines.iter().enumerate() {
+        assert!(line.outcome.accepted);
+        assert_eq!(line.outcome.run_group, group);
+        assert_eq!(line.outcome.turns_elapsed, 1);
+        assert_eq!(line.turn_before, lines[0].turn_before + step as u64);
+    }
+    assert_eq!(lines[0].command, run(Direction::East, RunMode::Rush));
+    assert!(lines[1..]
+        .iter()
+        .all(|line| line.command == CommandIntent::ContinueRun));
+    let events = events(&lines);
+    assert_eq!(
+        count(&events, |event| matches!(
+            event,
+            GameEvent::EntityMoved { .. }
+        )),
+        18
+    );
+
+    let mut replayed = quiet_session_at(Pos { x: 20, y: 12 });
+    let report = run_replay_to_turn(&mut replayed, session.turn(), &lines).unwrap();
+    assert_eq!(report.submitted_commands, 18);
+    assert_eq!(
+        replayed.snapshot().stable_hash(),
+        session.snapshot().stable_hash()
+    );
+}
+
+#[test]
+fn separate_runs_have_separate_groups_and_other_commands_end_a_run() {
+    let mut session = quiet_session_at(Pos { x: 20, y: 12 });
+
+    let first = session.submit(run(Direction::East, RunMode::Rush));
+    assert!(session
+        .observation()
+        .legal_actions
+        .contains(&CommandIntent::ContinueRun));
+    assert!(session.submit(CommandIntent::Search).accepted);
+    assert!(!session
+        .observation()
+        .legal_actions
+        .contains(&CommandIntent::ContinueRun));
+    assert!(!session.submit(CommandIntent::ContinueRun).accepted);
+
+    let second = session.submit(run(Direction::West, RunMode::Rush));
+    assert!(second.run_group.is_some());
+    assert_ne!(second.run_group, first.run_group);
+}
+
+#[test]
+fn an_active_run_survives_save_and_load_and_changes_the_snapshot_hash() {
+    let mut session = quiet_session_at(Pos { x: 20, y: 12 });
+    let idle_save = serde_json::to_string(&session.to_save_data()).unwrap();
+    assert!(!idle_save.contains("running"));
+
+    session.submit(run(Direction::East, RunMode::Rush));
+    let mut save = session.to_save_data();
+    assert!(save.world.running.is_some());
+    save.world.running = None;
+    let idle = GameSession::from_save_data(save).unwrap();
+    // 같은 칸·같은 턴이라도 달리는 중인지가 해시에 드러난다.
+    assert_ne!(
+        session.snapshot().stable_hash(),
+        idle.snapshot().stable_hash()
+    );
+
+    let mut restored = GameSession::from_save_data(session.to_save_data()).unwrap();
+    assert_eq!(
+        restored.snapshot().stable_hash(),
+        session.snapshot().stable_hash()
+    );
+    assert!(restored
+        .observation()
+        .legal_actions
+        .contains(&CommandIntent::ContinueRun));
+    let continued = session.submit(CommandIntent::ContinueRun);
+    let resumed = restored.submit(CommandIntent::ContinueRun);
+    assert!(resumed.accepted);
+    assert_eq!(resumed.run_group, continued.run_group);
+    assert_eq!(resumed.snapshot_hash, continued.snapshot_hash);
+}
+
+#[test]
+fn running_stops_beside_stairs_that_rushing_passes() {
+    let start = Pos { x: 25, y: 14 };
+    let mut running = quiet_session_at(start);
+    let mut rushing = quiet_session_at(start);
+
+    run_to_stop(&mut running, Direction::East, RunMode::Run);
+    run_to_stop(&mut rushing, Direction::East, RunMode::Rush);
+
+    // 내려가는 계단은 (34,15)에 있다.
+    assert_eq!(running.world().player_pos(), Pos { x: 33, y: 14 });
+    assert_eq!(rushing.world().player_pos(), Pos { x: 38, y: 14 });
+}
+
+#[test]
+fn running_follows_a_bending_corridor_and_stops_at_the_fork() {
+    for mode in [RunMode::Rush, RunMode::Run] {
+        let mut session = corridor_session();
+
+        let lines = run_to_stop(&mut session, Direction::East, mode);
+
+        assert!(lines.iter().all(|line| line.outcome.accepted));
+        assert_eq!(session.world().player_pos(), Pos { x: 25, y: 12 });
+        assert_eq!(lines.len(), 12);
+    }
+}
+
+#[test]
+fn running_stops_short_of_a_hostile_in_the_way() {
+    let mut session = quiet_session_at(Pos { x: 20, y: 12 });
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .spawn_monster(MonsterKind::Jackal, Pos { x: 30, y: 12 });
+    });
+
+    let lines = run_to_stop(&mut session, Direction::East, RunMode::Rush);
+
+    assert!(lines.iter().all(|line| line.outcome.accepted));
+    assert!(session.world().player_pos().x < 30);
+    assert!(!events(&lines).iter().any(|event| matches!(
+        event,
+        GameEvent::Message { text, .. } if text.starts_with("You stop")
+    )));
+}
+
+#[test]
+fn shift_direction_rushes_and_g_prefix_runs_in_the_tui() {
+    let session = quiet_session_at(Pos { x: 20, y: 12 });
+    let observation = session.observation();
+    assert_eq!(
+        key_to_candidate('L', &observation),
+        Some(UiCommandCandidate::Command(run(
+            Direction::East,
+            RunMode::Rush
+        )))
+    );
+    assert_eq!(
+        key_to_candidate('G', &observation),
+        Some(UiCommandCandidate::RunPrefix)
+    );
+
+    let mut app = TuiApp::new(session, UiRuntimeConfig::default());
+    let path = env::temp_dir();
+    for candidate in [
+        UiCommandCandidate::RunPrefix,
+        key_to_candidate('h', &observation).unwrap(),
+    ] {
+        app.handle_candidate(candidate, &path, &path).unwrap();
+    }
+    assert!(!app.pending_run());
+    assert_eq!(app.observation().player_pos, Pos { x: 1, y: 12 });
+}
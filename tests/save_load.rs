@@ -25,6 +25,24 @@ fn schema_roundtrip() {
     );
 }
 
+#[test]
+fn v1_saves_with_variant_name_kinds_still_load() {
+    let session = GameSession::new_for_playing(42);
+    let json = serde_json::to_string(&session.to_save_data()).unwrap();
+    let legacy = json
+        .replace("\"monster.jackal\"", "\"Jackal\"")
+        .replace("\"item.weapon.dagger\"", "\"Dagger\"")
+        .replace("\"item.potion.healing\"", "\"PotionHealing\"");
+    assert!(legacy.contains("\"Jackal\"") && !legacy.contains("monster.jackal"));
+
+    let restored = GameSession::from_save_data(serde_json::from_str(&legacy).unwrap()).unwrap();
+
+    assert_eq!(
+        session.snapshot().stable_hash(),
+        restored.snapshot().stable_hash()
+    );
+}
+
 #[test]
 fn rng_state_restores_continuation() {
     let mut rng = aihack::core::GameRng::new(42);
@@ -0,0 +1,366 @@
+use aihack::{
+    core::{CommandIntent, EntityId, GameEvent, GameSession, LevelId, Pos},
+    domain::{
+        entity::EntityLocation,
+        item::{try_item_data, BucStatus, ConsumableEffect, ItemClass, ItemKind},
+        tile::{TileKind, TrapKind},
+    },
+    testing::{has_message, session_with_item, SessionBuilder},
+};
+
+const DAGGER: EntityId = EntityId(5);
+const JACKAL: EntityId = EntityId(2);
+
+const SCROLLS: [ItemKind; 11] = [
+    ItemKind::ScrollReveal,
+    ItemKind::ScrollIdentify,
+    ItemKind::ScrollTeleportation,
+    ItemKind::ScrollEnchantWeapon,
+    ItemKind::ScrollEnchantArmor,
+    ItemKind::ScrollRemoveCurse,
+    ItemKind::ScrollFire,
+    ItemKind::ScrollLight,
+    ItemKind::ScrollGoldDetection,
+    ItemKind::ScrollCreateMonster,
+    ItemKind::ScrollScareMonster,
+];
+
+fn player_ac(session: &GameSession) -> i16 {
+    session
+        .world()
+        .entities()
+        .actor_stats(session.world().player_id())
+        .unwrap()
+        .ac
+}
+
+#[test]
+fn scroll_catalogue_declares_effects_in_content() {
+    for kind in SCROLLS {
+        let data = try_item_data(kind).unwrap();
+        assert_eq!(data.class, ItemClass::Scroll, "{kind:?}");
+        assert!(data.consumable_effect.is_some(), "{kind:?}");
+    }
+    let fire = try_item_data(ItemKind::ScrollFire).unwrap();
+    assert_eq!(
+        fire.consumable_effect,
+        Some(ConsumableEffect::Fire {
+            dice: 1,
+            sides: 4,
+            radius: 1,
+        })
+    );
+    assert_eq!(
+        try_item_data(ItemKind::ScrollTeleportation)
+            .unwrap()
+            .confused_effect,
+        Some(ConsumableEffect::LevelTeleport)
+    );
+}
+
+#[test]
+fn enchant_weapon_raises_wielded_weapon_and_is_observed() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollEnchantWeapon, BucStatus::Uncursed);
+    assert!(
+        session
+            .submit(CommandIntent::Wield { item: DAGGER })
+            .accepted
+    );
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    assert!(outcome.events.contains(&GameEvent::ItemEnchanted {
+        item: DAGGER,
+        enchantment: 1,
+    }));
+    assert!(session
+        .world()
+        .is_item_identified(ItemKind::ScrollEnchantWeapon));
+    let observed = session
+        .observation()
+        .inventory
+        .into_iter()
+        .find(|item| item.item == DAGGER)
+        .unwrap();
+    assert_eq!(observed.enchantment, 1);
+}
+
+#[test]
+fn enchant_weapon_without_weapon_only_twitches() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollEnchantWeapon, BucStatus::Uncursed);
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    assert!(outcome.accepted);
+    assert!(has_message(&outcome.events, "Your hands twitch."));
+    assert!(!session
+        .world()
+        .is_item_identified(ItemKind::ScrollEnchantWeapon));
+}
+
+#[test]
+fn blessed_enchant_armor_lowers_ac_and_blesses_worn_armor() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollEnchantArmor, BucStatus::Blessed);
+    let armor = SessionBuilder::mutate(&mut session, |world| {
+        world.give_item(ItemKind::ArmorLeather)
+    });
+    assert!(session.submit(CommandIntent::Wear { item: armor }).accepted);
+    let ac_before = player_ac(&session);
+
+    assert!(
+        session
+            .submit(CommandIntent::Read { item: scroll })
+            .accepted
+    );
+
+    let enchantment = session.world().entities().item_enchantment(armor);
+    assert!((1..=3).contains(&enchantment), "{enchantment}");
+    assert_eq!(player_ac(&session), ac_before - i16::from(enchantment));
+    assert_eq!(
+        session.world().entities().item_buc(armor),
+        Some(BucStatus::Blessed)
+    );
+}
+
+#[test]
+fn remove_curse_uncurses_wielded_weapon_and_confusion_scrambles() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollRemoveCurse, BucStatus::Uncursed);
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .set_item_buc(DAGGER, BucStatus::Cursed)
+    });
+    assert!(
+        session
+            .submit(CommandIntent::Wield { item: DAGGER })
+            .accepted
+    );
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    assert!(outcome.events.contains(&GameEvent::ItemBucChanged {
+        item: DAGGER,
+        buc: BucStatus::Uncursed,
+    }));
+
+    let (mut confused, scroll) =
+        session_with_item(42, ItemKind::ScrollRemoveCurse, BucStatus::Blessed);
+    SessionBuilder::mutate(&mut confused, |world| {
+        world.saved().conditions.confusion_turns = 10;
+    });
+    let outcome = confused.submit(CommandIntent::Read { item: scroll });
+    assert!(has_message(
+        &outcome.events,
+        "You feel like you need some help."
+    ));
+}
+
+#[test]
+fn teleportation_moves_to_a_safe_square_on_the_same_level() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollTeleportation, BucStatus::Uncursed);
+    let from = session.world().player_pos();
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    let to = session.world().player_pos();
+    assert!(outcome.events.contains(&GameEvent::EntityTeleported {
+        entity: session.world().player_id(),
+        from,
+        to,
+    }));
+    assert_eq!(session.world().current_level(), LevelId::main(1));
+    let tile = session.world().current_map().tile(to).unwrap();
+    assert!(tile.is_movement_passable());
+    assert!(!matches!(tile, TileKind::Trap(_) | TileKind::HiddenTrap(_)));
+}
+
+#[test]
+fn confused_teleportation_changes_level() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollTeleportation, BucStatus::Uncursed);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().conditions.confusion_turns = 10;
+    });
+
+    assert!(
+        session
+            .submit(CommandIntent::Read { item: scroll })
+            .accepted
+    );
+
+    assert_eq!(session.world().current_level(), LevelId::main(2));
+}
+
+#[test]
+fn fire_scroll_burns_player_and_adjacent_monsters() {
+    let mut session = GameSession::new_for_playing(42);
+    let scroll =
+        SessionBuilder::mutate(&mut session, |world| world.give_item(ItemKind::ScrollFire));
+    let player = session.world().player_id();
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    for entity in [JACKAL, player] {
+        assert!(
+            outcome.events.iter().any(|event| matches!(
+                event,
+                GameEvent::EntityDamaged { entity: damaged, .. } if *damaged == entity
+            )),
+            "{entity:?}"
+        );
+    }
+    assert!(!outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::EntityDamaged {
+            entity: EntityId(3),
+            ..
+        }
+    )));
+}
+
+#[test]
+fn cursed_light_darkens_and_uncursed_light_relights() {
+    let (mut session, scroll) = session_with_item(42, ItemKind::ScrollLight, BucStatus::Cursed);
+    let center = session.world().player_pos();
+
+    assert!(
+        session
+            .submit(CommandIntent::Read { item: scroll })
+            .accepted
+    );
+    assert!(!session.world().current_map().is_lit(center));
+    assert!(!session.world().current_map().is_lit(Pos {
+        x: center.x + 5,
+        y: center.y,
+    }));
+    assert!(session.world().current_map().is_lit(Pos {
+        x: center.x + 6,
+        y: center.y,
+    }));
+
+    let scroll =
+        SessionBuilder::mutate(&mut session, |world| world.give_item(ItemKind::ScrollLight));
+    assert!(
+        session
+            .submit(CommandIntent::Read { item: scroll })
+            .accepted
+    );
+    assert!(session.world().current_map().is_lit(center));
+}
+
+#[test]
+fn gold_detection_marks_gold_piles_on_the_map() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollGoldDetection, BucStatus::Uncursed);
+    let gold = Pos { x: 30, y: 3 };
+    assert!(!session
+        .observation()
+        .remembered_objects
+        .iter()
+        .any(|object| object.pos == gold));
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    assert!(has_message(
+        &outcome.events,
+        "You feel very greedy, and sense gold!"
+    ));
+    assert!(session
+        .observation()
+        .remembered_objects
+        .iter()
+        .any(|object| object.pos == gold && object.kind == ItemKind::GoldPiece));
+}
+
+#[test]
+fn gold_detection_without_gold_feels_materially_poor() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollGoldDetection, BucStatus::Uncursed);
+    SessionBuilder::mutate(&mut session, |world| {
+        let level = LevelId::main(1);
+        let pile = world.saved().entities.items_at(level, Pos { x: 30, y: 3 })[0];
+        world
+            .saved()
+            .entities
+            .set_item_location(pile, EntityLocation::Consumed);
+    });
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    assert!(has_message(&outcome.events, "You feel materially poor."));
+}
+
+#[test]
+fn confused_gold_detection_finds_hidden_traps() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollGoldDetection, BucStatus::Uncursed);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().conditions.confusion_turns = 10;
+    });
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    assert!(outcome.events.contains(&GameEvent::TileRevealed {
+        pos: Pos { x: 16, y: 5 },
+        tile: TileKind::Trap(TrapKind::Pit),
+    }));
+    assert!(!outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::TileRevealed {
+            tile: TileKind::Door(_),
+            ..
+        }
+    )));
+}
+
+#[test]
+fn create_monster_spawns_adjacent_hostile() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollCreateMonster, BucStatus::Uncursed);
+    let center = session.world().player_pos();
+
+    let outcome = session.submit(CommandIntent::Read { item: scroll });
+
+    let created = outcome
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::MonsterCreated { entity, pos, .. } => Some((*entity, *pos)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // 무리 짓는 jackal은 무리와 함께 나올 수 있다.
+    assert!(!created.is_empty());
+    for (monster, pos) in created {
+        assert_eq!(center.chebyshev_distance(pos), 1);
+        assert!(session
+            .world()
+            .current_level_hostile_monsters()
+            .contains(&monster));
+    }
+}
+
+#[test]
+fn blind_player_cannot_read_scrolls() {
+    let (mut session, scroll) =
+        session_with_item(42, ItemKind::ScrollIdentify, BucStatus::Uncursed);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().conditions.blindness_turns = 10;
+    });
+
+    assert!(
+        !session
+            .submit(CommandIntent::Read { item: scroll })
+            .accepted
+    );
+    assert!(!session
+        .observation()
+        .legal_actions
+        .contains(&CommandIntent::Read { item: scroll }));
+}
@@ -0,0 +1,183 @@
+use aihack::{
+    core::{CommandIntent, EntityId, GameEvent, GameRng, GameSession, Pos, SaveDataV1},
+    domain::{item::ItemKind, status::Encumbrance},
+    systems::speed::monster_move_amount,
+    testing::{session_with, SessionBuilder},
+};
+
+const JACKAL: EntityId = EntityId(2);
+const GOBLIN: EntityId = EntityId(3);
+
+fn lone_session() -> GameSession {
+    session_with(42, |_| {}).0
+}
+
+/// `actions`번 기다리는 동안 지나간 게임 턴 수다.
+fn turns_after_waiting(session: &mut GameSession, actions: usize) -> u64 {
+    let start = session.turn();
+    for _ in 0..actions {
+        assert!(session.submit(CommandIntent::Wait).accepted);
+    }
+    session.turn() - start
+}
+
+fn jackal_moves(events: &[GameEvent]) -> usize {
+    events
+        .iter()
+        .filter(|event| matches!(event, GameEvent::EntityMoved { entity, .. } if *entity == JACKAL))
+        .count()
+}
+
+#[test]
+fn a_normal_speed_player_acts_once_per_turn() {
+    let mut session = lone_session();
+
+    for turn in 1..=5 {
+        let outcome = session.submit(CommandIntent::Wait);
+        assert!(outcome.turn_advanced);
+        assert_eq!(outcome.player_actions, 1);
+        assert_eq!(session.turn(), turn);
+    }
+}
+
+#[test]
+fn intrinsic_speed_gives_extra_actions_in_some_turns() {
+    let mut session = lone_session();
+    SessionBuilder::mutate(&mut session, |world| world.saved().conditions.fast = true);
+
+    let outcomes = (0..60)
+        .map(|_| session.submit(CommandIntent::Wait))
+        .collect::<Vec<_>>();
+
+    assert!(session.turn() < 60);
+    assert!(outcomes
+        .iter()
+        .any(|outcome| !outcome.turn_advanced && outcome.player_actions == 1));
+    assert!(outcomes
+        .iter()
+        .any(|outcome| outcome.turn_advanced && outcome.player_actions == 2));
+    assert!(outcomes.iter().all(|outcome| outcome.player_actions <= 2));
+    assert!(session.observation().player.fast);
+}
+
+#[test]
+fn extrinsic_speed_is_faster_than_intrinsic_speed() {
+    let mut fast = lone_session();
+    SessionBuilder::mutate(&mut fast, |world| world.saved().conditions.fast = true);
+    let mut very_fast = lone_session();
+    SessionBuilder::mutate(&mut very_fast, |world| {
+        world.saved().conditions.very_fast_turns = 500;
+    });
+
+    let fast_turns = turns_after_waiting(&mut fast, 90);
+    let very_fast_turns = turns_after_waiting(&mut very_fast, 90);
+
+    assert!(very_fast_turns < fast_turns);
+    assert!(fast_turns < 90);
+}
+
+#[test]
+fn a_burdened_player_loses_some_turns() {
+    let mut session = lone_session();
+    SessionBuilder::mutate(&mut session, |world| {
+        world.give_item(ItemKind::Dagger);
+    });
+    assert_eq!(session.world().encumbrance(), Encumbrance::Burdened);
+    assert_eq!(
+        session.observation().player.encumbrance,
+        Encumbrance::Burdened
+    );
+
+    assert!(turns_after_waiting(&mut session, 12) > 12);
+}
+
+#[test]
+fn encumbrance_steps_up_between_the_free_and_hard_limits() {
+    let mut session = lone_session();
+    let levels = (0..3)
+        .map(|_| {
+            SessionBuilder::mutate(&mut session, |world| {
+                world.give_item(ItemKind::Rock);
+            });
+            session.world().encumbrance()
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        levels,
+        [
+            Encumbrance::Burdened,
+            Encumbrance::Strained,
+            Encumbrance::Overloaded
+        ]
+    );
+}
+
+#[test]
+fn a_fast_monster_acts_twice_per_turn_and_a_slow_one_sometimes_skips() {
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.set_alive(GOBLIN, false);
+        world
+            .saved()
+            .entities
+            .actor_stats_mut(JACKAL)
+            .unwrap()
+            .speed = 24;
+        world.set_player_pos(Pos { x: 5, y: 12 });
+    });
+
+    let first = session.submit(CommandIntent::Wait);
+    assert_eq!(jackal_moves(&first.events), 1);
+    let second = session.submit(CommandIntent::Wait);
+    assert_eq!(jackal_moves(&second.events), 2);
+    assert_eq!(session.turn(), 2);
+
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.set_alive(GOBLIN, false);
+        world
+            .saved()
+            .entities
+            .actor_stats_mut(JACKAL)
+            .unwrap()
+            .speed = 6;
+        world.set_player_pos(Pos { x: 30, y: 16 });
+    });
+    let moves = (0..20)
+        .map(|_| jackal_moves(&session.submit(CommandIntent::Wait).events))
+        .collect::<Vec<_>>();
+    assert!(moves.contains(&0));
+    assert!(moves.iter().all(|count| *count <= 1));
+    assert!(moves.iter().sum::<usize>() > 0);
+}
+
+#[test]
+fn monster_movement_is_rounded_randomly_to_whole_actions() {
+    let mut rng = GameRng::new(7);
+    let amounts = (0..600)
+        .map(|_| monster_move_amount(&mut rng, 18))
+        .collect::<Vec<_>>();
+
+    assert!(amounts.iter().all(|amount| [12, 24].contains(amount)));
+    let average = amounts.iter().map(|amount| i32::from(*amount)).sum::<i32>() / 600;
+    assert!((16..=20).contains(&average));
+    assert_eq!(monster_move_amount(&mut rng, 12), 12);
+    assert_eq!(monster_move_amount(&mut rng, 0), 0);
+}
+
+#[test]
+fn movement_points_survive_save_and_load() {
+    let mut session = lone_session();
+    SessionBuilder::mutate(&mut session, |world| world.saved().conditions.fast = true);
+    while session.submit(CommandIntent::Wait).turn_advanced {}
+
+    let json = serde_json::to_string(&session.to_save_data()).unwrap();
+    let decoded: SaveDataV1 = serde_json::from_str(&json).unwrap();
+    let mut restored = GameSession::from_save_data(decoded).unwrap();
+
+    assert_eq!(restored.snapshot(), session.snapshot());
+    let outcome = restored.submit(CommandIntent::Wait);
+    assert!(outcome.turn_advanced);
+    assert_eq!(outcome.player_actions, 2);
+}
@@ -0,0 +1,176 @@
+use aihack::{
+    core::{CommandIntent, Direction, EntityId, GameEvent, GameSession, Pos, RunState},
+    domain::{
+        combat::DeathCause, entity::EntityLocation, item::ItemKind, level::PHASE5_LEVEL2_ID,
+        tile::TileKind,
+    },
+    testing::{has_message, session_with, SessionBuilder},
+};
+
+const DAGGER: EntityId = EntityId(5);
+const FOOD_RATION: EntityId = EntityId(6);
+const ROCK: EntityId = EntityId(9);
+const EAST: Pos = Pos { x: 6, y: 5 };
+
+fn session_on(tiles: &[(Pos, TileKind)], confused: bool) -> GameSession {
+    session_with(42, |world| {
+        for (pos, tile) in tiles {
+            world.current_map_mut().set_tile(*pos, *tile).unwrap();
+        }
+        if confused {
+            world.saved().conditions.confusion_turns = 20;
+        }
+    })
+    .0
+}
+
+fn landing(events: &[GameEvent]) -> Pos {
+    events
+        .iter()
+        .find_map(|event| match event {
+            GameEvent::ItemThrown { to, .. } => Some(*to),
+            _ => None,
+        })
+        .unwrap()
+}
+
+#[test]
+fn terrain_passability_and_line_of_sight_follow_nethack() {
+    assert!(TileKind::Corridor.is_movement_passable());
+    assert!(TileKind::Corridor.is_los_transparent());
+    assert!(!TileKind::Tree.is_movement_passable());
+    assert!(!TileKind::Tree.is_los_transparent());
+    assert!(!TileKind::IronBars.is_movement_passable());
+    assert!(TileKind::IronBars.is_los_transparent());
+    for liquid in [TileKind::Pool, TileKind::Moat, TileKind::Lava] {
+        assert!(liquid.is_liquid());
+        assert!(!liquid.is_movement_passable());
+        assert!(liquid.is_los_transparent());
+    }
+}
+
+#[test]
+fn second_level_declares_terrain() {
+    let session = GameSession::new_for_playing(42);
+    let tiles = session.world().map(PHASE5_LEVEL2_ID).tiles();
+
+    for terrain in [
+        TileKind::Corridor,
+        TileKind::Pool,
+        TileKind::Moat,
+        TileKind::Lava,
+        TileKind::Tree,
+        TileKind::IronBars,
+    ] {
+        assert!(tiles.contains(&terrain), "{terrain:?}");
+    }
+}
+
+#[test]
+fn a_clear_headed_player_avoids_stepping_into_water() {
+    let mut session = session_on(&[(EAST, TileKind::Pool)], false);
+
+    let outcome = session.submit(CommandIntent::Move(Direction::East));
+
+    assert!(!outcome.accepted);
+    assert_eq!(session.world().player_pos(), Pos { x: 5, y: 5 });
+    assert!(!session.observation().action_space.commands.contains(
+        &aihack::core::ActionIntent::Command(CommandIntent::Move(Direction::East))
+    ));
+}
+
+#[test]
+fn a_confused_player_crawls_out_of_water_with_diluted_potions() {
+    let mut session = session_on(&[(EAST, TileKind::Pool)], true);
+    let potion = SessionBuilder::mutate(&mut session, |world| {
+        world.give_item(ItemKind::PotionHealing)
+    });
+
+    let outcome = session.submit(CommandIntent::Move(Direction::East));
+
+    assert!(has_message(&outcome.events, "You fall into the water!"));
+    assert!(has_message(&outcome.events, "Pheew!  That was close."));
+    assert!(has_message(&outcome.events, "Your potion dilutes."));
+    assert!(session.world().entities().item_diluted(potion));
+    assert_eq!(session.run_state(), RunState::Playing);
+    assert_ne!(session.world().player_pos(), EAST);
+}
+
+#[test]
+fn water_with_no_dry_land_nearby_drowns_the_player() {
+    let mut tiles = Vec::new();
+    for y in 4..=6 {
+        for x in 4..=7 {
+            tiles.push((Pos { x, y }, TileKind::Moat));
+        }
+    }
+    let mut session = session_on(&tiles, true);
+
+    let outcome = session.submit(CommandIntent::Move(Direction::East));
+
+    assert!(has_message(&outcome.events, "You drown."));
+    match session.run_state() {
+        RunState::GameOver { cause, .. } => assert_eq!(
+            cause,
+            DeathCause::Drowned {
+                tile: TileKind::Moat
+            }
+        ),
+        other => panic!("expected drowning, got {other:?}"),
+    }
+}
+
+#[test]
+fn lava_burns_the_player_and_flammable_gear() {
+    let mut session = session_on(&[(EAST, TileKind::Lava)], true);
+
+    let outcome = session.submit(CommandIntent::Move(Direction::East));
+
+    assert!(has_message(&outcome.events, "You burn to a crisp..."));
+    assert_eq!(
+        session.world().entities().item_location(FOOD_RATION),
+        Some(EntityLocation::Consumed)
+    );
+    assert!(matches!(
+        session.run_state(),
+        RunState::GameOver {
+            cause: DeathCause::Lava,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn iron_bars_stop_daggers_but_let_rocks_through() {
+    let bars = Pos { x: 7, y: 5 };
+
+    let mut session = session_on(&[(bars, TileKind::IronBars)], false);
+    let outcome = session.submit(CommandIntent::Throw {
+        item: DAGGER,
+        direction: Direction::East,
+    });
+    assert_eq!(landing(&outcome.events), EAST);
+
+    let mut session = session_on(&[(bars, TileKind::IronBars)], false);
+    let outcome = session.submit(CommandIntent::Throw {
+        item: ROCK,
+        direction: Direction::East,
+    });
+    assert!(landing(&outcome.events).x > bars.x);
+}
+
+#[test]
+fn thrown_items_sink_into_water_and_stop_at_trees() {
+    let mut session = session_on(
+        &[(EAST, TileKind::Pool), (Pos { x: 7, y: 5 }, TileKind::Tree)],
+        false,
+    );
+
+    let outcome = session.submit(CommandIntent::Throw {
+        item: DAGGER,
+        direction: Direction::East,
+    });
+
+    assert_eq!(landing(&outcome.events), EAST);
+    assert!(has_message(&outcome.events, "It sinks into the water."));
+}
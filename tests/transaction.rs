@@ -48,13 +48,13 @@ fn invariant_failure_discards_rng_draws_from_the_working_copy() {
 }
 
 #[test]
-fn accepted_turns_leave_a_six_check_valid_invariant_report() {
+fn accepted_turns_leave_an_eight_check_valid_invariant_report() {
     let mut session = GameSession::new_for_playing(42);
 
     let outcome = session.submit(CommandIntent::Wait);
     let report = session.world().validate_invariants();
 
     assert!(outcome.accepted);
-    assert_eq!(report.checked, 6);
+    assert_eq!(report.checked, 8);
     assert!(report.is_valid());
 }
@@ -0,0 +1,135 @@
+use aihack::{
+    core::{CommandIntent, GameEvent, GameSession, Pos},
+    domain::{
+        monster::MonsterKind,
+        player::{InterruptReason, Occupation},
+    },
+    testing::{session_with, SessionBuilder},
+    ui::tui::{compute_layout, map_mouse_event, UiCommandCandidate, UiInputEvent, Viewport},
+};
+
+fn quiet_session(seed: u64) -> GameSession {
+    session_with(seed, |world| {
+        world.saved().nutrition = 500;
+        world.set_player_pos(Pos { x: 5, y: 10 });
+    })
+    .0
+}
+
+/// 지금 보이는 칸 가운데 길이 가장 긴 여행 목적지와 그 길이다. 여행은 물건 위에서 멈추므로
+/// 물건이 놓인 칸을 지나는 길은 고르지 않는다.
+fn farthest_target(session: &GameSession) -> (Pos, Vec<Pos>) {
+    let observation = session.observation();
+    let occupied = observation
+        .remembered_objects
+        .iter()
+        .map(|object| object.pos)
+        .collect::<Vec<_>>();
+    observation
+        .visible_tiles
+        .iter()
+        .filter_map(|tile| {
+            observation
+                .travel_route(tile.pos)
+                .map(|route| (tile.pos, route))
+        })
+        .filter(|(_, route)| !route.iter().any(|pos| occupied.contains(pos)))
+        .max_by_key(|(pos, route)| (route.len(), pos.y, pos.x))
+        .unwrap()
+}
+
+#[test]
+fn travel_walks_the_previewed_route_in_one_command() {
+    let mut session = quiet_session(42);
+    let (target, route) = farthest_target(&session);
+    assert!(route.len() > 2);
+
+    let outcome = session.submit(CommandIntent::Travel { target });
+
+    assert!(outcome.accepted);
+    assert_eq!(session.world().player_pos(), target);
+    assert_eq!(outcome.turns_elapsed as usize, route.len());
+    let walked = outcome
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::EntityMoved { to, .. } => Some(*to),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(walked, route);
+}
+
+#[test]
+fn travel_rejects_unknown_or_current_targets() {
+    let mut session = quiet_session(42);
+    let here = session.world().player_pos();
+
+    assert!(
+        !session
+            .submit(CommandIntent::Travel { target: here })
+            .accepted
+    );
+    // 아직 본 적 없는 먼 칸으로는 길을 모른다.
+    let unknown = Pos { x: 70, y: 18 };
+    assert_eq!(session.observation().travel_route(unknown), None);
+    let outcome = session.submit(CommandIntent::Travel { target: unknown });
+    assert!(!outcome.accepted);
+    assert_eq!(session.turn(), 0);
+}
+
+#[test]
+fn travel_stops_short_of_a_hostile_without_a_stop_message() {
+    let mut session = quiet_session(42);
+    let (target, route) = farthest_target(&session);
+    SessionBuilder::mutate(&mut session, |world| {
+        world
+            .saved()
+            .entities
+            .spawn_monster(MonsterKind::Jackal, target)
+    });
+
+    let outcome = session.submit(CommandIntent::Travel { target });
+
+    assert!(outcome.accepted);
+    assert!((outcome.turns_elapsed as usize) < route.len());
+    assert_ne!(session.world().player_pos(), target);
+    // 적이 길을 막으면 조용히 서고, 곁에 붙으면 방해로 멈춘다. 어느 쪽이든 걷기처럼 말없이 멈춘다.
+    for event in &outcome.events {
+        if let GameEvent::OccupationInterrupted { occupation, reason } = event {
+            assert_eq!(*occupation, Occupation::Traveling { target });
+            assert!(matches!(
+                reason,
+                InterruptReason::MonsterAppeared { .. } | InterruptReason::Damaged
+            ));
+        }
+    }
+    assert!(!outcome.events.iter().any(|event| matches!(
+        event,
+        GameEvent::Message { text, .. } if text.starts_with("You stop")
+    )));
+}
+
+#[test]
+fn clicking_a_distant_known_tile_travels_there() {
+    let session = quiet_session(42);
+    let (target, _) = farthest_target(&session);
+    let observation = session.observation();
+    let layout = compute_layout(100, 32);
+    let viewport = Viewport::from_rect(Pos { x: 0, y: 0 }, observation.player_pos, layout.map);
+    let (column, row) = viewport.world_to_terminal(target, layout.map).unwrap();
+
+    let candidate = map_mouse_event(
+        UiInputEvent::MouseClick { column, row },
+        layout,
+        viewport,
+        &observation,
+    );
+
+    assert_eq!(
+        candidate,
+        Some(UiCommandCandidate::Command(CommandIntent::Travel {
+            target
+        }))
+    );
+}
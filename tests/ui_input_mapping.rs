@@ -2,7 +2,7 @@ use aihack::{
     core::{CommandIntent, Direction, GameSession, Pos},
     ui::tui::{
         compute_layout, key_to_candidate, keyboard_baseline, map_mouse_event, UiCommandCandidate,
-        UiInputEvent, UiPanel, Viewport,
+        UiInputEvent, UiPanel, Viewport, CTRL_D, CTRL_L,
     },
 };
 
@@ -28,10 +28,13 @@ fn keyboard_baseline_maps_commands() {
     )));
     assert!(baseline.contains(&('s', UiInputEvent::Key(CommandIntent::Search))));
     assert!(baseline.contains(&('.', UiInputEvent::Key(CommandIntent::Wait))));
-    assert!(baseline.contains(&('K', UiInputEvent::Key(CommandIntent::Kick(Direction::East)))));
+    assert!(baseline.contains(&(
+        CTRL_D,
+        UiInputEvent::Key(CommandIntent::Kick(Direction::East))
+    )));
     assert!(baseline.contains(&('p', UiInputEvent::Key(CommandIntent::Pray))));
     assert!(baseline.contains(&('S', UiInputEvent::SaveRequest)));
-    assert!(baseline.contains(&('L', UiInputEvent::LoadRequest)));
+    assert!(baseline.contains(&(CTRL_L, UiInputEvent::LoadRequest)));
 
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
@@ -52,6 +55,10 @@ fn keyboard_baseline_maps_commands() {
         Some(UiCommandCandidate::Command(CommandIntent::Read { .. }))
     ));
     assert_eq!(key_to_candidate('o', &observation), None);
+    assert_eq!(
+        key_to_candidate('2', &observation),
+        Some(UiCommandCandidate::Count(2))
+    );
 }
 
 #[test]
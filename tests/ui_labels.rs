@@ -6,7 +6,7 @@ use aihack::ui::tui::labels::{collect_auto_labels, filter_expired_labels, LabelK
 fn collect_auto_labels_returns_labels() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
-    let labels = collect_auto_labels(&observation, 0);
+    let labels = collect_auto_labels(&observation, session.world().content(), 0);
 
     // 플레이어 시작 위치 (5,5)에서 jackal (6,5)은 인접하므로 HostileAdjacent 라벨이 생성되어야 한다.
     assert!(
@@ -20,7 +20,7 @@ fn collect_auto_labels_returns_labels() {
 fn collect_auto_labels_includes_hostile_adjacent() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
-    let labels = collect_auto_labels(&observation, 0);
+    let labels = collect_auto_labels(&observation, session.world().content(), 0);
 
     let has_hostile = labels
         .iter()
@@ -36,7 +36,7 @@ fn collect_auto_labels_includes_hostile_adjacent() {
 fn collect_auto_labels_includes_stairs() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
-    let labels = collect_auto_labels(&observation, 0);
+    let labels = collect_auto_labels(&observation, session.world().content(), 0);
 
     let has_stairs = labels.iter().any(|l| matches!(l.kind, LabelKind::Stairs));
     // 계단이 (34,15)에 있고 플레이어가 (5,5)에 있으므로 시야에 들어오지 않을 수 있다.
@@ -49,7 +49,7 @@ fn collect_auto_labels_includes_stairs() {
 fn collect_auto_labels_limits_to_three() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
-    let labels = collect_auto_labels(&observation, 0);
+    let labels = collect_auto_labels(&observation, session.world().content(), 0);
 
     assert!(
         labels.len() <= 3,
@@ -63,7 +63,7 @@ fn collect_auto_labels_limits_to_three() {
 fn collect_auto_labels_are_sorted_by_priority() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
-    let labels = collect_auto_labels(&observation, 0);
+    let labels = collect_auto_labels(&observation, session.world().content(), 0);
 
     if labels.len() >= 2 {
         for i in 0..labels.len() - 1 {
@@ -80,7 +80,7 @@ fn collect_auto_labels_are_sorted_by_priority() {
 fn filter_expired_labels_removes_old_labels() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
-    let mut labels = collect_auto_labels(&observation, 0);
+    let mut labels = collect_auto_labels(&observation, session.world().content(), 0);
 
     assert!(!labels.is_empty());
 
@@ -97,7 +97,7 @@ fn filter_expired_labels_removes_old_labels() {
 fn filter_expired_labels_keeps_fresh_labels() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
-    let mut labels = collect_auto_labels(&observation, 0);
+    let mut labels = collect_auto_labels(&observation, session.world().content(), 0);
 
     let initial_count = labels.len();
     assert!(!labels.is_empty());
@@ -43,7 +43,11 @@ fn priority_message_and_command_hint_render_have_accessible_text() {
         world.saved().entities.actor_stats_mut(player).unwrap().hp = 3;
     });
     let observation = session.observation();
-    let log_lines = render_panels::log_lines(&observation, &["Narrative(idle)".to_string()]);
+    let log_lines = render_panels::log_lines(
+        &observation,
+        session.world().content(),
+        &["Narrative(idle)".to_string()],
+    );
     let command_lines = render_panels::command_lines(&observation, UiPanel::Inspect);
     let status_lines = render_panels::status_lines(&observation);
     assert!(log_lines.iter().any(|line| line.contains("hp critical")));
@@ -1,7 +1,9 @@
 use std::{env, fs};
 
 use aihack::{
-    core::GameSession,
+    core::{CommandIntent, Direction, GameSession, Pos, RunMode},
+    llm::soft_adjudication::fallback_soft_adjudication,
+    testing::SessionBuilder,
     ui::tui::{compute_layout, runtime_smoke, TuiApp, UiCommandCandidate, UiRuntimeConfig},
 };
 
@@ -99,6 +101,7 @@ fn inspect_panel_prefers_hovered_read_only_lines() {
     let observation = app.observation();
     let lines = aihack::ui::tui::render_panels::inspect_lines(
         &observation,
+        app.content(),
         app.hovered_pos(),
         app.focused_panel(),
         &app.decision_lines(),
@@ -106,3 +109,92 @@ fn inspect_panel_prefers_hovered_read_only_lines() {
     assert_eq!(observation.turn, before_turn);
     assert!(lines.iter().any(|line| line.contains("read-only inspect")));
 }
+
+#[test]
+fn count_prefix_and_repeat_last_submit_whole_counted_commands() {
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters()
+    });
+    let mut app = TuiApp::new(session, UiRuntimeConfig::default());
+    let path = env::temp_dir();
+
+    for candidate in [
+        UiCommandCandidate::Count(1),
+        UiCommandCandidate::Count(2),
+        UiCommandCandidate::Command(CommandIntent::Search),
+    ] {
+        app.handle_candidate(candidate, &path, &path).unwrap();
+    }
+    assert_eq!(app.pending_count(), None);
+    assert_eq!(app.revision().turn, 12);
+
+    app.handle_candidate(UiCommandCandidate::RepeatLast, &path, &path)
+        .unwrap();
+    assert_eq!(app.revision().turn, 24);
+}
+
+#[test]
+fn capital_n_dismisses_an_llm_result_and_otherwise_rushes_southeast() {
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters();
+        world.set_player_pos(Pos { x: 20, y: 12 });
+    });
+    let mut app = TuiApp::new(session, UiRuntimeConfig::default());
+    assert_eq!(
+        app.candidate_for_key('N'),
+        Some(UiCommandCandidate::Command(CommandIntent::Run {
+            direction: Direction::SouthEast,
+            mode: RunMode::Rush,
+        }))
+    );
+
+    app.set_soft_adjudication(fallback_soft_adjudication());
+
+    assert_eq!(
+        app.candidate_for_key('N'),
+        Some(UiCommandCandidate::DismissLlmResult)
+    );
+}
+
+#[test]
+fn number_pad_walks_with_digits_and_counts_after_n() {
+    let mut session = GameSession::new_for_playing(42);
+    SessionBuilder::mutate(&mut session, |world| {
+        world.saved().entities.clear_monsters()
+    });
+    let default_keys = TuiApp::new(session.clone(), UiRuntimeConfig::default());
+    assert_eq!(
+        default_keys.candidate_for_key('4'),
+        Some(UiCommandCandidate::Count(4))
+    );
+    assert_eq!(
+        default_keys.candidate_for_key('n'),
+        Some(UiCommandCandidate::Command(CommandIntent::Move(
+            Direction::SouthEast
+        )))
+    );
+
+    let mut app = TuiApp::new(
+        session,
+        UiRuntimeConfig {
+            number_pad: true,
+            ..UiRuntimeConfig::default()
+        },
+    );
+    assert_eq!(
+        app.candidate_for_key('6'),
+        Some(UiCommandCandidate::Command(CommandIntent::Move(
+            Direction::East
+        )))
+    );
+    assert_eq!(app.candidate_for_key('5'), None);
+    let path = env::temp_dir();
+    for key in ['n', '1', '2', 's'] {
+        let candidate = app.candidate_for_key(key).unwrap();
+        app.handle_candidate(candidate, &path, &path).unwrap();
+    }
+    assert_eq!(app.pending_count(), None);
+    assert_eq!(app.revision().turn, 12);
+}
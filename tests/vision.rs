@@ -73,7 +73,7 @@ fn observation_contains_visible_tiles() {
     let session = GameSession::new_for_playing(42);
     let observation = session.observation();
 
-    assert_eq!(observation.schema_version, 1);
+    assert_eq!(observation.schema_version, 2);
     assert_eq!(observation.seed, 42);
     assert_eq!(observation.turn, 0);
     assert_eq!(observation.player_pos, Pos { x: 5, y: 5 });
@@ -1,6 +1,6 @@
 use aihack::{
     core::{invariant::WorldInvariantError, GameSession, GameWorld, LevelId},
-    domain::{entity::EntityLocation, inventory::Inventory},
+    domain::{entity::EntityLocation, inventory::Inventory, tile::TileKind},
 };
 
 fn saved_world() -> aihack::core::save::SavedWorldV1 {
@@ -8,10 +8,10 @@ fn saved_world() -> aihack::core::save::SavedWorldV1 {
 }
 
 #[test]
-fn fixture_checks_all_six_world_invariants() {
+fn fixture_checks_all_eight_world_invariants() {
     let report = GameWorld::fixture_phase5().validate_invariants();
 
-    assert_eq!(report.checked, 6);
+    assert_eq!(report.checked, 8);
     assert!(report.errors.is_empty());
 }
 
@@ -90,4 +90,37 @@ fn invariant_report_classifies_each_persisted_world_violation() {
             .as_slice(),
         [WorldInvariantError::InventoryOwnerMismatch { .. }]
     ));
+
+    let mut inside_wall = saved_world();
+    assert!(inside_wall.entities.set_actor_location(
+        inside_wall.player_id,
+        inside_wall.current_level,
+        aihack::core::Pos { x: 12, y: 4 },
+    ));
+    assert!(matches!(
+        GameWorld::from_saved_world(inside_wall)
+            .validate_invariants()
+            .errors
+            .as_slice(),
+        [WorldInvariantError::PlayerInSolidTerrain {
+            tile: TileKind::Wall,
+            ..
+        }]
+    ));
+
+    let mut open_border = saved_world();
+    open_border
+        .levels
+        .get_mut(LevelId::main(2))
+        .unwrap()
+        .map
+        .set_tile(aihack::core::Pos { x: 0, y: 3 }, TileKind::Corridor)
+        .unwrap();
+    assert!(matches!(
+        GameWorld::from_saved_world(open_border)
+            .validate_invariants()
+            .errors
+            .as_slice(),
+        [WorldInvariantError::MapBorderOpen { level, .. }] if *level == LevelId::main(2)
+    ));
 }